@@ -22,6 +22,7 @@ use common_exception::Result;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_period_separated_list;
 use crate::ast::Identifier;
+use crate::ast::OrderByExpr;
 use crate::ast::Query;
 use crate::parser::token::Token;
 
@@ -126,7 +127,10 @@ pub enum Expr<'a> {
     /// A literal value, such as string, number, date or NULL
     Literal { span: &'a [Token<'a>], lit: Literal },
     /// `COUNT(*)` expression
-    CountAll { span: &'a [Token<'a>] },
+    CountAll {
+        span: &'a [Token<'a>],
+        window: Option<WindowSpec<'a>>,
+    },
     /// `(foo, bar)`
     Tuple {
         span: &'a [Token<'a>],
@@ -140,6 +144,8 @@ pub enum Expr<'a> {
         name: Identifier<'a>,
         args: Vec<Expr<'a>>,
         params: Vec<Literal>,
+        /// The window specification of window function, like `rank() OVER (ORDER BY a)`
+        window: Option<WindowSpec<'a>>,
     },
     /// `CASE ... WHEN ... ELSE ...` expression
     Case {
@@ -245,6 +251,37 @@ pub enum MapAccessor<'a> {
     Colon { key: Identifier<'a> },
 }
 
+/// `OVER ([PARTITION BY <expr>, ...] [ORDER BY <expr>, ...] [<window frame>])`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowSpec<'a> {
+    pub partition_by: Vec<Expr<'a>>,
+    pub order_by: Vec<OrderByExpr<'a>>,
+    pub window_frame: Option<WindowFrame>,
+}
+
+/// `{ROWS | RANGE} [BETWEEN <start bound> AND <end bound> | <start bound>]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowFrame {
+    pub units: WindowFrameUnits,
+    pub start_bound: WindowFrameBound,
+    pub end_bound: WindowFrameBound,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WindowFrameBound {
+    CurrentRow,
+    /// `<N> PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<u64>),
+    /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`
+    Following(Option<u64>),
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeName {
     Boolean,
@@ -258,6 +295,10 @@ pub enum TypeName {
     Int64,
     Float32,
     Float64,
+    Decimal {
+        precision: Option<u64>,
+        scale: Option<u64>,
+    },
     Date,
     Timestamp {
         precision: Option<u64>,
@@ -270,6 +311,10 @@ pub enum TypeName {
         fields_name: Option<Vec<String>>,
         fields_type: Vec<TypeName>,
     },
+    Map {
+        key_type: Box<TypeName>,
+        value_type: Box<TypeName>,
+    },
     Object,
     Variant,
     Nullable(Box<TypeName>),
@@ -366,7 +411,7 @@ impl<'a> Expr<'a> {
             | Expr::Substring { span, .. }
             | Expr::Trim { span, .. }
             | Expr::Literal { span, .. }
-            | Expr::CountAll { span }
+            | Expr::CountAll { span, .. }
             | Expr::Tuple { span, .. }
             | Expr::FunctionCall { span, .. }
             | Expr::Case { span, .. }
@@ -529,6 +574,16 @@ impl Display for TypeName {
             TypeName::Float64 => {
                 write!(f, "Float64")?;
             }
+            TypeName::Decimal { precision, scale } => {
+                write!(f, "DECIMAL")?;
+                if let Some(precision) = precision {
+                    write!(f, "({}", *precision)?;
+                    if let Some(scale) = scale {
+                        write!(f, ", {}", *scale)?;
+                    }
+                    write!(f, ")")?;
+                }
+            }
             TypeName::Date => {
                 write!(f, "DATE")?;
             }
@@ -575,6 +630,12 @@ impl Display for TypeName {
                 }
                 write!(f, ")")?;
             }
+            TypeName::Map {
+                key_type,
+                value_type,
+            } => {
+                write!(f, "MAP({}, {})", key_type, value_type)?;
+            }
             TypeName::Object => {
                 write!(f, "OBJECT")?;
             }
@@ -599,6 +660,54 @@ impl Display for TrimWhere {
     }
 }
 
+impl<'a> Display for WindowSpec<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        if !self.partition_by.is_empty() {
+            write!(f, "PARTITION BY ")?;
+            write_comma_separated_list(f, &self.partition_by)?;
+            first = false;
+        }
+        if !self.order_by.is_empty() {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "ORDER BY ")?;
+            write_comma_separated_list(f, &self.order_by)?;
+            first = false;
+        }
+        if let Some(frame) = &self.window_frame {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{frame}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.units {
+            WindowFrameUnits::Rows => write!(f, "ROWS")?,
+            WindowFrameUnits::Range => write!(f, "RANGE")?,
+        }
+        write!(f, " BETWEEN {} AND {}", self.start_bound, self.end_bound)
+    }
+}
+
+impl Display for WindowFrameBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            WindowFrameBound::Preceding(None) => write!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::Preceding(Some(n)) => write!(f, "{n} PRECEDING"),
+            WindowFrameBound::Following(None) => write!(f, "UNBOUNDED FOLLOWING"),
+            WindowFrameBound::Following(Some(n)) => write!(f, "{n} FOLLOWING"),
+        }
+    }
+}
+
 impl Display for Literal {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -769,8 +878,11 @@ impl<'a> Display for Expr<'a> {
             Expr::Literal { lit, .. } => {
                 write!(f, "{lit}")?;
             }
-            Expr::CountAll { .. } => {
+            Expr::CountAll { window, .. } => {
                 write!(f, "COUNT(*)")?;
+                if let Some(window) = window {
+                    write!(f, " OVER ({window})")?;
+                }
             }
             Expr::Tuple { exprs, .. } => {
                 write!(f, "(")?;
@@ -785,6 +897,7 @@ impl<'a> Display for Expr<'a> {
                 name,
                 args,
                 params,
+                window,
                 ..
             } => {
                 write!(f, "{name}")?;
@@ -799,6 +912,9 @@ impl<'a> Display for Expr<'a> {
                 }
                 write_comma_separated_list(f, args)?;
                 write!(f, ")")?;
+                if let Some(window) = window {
+                    write!(f, " OVER ({window})")?;
+                }
             }
             Expr::Case {
                 operand,
@@ -26,6 +26,9 @@ use crate::parser::token::Token;
 pub struct Query<'a> {
     pub span: &'a [Token<'a>],
 
+    // With clause, common table expressions
+    pub with: Option<With<'a>>,
+
     // Set operator: SELECT or UNION / EXCEPT / INTERSECT
     pub body: SetExpr<'a>,
 
@@ -40,6 +43,21 @@ pub struct Query<'a> {
     pub format: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct With<'a> {
+    pub span: &'a [Token<'a>],
+    pub recursive: bool,
+    pub ctes: Vec<CTE<'a>>,
+}
+
+/// Common table expression, e.g. `t(a, b) AS (SELECT ...)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CTE<'a> {
+    pub span: &'a [Token<'a>],
+    pub alias: TableAlias<'a>,
+    pub query: Query<'a>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct SetOperation<'a> {
     pub span: &'a [Token<'a>],
@@ -63,11 +81,24 @@ pub struct SelectStmt<'a> {
     // `WHERE` clause
     pub selection: Option<Expr<'a>>,
     // `GROUP BY` clause
-    pub group_by: Vec<Expr<'a>>,
+    pub group_by: Option<GroupBy<'a>>,
     // `HAVING` clause
     pub having: Option<Expr<'a>>,
 }
 
+/// `GROUP BY` clause
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupBy<'a> {
+    /// `GROUP BY expr [, expr]*`
+    Normal(Vec<Expr<'a>>),
+    /// `GROUP BY GROUPING SETS ((expr [, expr]*) [, (expr [, expr]*)]*)`
+    GroupingSets(Vec<Vec<Expr<'a>>>),
+    /// `GROUP BY ROLLUP (expr [, expr]*)`
+    Rollup(Vec<Expr<'a>>),
+    /// `GROUP BY CUBE (expr [, expr]*)`
+    Cube(Vec<Expr<'a>>),
+}
+
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
 #[derive(Debug, Clone, PartialEq)]
 pub enum SetExpr<'a> {
@@ -216,8 +247,8 @@ impl<'a> Display for TableAlias<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", &self.name)?;
         if !self.columns.is_empty() {
-            write!(f, " (")?;
-            write_period_separated_list(f, &self.columns)?;
+            write!(f, "(")?;
+            write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
         Ok(())
@@ -367,9 +398,8 @@ impl<'a> Display for SelectStmt<'a> {
         }
 
         // GROUP BY clause
-        if !self.group_by.is_empty() {
-            write!(f, " GROUP BY ")?;
-            write_comma_separated_list(f, &self.group_by)?;
+        if let Some(group_by) = &self.group_by {
+            write!(f, " GROUP BY {group_by}")?;
         }
 
         // HAVING clause
@@ -381,6 +411,39 @@ impl<'a> Display for SelectStmt<'a> {
     }
 }
 
+impl<'a> Display for GroupBy<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupBy::Normal(exprs) => {
+                write_comma_separated_list(f, exprs)?;
+            }
+            GroupBy::GroupingSets(sets) => {
+                write!(f, "GROUPING SETS (")?;
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "(")?;
+                    write_comma_separated_list(f, set)?;
+                    write!(f, ")")?;
+                }
+                write!(f, ")")?;
+            }
+            GroupBy::Rollup(exprs) => {
+                write!(f, "ROLLUP (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")?;
+            }
+            GroupBy::Cube(exprs) => {
+                write!(f, "CUBE (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<'a> Display for SetExpr<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -413,8 +476,29 @@ impl<'a> Display for SetExpr<'a> {
     }
 }
 
+impl<'a> Display for CTE<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} AS ({})", self.alias, self.query)
+    }
+}
+
+impl<'a> Display for With<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WITH ")?;
+        if self.recursive {
+            write!(f, "RECURSIVE ")?;
+        }
+        write_comma_separated_list(f, &self.ctes)
+    }
+}
+
 impl<'a> Display for Query<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // WITH clause
+        if let Some(with) = &self.with {
+            write!(f, "{with} ")?;
+        }
+
         // Query body
         write!(f, "{}", self.body)?;
 
@@ -18,11 +18,16 @@ mod database;
 mod explain;
 mod insert;
 mod kill;
+mod masking_policy;
+mod merge;
+mod network_policy;
 mod presign;
+mod row_access_policy;
 mod show;
 mod stage;
 mod statement;
 mod table;
+mod update;
 mod user;
 mod view;
 
@@ -32,10 +37,15 @@ pub use database::*;
 pub use explain::*;
 pub use insert::*;
 pub use kill::*;
+pub use masking_policy::*;
+pub use merge::*;
+pub use network_policy::*;
 pub use presign::*;
+pub use row_access_policy::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
 pub use table::*;
+pub use update::*;
 pub use user::*;
 pub use view::*;
@@ -0,0 +1,49 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateNetworkPolicyStmt<'a> {
+    pub if_not_exists: bool,
+    pub policy_name: Identifier<'a>,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: Option<String>,
+}
+
+impl Display for CreateNetworkPolicyStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE NETWORK POLICY")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {} ALLOWED_IP_LIST = (", self.policy_name)?;
+        write_comma_separated_list(f, self.allowed_ip_list.iter().map(|ip| format!("'{ip}'")))?;
+        write!(f, ")")?;
+        if !self.blocked_ip_list.is_empty() {
+            write!(f, " BLOCKED_IP_LIST = (")?;
+            write_comma_separated_list(f, self.blocked_ip_list.iter().map(|ip| format!("'{ip}'")))?;
+            write!(f, ")")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        Ok(())
+    }
+}
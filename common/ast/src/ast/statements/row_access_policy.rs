@@ -0,0 +1,51 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateRowAccessPolicyStmt<'a> {
+    pub if_not_exists: bool,
+    pub policy_name: Identifier<'a>,
+    pub parameters: Vec<(Identifier<'a>, TypeName)>,
+    pub definition: Box<Expr<'a>>,
+    pub description: Option<String>,
+}
+
+impl Display for CreateRowAccessPolicyStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE ROW ACCESS POLICY")?;
+        if self.if_not_exists {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {} AS (", self.policy_name)?;
+        write_comma_separated_list(
+            f,
+            self.parameters
+                .iter()
+                .map(|(name, data_type)| format!("{name} {data_type}")),
+        )?;
+        write!(f, ") RETURNS BOOLEAN -> {}", self.definition)?;
+        if let Some(description) = &self.description {
+            write!(f, " DESC = '{description}'")?;
+        }
+        Ok(())
+    }
+}
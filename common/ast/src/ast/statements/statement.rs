@@ -65,6 +65,16 @@ pub enum Statement<'a> {
         table_reference: TableReference<'a>,
         selection: Option<Expr<'a>>,
     },
+
+    Update(UpdateStmt<'a>),
+
+    Merge(MergeStmt<'a>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
+
     // Databases
     ShowDatabases(ShowDatabasesStmt<'a>),
     ShowCreateDatabase(ShowCreateDatabaseStmt<'a>),
@@ -88,6 +98,7 @@ pub enum Statement<'a> {
     RenameTable(RenameTableStmt<'a>),
     TruncateTable(TruncateTableStmt<'a>),
     OptimizeTable(OptimizeTableStmt<'a>),
+    AnalyzeTable(AnalyzeTableStmt<'a>),
     ExistsTable(ExistsTableStmt<'a>),
 
     // Views
@@ -137,6 +148,27 @@ pub enum Statement<'a> {
         description: Option<String>,
     },
 
+    // Row access policies
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt<'a>),
+    DropRowAccessPolicy {
+        if_exists: bool,
+        policy_name: Identifier<'a>,
+    },
+
+    // Masking policies
+    CreateMaskingPolicy(CreateMaskingPolicyStmt<'a>),
+    DropMaskingPolicy {
+        if_exists: bool,
+        policy_name: Identifier<'a>,
+    },
+
+    // Network policies
+    CreateNetworkPolicy(CreateNetworkPolicyStmt<'a>),
+    DropNetworkPolicy {
+        if_exists: bool,
+        policy_name: Identifier<'a>,
+    },
+
     // Stages
     CreateStage(CreateStageStmt),
     ShowStages,
@@ -175,6 +207,7 @@ impl<'a> Display for Statement<'a> {
                     ExplainKind::Graph => write!(f, " GRAPH")?,
                     ExplainKind::Pipeline => write!(f, " PIPELINE")?,
                     ExplainKind::Fragments => write!(f, " FRAGMENTS")?,
+                    ExplainKind::Analyze => write!(f, " ANALYZE")?,
                 }
                 write!(f, " {query}")?;
             }
@@ -190,6 +223,11 @@ impl<'a> Display for Statement<'a> {
                     write!(f, "WHERE {conditions} ")?;
                 }
             }
+            Statement::Update(update) => write!(f, "{update}")?,
+            Statement::Merge(merge) => write!(f, "{merge}")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Rollback => write!(f, "ROLLBACK")?,
             Statement::Copy(stmt) => write!(f, "{stmt}")?,
             Statement::ShowSettings { like } => {
                 write!(f, "SHOW SETTINGS")?;
@@ -246,6 +284,7 @@ impl<'a> Display for Statement<'a> {
             Statement::RenameTable(stmt) => write!(f, "{stmt}")?,
             Statement::TruncateTable(stmt) => write!(f, "{stmt}")?,
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
+            Statement::AnalyzeTable(stmt) => write!(f, "{stmt}")?,
             Statement::ExistsTable(stmt) => write!(f, "{stmt}")?,
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
@@ -331,6 +370,39 @@ impl<'a> Display for Statement<'a> {
                     write!(f, " DESC = '{description}'")?;
                 }
             }
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy {
+                if_exists,
+                policy_name,
+            } => {
+                write!(f, "DROP ROW ACCESS POLICY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {policy_name}")?;
+            }
+            Statement::CreateMaskingPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropMaskingPolicy {
+                if_exists,
+                policy_name,
+            } => {
+                write!(f, "DROP MASKING POLICY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {policy_name}")?;
+            }
+            Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropNetworkPolicy {
+                if_exists,
+                policy_name,
+            } => {
+                write!(f, "DROP NETWORK POLICY")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {policy_name}")?;
+            }
             Statement::ListStage { location, pattern } => {
                 write!(f, "LIST @{location}")?;
                 if !pattern.is_empty() {
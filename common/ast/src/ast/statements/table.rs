@@ -276,9 +276,37 @@ impl Display for AlterTableStmt<'_> {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AlterTableAction<'a> {
-    RenameTable { new_table: Identifier<'a> },
-    AlterTableClusterKey { cluster_by: Vec<Expr<'a>> },
+    RenameTable {
+        new_table: Identifier<'a>,
+    },
+    AlterTableClusterKey {
+        cluster_by: Vec<Expr<'a>>,
+    },
     DropTableClusterKey,
+    AddColumn {
+        column: ColumnDefinition<'a>,
+    },
+    DropColumn {
+        column: Identifier<'a>,
+    },
+    RenameColumn {
+        old_column: Identifier<'a>,
+        new_column: Identifier<'a>,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier<'a>,
+        columns: Vec<Identifier<'a>>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier<'a>,
+    },
+    SetMaskingPolicy {
+        column: Identifier<'a>,
+        policy: Identifier<'a>,
+    },
+    UnsetMaskingPolicy {
+        column: Identifier<'a>,
+    },
 }
 
 impl Display for AlterTableAction<'_> {
@@ -294,6 +322,32 @@ impl Display for AlterTableAction<'_> {
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")
             }
+            AlterTableAction::AddColumn { column } => {
+                write!(f, "ADD COLUMN {column}")
+            }
+            AlterTableAction::DropColumn { column } => {
+                write!(f, "DROP COLUMN {column}")
+            }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => {
+                write!(f, "RENAME COLUMN {old_column} TO {new_column}")
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")
+            }
+            AlterTableAction::SetMaskingPolicy { column, policy } => {
+                write!(f, "MODIFY COLUMN {column} SET MASKING POLICY {policy}")
+            }
+            AlterTableAction::UnsetMaskingPolicy { column } => {
+                write!(f, "MODIFY COLUMN {column} UNSET MASKING POLICY")
+            }
         }
     }
 }
@@ -385,6 +439,35 @@ impl Display for OptimizeTableStmt<'_> {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalyzeTableStmt<'a> {
+    pub catalog: Option<Identifier<'a>>,
+    pub database: Option<Identifier<'a>>,
+    pub table: Identifier<'a>,
+    /// The columns to be analyzed, all the columns if it's empty
+    pub columns: Vec<Identifier<'a>>,
+}
+
+impl Display for AnalyzeTableStmt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ANALYZE TABLE ")?;
+        write_period_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if !self.columns.is_empty() {
+            write!(f, " COLUMNS (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExistsTableStmt<'a> {
     pub catalog: Option<Identifier<'a>>,
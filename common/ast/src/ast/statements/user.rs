@@ -21,6 +21,7 @@ use common_meta_types::UserIdentity;
 use common_meta_types::UserOption;
 use common_meta_types::UserOptionFlag;
 use common_meta_types::UserPrivilegeType;
+use common_meta_types::UserQuota;
 
 use crate::ast::write_comma_separated_list;
 
@@ -76,6 +77,7 @@ pub struct AlterUserStmt {
     pub user: Option<UserIdentity>,
     // None means no change to make
     pub auth_option: Option<AuthOption>,
+    pub quota_options: Vec<QuotaOption>,
     pub role_options: Vec<RoleOption>,
 }
 
@@ -90,6 +92,10 @@ impl Display for AlterUserStmt {
         if let Some(auth_option) = &self.auth_option {
             write!(f, " IDENTIFIED {}", auth_option)?;
         }
+        if !self.quota_options.is_empty() {
+            write!(f, " WITH QUOTA ")?;
+            write_comma_separated_list(f, &self.quota_options)?;
+        }
         if !self.role_options.is_empty() {
             write!(f, " WITH")?;
             for with_option in &self.role_options {
@@ -142,6 +148,12 @@ pub enum AccountMgrSource {
         privileges: Vec<UserPrivilegeType>,
         level: AccountMgrLevel,
     },
+    /// Privilege on some columns of a table, e.g. `SELECT(a, b) ON db.t`
+    ColumnPrivs {
+        privilege: UserPrivilegeType,
+        columns: Vec<String>,
+        level: AccountMgrLevel,
+    },
     ALL {
         level: AccountMgrLevel,
     },
@@ -160,6 +172,8 @@ pub enum RoleOption {
     NoTenantSetting,
     ConfigReload,
     NoConfigReload,
+    SetNetworkPolicy(String),
+    UnsetNetworkPolicy,
 }
 
 impl RoleOption {
@@ -177,6 +191,39 @@ impl RoleOption {
             Self::NoConfigReload => {
                 option.unset_option_flag(UserOptionFlag::ConfigReload);
             }
+            Self::SetNetworkPolicy(policy) => {
+                option.set_network_policy(Some(policy.clone()));
+            }
+            Self::UnsetNetworkPolicy => {
+                option.set_network_policy(None);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuotaOption {
+    MaxCpu(u64),
+    MaxMemoryInBytes(u64),
+    MaxStorageInBytes(u64),
+}
+
+impl QuotaOption {
+    pub fn apply(&self, quota: &mut UserQuota) {
+        match self {
+            Self::MaxCpu(v) => quota.max_cpu = *v,
+            Self::MaxMemoryInBytes(v) => quota.max_memory_in_bytes = *v,
+            Self::MaxStorageInBytes(v) => quota.max_storage_in_bytes = *v,
+        }
+    }
+}
+
+impl Display for QuotaOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuotaOption::MaxCpu(v) => write!(f, "MAX_CPU = {v}"),
+            QuotaOption::MaxMemoryInBytes(v) => write!(f, "MAX_MEMORY_IN_BYTES = {v}"),
+            QuotaOption::MaxStorageInBytes(v) => write!(f, "MAX_STORAGE_IN_BYTES = {v}"),
         }
     }
 }
@@ -188,51 +235,48 @@ impl Display for AccountMgrSource {
             AccountMgrSource::Privs { privileges, level } => {
                 write!(f, " ")?;
                 write_comma_separated_list(f, privileges.iter().map(|p| p.to_string()))?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
+            }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                write!(f, " {privilege}(")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") ON {level}")?;
             }
             AccountMgrSource::ALL { level, .. } => {
                 write!(f, " ALL PRIVILEGES")?;
-                write!(f, " ON")?;
-                match level {
-                    AccountMgrLevel::Global => write!(f, " *.*")?,
-                    AccountMgrLevel::Database(database_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.*")?;
-                        } else {
-                            write!(f, " *")?;
-                        }
-                    }
-                    AccountMgrLevel::Table(database_name, table_name) => {
-                        if let Some(database_name) = database_name {
-                            write!(f, " {database_name}.{table_name}")?;
-                        } else {
-                            write!(f, " {table_name}")?;
-                        }
-                    }
-                }
+                write!(f, " ON {level}")?;
             }
         }
         Ok(())
     }
 }
 
+impl Display for AccountMgrLevel {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AccountMgrLevel::Global => write!(f, "*.*"),
+            AccountMgrLevel::Database(database_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.*")
+                } else {
+                    write!(f, "*")
+                }
+            }
+            AccountMgrLevel::Table(database_name, table_name) => {
+                if let Some(database_name) = database_name {
+                    write!(f, "{database_name}.{table_name}")
+                } else {
+                    write!(f, "{table_name}")
+                }
+            }
+        }
+    }
+}
+
 impl Display for RoleOption {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -240,6 +284,8 @@ impl Display for RoleOption {
             RoleOption::NoTenantSetting => write!(f, "NOTENANTSETTING"),
             RoleOption::ConfigReload => write!(f, "CONFIGRELOAD"),
             RoleOption::NoConfigReload => write!(f, "NOCONFIGRELOAD"),
+            RoleOption::SetNetworkPolicy(policy) => write!(f, "SET NETWORK POLICY {policy}"),
+            RoleOption::UnsetNetworkPolicy => write!(f, "UNSET NETWORK POLICY"),
         }
     }
 }
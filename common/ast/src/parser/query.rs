@@ -32,15 +32,20 @@ use crate::util::*;
 pub fn query(i: Input) -> IResult<Query> {
     map(
         consumed(rule! {
-            #set_operation
+            #with?
+            ~ #set_operation
             ~ ( ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr) )?
             ~ ( LIMIT ~ ^#comma_separated_list1(expr) )?
             ~ ( OFFSET ~ ^#expr )?
             ~ ( FORMAT ~ #ident )?
             : "`SELECT ...`"
         }),
-        |(span, (body, opt_order_by_block, opt_limit_block, opt_offset_block, opt_format))| Query {
+        |(
+            span,
+            (with, body, opt_order_by_block, opt_limit_block, opt_offset_block, opt_format),
+        )| Query {
             span: span.0,
+            with,
             body,
             order_by: opt_order_by_block
                 .map(|(_, _, order_by)| order_by)
@@ -52,6 +57,30 @@ pub fn query(i: Input) -> IResult<Query> {
     )(i)
 }
 
+pub fn with(i: Input) -> IResult<With> {
+    let cte = map(
+        consumed(rule! {
+            #table_alias_with_columns ~ AS ~ "(" ~ #query ~ ")"
+        }),
+        |(span, (alias, _, _, query, _))| CTE {
+            span: span.0,
+            alias,
+            query,
+        },
+    );
+
+    map(
+        consumed(rule! {
+            WITH ~ RECURSIVE? ~ ^#comma_separated_list1(cte)
+        }),
+        |(span, (_, opt_recursive, ctes))| With {
+            span: span.0,
+            recursive: opt_recursive.is_some(),
+            ctes,
+        },
+    )(i)
+}
+
 pub fn select_target(i: Input) -> IResult<SelectTarget> {
     let qualified_wildcard = map(
         rule! {
@@ -142,6 +171,20 @@ pub fn table_alias(i: Input) -> IResult<TableAlias> {
     })(i)
 }
 
+pub fn table_alias_with_columns(i: Input) -> IResult<TableAlias> {
+    map(
+        rule! {
+            #ident ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+        },
+        |(name, opt_columns)| TableAlias {
+            name,
+            columns: opt_columns
+                .map(|(_, columns, _)| columns)
+                .unwrap_or_default(),
+        },
+    )(i)
+}
+
 pub fn table_function(i: Input) -> IResult<TableReference> {
     map(
         consumed(rule! {
@@ -272,6 +315,45 @@ pub fn join_operator(i: Input) -> IResult<JoinOperator> {
     ))(i)
 }
 
+pub fn group_by_items(i: Input) -> IResult<GroupBy> {
+    let normal = map(rule! { #comma_separated_list1(expr) }, GroupBy::Normal);
+    let rollup = map(
+        rule! {
+            ROLLUP ~ "(" ~ #comma_separated_list1(expr) ~ ")"
+        },
+        |(_, _, exprs, _)| GroupBy::Rollup(exprs),
+    );
+    let cube = map(
+        rule! {
+            CUBE ~ "(" ~ #comma_separated_list1(expr) ~ ")"
+        },
+        |(_, _, exprs, _)| GroupBy::Cube(exprs),
+    );
+    // A grouping set is either a parenthesized list, e.g. `(a, b)` or `()`, or a single expression
+    let grouping_set = alt((
+        map(
+            rule! {
+                "(" ~ #comma_separated_list0(expr) ~ ")"
+            },
+            |(_, exprs, _)| exprs,
+        ),
+        map(expr, |expr| vec![expr]),
+    ));
+    let grouping_sets = map(
+        rule! {
+            GROUPING ~ SETS ~ "(" ~ #comma_separated_list1(grouping_set) ~ ")"
+        },
+        |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
+    );
+
+    rule!(
+        #rollup
+        | #cube
+        | #grouping_sets
+        | #normal
+    )(i)
+}
+
 pub fn order_by_expr(i: Input) -> IResult<OrderByExpr> {
     let nulls_first = map(
         rule! {
@@ -299,7 +381,7 @@ pub enum SetOperationElement<'a> {
         select_list: Box<Vec<SelectTarget<'a>>>,
         from: Box<Vec<TableReference<'a>>>,
         selection: Box<Option<Expr<'a>>>,
-        group_by: Box<Vec<Expr<'a>>>,
+        group_by: Box<Option<GroupBy<'a>>>,
         having: Box<Option<Expr<'a>>>,
     },
     SetOperation {
@@ -333,7 +415,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
              SELECT ~ DISTINCT? ~ ^#comma_separated_list1(select_target)
                 ~ ( FROM ~ ^#comma_separated_list1(table_reference) )?
                 ~ ( WHERE ~ ^#expr )?
-                ~ ( GROUP ~ ^BY ~ ^#comma_separated_list1(expr) )?
+                ~ ( GROUP ~ ^BY ~ ^#group_by_items )?
                 ~ ( HAVING ~ ^#expr )?
         },
         |(
@@ -354,11 +436,7 @@ pub fn set_operation_element(i: Input) -> IResult<WithSpan<SetOperationElement>>
                         .unwrap_or_default(),
                 ),
                 selection: Box::new(opt_where_block.map(|(_, selection)| selection)),
-                group_by: Box::new(
-                    opt_group_by_block
-                        .map(|(_, _, group_by)| group_by)
-                        .unwrap_or_default(),
-                ),
+                group_by: Box::new(opt_group_by_block.map(|(_, _, group_by)| group_by)),
                 having: Box::new(opt_having_block.map(|(_, having)| having)),
             }
         },
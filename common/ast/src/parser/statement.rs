@@ -38,13 +38,14 @@ use crate::ErrorKind;
 pub fn statement(i: Input) -> IResult<StatementMsg> {
     let explain = map(
         rule! {
-            EXPLAIN ~ ( PIPELINE | GRAPH | FRAGMENTS )? ~ #statement
+            EXPLAIN ~ ( PIPELINE | GRAPH | FRAGMENTS | ANALYZE )? ~ #statement
         },
         |(_, opt_kind, statement)| Statement::Explain {
             kind: match opt_kind.map(|token| token.kind) {
                 Some(TokenKind::PIPELINE) => ExplainKind::Pipeline,
                 Some(TokenKind::GRAPH) => ExplainKind::Graph,
                 Some(TokenKind::FRAGMENTS) => ExplainKind::Fragments,
+                Some(TokenKind::ANALYZE) => ExplainKind::Analyze,
                 None => ExplainKind::Syntax,
                 _ => unreachable!(),
             },
@@ -97,6 +98,48 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let update = map(
+        rule! {
+            UPDATE ~ #table_reference_only ~ SET ~ ^#comma_separated_list1(update_expr)
+            ~ ( WHERE ~ ^#expr )?
+        },
+        |(_, table_reference, _, update_list, opt_selection)| {
+            Statement::Update(UpdateStmt {
+                table_reference,
+                update_list,
+                selection: opt_selection.map(|(_, selection)| selection),
+            })
+        },
+    );
+
+    let merge = map(
+        rule! {
+            MERGE ~ INTO ~ #merge_target ~ USING ~ ^#table_reference
+            ~ ^ON ~ ^#expr ~ ( #merge_clause )+
+        },
+        |(_, _, target, _, source, _, join_condition, clauses)| {
+            let mut matched_clauses = vec![];
+            let mut unmatched_clauses = vec![];
+            for clause in clauses {
+                match clause {
+                    MergeClause::Matched(clause) => matched_clauses.push(clause),
+                    MergeClause::Unmatched(clause) => unmatched_clauses.push(clause),
+                }
+            }
+            Statement::Merge(MergeStmt {
+                target,
+                source,
+                join_condition,
+                matched_clauses,
+                unmatched_clauses,
+            })
+        },
+    );
+
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let rollback = value(Statement::Rollback, rule! { ROLLBACK });
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ (LIKE ~ #literal_string)?
@@ -396,6 +439,22 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let analyze_table = map(
+        rule! {
+            ANALYZE ~ TABLE ~ #peroid_separated_idents_1_to_3
+            ~ ( COLUMNS ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+        },
+        |(_, _, (catalog, database, table), opt_columns)| {
+            Statement::AnalyzeTable(AnalyzeTableStmt {
+                catalog,
+                database,
+                table,
+                columns: opt_columns
+                    .map(|(_, _, columns, _)| columns)
+                    .unwrap_or_default(),
+            })
+        },
+    );
     let exists_table = map(
         rule! {
             EXISTS ~ TABLE ~ #peroid_separated_idents_1_to_3
@@ -478,15 +537,19 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! {
             ALTER ~ USER ~ ( #map(rule! { USER ~ "(" ~ ")" }, |_| None) | #map(user_identity, Some) )
             ~ ( IDENTIFIED ~ ( WITH ~ ^#auth_type )? ~ ( BY ~ ^#literal_string )? )?
+            ~ ( WITH ~ QUOTA ~ ^#comma_separated_list1(quota_option) )?
             ~ ( WITH ~ ^#role_option+ )?
         },
-        |(_, _, user, opt_auth_option, opt_role_options)| {
+        |(_, _, user, opt_auth_option, opt_quota_options, opt_role_options)| {
             Statement::AlterUser(AlterUserStmt {
                 user,
                 auth_option: opt_auth_option.map(|(_, opt_auth_type, opt_password)| AuthOption {
                     auth_type: opt_auth_type.map(|(_, auth_type)| auth_type),
                     password: opt_password.map(|(_, password)| password),
                 }),
+                quota_options: opt_quota_options
+                    .map(|(_, _, quota_options)| quota_options)
+                    .unwrap_or_default(),
                 role_options: opt_role_options
                     .map(|(_, role_options)| role_options)
                     .unwrap_or_default(),
@@ -608,6 +671,140 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    // row access policies
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(policy_parameter) ~ ")"
+            ~ RETURNS ~ BOOLEAN ~ "->" ~ #expr
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            policy_name,
+            _,
+            _,
+            parameters,
+            _,
+            _,
+            _,
+            _,
+            definition,
+            opt_description,
+        )| {
+            Statement::CreateRowAccessPolicy(CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                policy_name,
+                parameters,
+                definition: Box::new(definition),
+                description: opt_description.map(|(_, _, description)| description),
+            })
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, policy_name)| Statement::DropRowAccessPolicy {
+            if_exists: opt_if_exists.is_some(),
+            policy_name,
+        },
+    );
+
+    // masking policies
+    let create_masking_policy = map(
+        rule! {
+            CREATE ~ MASKING ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ AS ~ "(" ~ #comma_separated_list1(policy_parameter) ~ ")"
+            ~ RETURNS ~ #type_name ~ "->" ~ #expr
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            policy_name,
+            _,
+            _,
+            parameters,
+            _,
+            _,
+            return_type,
+            _,
+            definition,
+            opt_description,
+        )| {
+            Statement::CreateMaskingPolicy(CreateMaskingPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                policy_name,
+                parameters,
+                return_type,
+                definition: Box::new(definition),
+                description: opt_description.map(|(_, _, description)| description),
+            })
+        },
+    );
+    let drop_masking_policy = map(
+        rule! {
+            DROP ~ MASKING ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, policy_name)| Statement::DropMaskingPolicy {
+            if_exists: opt_if_exists.is_some(),
+            policy_name,
+        },
+    );
+
+    // network policies
+    let create_network_policy = map(
+        rule! {
+            CREATE ~ NETWORK ~ POLICY ~ ( IF ~ NOT ~ EXISTS )?
+            ~ #ident
+            ~ ALLOWED_IP_LIST ~ ^"=" ~ ^"(" ~ ^#comma_separated_list1(literal_string) ~ ^")"
+            ~ ( BLOCKED_IP_LIST ~ ^"=" ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" )?
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            _,
+            _,
+            opt_if_not_exists,
+            policy_name,
+            _,
+            _,
+            _,
+            allowed_ip_list,
+            _,
+            opt_blocked_ip_list,
+            opt_comment,
+        )| {
+            Statement::CreateNetworkPolicy(CreateNetworkPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                policy_name,
+                allowed_ip_list,
+                blocked_ip_list: opt_blocked_ip_list
+                    .map(|(_, _, _, blocked_ip_list, _)| blocked_ip_list)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_network_policy = map(
+        rule! {
+            DROP ~ NETWORK ~ POLICY ~ ( IF ~ EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, policy_name)| Statement::DropNetworkPolicy {
+            if_exists: opt_if_exists.is_some(),
+            policy_name,
+        },
+    );
+
     // stages
     let create_stage = map_res(
         rule! {
@@ -747,6 +944,8 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
+            | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
+            | #merge : "`MERGE INTO <table> USING <source> ON <expr> WHEN [NOT] MATCHED [AND <expr>] THEN <action> ...`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
@@ -776,12 +975,16 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #rename_table : "`RENAME TABLE [<database>.]<table> TO <new_table>`"
             | #truncate_table : "`TRUNCATE TABLE [<database>.]<table> [PURGE]`"
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT)`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table> [COLUMNS (<column>, ...)]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
         ),
         rule!(
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> AS SELECT ...`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #rollback : "`ROLLBACK`"
         ),
         rule!(
             #show_users : "`SHOW USERS`"
@@ -795,6 +998,14 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
         ),
+        rule!(
+            #create_row_access_policy : "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy_name> AS (<parameter> <type>, ...) RETURNS BOOLEAN -> <definition expr> [DESC = <description>]`"
+            | #drop_row_access_policy : "`DROP ROW ACCESS POLICY [IF EXISTS] <policy_name>`"
+            | #create_masking_policy : "`CREATE MASKING POLICY [IF NOT EXISTS] <policy_name> AS (<parameter> <type>) RETURNS <type> -> <definition expr> [DESC = <description>]`"
+            | #drop_masking_policy : "`DROP MASKING POLICY [IF EXISTS] <policy_name>`"
+            | #create_network_policy : "`CREATE NETWORK POLICY [IF NOT EXISTS] <policy_name> ALLOWED_IP_LIST = ('<ip>', ...) [BLOCKED_IP_LIST = ('<ip>', ...)] [COMMENT = '<comment>']`"
+            | #drop_network_policy : "`DROP NETWORK POLICY [IF EXISTS] <policy_name>`"
+        ),
         rule!(
             #create_stage: "`CREATE STAGE [ IF NOT EXISTS ] <internal_stage_name>
                 [ FILE_FORMAT = ( { TYPE = { CSV | PARQUET } [ formatTypeOptions ] ) } ]
@@ -878,6 +1089,75 @@ pub fn rest_tokens<'a>(i: Input<'a>) -> IResult<&'a [Token]> {
     }
 }
 
+pub fn merge_target(i: Input) -> IResult<TableReference> {
+    map(
+        consumed(rule! {
+            #peroid_separated_idents_1_to_3 ~ #table_alias?
+        }),
+        |(input, ((catalog, database, table), alias))| TableReference::Table {
+            span: input.0,
+            catalog,
+            database,
+            table,
+            alias,
+            travel_point: None,
+        },
+    )(i)
+}
+
+pub enum MergeClause<'a> {
+    Matched(MatchedClause<'a>),
+    Unmatched(UnmatchedClause<'a>),
+}
+
+pub fn merge_clause(i: Input) -> IResult<MergeClause> {
+    let update = map(
+        rule! {
+            UPDATE ~ ^SET ~ ^#comma_separated_list1(update_expr)
+        },
+        |(_, _, update_list)| MatchOperation::Update { update_list },
+    );
+    let delete = value(MatchOperation::Delete, rule! { DELETE });
+    let matched = map(
+        rule! {
+            WHEN ~ MATCHED ~ ( AND ~ ^#expr )? ~ ^THEN ~ ^( #update | #delete )
+        },
+        |(_, _, opt_selection, _, operation)| {
+            MergeClause::Matched(MatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                operation,
+            })
+        },
+    );
+    let unmatched = map(
+        rule! {
+            WHEN ~ NOT ~ ^MATCHED ~ ( AND ~ ^#expr )? ~ ^THEN ~ ^INSERT
+            ~ ( "(" ~ ^#comma_separated_list1(ident) ~ ^")" )?
+            ~ ^VALUES ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, _, opt_selection, _, _, opt_columns, _, _, values, _)| {
+            MergeClause::Unmatched(UnmatchedClause {
+                selection: opt_selection.map(|(_, selection)| selection),
+                columns: opt_columns
+                    .map(|(_, columns, _)| columns)
+                    .unwrap_or_default(),
+                values,
+            })
+        },
+    );
+
+    rule!(
+        #matched
+        | #unmatched
+    )(i)
+}
+
+pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
+    map(rule! { #ident ~ "=" ~ ^#expr }, |(name, _, expr)| {
+        UpdateExpr { name, expr }
+    })(i)
+}
+
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint<'a> {
@@ -951,6 +1231,16 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
             level,
         },
     );
+    let column_privs = map(
+        rule! {
+            #priv_type ~ "(" ~ #comma_separated_list1(ident) ~ ")" ~ ON ~ #grant_level
+        },
+        |(privilege, _, columns, _, _, level)| AccountMgrSource::ColumnPrivs {
+            privilege,
+            columns: columns.into_iter().map(|column| column.name).collect(),
+            level,
+        },
+    );
     let all = map(
         rule! { ALL ~ PRIVILEGES? ~ ON ~ #grant_level },
         |(_, _, _, level)| AccountMgrSource::ALL { level },
@@ -959,6 +1249,7 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     rule!(
         #role : "ROLE <role_name>"
         | #privs : "<privileges> ON <privileges_level>"
+        | #column_privs : "<privilege>(<column>, ...) ON <privileges_level>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
 }
@@ -1091,13 +1382,79 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _)| AlterTableAction::DropTableClusterKey,
     );
 
+    let add_column = map(
+        rule! {
+            ADD ~ COLUMN ~ #column_def
+        },
+        |(_, _, column)| AlterTableAction::AddColumn { column },
+    );
+
+    let drop_column = map(
+        rule! {
+            DROP ~ COLUMN ~ #ident
+        },
+        |(_, _, column)| AlterTableAction::DropColumn { column },
+    );
+
+    let rename_column = map(
+        rule! {
+            RENAME ~ COLUMN ~ #ident ~ TO ~ #ident
+        },
+        |(_, _, old_column, _, new_column)| AlterTableAction::RenameColumn {
+            old_column,
+            new_column,
+        },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ #comma_separated_list1(ident) ~ ")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
+    let set_masking_policy = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ SET ~ MASKING ~ POLICY ~ #ident
+        },
+        |(_, _, column, _, _, _, policy)| AlterTableAction::SetMaskingPolicy { column, policy },
+    );
+
+    let unset_masking_policy = map(
+        rule! {
+            MODIFY ~ COLUMN ~ #ident ~ UNSET ~ MASKING ~ POLICY
+        },
+        |(_, _, column, _, _, _)| AlterTableAction::UnsetMaskingPolicy { column },
+    );
+
     rule!(
         #rename_table
         | #alter_table_cluster_key
         | #drop_table_cluster_key
+        | #add_column
+        | #drop_column
+        | #rename_column
+        | #add_row_access_policy
+        | #drop_row_access_policy
+        | #set_masking_policy
+        | #unset_masking_policy
     )(i)
 }
 
+pub fn policy_parameter(i: Input) -> IResult<(Identifier, TypeName)> {
+    rule!(#ident ~ #type_name)(i)
+}
+
 pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
     alt((
         value(OptimizeTableAction::All, rule! { ALL }),
@@ -1283,6 +1640,30 @@ pub fn role_option(i: Input) -> IResult<RoleOption> {
         value(RoleOption::NoTenantSetting, rule! { NOTENANTSETTING }),
         value(RoleOption::ConfigReload, rule! { CONFIGRELOAD }),
         value(RoleOption::NoConfigReload, rule! { NOCONFIGRELOAD }),
+        map(
+            rule! { SET ~ NETWORK ~ ^POLICY ~ ^#ident },
+            |(_, _, _, policy)| RoleOption::SetNetworkPolicy(policy.name),
+        ),
+        value(
+            RoleOption::UnsetNetworkPolicy,
+            rule! { UNSET ~ NETWORK ~ ^POLICY },
+        ),
+    ))(i)
+}
+
+pub fn quota_option(i: Input) -> IResult<QuotaOption> {
+    alt((
+        map(rule! { MAX_CPU ~ "=" ~ #literal_u64 }, |(_, _, v)| {
+            QuotaOption::MaxCpu(v)
+        }),
+        map(
+            rule! { MAX_MEMORY_IN_BYTES ~ "=" ~ #literal_u64 },
+            |(_, _, v)| QuotaOption::MaxMemoryInBytes(v),
+        ),
+        map(
+            rule! { MAX_STORAGE_IN_BYTES ~ "=" ~ #literal_u64 },
+            |(_, _, v)| QuotaOption::MaxStorageInBytes(v),
+        ),
     ))(i)
 }
 
@@ -235,8 +235,14 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserverd keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
+    #[token("ADD", ignore(ascii_case))]
+    ADD,
     #[token("ALL", ignore(ascii_case))]
     ALL,
+    #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
+    ALLOWED_IP_LIST,
     #[token("ANY", ignore(ascii_case))]
     ANY,
     #[token("SOME", ignore(ascii_case))]
@@ -259,10 +265,14 @@ pub enum TokenKind {
     AWS_KEY_ID,
     #[token("AWS_SECRET_KEY", ignore(ascii_case))]
     AWS_SECRET_KEY,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
     BIGINT,
+    #[token("BLOCKED_IP_LIST", ignore(ascii_case))]
+    BLOCKED_IP_LIST,
     #[token("BOOL", ignore(ascii_case))]
     BOOL,
     #[token("BOOLEAN", ignore(ascii_case))]
@@ -281,10 +291,16 @@ pub enum TokenKind {
     CENTURY,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
+    #[token("COLUMN", ignore(ascii_case))]
+    COLUMN,
+    #[token("COLUMNS", ignore(ascii_case))]
+    COLUMNS,
     #[token("COMMENT", ignore(ascii_case))]
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONFIGRELOAD", ignore(ascii_case))]
@@ -309,6 +325,10 @@ pub enum TokenKind {
     CROSS,
     #[token("CSV", ignore(ascii_case))]
     CSV,
+    #[token("CUBE", ignore(ascii_case))]
+    CUBE,
+    #[token("CURRENT", ignore(ascii_case))]
+    CURRENT,
     #[token("CURRENT_TIMESTAMP", ignore(ascii_case))]
     CURRENT_TIMESTAMP,
     #[token("DATABASE", ignore(ascii_case))]
@@ -329,6 +349,8 @@ pub enum TokenKind {
     DAY,
     #[token("DECADE", ignore(ascii_case))]
     DECADE,
+    #[token("DECIMAL", ignore(ascii_case))]
+    DECIMAL,
     #[token("DEFAULT", ignore(ascii_case))]
     DEFAULT,
     #[token("DELETE", ignore(ascii_case))]
@@ -393,6 +415,8 @@ pub enum TokenKind {
     FLOAT32,
     #[token("FLOAT64", ignore(ascii_case))]
     FLOAT64,
+    #[token("FOLLOWING", ignore(ascii_case))]
+    FOLLOWING,
     #[token("FOR", ignore(ascii_case))]
     FOR,
     #[token("FORMAT", ignore(ascii_case))]
@@ -417,6 +441,8 @@ pub enum TokenKind {
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPING", ignore(ascii_case))]
+    GROUPING,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
     #[token("HISTORY", ignore(ascii_case))]
@@ -483,10 +509,22 @@ pub enum TokenKind {
     LIST,
     #[token("MAP", ignore(ascii_case))]
     MAP,
+    #[token("MASKING", ignore(ascii_case))]
+    MASKING,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
+    #[token("MATCHED", ignore(ascii_case))]
+    MATCHED,
+    #[token("MAX_CPU", ignore(ascii_case))]
+    MAX_CPU,
+    #[token("MAX_MEMORY_IN_BYTES", ignore(ascii_case))]
+    MAX_MEMORY_IN_BYTES,
+    #[token("MAX_STORAGE_IN_BYTES", ignore(ascii_case))]
+    MAX_STORAGE_IN_BYTES,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MERGE", ignore(ascii_case))]
+    MERGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -497,10 +535,14 @@ pub enum TokenKind {
     MILLISECONDS,
     #[token("MINUTE", ignore(ascii_case))]
     MINUTE,
+    #[token("MODIFY", ignore(ascii_case))]
+    MODIFY,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
     #[token("NATURAL", ignore(ascii_case))]
     NATURAL,
+    #[token("NETWORK", ignore(ascii_case))]
+    NETWORK,
     #[token("NO_PASSWORD", ignore(ascii_case))]
     NO_PASSWORD,
     #[token("NOCONFIGRELOAD", ignore(ascii_case))]
@@ -511,6 +553,8 @@ pub enum TokenKind {
     NOTENANTSETTING,
     #[token("NULL", ignore(ascii_case))]
     NULL,
+    #[token("NUMERIC", ignore(ascii_case))]
+    NUMERIC,
     #[token("OBJECT", ignore(ascii_case))]
     OBJECT,
     #[token("OFFSET", ignore(ascii_case))]
@@ -527,16 +571,22 @@ pub enum TokenKind {
     OUTER,
     #[token("ON_ERROR", ignore(ascii_case))]
     ON_ERROR,
+    #[token("OVER", ignore(ascii_case))]
+    OVER,
     #[token("OVERWRITE", ignore(ascii_case))]
     OVERWRITE,
     #[token("PARQUET", ignore(ascii_case))]
     PARQUET,
+    #[token("PARTITION", ignore(ascii_case))]
+    PARTITION,
     #[token("PATTERN", ignore(ascii_case))]
     PATTERN,
     #[token("PIPELINE", ignore(ascii_case))]
     PIPELINE,
     #[token("PLAINTEXT_PASSWORD", ignore(ascii_case))]
     PLAINTEXT_PASSWORD,
+    #[token("POLICY", ignore(ascii_case))]
+    POLICY,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
     #[token("PROCESSLIST", ignore(ascii_case))]
@@ -547,8 +597,12 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUOTA", ignore(ascii_case))]
+    QUOTA,
     #[token("RECORD_DELIMITER", ignore(ascii_case))]
     RECORD_DELIMITER,
+    #[token("RECURSIVE", ignore(ascii_case))]
+    RECURSIVE,
     #[token("REGEXP", ignore(ascii_case))]
     REGEXP,
     #[token("RENAME", ignore(ascii_case))]
@@ -557,12 +611,24 @@ pub enum TokenKind {
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
+    #[token("ROLLUP", ignore(ascii_case))]
+    ROLLUP,
+    #[token("ROW", ignore(ascii_case))]
+    ROW,
+    #[token("ROWS", ignore(ascii_case))]
+    ROWS,
+    #[token("PRECEDING", ignore(ascii_case))]
+    PRECEDING,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("REMOVE", ignore(ascii_case))]
     REMOVE,
+    #[token("RETURNS", ignore(ascii_case))]
+    RETURNS,
     #[token("REVOKE", ignore(ascii_case))]
     REVOKE,
     #[token("GRANTS", ignore(ascii_case))]
@@ -581,6 +647,8 @@ pub enum TokenKind {
     SELECT,
     #[token("SET", ignore(ascii_case))]
     SET,
+    #[token("SETS", ignore(ascii_case))]
+    SETS,
     #[token("SETTINGS", ignore(ascii_case))]
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
@@ -641,6 +709,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -665,8 +735,12 @@ pub enum TokenKind {
     UINT64,
     #[token("UINT8", ignore(ascii_case))]
     UINT8,
+    #[token("UNBOUNDED", ignore(ascii_case))]
+    UNBOUNDED,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNSET", ignore(ascii_case))]
+    UNSET,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -705,6 +779,8 @@ pub enum TokenKind {
     NULLIF,
     #[token("COALESCE", ignore(ascii_case))]
     COALESCE,
+    #[token("RANGE", ignore(ascii_case))]
+    RANGE,
     #[token("RANDOM", ignore(ascii_case))]
     RANDOM,
     #[token("IFNULL", ignore(ascii_case))]
@@ -794,7 +870,7 @@ impl TokenKind {
             // | TokenKind::CHECK
             | TokenKind::COALESCE
             // | TokenKind::COLLATE
-            // | TokenKind::COLUMN
+            | TokenKind::COLUMN
             // | TokenKind::CONSTRAINT
             // | TokenKind::CURRENT_CATALOG
             // | TokenKind::CURRENT_DATE
@@ -932,7 +1008,7 @@ impl TokenKind {
             // | TokenKind::CHECK
             // | TokenKind::COLLATE
             // | TokenKind::COLLATION
-            // | TokenKind::COLUMN
+            | TokenKind::COLUMN
             // | TokenKind::CONCURRENTLY
             // | TokenKind::CONSTRAINT
             | TokenKind::CROSS
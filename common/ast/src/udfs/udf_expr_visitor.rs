@@ -85,6 +85,12 @@ pub trait UDFExprVisitor: Sized + Send {
             Expr::MapAccess { column, keys } => self.visit_map_access(column, keys),
             Expr::Trim { expr, trim_where } => self.visit_trim(expr, trim_where),
             Expr::Array(exprs) => self.visit_array(exprs),
+            Expr::Case {
+                operand,
+                conditions,
+                results,
+                else_result,
+            } => self.visit_case(operand, conditions, results, else_result),
             other => Result::Err(ErrorCode::SyntaxException(format!(
                 "Unsupported expression: {}, type: {:?}",
                 expr, other
@@ -263,4 +269,23 @@ pub trait UDFExprVisitor: Sized + Send {
         }
         Ok(())
     }
+
+    fn visit_case(
+        &mut self,
+        operand: &Option<Box<Expr>>,
+        conditions: &[Expr],
+        results: &[Expr],
+        else_result: &Option<Box<Expr>>,
+    ) -> Result<()> {
+        if let Some(operand) = operand {
+            UDFExprTraverser::accept(operand, self)?;
+        }
+        for expr in conditions.iter().chain(results.iter()) {
+            UDFExprTraverser::accept(expr, self)?;
+        }
+        if let Some(else_result) = else_result {
+            UDFExprTraverser::accept(else_result, self)?;
+        }
+        Ok(())
+    }
 }
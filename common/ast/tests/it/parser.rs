@@ -70,6 +70,7 @@ fn test_statement() {
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
+        r#"explain analyze select a from b;"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"create table if not exists a.b (c integer not null default 1, b varchar);"#,
@@ -95,6 +96,7 @@ fn test_statement() {
         r#"rename table d.t to e.s;"#,
         r#"truncate table test;"#,
         r#"truncate table test_db.test;"#,
+        r#"analyze table test_db.test columns (a, b);"#,
         r#"DROP table table1;"#,
         r#"DROP table IF EXISTS table1;"#,
         r#"CREATE TABLE t(c1 int null, c2 bigint null, c3 varchar null);"#,
@@ -129,10 +131,24 @@ fn test_statement() {
         r#"create user 'test-e'@'localhost' identified by 'password';"#,
         r#"drop user if exists 'test-j'@'localhost';"#,
         r#"alter user 'test-e'@'localhost' identified by 'new-password';"#,
+        r#"alter user 'test-e'@'localhost' with quota max_cpu = 4, max_memory_in_bytes = 1073741824;"#,
         r#"create role 'test'"#,
         r#"drop role if exists 'test'"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
+        r#"ALTER TABLE t ADD COLUMN c int DEFAULT 1;"#,
+        r#"ALTER TABLE t DROP COLUMN c;"#,
+        r#"ALTER TABLE t RENAME COLUMN a TO b;"#,
+        r#"ALTER TABLE t ADD ROW ACCESS POLICY p ON (customer_id);"#,
+        r#"CREATE ROW ACCESS POLICY p AS (customer_id String) RETURNS BOOLEAN -> customer_id = 'c1';"#,
+        r#"DROP ROW ACCESS POLICY IF EXISTS p;"#,
+        r#"ALTER TABLE t MODIFY COLUMN email SET MASKING POLICY m;"#,
+        r#"CREATE MASKING POLICY m AS (val String) RETURNS String -> concat('***', val);"#,
+        r#"DROP MASKING POLICY IF EXISTS m;"#,
+        r#"CREATE NETWORK POLICY np ALLOWED_IP_LIST=('10.0.0.0/8') BLOCKED_IP_LIST=('10.1.0.0/16') COMMENT='office';"#,
+        r#"DROP NETWORK POLICY IF EXISTS np;"#,
+        r#"ALTER USER 'u1'@'%' WITH SET NETWORK POLICY np;"#,
+        r#"ALTER USER 'u1'@'%' WITH UNSET NETWORK POLICY;"#,
         r#"ALTER DATABASE IF EXISTS catalog.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE catalog.c RENAME TO a;"#,
@@ -156,6 +172,7 @@ fn test_statement() {
         r#"GRANT SELECT ON db01.tb1 TO ROLE 'role1';"#,
         r#"GRANT SELECT ON tb1 TO ROLE 'role1';"#,
         r#"GRANT ALL ON tb1 TO 'u1';"#,
+        r#"GRANT SELECT(a, b) ON db01.tb1 TO ROLE 'role1';"#,
         r#"SHOW GRANTS;"#,
         r#"SHOW GRANTS FOR 'test-grant'@'localhost';"#,
         r#"SHOW GRANTS FOR USER 'test-grant'@'localhost';"#,
@@ -163,6 +180,7 @@ fn test_statement() {
         r#"REVOKE SELECT, CREATE ON * FROM 'test-grant'@'localhost';"#,
         r#"REVOKE SELECT ON tb1 FROM ROLE 'role1';"#,
         r#"REVOKE ALL ON tb1 FROM 'u1';"#,
+        r#"REVOKE SELECT(a) ON tb1 FROM ROLE 'role1';"#,
         r#"COPY INTO mytable
                 FROM 's3://mybucket/data.csv'
                 FILE_FORMAT = (
@@ -354,6 +372,10 @@ fn test_query() {
         r#"select * from t1 union select * from t2 intersect select * from t3"#,
         r#"(select * from t1 union select * from t2) union select * from t3"#,
         r#"select * from t1 union (select * from t2 union select * from t3)"#,
+        r#"with t(c) as (select * from a) select * from t"#,
+        r#"select * from t group by grouping sets ((a, b), (a), ())"#,
+        r#"select * from t group by rollup (a, b)"#,
+        r#"select * from t group by cube (a, b)"#,
     ];
 
     for case in cases {
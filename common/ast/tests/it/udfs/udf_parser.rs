@@ -77,6 +77,15 @@ async fn test_udf_parser() -> Result<()> {
             )
             .is_ok()
     );
+    assert!(
+        parser
+            .parse(
+                "test",
+                &["p".to_string()],
+                "CASE WHEN is_null(p) THEN 'null' ELSE p END"
+            )
+            .is_ok()
+    );
 
     Ok(())
 }
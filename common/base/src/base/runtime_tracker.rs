@@ -17,6 +17,9 @@ use std::sync::atomic::AtomicI64;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
+use common_exception::Result;
+
 #[thread_local]
 static mut TRACKER: *mut ThreadTracker = std::ptr::null_mut();
 
@@ -100,6 +103,8 @@ impl ThreadTracker {
 
 pub struct MemoryTracker {
     memory_usage: AtomicI64,
+    // The max memory(bytes) can be used(0 is no limited).
+    memory_limit: AtomicI64,
     parent_memory_tracker: Option<Arc<MemoryTracker>>,
 }
 
@@ -108,9 +113,39 @@ impl MemoryTracker {
         Arc::new(MemoryTracker {
             parent_memory_tracker,
             memory_usage: AtomicI64::new(0),
+            memory_limit: AtomicI64::new(0),
         })
     }
 
+    #[inline]
+    pub fn set_memory_limit(&self, limit: i64) {
+        self.memory_limit.store(limit, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub fn get_memory_limit(&self) -> i64 {
+        self.memory_limit.load(Ordering::Relaxed)
+    }
+
+    /// The allocation cannot fail in the allocator, so the caller should check
+    /// the limit periodically and abort the work if it is exceeded.
+    #[inline]
+    pub fn check_memory_limit(&self) -> Result<()> {
+        let memory_limit = self.get_memory_limit();
+        let memory_usage = self.get_memory_usage();
+        if memory_limit > 0 && memory_usage > memory_limit {
+            return Err(ErrorCode::UserQuotaExceeded(format!(
+                "Memory limit exceeded: memory usage {} bytes, max memory quota {} bytes",
+                memory_usage, memory_limit
+            )));
+        }
+
+        match &self.parent_memory_tracker {
+            None => Ok(()),
+            Some(parent_memory_tracker) => parent_memory_tracker.check_memory_limit(),
+        }
+    }
+
     #[inline]
     pub fn alloc_memory(&self, size: i64) {
         self.memory_usage.fetch_add(size, Ordering::Relaxed);
@@ -16,6 +16,7 @@ use std::sync::Arc;
 use std::sync::Mutex;
 
 use common_base::base::*;
+use common_exception::ErrorCode;
 use common_exception::Result;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
@@ -59,3 +60,24 @@ async fn test_runtime() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_memory_tracker_limit() -> Result<()> {
+    let parent = MemoryTracker::create(None);
+    let tracker = MemoryTracker::create(Some(parent.clone()));
+
+    tracker.alloc_memory(100);
+    assert!(tracker.check_memory_limit().is_ok());
+
+    parent.set_memory_limit(150);
+    assert!(tracker.check_memory_limit().is_ok());
+
+    tracker.alloc_memory(100);
+    let cause = tracker.check_memory_limit().unwrap_err();
+    assert_eq!(cause.code(), ErrorCode::UserQuotaExceeded("").code());
+
+    tracker.dealloc_memory(100);
+    assert!(tracker.check_memory_limit().is_ok());
+
+    Ok(())
+}
@@ -23,17 +23,26 @@ use common_datavalues::chrono;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_fuse_meta::meta::DistributionsOfColumns;
+use common_fuse_meta::meta::StatisticsOfColumns;
 use common_meta_app::schema::TableInfo;
 use common_meta_types::MetaId;
 use common_pipeline::Pipeline;
+use common_planners::AddTableColumnPlan;
+use common_planners::AnalyzeTablePlan;
 use common_planners::DeletePlan;
+use common_planners::DropTableColumnPlan;
 use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::MergePlan;
 use common_planners::OptimizeTablePlan;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
+use common_planners::RenameTableColumnPlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_streams::SendableDataBlockStream;
 
 use crate::table_context::TableContext;
 
@@ -167,10 +176,44 @@ pub trait Table: Sync + Send {
         Ok(())
     }
 
+    /// Called after the writes of a transaction to the table are committed by `COMMIT`,
+    /// the table is the committed version.
+    async fn txn_committed(&self, _ctx: Arc<dyn TableContext>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Purges the files written by a transaction which is rolled back, which are never
+    /// committed, see `TxnManager::buffer_table`. The table is the version pinned by the
+    /// transaction, the data referenced by it are kept.
+    async fn purge_txn_writes(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _written_locations: &[String],
+    ) -> Result<()> {
+        Ok(())
+    }
+
     async fn statistics(&self, _ctx: Arc<dyn TableContext>) -> Result<Option<TableStatistics>> {
         Ok(None)
     }
 
+    /// Returns the statistics of the columns, keyed by the positions of the columns in the schema.
+    async fn column_statistics(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<StatisticsOfColumns>> {
+        Ok(None)
+    }
+
+    /// Returns the distributions of the columns collected by `ANALYZE TABLE`, keyed by the
+    /// positions of the columns in the schema.
+    async fn column_distributions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<DistributionsOfColumns>> {
+        Ok(None)
+    }
+
     async fn navigate_to(
         &self,
         _ctx: Arc<dyn TableContext>,
@@ -191,6 +234,27 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    async fn update(&self, _ctx: Arc<dyn TableContext>, _plan: UpdatePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support UPDATE",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    async fn merge(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _plan: MergePlan,
+        _joined: SendableDataBlockStream,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support MERGE INTO",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     async fn compact(&self, _ctx: Arc<dyn TableContext>, _plan: OptimizeTablePlan) -> Result<()> {
         Err(ErrorCode::UnImplement(format!(
             "table {},  of engine type {}, does not support compact",
@@ -198,6 +262,50 @@ pub trait Table: Sync + Send {
             self.get_table_info().engine(),
         )))
     }
+
+    async fn analyze(&self, _ctx: Arc<dyn TableContext>, _plan: AnalyzeTablePlan) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support ANALYZE TABLE",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    async fn add_column(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _plan: AddTableColumnPlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support ADD COLUMN",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    async fn drop_column(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _plan: DropTableColumnPlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support DROP COLUMN",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
+    async fn rename_column(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _plan: RenameTableColumnPlan,
+    ) -> Result<()> {
+        Err(ErrorCode::UnImplement(format!(
+            "table {},  of engine type {}, does not support RENAME COLUMN",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
 }
 
 #[derive(Debug)]
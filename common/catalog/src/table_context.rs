@@ -42,6 +42,7 @@ use crate::catalog::Catalog;
 use crate::catalog::CatalogManager;
 use crate::cluster_info::Cluster;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 pub struct ProcessInfo {
     pub id: String,
@@ -119,4 +120,6 @@ pub trait TableContext: Send + Sync {
     fn get_user_manager(&self) -> Arc<UserApiProvider>;
     fn get_cluster(&self) -> Arc<Cluster>;
     async fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    /// Get the transaction of the session.
+    fn get_txn_manager(&self) -> TxnManagerRef;
 }
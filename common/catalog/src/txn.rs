@@ -0,0 +1,198 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MetaId;
+use parking_lot::Mutex;
+
+use crate::table::Table;
+
+pub type TxnManagerRef = Arc<TxnManager>;
+
+/// The explicit transaction of a session, started by `BEGIN`.
+///
+/// The tables are pinned at the first time they are accessed in the transaction,
+/// so the statements see the same version of a table, including the writes of
+/// the transaction itself. The new table metas are buffered here instead of being
+/// committed, and are committed together by `COMMIT`.
+#[derive(Default)]
+pub struct TxnManager {
+    state: Mutex<Option<TxnState>>,
+}
+
+#[derive(Default)]
+struct TxnState {
+    tables: BTreeMap<MetaId, TxnTable>,
+}
+
+struct TxnTable {
+    catalog_name: String,
+    // the version of the table when it is pinned, the seq of it is checked by the commit
+    base_table: Arc<dyn Table>,
+    table: Arc<dyn Table>,
+    dirty: bool,
+    written_locations: Vec<String>,
+}
+
+/// The writes of a transaction to a table, returned when the transaction ends.
+pub struct TxnTableWrites {
+    pub catalog_name: String,
+    /// The version of the table pinned by the transaction.
+    pub base_table: Arc<dyn Table>,
+    /// The version of the table written by the transaction.
+    pub table: Arc<dyn Table>,
+    /// The locations written by the transaction, see `TxnManager::buffer_table`.
+    pub written_locations: Vec<String>,
+}
+
+impl TxnTableWrites {
+    /// The new table meta is committed only if the table has not been changed since it
+    /// is pinned, so the concurrent writes to the same table are detected.
+    pub fn update_table_meta_req(&self) -> UpdateTableMetaReq {
+        let base_table_info = self.base_table.get_table_info();
+        UpdateTableMetaReq {
+            table_id: base_table_info.ident.table_id,
+            seq: MatchSeq::Exact(base_table_info.ident.seq),
+            new_table_meta: self.table.get_table_info().meta.clone(),
+        }
+    }
+}
+
+impl TxnManager {
+    pub fn create() -> TxnManagerRef {
+        Arc::new(TxnManager::default())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state.lock().is_some()
+    }
+
+    pub fn begin(&self) -> Result<()> {
+        let mut state = self.state.lock();
+        if state.is_some() {
+            return Err(ErrorCode::BadArguments(
+                "There is already a transaction in progress",
+            ));
+        }
+        *state = Some(TxnState::default());
+        Ok(())
+    }
+
+    /// Returns the version of the table seen by the transaction, the given table
+    /// is pinned if the table has not been accessed yet.
+    ///
+    /// Returns the given table as it is if there is no transaction.
+    pub fn pin_table(&self, catalog_name: &str, table: Arc<dyn Table>) -> Arc<dyn Table> {
+        let mut state = self.state.lock();
+        let state = match state.as_mut() {
+            Some(state) => state,
+            None => return table,
+        };
+
+        let table_info = table.get_table_info();
+        state
+            .tables
+            .entry(table_info.ident.table_id)
+            .or_insert_with(|| TxnTable::new(catalog_name, table.clone()))
+            .table
+            .clone()
+    }
+
+    /// Buffers the new version of the table written by the transaction, `base_table`
+    /// is the version the new one is written upon. `written_locations` are the files
+    /// written for the new version, e.g. the snapshot of a fuse table, which are purged
+    /// if the transaction is rolled back, see `Table::purge_txn_writes`.
+    ///
+    /// Returns false if there is no transaction, the caller should commit the new
+    /// table meta right away.
+    pub fn buffer_table(
+        &self,
+        catalog_name: &str,
+        base_table: Arc<dyn Table>,
+        new_table: Arc<dyn Table>,
+        written_locations: Vec<String>,
+    ) -> Result<bool> {
+        let mut state = self.state.lock();
+        let state = match state.as_mut() {
+            Some(state) => state,
+            None => return Ok(false),
+        };
+
+        let table_info = new_table.get_table_info();
+        let txn_table = state
+            .tables
+            .entry(table_info.ident.table_id)
+            .or_insert_with(|| TxnTable::new(catalog_name, base_table));
+        if txn_table.catalog_name != catalog_name {
+            return Err(ErrorCode::LogicalError(format!(
+                "table {} is accessed through different catalogs in the transaction",
+                table_info.name
+            )));
+        }
+        txn_table.table = new_table;
+        txn_table.dirty = true;
+        txn_table.written_locations.extend(written_locations);
+        Ok(true)
+    }
+
+    /// Ends the transaction, returns the writes to be committed.
+    ///
+    /// Nothing is returned if there is no transaction in progress.
+    pub fn commit(&self) -> Vec<TxnTableWrites> {
+        self.take_writes()
+    }
+
+    /// Ends the transaction and returns the writes of it to be discarded, the caller
+    /// should purge the written locations, which are never committed.
+    pub fn rollback(&self) -> Vec<TxnTableWrites> {
+        self.take_writes()
+    }
+
+    fn take_writes(&self) -> Vec<TxnTableWrites> {
+        let state = match self.state.lock().take() {
+            Some(state) => state,
+            None => return vec![],
+        };
+
+        state
+            .tables
+            .into_values()
+            .filter(|txn_table| txn_table.dirty)
+            .map(|txn_table| TxnTableWrites {
+                catalog_name: txn_table.catalog_name,
+                base_table: txn_table.base_table,
+                table: txn_table.table,
+                written_locations: txn_table.written_locations,
+            })
+            .collect()
+    }
+}
+
+impl TxnTable {
+    fn new(catalog_name: &str, table: Arc<dyn Table>) -> TxnTable {
+        TxnTable {
+            catalog_name: catalog_name.to_string(),
+            base_table: table.clone(),
+            table,
+            dirty: false,
+            written_locations: vec![],
+        }
+    }
+}
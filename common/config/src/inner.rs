@@ -141,6 +141,8 @@ pub struct QueryConfig {
     pub table_cache_block_meta_count: u64,
     /// Table memory cache size (mb)
     pub table_memory_cache_mb_size: u64,
+    /// Table disk cache enabled, column chunks of data blocks are cached on local disk
+    pub table_disk_cache_enabled: bool,
     /// Table disk cache folder root
     pub table_disk_cache_root: String,
     /// Table disk cache size (mb)
@@ -151,6 +153,11 @@ pub struct QueryConfig {
     pub async_insert_max_data_size: u64,
     pub async_insert_busy_timeout: u64,
     pub async_insert_stale_timeout: u64,
+    pub audit_log_enabled: bool,
+    pub audit_log_dir: String,
+    pub audit_log_max_file_size_mb: u64,
+    pub audit_log_max_files: u64,
+    pub audit_log_table: String,
 }
 
 impl Default for QueryConfig {
@@ -191,6 +198,7 @@ impl Default for QueryConfig {
             table_cache_segment_count: 10240,
             table_cache_block_meta_count: 102400,
             table_memory_cache_mb_size: 256,
+            table_disk_cache_enabled: false,
             table_disk_cache_root: "_cache".to_string(),
             table_disk_cache_mb_size: 1024,
             management_mode: false,
@@ -198,6 +206,11 @@ impl Default for QueryConfig {
             async_insert_max_data_size: 10000,
             async_insert_busy_timeout: 200,
             async_insert_stale_timeout: 0,
+            audit_log_enabled: false,
+            audit_log_dir: "./.databend/audit".to_string(),
+            audit_log_max_file_size_mb: 100,
+            audit_log_max_files: 10,
+            audit_log_table: "".to_string(),
         }
     }
 }
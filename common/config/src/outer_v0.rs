@@ -620,6 +620,10 @@ pub struct QueryConfig {
     #[clap(long, default_value = "256")]
     pub table_memory_cache_mb_size: u64,
 
+    /// Table disk cache enabled, column chunks of data blocks are cached on local disk
+    #[clap(long)]
+    pub table_disk_cache_enabled: bool,
+
     /// Table disk cache folder root
     #[clap(long, default_value = "_cache")]
     pub table_disk_cache_root: String,
@@ -646,6 +650,26 @@ pub struct QueryConfig {
     /// The maximum timeout in milliseconds since the last insert before inserting collected data.
     #[clap(long, default_value = "0")]
     pub async_insert_stale_timeout: u64,
+
+    /// Enable the audit log of logins and privileged statements.
+    #[clap(long)]
+    pub audit_log_enabled: bool,
+
+    /// The directory of the rotating audit log files.
+    #[clap(long, default_value = "./.databend/audit")]
+    pub audit_log_dir: String,
+
+    /// The maximum size in megabytes of an audit log file before it is rotated.
+    #[clap(long, default_value = "100")]
+    pub audit_log_max_file_size_mb: u64,
+
+    /// The number of rotated audit log files to keep.
+    #[clap(long, default_value = "10")]
+    pub audit_log_max_files: u64,
+
+    /// The fuse table (`database.table`) audit events are also appended into, disabled if empty.
+    #[clap(long, default_value_t)]
+    pub audit_log_table: String,
 }
 
 impl Default for QueryConfig {
@@ -658,6 +682,12 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
     type Error = ErrorCode;
 
     fn try_into(self) -> Result<InnerQueryConfig> {
+        if self.audit_log_max_files == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "audit_log_max_files must be greater than 0",
+            ));
+        }
+
         Ok(InnerQueryConfig {
             tenant_id: self.tenant_id,
             cluster_id: self.cluster_id,
@@ -694,6 +724,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_cache_segment_count: self.table_cache_segment_count,
             table_cache_block_meta_count: self.table_cache_block_meta_count,
             table_memory_cache_mb_size: self.table_memory_cache_mb_size,
+            table_disk_cache_enabled: self.table_disk_cache_enabled,
             table_disk_cache_root: self.table_disk_cache_root,
             table_disk_cache_mb_size: self.table_disk_cache_mb_size,
             management_mode: self.management_mode,
@@ -701,6 +732,11 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             async_insert_max_data_size: self.async_insert_max_data_size,
             async_insert_busy_timeout: self.async_insert_busy_timeout,
             async_insert_stale_timeout: self.async_insert_stale_timeout,
+            audit_log_enabled: self.audit_log_enabled,
+            audit_log_dir: self.audit_log_dir,
+            audit_log_max_file_size_mb: self.audit_log_max_file_size_mb,
+            audit_log_max_files: self.audit_log_max_files,
+            audit_log_table: self.audit_log_table,
         })
     }
 }
@@ -743,6 +779,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_cache_segment_count: inner.table_cache_segment_count,
             table_cache_block_meta_count: inner.table_cache_block_meta_count,
             table_memory_cache_mb_size: inner.table_memory_cache_mb_size,
+            table_disk_cache_enabled: inner.table_disk_cache_enabled,
             table_disk_cache_root: inner.table_disk_cache_root,
             table_disk_cache_mb_size: inner.table_disk_cache_mb_size,
             management_mode: inner.management_mode,
@@ -750,6 +787,11 @@ impl From<InnerQueryConfig> for QueryConfig {
             async_insert_max_data_size: inner.async_insert_max_data_size,
             async_insert_busy_timeout: inner.async_insert_busy_timeout,
             async_insert_stale_timeout: inner.async_insert_stale_timeout,
+            audit_log_enabled: inner.audit_log_enabled,
+            audit_log_dir: inner.audit_log_dir,
+            audit_log_max_file_size_mb: inner.audit_log_max_file_size_mb,
+            audit_log_max_files: inner.audit_log_max_files,
+            audit_log_table: inner.audit_log_table,
         }
     }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::iter::once;
 
 use common_arrow::arrow::array::ord as arrow_ord;
@@ -142,6 +143,86 @@ impl DataBlock {
         Ok(DataBlock::create(lhs.schema().clone(), columns))
     }
 
+    /// Compare the `lhs_row`-th row of `lhs` with the `rhs_row`-th row of `rhs` by the sort columns.
+    pub fn compare_rows(
+        lhs: &DataBlock,
+        lhs_row: usize,
+        rhs: &DataBlock,
+        rhs_row: usize,
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<Ordering> {
+        Self::with_rows_comparator(lhs, rhs, sort_columns_descriptions, |comparator| {
+            comparator(0, lhs_row, 1, rhs_row)
+        })
+    }
+
+    /// Returns the number of leading rows of the sorted `block` which are not greater than
+    /// the `row`-th row of `bound`.
+    pub fn sorted_rows_not_greater_than(
+        block: &DataBlock,
+        bound: &DataBlock,
+        row: usize,
+        sort_columns_descriptions: &[SortColumnDescription],
+    ) -> Result<usize> {
+        Self::with_rows_comparator(block, bound, sort_columns_descriptions, |comparator| {
+            let mut low = 0;
+            let mut high = block.num_rows();
+            while low < high {
+                let mid = low + (high - low) / 2;
+                match comparator(0, mid, 1, row) {
+                    Ordering::Greater => high = mid,
+                    _ => low = mid + 1,
+                }
+            }
+            low
+        })
+    }
+
+    fn with_rows_comparator<R>(
+        lhs: &DataBlock,
+        rhs: &DataBlock,
+        sort_columns_descriptions: &[SortColumnDescription],
+        f: impl FnOnce(&dyn Fn(usize, usize, usize, usize) -> Ordering) -> R,
+    ) -> Result<R> {
+        let sort_arrays = sort_columns_descriptions
+            .iter()
+            .map(|f| {
+                let left = lhs.try_column_by_name(&f.column_name)?.clone();
+                let left = left.as_arrow_array(left.data_type());
+
+                let right = rhs.try_column_by_name(&f.column_name)?.clone();
+                let right = right.as_arrow_array(right.data_type());
+
+                Ok(vec![left, right])
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let sort_dyn_arrays = sort_arrays
+            .iter()
+            .map(|f| vec![f[0].as_ref(), f[1].as_ref()])
+            .collect::<Vec<_>>();
+
+        let sort_options = sort_columns_descriptions
+            .iter()
+            .map(|f| arrow_sort::SortOptions {
+                descending: !f.asc,
+                nulls_first: f.nulls_first,
+            })
+            .collect::<Vec<_>>();
+
+        let sort_options_with_array = sort_dyn_arrays
+            .iter()
+            .zip(sort_options.iter())
+            .map(|(s, opt)| {
+                let paris: (&[&dyn Array], &SortOptions) = (s, opt);
+                paris
+            })
+            .collect::<Vec<_>>();
+
+        let comparator = build_comparator_impl(&sort_options_with_array, &build_compare)?;
+        Ok(f(&comparator))
+    }
+
     pub fn merge_sort_blocks(
         blocks: &[DataBlock],
         sort_columns_descriptions: &[SortColumnDescription],
@@ -182,6 +263,17 @@ fn compare_variant(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynCompar
     }))
 }
 
+fn compare_decimal256(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
+    let left = Decimal256Column::try_from_arrow_array(left)
+        .map_err(|e| ArrowError::InvalidArgumentError(e.message()))?;
+    let right = Decimal256Column::try_from_arrow_array(right)
+        .map_err(|e| ArrowError::InvalidArgumentError(e.message()))?;
+
+    Ok(Box::new(move |i, j| {
+        left.get_data(i).cmp(right.get_data(j))
+    }))
+}
+
 fn compare_array(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparator> {
     let left = ArrayColumn::from_arrow_array(left);
     let right = ArrayColumn::from_arrow_array(right);
@@ -197,6 +289,8 @@ fn build_compare(left: &dyn Array, right: &dyn Array) -> ArrowResult<DynComparat
         ArrowType::Extension(name, _, _) => {
             if name == "Variant" || name == "VariantArray" || name == "VariantObject" {
                 compare_variant(left, right)
+            } else if name == "Decimal256" {
+                compare_decimal256(left, right)
             } else {
                 Err(ArrowError::NotYetImplemented(format!(
                     "Sort not supported for data type {:?}",
@@ -374,3 +374,44 @@ fn test_data_block_merge_sort() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_data_block_compare_rows() -> Result<()> {
+    let schema = DataSchemaRefExt::create(vec![DataField::new("a", i64::to_data_type())]);
+
+    let lhs = DataBlock::create(schema.clone(), vec![Series::from_data(vec![1i64, 3, 3, 5, 7])]);
+    let rhs = DataBlock::create(schema, vec![Series::from_data(vec![2i64, 3, 6])]);
+
+    let options = vec![SortColumnDescription {
+        column_name: "a".to_owned(),
+        asc: true,
+        nulls_first: false,
+    }];
+
+    assert_eq!(
+        DataBlock::compare_rows(&lhs, 0, &rhs, 0, &options)?,
+        std::cmp::Ordering::Less
+    );
+    assert_eq!(
+        DataBlock::compare_rows(&lhs, 1, &rhs, 1, &options)?,
+        std::cmp::Ordering::Equal
+    );
+    assert_eq!(
+        DataBlock::compare_rows(&lhs, 4, &rhs, 2, &options)?,
+        std::cmp::Ordering::Greater
+    );
+
+    assert_eq!(
+        DataBlock::sorted_rows_not_greater_than(&lhs, &rhs, 0, &options)?,
+        1
+    );
+    assert_eq!(
+        DataBlock::sorted_rows_not_greater_than(&lhs, &rhs, 1, &options)?,
+        3
+    );
+    assert_eq!(
+        DataBlock::sorted_rows_not_greater_than(&lhs, &rhs, 2, &options)?,
+        4
+    );
+    Ok(())
+}
@@ -61,7 +61,24 @@ impl ArrayColumn {
         }
     }
 
+    /// Map array is stored as an array of the entries struct, the i32 offsets
+    /// of arrow map array are widened to i64.
+    pub fn from_map_array(array: &MapArray) -> Self {
+        let data_type = from_arrow_type(array.data_type());
+        let values = array.field().clone().into_column();
+        let offsets: Vec<i64> = array.offsets().iter().map(|v| *v as i64).collect();
+
+        Self {
+            data_type,
+            offsets: offsets.into(),
+            values,
+        }
+    }
+
     pub fn from_arrow_array(array: &dyn Array) -> Self {
+        if let Some(array) = array.as_any().downcast_ref::<MapArray>() {
+            return Self::from_map_array(array);
+        }
         Self::new(
             array
                 .as_any()
@@ -105,13 +122,24 @@ impl Column for ArrayColumn {
     }
 
     fn column_type_name(&self) -> String {
-        "Array".to_string()
+        match self.data_type {
+            DataTypeImpl::Map(_) => "Map".to_string(),
+            _ => "Array".to_string(),
+        }
     }
 
     fn column_meta(&self) -> ColumnMeta {
-        let data_type: ArrayType = self.data_type.clone().try_into().unwrap();
-        ColumnMeta::Array {
-            inner_type: data_type.inner_type().clone(),
+        match &self.data_type {
+            DataTypeImpl::Map(map_type) => ColumnMeta::Map {
+                key_type: map_type.key_type().clone(),
+                value_type: map_type.value_type().clone(),
+            },
+            _ => {
+                let data_type: ArrayType = self.data_type.clone().try_into().unwrap();
+                ColumnMeta::Array {
+                    inner_type: data_type.inner_type().clone(),
+                }
+            }
         }
     }
 
@@ -125,17 +153,24 @@ impl Column for ArrayColumn {
 
     fn as_arrow_array(&self, data_type: DataTypeImpl) -> ArrayRef {
         let arrow_type = data_type.arrow_type();
-        if let ArrowType::LargeList(ref f) = arrow_type {
-            let inner_f = from_arrow_field(f.as_ref());
-            let array = self.values.as_arrow_array(inner_f);
-            Box::new(LargeListArray::from_data(
-                arrow_type,
-                self.offsets.clone(),
-                array,
-                None,
-            ))
-        } else {
-            unreachable!()
+        match arrow_type {
+            ArrowType::LargeList(ref f) => {
+                let inner_f = from_arrow_field(f.as_ref());
+                let array = self.values.as_arrow_array(inner_f);
+                Box::new(LargeListArray::from_data(
+                    arrow_type,
+                    self.offsets.clone(),
+                    array,
+                    None,
+                ))
+            }
+            ArrowType::Map(ref f, _) => {
+                let entries_f = from_arrow_field(f.as_ref());
+                let array = self.values.as_arrow_array(entries_f);
+                let offsets: Vec<i32> = self.offsets.iter().map(|v| *v as i32).collect();
+                Box::new(MapArray::from_data(arrow_type, offsets.into(), array, None))
+            }
+            _ => unreachable!(),
         }
     }
 
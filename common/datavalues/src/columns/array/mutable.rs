@@ -21,6 +21,7 @@ use crate::prelude::*;
 use crate::types::DataTypeImpl;
 
 pub struct MutableArrayColumn {
+    data_type: DataTypeImpl,
     inner_data_type: DataTypeImpl,
     last_offset: usize,
     offsets: Vec<i64>,
@@ -82,7 +83,7 @@ impl Default for MutableArrayColumn {
 
 impl MutableColumn for MutableArrayColumn {
     fn data_type(&self) -> DataTypeImpl {
-        ArrayType::new_impl(self.inner_data_type.clone())
+        self.data_type.clone()
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -152,19 +153,31 @@ impl ScalarColumnBuilder for MutableArrayColumn {
     }
 
     fn with_capacity_meta(capacity: usize, meta: ColumnMeta) -> Self {
-        match meta {
+        // Map is stored as an array of Struct(key, value).
+        let (data_type, inner_type) = match meta {
             ColumnMeta::Array { inner_type } => {
-                let mut offsets = Vec::with_capacity(capacity + 1);
-                offsets.push(0);
-
-                Self {
-                    inner_data_type: inner_type.clone(),
-                    last_offset: 0,
-                    offsets,
-                    inner_column: inner_type.create_mutable(capacity),
-                }
+                (ArrayType::new_impl(inner_type.clone()), inner_type)
+            }
+            ColumnMeta::Map {
+                key_type,
+                value_type,
+            } => {
+                let map_type = MapType::create(key_type, value_type);
+                let inner_type = map_type.entries_type();
+                (DataTypeImpl::Map(map_type), inner_type)
             }
-            _ => panic!("must be ColumnMeta::Array"),
+            _ => panic!("must be ColumnMeta::Array or ColumnMeta::Map"),
+        };
+
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+
+        Self {
+            data_type,
+            inner_column: inner_type.create_mutable(capacity),
+            inner_data_type: inner_type,
+            last_offset: 0,
+            offsets,
         }
     }
 
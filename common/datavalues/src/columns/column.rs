@@ -197,7 +197,9 @@ where A: AsRef<dyn Array>
             Int64 | Interval | Timestamp => Arc::new(Int64Column::from_arrow_array(self.as_ref())),
             Float32 => Arc::new(Float32Column::from_arrow_array(self.as_ref())),
             Float64 => Arc::new(Float64Column::from_arrow_array(self.as_ref())),
-            Array => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
+            Decimal => Arc::new(Int128Column::from_arrow_array(self.as_ref())),
+            Decimal256 => Arc::new(Decimal256Column::from_arrow_array(self.as_ref())),
+            Array | Map => Arc::new(ArrayColumn::from_arrow_array(self.as_ref())),
             Struct => Arc::new(StructColumn::from_arrow_array(self.as_ref())),
             String => Arc::new(StringColumn::from_arrow_array(self.as_ref())),
             Variant => Arc::new(VariantColumn::from_arrow_array(self.as_ref())),
@@ -261,10 +263,16 @@ impl std::fmt::Debug for dyn Column + '_ {
                 Boolean => {
                     fmt_dyn!(col, BooleanColumn, f)
                 },
+                Decimal => {
+                    fmt_dyn!(col, Int128Column, f)
+                },
+                Decimal256 => {
+                    fmt_dyn!(col, Decimal256Column, f)
+                },
                 String => {
                     fmt_dyn!(col, StringColumn, f)
                 },
-                Array => {
+                Array | Map => {
                     fmt_dyn!(col, ArrayColumn, f)
                 },
                 Struct => {
@@ -19,7 +19,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::array::Array;
 use common_arrow::arrow::bitmap::Bitmap;
-use common_arrow::arrow::buffer::Buffer;
+use common_exception::Result;
 use common_io::prelude::BinaryWrite;
 pub use iterator::*;
 pub use mutable::*;
@@ -41,37 +41,22 @@ impl<T: ObjectType> From<LargeBinaryArray> for ObjectColumn<T> {
 
 impl<T: ObjectType> ObjectColumn<T> {
     pub fn new(array: LargeBinaryArray) -> Self {
-        let mut values: Vec<T> = Vec::with_capacity(array.len());
-        let offsets = array.offsets().as_slice();
-        let array_values = array.values().as_slice();
-        for i in 0..offsets.len() - 1 {
-            if let Some(validity) = array.validity() {
-                if let Some(is_set) = validity.get(i) {
-                    if !is_set {
-                        values.push(T::default());
-                        continue;
-                    }
-                }
-            }
-            let off = offsets[i] as usize;
-            let len = (offsets[i + 1] - offsets[i]) as usize;
-            let val = std::str::from_utf8(&array_values[off..off + len]).unwrap();
-            match T::from_str(val) {
-                Ok(v) => values.push(v),
-                Err(_) => values.push(T::default()),
-            }
-        }
-
-        Self { values }
+        Self::from_arrow_array(&array)
     }
 
+    /// Panics if the values can't be read from the array, use `try_from_arrow_array`
+    /// to get the error instead.
     pub fn from_arrow_array(array: &dyn Array) -> Self {
-        let array = array
-            .as_any()
-            .downcast_ref::<LargeBinaryArray>()
-            .expect("object cast should be ok");
+        match Self::try_from_arrow_array(array) {
+            Ok(column) => column,
+            Err(cause) => panic!("Failed to read {}: {}", T::column_name(), cause.message()),
+        }
+    }
 
-        Self::new(array.clone())
+    pub fn try_from_arrow_array(array: &dyn Array) -> Result<Self> {
+        Ok(Self {
+            values: T::from_arrow_array(array)?,
+        })
     }
 
     /// # Safety
@@ -118,24 +103,7 @@ impl<T: ObjectType> Column for ObjectColumn<T> {
     }
 
     fn as_arrow_array(&self, logical_type: DataTypeImpl) -> common_arrow::ArrayRef {
-        let mut offsets: Vec<i64> = Vec::with_capacity(self.values.len());
-        let mut values: Vec<u8> = Vec::with_capacity(self.values.len());
-
-        let mut offset: i64 = 0;
-        offsets.push(offset);
-        for val in &self.values {
-            let v = val.to_string();
-            values.extend(v.as_bytes().to_vec());
-            offset += v.len() as i64;
-            offsets.push(offset);
-        }
-
-        Box::new(LargeBinaryArray::from_data(
-            logical_type.arrow_type(),
-            Buffer::from(offsets),
-            Buffer::from(values),
-            None,
-        ))
+        T::to_arrow_array(&self.values, logical_type.arrow_type())
     }
 
     fn arc(&self) -> ColumnRef {
@@ -247,6 +215,7 @@ where T: Scalar<ColumnType = Self> + ObjectType
 }
 
 pub type VariantColumn = ObjectColumn<VariantValue>;
+pub type Decimal256Column = ObjectColumn<I256>;
 
 impl<T: ObjectType> std::fmt::Debug for ObjectColumn<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -122,6 +122,11 @@ impl<T: PrimitiveType> PrimitiveColumn<T> {
                     let array = unary(array, |x| x as i64 * p.0 / p.1, expected_arrow);
                     Self::from_arrow_array(&array)
                 }
+                // decimal column is i128 for all the precisions and scales
+                ArrowDataType::Decimal(_, _) => {
+                    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+                    Self::new(array.clone())
+                }
                 _ => unreachable!(),
             }
         } else {
@@ -385,6 +390,7 @@ pub type Int8Column = PrimitiveColumn<i8>;
 pub type Int16Column = PrimitiveColumn<i16>;
 pub type Int32Column = PrimitiveColumn<i32>;
 pub type Int64Column = PrimitiveColumn<i64>;
+pub type Int128Column = PrimitiveColumn<i128>;
 
 pub type Float32Column = PrimitiveColumn<f32>;
 pub type Float64Column = PrimitiveColumn<f64>;
@@ -221,6 +221,12 @@ impl SeriesFrom<Vec<Option<VariantValue>>, Vec<Option<VariantValue>>> for Series
     }
 }
 
+impl SeriesFrom<Vec<I256>, Vec<I256>> for Series {
+    fn from_data(v: Vec<I256>) -> ColumnRef {
+        Decimal256Column::new_from_vec(v).arc()
+    }
+}
+
 impl SeriesFrom<Vec<ArrayValue>, Vec<ArrayValue>> for Series {
     fn from_data(vals: Vec<ArrayValue>) -> ColumnRef {
         let inner_data_type = match vals.iter().find(|&x| x.inner_type().is_some()) {
@@ -37,6 +37,10 @@ pub enum DataValue {
     UInt64(u64),
     Float64(f64),
     String(Vec<u8>),
+    /// Unscaled value of decimal, the scale is kept in the data type.
+    Int128(i128),
+    /// Unscaled value of decimal whose precision is larger than 38.
+    Int256(I256),
 
     // Container struct.
     Array(Vec<DataValue>),
@@ -56,6 +60,8 @@ pub enum ValueType {
     Int64,
     Float64,
     String,
+    Int128,
+    Int256,
     Array,
     Struct,
     Variant,
@@ -86,6 +92,8 @@ impl DataValue {
             DataValue::UInt64(_) => ValueType::UInt64,
             DataValue::Float64(_) => ValueType::Float64,
             DataValue::String(_) => ValueType::String,
+            DataValue::Int128(_) => ValueType::Int128,
+            DataValue::Int256(_) => ValueType::Int256,
             DataValue::Array(_) => ValueType::Array,
             DataValue::Struct(_) => ValueType::Struct,
             DataValue::Variant(_) => ValueType::Variant,
@@ -123,6 +131,8 @@ impl DataValue {
             }
             DataValue::Float64(_) => Float64Type::new_impl(),
             DataValue::String(_) => StringType::new_impl(),
+            DataValue::Int128(_) => DecimalType::new_impl(DECIMAL128_MAX_PRECISION, 0),
+            DataValue::Int256(_) => DecimalType::new_impl(DECIMAL_MAX_PRECISION, 0),
             DataValue::Array(vals) => {
                 let inner_type = if vals.is_empty() {
                     NullType::new_impl()
@@ -152,6 +162,8 @@ impl DataValue {
             DataValue::UInt64(_) => UInt64Type::new_impl(),
             DataValue::Float64(_) => Float64Type::new_impl(),
             DataValue::String(_) => StringType::new_impl(),
+            DataValue::Int128(_) => DecimalType::new_impl(DECIMAL128_MAX_PRECISION, 0),
+            DataValue::Int256(_) => DecimalType::new_impl(DECIMAL_MAX_PRECISION, 0),
             DataValue::Array(vals) => {
                 let inner_type = if vals.is_empty() {
                     NullType::new_impl()
@@ -222,6 +234,31 @@ impl DataValue {
         }
     }
 
+    pub fn as_i128(&self) -> Result<i128> {
+        match self {
+            DataValue::Int128(v) => Ok(*v),
+            DataValue::Int64(v) => Ok(*v as i128),
+            DataValue::UInt64(v) => Ok(*v as i128),
+            other => Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to get i128 number",
+                other.value_type()
+            ))),
+        }
+    }
+
+    pub fn as_i256(&self) -> Result<I256> {
+        match self {
+            DataValue::Int256(v) => Ok(*v),
+            DataValue::Int128(v) => Ok(I256::from(*v)),
+            DataValue::Int64(v) => Ok(I256::from(*v)),
+            DataValue::UInt64(v) => Ok(I256::from(*v)),
+            other => Result::Err(ErrorCode::BadDataValueType(format!(
+                "Unexpected type:{:?} to get i256 number",
+                other.value_type()
+            ))),
+        }
+    }
+
     pub fn as_bool(&self) -> Result<bool> {
         match self {
             DataValue::Boolean(v) => Ok(*v),
@@ -249,6 +286,8 @@ impl DataValue {
             DataValue::Int64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::UInt64(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::Float64(v) => Ok(Vec::<u8>::from((*v).to_string())),
+            DataValue::Int128(v) => Ok(Vec::<u8>::from((*v).to_string())),
+            DataValue::Int256(v) => Ok(Vec::<u8>::from((*v).to_string())),
             DataValue::String(v) => Ok(v.to_owned()),
             DataValue::Variant(v) => Ok(v.to_string().into_bytes()),
             other => Result::Err(ErrorCode::BadDataValueType(format!(
@@ -319,6 +358,8 @@ impl Ord for DataValue {
                     OrderedFloat::from(*v1).cmp(&OrderedFloat::from(*v2))
                 }
                 (DataValue::String(v1), DataValue::String(v2)) => v1.cmp(v2),
+                (DataValue::Int128(v1), DataValue::Int128(v2)) => v1.cmp(v2),
+                (DataValue::Int256(v1), DataValue::Int256(v2)) => v1.cmp(v2),
                 (DataValue::Array(v1), DataValue::Array(v2)) => {
                     for (l, r) in v1.iter().zip(v2) {
                         let cmp = l.cmp(r);
@@ -395,6 +436,8 @@ impl DFTryFrom<DataValue> for VariantValue {
             DataValue::UInt64(v) => Ok(VariantValue::from(json!(v))),
             DataValue::Float64(v) => Ok(VariantValue::from(json!(v))),
             DataValue::String(v) => Ok(VariantValue::from(json!(v))),
+            DataValue::Int128(v) => Ok(VariantValue::from(json!(v as f64))),
+            DataValue::Int256(v) => Ok(VariantValue::from(json!(v.as_f64()))),
             DataValue::Array(v) => Ok(VariantValue::from(json!(v))),
             DataValue::Struct(v) => Ok(VariantValue::from(json!(v))),
             DataValue::Variant(v) => Ok(v),
@@ -413,6 +456,8 @@ impl DFTryFrom<&DataValue> for VariantValue {
             DataValue::String(v) => Ok(VariantValue::from(json!(
                 String::from_utf8(v.to_vec()).unwrap()
             ))),
+            DataValue::Int128(v) => Ok(VariantValue::from(json!(*v as f64))),
+            DataValue::Int256(v) => Ok(VariantValue::from(json!(v.as_f64()))),
             DataValue::Array(v) => Ok(VariantValue::from(json!(*v))),
             DataValue::Struct(v) => Ok(VariantValue::from(json!(*v))),
             DataValue::Variant(v) => Ok(v.to_owned()),
@@ -429,6 +474,7 @@ try_cast_data_value_to_std!(i8, as_i64);
 try_cast_data_value_to_std!(i16, as_i64);
 try_cast_data_value_to_std!(i32, as_i64);
 try_cast_data_value_to_std!(i64, as_i64);
+try_cast_data_value_to_std!(i128, as_i128);
 
 try_cast_data_value_to_std!(f32, as_f64);
 try_cast_data_value_to_std!(f64, as_f64);
@@ -438,12 +484,40 @@ std_to_data_value!(Int64, i8, i64);
 std_to_data_value!(Int64, i16, i64);
 std_to_data_value!(Int64, i32, i64);
 std_to_data_value!(Int64, i64, i64);
+std_to_data_value!(Int128, i128, i128);
 std_to_data_value!(UInt64, u8, u64);
 std_to_data_value!(UInt64, u16, u64);
 std_to_data_value!(UInt64, u32, u64);
 std_to_data_value!(UInt64, u64, u64);
 std_to_data_value!(Float64, f32, f64);
 std_to_data_value!(Float64, f64, f64);
+
+impl DFTryFrom<DataValue> for I256 {
+    fn try_from(value: DataValue) -> Result<Self> {
+        value.as_i256()
+    }
+}
+
+impl DFTryFrom<&DataValue> for I256 {
+    fn try_from(value: &DataValue) -> Result<Self> {
+        value.as_i256()
+    }
+}
+
+impl From<I256> for DataValue {
+    fn from(value: I256) -> Self {
+        DataValue::Int256(value)
+    }
+}
+
+impl From<Option<I256>> for DataValue {
+    fn from(value: Option<I256>) -> Self {
+        match value {
+            Some(v) => DataValue::Int256(v),
+            None => DataValue::Null,
+        }
+    }
+}
 std_to_data_value!(Boolean, bool, bool);
 
 impl From<&[u8]> for DataValue {
@@ -488,6 +562,8 @@ impl fmt::Display for DataValue {
             DataValue::Float64(v) => write!(f, "{}", v),
             DataValue::Int64(v) => write!(f, "{}", v),
             DataValue::UInt64(v) => write!(f, "{}", v),
+            DataValue::Int128(v) => write!(f, "{}", v),
+            DataValue::Int256(v) => write!(f, "{}", v),
             DataValue::String(v) => match std::str::from_utf8(v) {
                 Ok(v) => write!(f, "{}", v),
                 Err(_e) => {
@@ -530,6 +606,8 @@ impl fmt::Debug for DataValue {
             DataValue::Int64(v) => write!(f, "{}", v),
             DataValue::UInt64(v) => write!(f, "{}", v),
             DataValue::Float64(v) => write!(f, "{}", v),
+            DataValue::Int128(v) => write!(f, "{}", v),
+            DataValue::Int256(v) => write!(f, "{}", v),
             DataValue::String(_) => write!(f, "{}", self),
             DataValue::Array(_) => write!(f, "{}", self),
             DataValue::Struct(_) => write!(f, "{}", self),
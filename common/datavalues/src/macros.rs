@@ -21,6 +21,8 @@ macro_rules! for_all_scalar_types {
             { i16 },
             { i32 },
             { i64 },
+            { i128 },
+            { I256 },
             { u8 },
             { u16 },
             { u32 },
@@ -186,6 +188,8 @@ macro_rules! with_match_scalar_type {
             PhysicalTypeID::Int16 => __with_ty__! { i16 },
             PhysicalTypeID::Int32 => __with_ty__! { i32 },
             PhysicalTypeID::Int64 => __with_ty__! { i64 },
+            PhysicalTypeID::Int128 => __with_ty__! { i128 },
+            PhysicalTypeID::Int256 => __with_ty__! { I256 },
             PhysicalTypeID::UInt8 => __with_ty__! { u8 },
             PhysicalTypeID::UInt16 => __with_ty__! { u16 },
             PhysicalTypeID::UInt32 => __with_ty__! { u32 },
@@ -216,6 +220,8 @@ macro_rules! with_match_scalar_types_error {(
         PhysicalTypeID::Int16 => __with_ty__! { i16 },
         PhysicalTypeID::Int32 => __with_ty__! { i32 },
         PhysicalTypeID::Int64 => __with_ty__! { i64 },
+        PhysicalTypeID::Int128 => __with_ty__! { i128 },
+        PhysicalTypeID::Int256 => __with_ty__! { I256 },
         PhysicalTypeID::UInt8 => __with_ty__! { u8 },
         PhysicalTypeID::UInt16 => __with_ty__! { u16 },
         PhysicalTypeID::UInt32 => __with_ty__! { u32 },
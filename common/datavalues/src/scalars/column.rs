@@ -115,4 +115,8 @@ pub enum ColumnMeta {
         inner_names: Option<Vec<String>>,
         inner_types: Vec<DataTypeImpl>,
     },
+    Map {
+        key_type: DataTypeImpl,
+        value_type: DataTypeImpl,
+    },
 }
@@ -98,6 +98,7 @@ impl_primitive_scalar_type!(i8);
 impl_primitive_scalar_type!(i16);
 impl_primitive_scalar_type!(i32);
 impl_primitive_scalar_type!(i64);
+impl_primitive_scalar_type!(i128);
 impl_primitive_scalar_type!(f32);
 impl_primitive_scalar_type!(f64);
 
@@ -189,6 +190,33 @@ impl<'a> ScalarRef<'a> for &'a VariantValue {
     }
 }
 
+impl Scalar for I256 {
+    type ColumnType = ObjectColumn<I256>;
+    type RefType<'a> = &'a I256;
+    type Viewer<'a> = ObjectViewer<'a, I256>;
+
+    #[inline]
+    fn as_scalar_ref(&self) -> &I256 {
+        self
+    }
+
+    #[allow(clippy::needless_lifetimes)]
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: &'long I256) -> &'short I256 {
+        long
+    }
+}
+
+impl<'a> ScalarRef<'a> for &'a I256 {
+    type ColumnType = ObjectColumn<I256>;
+    type ScalarType = I256;
+
+    #[inline]
+    fn to_owned_scalar(&self) -> I256 {
+        **self
+    }
+}
+
 impl Scalar for ArrayValue {
     type ColumnType = ArrayColumn;
     type RefType<'a> = ArrayValueRef<'a>;
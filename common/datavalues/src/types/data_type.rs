@@ -63,11 +63,13 @@ pub enum DataTypeImpl {
     UInt64(UInt64Type),
     Float32(Float32Type),
     Float64(Float64Type),
+    Decimal(DecimalType),
     Date(DateType),
     Timestamp(TimestampType),
     String(StringType),
     Struct(StructType),
     Array(ArrayType),
+    Map(MapType),
     Variant(VariantType),
     VariantArray(VariantArrayType),
     VariantObject(VariantObjectType),
@@ -175,6 +177,9 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypeImpl {
         ArrowType::Boolean => DataTypeImpl::Boolean(BooleanType::default()),
         ArrowType::Float32 => DataTypeImpl::Float32(Float32Type::default()),
         ArrowType::Float64 => DataTypeImpl::Float64(Float64Type::default()),
+        ArrowType::Decimal(precision, scale) => {
+            DataTypeImpl::Decimal(DecimalType::create(*precision, *scale))
+        }
 
         // TODO support other list
         ArrowType::List(f) | ArrowType::LargeList(f) | ArrowType::FixedSizeList(f, _) => {
@@ -208,7 +213,18 @@ pub fn from_arrow_type(dt: &ArrowType) -> DataTypeImpl {
 
             DataTypeImpl::Struct(StructType::create(Some(names), types))
         }
-        ArrowType::Extension(custom_name, _, _) => match custom_name.as_str() {
+
+        ArrowType::Map(f, _) => match f.data_type() {
+            ArrowType::Struct(fields) if fields.len() == 2 => DataTypeImpl::Map(MapType::create(
+                from_arrow_field(&fields[0]),
+                from_arrow_field(&fields[1]),
+            )),
+            _ => unimplemented!("data_type: {:?}", dt),
+        },
+        ArrowType::Extension(custom_name, _, meta) => match custom_name.as_str() {
+            "Decimal256" => {
+                DataTypeImpl::Decimal(DecimalType::from_extension_meta(meta.as_deref()))
+            }
             "Variant" => DataTypeImpl::Variant(VariantType::default()),
             "VariantArray" => DataTypeImpl::VariantArray(VariantArrayType::default()),
             "VariantObject" => DataTypeImpl::VariantObject(VariantObjectType::default()),
@@ -238,6 +254,15 @@ pub fn from_arrow_field(f: &ArrowField) -> DataTypeImpl {
                 None => return TimestampType::new_impl(0),
             },
             "Interval" => return IntervalType::new_impl(metadata.unwrap().into()),
+            "Decimal256" => {
+                let decimal = DecimalType::from_extension_meta(metadata.as_deref());
+                let ty = DataTypeImpl::Decimal(decimal);
+                return if f.is_nullable {
+                    NullableType::new_impl(ty)
+                } else {
+                    ty
+                };
+            }
             "Variant" => return VariantType::new_impl(),
             "VariantArray" => return VariantArrayType::new_impl(),
             "VariantObject" => return VariantObjectType::new_impl(),
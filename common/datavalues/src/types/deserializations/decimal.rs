@@ -0,0 +1,142 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+
+use crate::prelude::*;
+
+pub struct DecimalDeserializer<T: DecimalNativeType> {
+    pub values: Vec<T>,
+    pub decimal: DecimalType,
+}
+
+impl<T: DecimalNativeType> DecimalDeserializer<T> {
+    pub fn create(decimal: DecimalType, capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            decimal,
+        }
+    }
+
+    fn read_decimal_text<R: BufferRead>(&self, reader: &mut R) -> Result<T> {
+        let mut buf = Vec::with_capacity(T::MAX_PRECISION + 2);
+        reader.keep_read(&mut buf, |f| {
+            f.is_ascii_digit() || matches!(f, b'-' | b'+' | b'.' | b'e' | b'E')
+        })?;
+        self.decimal.parse_value(&buf)
+    }
+}
+
+impl<T: DecimalNativeType> TypeDeserializer for DecimalDeserializer<T> {
+    fn de_binary(&mut self, reader: &mut &[u8], _format: &FormatSettings) -> Result<()> {
+        let v = T::read_binary(reader)?;
+        self.values.push(v);
+        Ok(())
+    }
+
+    fn de_default(&mut self, _format: &FormatSettings) {
+        self.values.push(T::default());
+    }
+
+    fn de_fixed_binary_batch(
+        &mut self,
+        reader: &[u8],
+        step: usize,
+        rows: usize,
+        format: &FormatSettings,
+    ) -> Result<()> {
+        for row in 0..rows {
+            let mut reader = &reader[step * row..];
+            self.de_binary(&mut reader, format)?;
+        }
+        Ok(())
+    }
+
+    fn de_json(&mut self, value: &serde_json::Value, _format: &FormatSettings) -> Result<()> {
+        let v = match value {
+            serde_json::Value::Number(v) => self.decimal.parse_value(v.to_string().as_bytes()),
+            serde_json::Value::String(v) => self.decimal.parse_value(v.as_bytes()),
+            _ => Err(ErrorCode::BadBytes(
+                "Incorrect json value, must be number or string",
+            )),
+        }?;
+        self.values.push(v);
+        Ok(())
+    }
+
+    fn de_whole_text(&mut self, reader: &[u8], _format: &FormatSettings) -> Result<()> {
+        let v = self.decimal.parse_value(reader)?;
+        self.values.push(v);
+        Ok(())
+    }
+
+    fn de_text<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        let v = self.read_decimal_text(reader)?;
+        self.values.push(v);
+        Ok(())
+    }
+
+    fn de_text_csv<R: BufferRead>(
+        &mut self,
+        reader: &mut NestedCheckpointReader<R>,
+        _format: &FormatSettings,
+    ) -> Result<()> {
+        let maybe_quote = reader.ignore(|f| f == b'\'' || f == b'"')?;
+        let v = self.read_decimal_text(reader)?;
+        if maybe_quote {
+            reader.must_ignore(|f| f == b'\'' || f == b'"')?;
+        }
+        self.values.push(v);
+        Ok(())
+    }
+
+    fn append_data_value(&mut self, value: DataValue, _format: &FormatSettings) -> Result<()> {
+        let v = match value {
+            DataValue::Int128(_) | DataValue::Int256(_) => T::try_from_data_value(&value)?,
+            // integer literals are not scaled yet
+            DataValue::Int64(_) | DataValue::UInt64(_) => {
+                self.decimal.rescale(T::try_from_data_value(&value)?, 0)?
+            }
+            DataValue::Float64(v) => self.decimal.from_f64(v)?,
+            DataValue::String(v) => self.decimal.parse_value(&v)?,
+            other => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} to get decimal",
+                    other.value_type()
+                )));
+            }
+        };
+        self.values.push(self.decimal.check_value(v)?);
+        Ok(())
+    }
+
+    fn pop_data_value(&mut self) -> Result<DataValue> {
+        match self.values.pop() {
+            Some(v) => Ok(v.to_data_value()),
+            None => Err(ErrorCode::BadDataArrayLength(
+                "Decimal column is empty when pop data value",
+            )),
+        }
+    }
+
+    fn finish_to_column(&mut self) -> ColumnRef {
+        T::new_column(std::mem::take(&mut self.values))
+    }
+}
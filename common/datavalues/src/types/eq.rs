@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use super::type_array::ArrayType;
+use super::type_decimal::DecimalType;
+use super::type_map::MapType;
 use super::type_nullable::NullableType;
 use super::type_struct::StructType;
 use super::type_timestamp::TimestampType;
@@ -53,6 +55,13 @@ pub fn equal(lhs: &DataTypeImpl, rhs: &DataTypeImpl) -> bool {
             true
         }
 
+        Decimal | Decimal256 => {
+            let lhs: DecimalType = lhs.to_owned().try_into().unwrap();
+            let rhs: DecimalType = rhs.to_owned().try_into().unwrap();
+
+            lhs.precision() == rhs.precision() && lhs.scale() == rhs.scale()
+        }
+
         Timestamp => {
             let lhs: TimestampType = lhs.to_owned().try_into().unwrap();
             let rhs: TimestampType = rhs.to_owned().try_into().unwrap();
@@ -80,5 +89,12 @@ pub fn equal(lhs: &DataTypeImpl, rhs: &DataTypeImpl) -> bool {
 
             lhs.types() == rhs.types() && lhs.names() == rhs.names()
         }
+
+        Map => {
+            let lhs: MapType = lhs.to_owned().try_into().unwrap();
+            let rhs: MapType = rhs.to_owned().try_into().unwrap();
+
+            lhs.key_type() == rhs.key_type() && lhs.value_type() == rhs.value_type()
+        }
     }
 }
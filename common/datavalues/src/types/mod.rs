@@ -18,7 +18,9 @@ pub mod type_array;
 pub mod type_boolean;
 pub mod type_coercion;
 pub mod type_date;
+pub mod type_decimal;
 pub mod type_interval;
+pub mod type_map;
 pub mod type_null;
 pub mod type_nullable;
 pub mod type_primitive;
@@ -48,9 +50,11 @@ pub use serializations::TypeSerializerImpl;
 pub use type_array::*;
 pub use type_boolean::*;
 pub use type_date::*;
+pub use type_decimal::*;
 pub use type_factory::*;
 pub use type_id::*;
 pub use type_interval::*;
+pub use type_map::*;
 pub use type_null::*;
 pub use type_nullable::*;
 pub use type_primitive::*;
@@ -0,0 +1,101 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use opensrv_clickhouse::types::column::ArcColumnWrapper;
+use opensrv_clickhouse::types::column::ColumnFrom;
+use serde_json::Value;
+
+use crate::ColumnRef;
+use crate::DecimalNativeType;
+use crate::DecimalType;
+use crate::TypeSerializer;
+
+#[derive(Debug, Clone)]
+pub struct DecimalSerializer<'a, T: DecimalNativeType> {
+    pub(crate) decimal: DecimalType,
+    pub(crate) values: &'a [T],
+}
+
+impl<'a, T: DecimalNativeType> DecimalSerializer<'a, T> {
+    pub fn try_create(decimal: DecimalType, col: &'a ColumnRef) -> Result<Self> {
+        Ok(Self {
+            decimal,
+            values: T::column_values(col)?,
+        })
+    }
+}
+
+impl<'a, T: DecimalNativeType> TypeSerializer<'a> for DecimalSerializer<'a, T> {
+    fn write_field(&self, row_index: usize, buf: &mut Vec<u8>, _format: &FormatSettings) {
+        let s = self.decimal.format_value(self.values[row_index]);
+        buf.extend_from_slice(s.as_bytes())
+    }
+
+    fn serialize_json_values(&self, _format: &FormatSettings) -> Result<Vec<Value>> {
+        // Keep the decimal as string to avoid losing precision in json number.
+        let result: Vec<Value> = self
+            .values
+            .iter()
+            .map(|v| Value::String(self.decimal.format_value(*v)))
+            .collect();
+        Ok(result)
+    }
+
+    fn serialize_clickhouse_const(
+        &self,
+        _format: &FormatSettings,
+        size: usize,
+    ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
+        let strings: Vec<String> = self
+            .values
+            .iter()
+            .map(|v| self.decimal.format_value(*v))
+            .collect();
+        let mut values: Vec<String> = Vec::with_capacity(self.values.len() * size);
+        for _ in 0..size {
+            for v in strings.iter() {
+                values.push(v.clone())
+            }
+        }
+        Ok(Vec::column_from::<ArcColumnWrapper>(values))
+    }
+
+    fn serialize_clickhouse_column(
+        &self,
+        _format: &FormatSettings,
+    ) -> Result<opensrv_clickhouse::types::column::ArcColumnData> {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|v| self.decimal.format_value(*v))
+            .collect();
+        Ok(Vec::column_from::<ArcColumnWrapper>(values))
+    }
+
+    fn serialize_json_object(
+        &self,
+        _valids: Option<&Bitmap>,
+        _format: &FormatSettings,
+    ) -> Result<Vec<Value>> {
+        let result: Vec<Value> = self
+            .values
+            .iter()
+            .map(|v| serde_json::json!(self.decimal.to_f64(*v)))
+            .collect();
+        Ok(result)
+    }
+}
@@ -16,7 +16,9 @@ mod array;
 mod boolean;
 mod const_;
 mod date;
+mod decimal;
 pub mod helper;
+mod map;
 mod null;
 mod nullable;
 mod number;
@@ -34,9 +36,11 @@ use common_exception::ToErrorCode;
 use common_io::prelude::FormatSettings;
 pub use const_::ConstSerializer;
 pub use date::DateSerializer;
+pub use decimal::DecimalSerializer;
 use enum_dispatch::enum_dispatch;
 pub use helper::escape::write_escaped_string;
 pub use helper::json::write_json_string;
+pub use map::MapSerializer;
 pub use null::NullSerializer;
 pub use nullable::NullableSerializer;
 pub use number::NumberSerializer;
@@ -47,6 +51,8 @@ pub use struct_::StructSerializer;
 pub use timestamp::TimestampSerializer;
 pub use variant::VariantSerializer;
 
+use crate::I256;
+
 #[enum_dispatch]
 pub trait TypeSerializer<'a>: Send + Sync {
     fn need_quote(&self) -> bool {
@@ -145,6 +151,8 @@ pub enum TypeSerializerImpl<'a> {
     UInt64(NumberSerializer<'a, u64>),
     Float32(NumberSerializer<'a, f32>),
     Float64(NumberSerializer<'a, f64>),
+    Decimal(DecimalSerializer<'a, i128>),
+    Decimal256(DecimalSerializer<'a, I256>),
 
     Date(DateSerializer<'a, i32>),
     Interval(DateSerializer<'a, i64>),
@@ -152,5 +160,6 @@ pub enum TypeSerializerImpl<'a> {
     String(StringSerializer<'a>),
     Array(ArraySerializer<'a>),
     Struct(StructSerializer<'a>),
+    Map(MapSerializer<'a>),
     Variant(VariantSerializer<'a>),
 }
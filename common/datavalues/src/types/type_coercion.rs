@@ -198,6 +198,102 @@ pub fn numerical_arithmetic_coercion(
     }
 }
 
+fn as_decimal_type(data_type: &DataTypeImpl) -> Option<DecimalType> {
+    match data_type.data_type_id() {
+        TypeID::Decimal | TypeID::Decimal256 => data_type.to_owned().try_into().ok(),
+        type_id => DecimalType::from_integer_type(type_id),
+    }
+}
+
+/// Keep the integral digits and reduce the scale if the precision exceeds the max precision.
+fn bounded_decimal_type(precision: usize, scale: usize) -> DataTypeImpl {
+    if precision <= DECIMAL_MAX_PRECISION {
+        return DecimalType::new_impl(precision, scale);
+    }
+
+    let integral_digits = precision - scale;
+    let min_scale = cmp::min(scale, DECIMAL_MIN_ADJUSTED_SCALE);
+    let scale = cmp::max(
+        DECIMAL_MAX_PRECISION.saturating_sub(integral_digits),
+        min_scale,
+    );
+    DecimalType::new_impl(DECIMAL_MAX_PRECISION, scale)
+}
+
+/// Coercion rule for decimal types: integers are treated as decimals with zero scale,
+/// and the result is Float64 if one of them is floating.
+pub fn decimal_coercion(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<DataTypeImpl> {
+    let lhs_id = lhs_type.data_type_id();
+    let rhs_id = rhs_type.data_type_id();
+    if lhs_id.is_floating() || rhs_id.is_floating() {
+        return Ok(Float64Type::new_impl());
+    }
+
+    match (as_decimal_type(lhs_type), as_decimal_type(rhs_type)) {
+        (Some(lhs), Some(rhs)) => {
+            let scale = cmp::max(lhs.scale(), rhs.scale());
+            let integral_digits =
+                cmp::max(lhs.precision() - lhs.scale(), rhs.precision() - rhs.scale());
+            Ok(bounded_decimal_type(integral_digits + scale, scale))
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "Can't construct decimal type from {:?} and {:?}",
+            lhs_type, rhs_type
+        ))),
+    }
+}
+
+/// Coercion rule for decimal arithmetic, follows the precision and scale rules of SQL:
+///
+/// | Operation | Result precision                      | Result scale         |
+/// |-----------|---------------------------------------|----------------------|
+/// | e1 + e2   | max(s1, s2) + max(p1-s1, p2-s2) + 1   | max(s1, s2)          |
+/// | e1 - e2   | max(s1, s2) + max(p1-s1, p2-s2) + 1   | max(s1, s2)          |
+/// | e1 * e2   | p1 + p2                               | s1 + s2              |
+/// | e1 / e2   | p1 - s1 + s2 + max(6, s1 + p2 + 1)    | max(6, s1 + p2 + 1)  |
+///
+/// The precision is capped at 38 by reducing the scale.
+pub fn decimal_arithmetic_coercion(
+    op: &DataValueBinaryOperator,
+    lhs_type: &DataTypeImpl,
+    rhs_type: &DataTypeImpl,
+) -> Result<DataTypeImpl> {
+    let lhs_id = lhs_type.data_type_id();
+    let rhs_id = rhs_type.data_type_id();
+    if lhs_id.is_floating() || rhs_id.is_floating() {
+        return Ok(Float64Type::new_impl());
+    }
+
+    let (lhs, rhs) = match (as_decimal_type(lhs_type), as_decimal_type(rhs_type)) {
+        (Some(lhs), Some(rhs)) => (lhs, rhs),
+        _ => {
+            return Result::Err(ErrorCode::BadDataValueType(format!(
+                "DataValue Error: Unsupported ({:?}) {} ({:?})",
+                lhs_type, op, rhs_type
+            )));
+        }
+    };
+
+    let (p1, s1) = (lhs.precision(), lhs.scale());
+    let (p2, s2) = (rhs.precision(), rhs.scale());
+    match op {
+        DataValueBinaryOperator::Plus | DataValueBinaryOperator::Minus => {
+            let scale = cmp::max(s1, s2);
+            let integral_digits = cmp::max(p1 - s1, p2 - s2);
+            Ok(bounded_decimal_type(integral_digits + scale + 1, scale))
+        }
+        DataValueBinaryOperator::Mul => Ok(bounded_decimal_type(p1 + p2, s1 + s2)),
+        DataValueBinaryOperator::Div => {
+            let scale = cmp::max(DECIMAL_MIN_ADJUSTED_SCALE, s1 + p2 + 1);
+            Ok(bounded_decimal_type(p1 - s1 + s2 + scale, scale))
+        }
+        _ => Result::Err(ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported ({:?}) {} ({:?})",
+            lhs_type, op, rhs_type
+        ))),
+    }
+}
+
 #[inline]
 pub fn numerical_unary_arithmetic_coercion(
     op: &DataValueUnaryOperator,
@@ -241,6 +337,12 @@ pub fn compare_coercion(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Res
         return numerical_coercion(lhs_type, rhs_type, true);
     }
 
+    if (lhs_id.is_decimal() && (rhs_id.is_numeric() || rhs_id.is_decimal()))
+        || (rhs_id.is_decimal() && lhs_id.is_numeric())
+    {
+        return decimal_coercion(lhs_type, rhs_type);
+    }
+
     //  one of is nothing
     {
         if lhs_id == TypeID::Null {
@@ -257,6 +359,17 @@ pub fn compare_coercion(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Res
         return Ok(Float64Type::new_impl());
     }
 
+    // one of is String and other is decimal
+    {
+        if lhs_id.is_decimal() && rhs_id.is_string() {
+            return Ok(lhs_type.clone());
+        }
+
+        if rhs_id.is_decimal() && lhs_id.is_string() {
+            return Ok(rhs_type.clone());
+        }
+    }
+
     // one of is datetime and other is number or string
     {
         if (lhs_id.is_numeric() || lhs_id.is_string()) && rhs_id.is_date_or_date_time() {
@@ -335,6 +448,14 @@ pub fn merge_types(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<D
             let typ = merge_types(a.inner_type(), b.inner_type())?;
             Ok(DataTypeImpl::Array(ArrayType::create(typ)))
         }
+        (Map, Map) => {
+            let a: MapType = lhs_type.to_owned().try_into()?;
+            let b: MapType = rhs_type.to_owned().try_into()?;
+
+            let key_type = merge_types(a.key_type(), b.key_type())?;
+            let value_type = merge_types(a.value_type(), b.value_type())?;
+            Ok(DataTypeImpl::Map(MapType::create(key_type, value_type)))
+        }
         (Struct, Struct) => {
             let a: StructType = lhs_type.to_owned().try_into()?;
             let b: StructType = rhs_type.to_owned().try_into()?;
@@ -362,6 +483,11 @@ pub fn merge_types(lhs_type: &DataTypeImpl, rhs_type: &DataTypeImpl) -> Result<D
             }
             if lhs_id.is_numeric() && rhs_id.is_numeric() {
                 numerical_coercion(lhs_type, rhs_type, false)
+            } else if (lhs_id.is_decimal() || rhs_id.is_decimal())
+                && (lhs_id.is_numeric() || lhs_id.is_decimal())
+                && (rhs_id.is_numeric() || rhs_id.is_decimal())
+            {
+                decimal_coercion(lhs_type, rhs_type)
             } else {
                 Result::Err(ErrorCode::BadDataValueType(format!(
                     "Can't merge types from {:?} and {:?}",
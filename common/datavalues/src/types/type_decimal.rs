@@ -0,0 +1,648 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::Read;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Rem;
+use std::ops::Sub;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+pub use ethnum::I256;
+use rand::prelude::*;
+
+use super::data_type::DataType;
+use super::data_type::ARROW_EXTENSION_META;
+use super::data_type::ARROW_EXTENSION_NAME;
+use super::type_id::TypeID;
+use crate::prelude::*;
+use crate::serializations::DecimalSerializer;
+use crate::serializations::TypeSerializerImpl;
+
+/// The max precision of decimal backed by i128, 10^38 - 1 is the largest value fits in i128.
+pub const DECIMAL128_MAX_PRECISION: usize = 38;
+/// The max precision of decimal backed by i256, 10^76 - 1 is the largest value fits in i256.
+pub const DECIMAL_MAX_PRECISION: usize = 76;
+/// The size of the unscaled value of decimal backed by i256, when it is kept in arrow and parquet.
+pub const DECIMAL256_BYTE_SIZE: usize = 32;
+/// `DECIMAL` without precision and scale is `DECIMAL(38, 0)`.
+pub const DECIMAL_DEFAULT_PRECISION: usize = 38;
+pub const DECIMAL_DEFAULT_SCALE: usize = 0;
+/// The minimal scale of decimal division, also the minimal scale kept when
+/// the precision of decimal arithmetic result exceeds the max precision.
+pub const DECIMAL_MIN_ADJUSTED_SCALE: usize = 6;
+
+/// Decimal type stores the unscaled value, e.g. `1.23` in `DECIMAL(10, 2)` is stored as `123`.
+/// The value is stored in i128 if the precision is no more than 38, otherwise in i256.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct DecimalType {
+    /// The total number of digits, range from 1 to 76
+    precision: usize,
+    /// The number of digits after the decimal point, range from 0 to precision
+    scale: usize,
+}
+
+/// The native integer which stores the unscaled value of decimal.
+pub trait DecimalNativeType:
+    Copy
+    + Ord
+    + Default
+    + Display
+    + FromStr
+    + Send
+    + Sync
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+    + 'static
+{
+    /// The max precision of the decimal stored in this type.
+    const MAX_PRECISION: usize;
+
+    fn from_i128(v: i128) -> Self;
+
+    fn to_i256(self) -> I256;
+
+    /// Returns `None` if the value doesn't fit in this type.
+    fn try_from_i256(v: I256) -> Option<Self>;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    /// The value is saturated if it is out of range.
+    fn from_f64(v: f64) -> Self;
+
+    /// Read the value written by `Column::serialize` of the column which stores this type.
+    fn read_binary(reader: &mut &[u8]) -> Result<Self>;
+
+    fn to_data_value(self) -> DataValue;
+
+    fn try_from_data_value(value: &DataValue) -> Result<Self>;
+
+    fn column_values(column: &ColumnRef) -> Result<&[Self]>;
+
+    fn new_column(values: Vec<Self>) -> ColumnRef;
+}
+
+impl DecimalNativeType for i128 {
+    const MAX_PRECISION: usize = DECIMAL128_MAX_PRECISION;
+
+    #[inline]
+    fn from_i128(v: i128) -> Self {
+        v
+    }
+
+    #[inline]
+    fn to_i256(self) -> I256 {
+        I256::from(self)
+    }
+
+    #[inline]
+    fn try_from_i256(v: I256) -> Option<Self> {
+        i128::try_from(v).ok()
+    }
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        i128::checked_add(self, rhs)
+    }
+
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        i128::checked_sub(self, rhs)
+    }
+
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        i128::checked_mul(self, rhs)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        i128::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        i128::signum(self)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        v as i128
+    }
+
+    fn read_binary(reader: &mut &[u8]) -> Result<Self> {
+        let mut buf = [0u8; 16];
+        reader.read_exact(&mut buf)?;
+        Ok(i128::from_le_bytes(buf))
+    }
+
+    fn to_data_value(self) -> DataValue {
+        DataValue::Int128(self)
+    }
+
+    fn try_from_data_value(value: &DataValue) -> Result<Self> {
+        value.as_i128()
+    }
+
+    fn column_values(column: &ColumnRef) -> Result<&[Self]> {
+        let column: &Int128Column = Series::check_get(column)?;
+        Ok(column.values())
+    }
+
+    fn new_column(values: Vec<Self>) -> ColumnRef {
+        Int128Column::new_from_vec(values).arc()
+    }
+}
+
+impl DecimalNativeType for I256 {
+    const MAX_PRECISION: usize = DECIMAL_MAX_PRECISION;
+
+    #[inline]
+    fn from_i128(v: i128) -> Self {
+        I256::from(v)
+    }
+
+    #[inline]
+    fn to_i256(self) -> I256 {
+        self
+    }
+
+    #[inline]
+    fn try_from_i256(v: I256) -> Option<Self> {
+        Some(v)
+    }
+
+    #[inline]
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        I256::checked_add(self, rhs)
+    }
+
+    #[inline]
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        I256::checked_sub(self, rhs)
+    }
+
+    #[inline]
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        I256::checked_mul(self, rhs)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        I256::abs(self)
+    }
+
+    #[inline]
+    fn signum(self) -> Self {
+        I256::signum(self)
+    }
+
+    #[inline]
+    fn to_f64(self) -> f64 {
+        self.as_f64()
+    }
+
+    #[inline]
+    fn from_f64(v: f64) -> Self {
+        ethnum::AsI256::as_i256(v)
+    }
+
+    fn read_binary(reader: &mut &[u8]) -> Result<Self> {
+        // Same as the other object columns, the value is serialized as json with its length.
+        let len = reader.read_uvarint()? as usize;
+        let mut buf = vec![0u8; len];
+        reader.read_exact(&mut buf)?;
+        Ok(serde_json::from_slice(&buf)?)
+    }
+
+    fn to_data_value(self) -> DataValue {
+        DataValue::Int256(self)
+    }
+
+    fn try_from_data_value(value: &DataValue) -> Result<Self> {
+        value.as_i256()
+    }
+
+    fn column_values(column: &ColumnRef) -> Result<&[Self]> {
+        let column: &Decimal256Column = Series::check_get(column)?;
+        Ok(column.values())
+    }
+
+    fn new_column(values: Vec<Self>) -> ColumnRef {
+        Decimal256Column::new_from_vec(values).arc()
+    }
+}
+
+#[inline]
+pub fn decimal_pow10<T: DecimalNativeType>(n: usize) -> T {
+    let ten = T::from_i128(10);
+    let mut v = T::from_i128(1);
+    for _ in 0..n {
+        v = v
+            .checked_mul(ten)
+            .expect("10^n fits in the native type of decimal");
+    }
+    v
+}
+
+/// `a / b` rounded half away from zero.
+#[inline]
+pub fn decimal_div_round<T: DecimalNativeType>(a: T, b: T) -> T {
+    let (q, r) = (a / b, a % b);
+    if r.abs() >= b.abs() - r.abs() {
+        q + a.signum() * b.signum()
+    } else {
+        q
+    }
+}
+
+impl DecimalType {
+    pub fn create(precision: usize, scale: usize) -> Self {
+        DecimalType { precision, scale }
+    }
+
+    pub fn try_create(precision: usize, scale: usize) -> Result<Self> {
+        if precision == 0 || precision > DECIMAL_MAX_PRECISION {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Decimal precision must be between 1 and {}, but got {}",
+                DECIMAL_MAX_PRECISION, precision
+            )));
+        }
+        if scale > precision {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Decimal scale must be between 0 and precision {}, but got {}",
+                precision, scale
+            )));
+        }
+        Ok(DecimalType { precision, scale })
+    }
+
+    pub fn new_impl(precision: usize, scale: usize) -> DataTypeImpl {
+        DataTypeImpl::Decimal(DecimalType { precision, scale })
+    }
+
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    pub fn scale(&self) -> usize {
+        self.scale
+    }
+
+    /// Whether the unscaled value is stored in i256 instead of i128.
+    #[inline]
+    pub fn is_decimal256(&self) -> bool {
+        self.precision > DECIMAL128_MAX_PRECISION
+    }
+
+    /// The decimal with the same scale whose values are stored in i256.
+    pub fn to_decimal256(&self) -> DecimalType {
+        DecimalType::create(
+            cmp::max(self.precision, DECIMAL128_MAX_PRECISION + 1),
+            self.scale,
+        )
+    }
+
+    /// The decimal which can hold all the values of the integer type without loss.
+    pub fn from_integer_type(type_id: TypeID) -> Option<DecimalType> {
+        let precision = match type_id {
+            TypeID::Int8 | TypeID::UInt8 => 3,
+            TypeID::Int16 | TypeID::UInt16 => 5,
+            TypeID::Int32 | TypeID::UInt32 => 10,
+            TypeID::Int64 => 19,
+            TypeID::UInt64 => 20,
+            _ => return None,
+        };
+        Some(DecimalType::create(precision, 0))
+    }
+
+    /// Parse the precision and scale kept in the arrow extension metadata, e.g. `50,2`.
+    pub(crate) fn from_extension_meta(meta: Option<&str>) -> DecimalType {
+        let mut parts = meta
+            .unwrap_or_default()
+            .split(',')
+            .map(|v| v.trim().parse::<usize>().ok());
+        match (parts.next().flatten(), parts.next().flatten()) {
+            (Some(precision), Some(scale)) => DecimalType::create(precision, scale),
+            _ => DecimalType::create(DECIMAL_MAX_PRECISION, 0),
+        }
+    }
+
+    /// The largest unscaled value of this decimal, `10^precision - 1`.
+    #[inline]
+    pub fn max_value<T: DecimalNativeType>(&self) -> T {
+        decimal_pow10::<T>(self.precision) - T::from_i128(1)
+    }
+
+    #[inline]
+    pub fn check_value<T: DecimalNativeType>(&self, v: T) -> Result<T> {
+        let max = self.max_value::<T>();
+        if v > max || v < -max {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: {} is out of range of {}",
+                self.format_value(v),
+                self.sql_name()
+            )));
+        }
+        Ok(v)
+    }
+
+    /// Convert the unscaled value in `from_scale` to the scale of this decimal,
+    /// extra digits are rounded half away from zero.
+    pub fn rescale<T: DecimalNativeType>(&self, v: T, from_scale: usize) -> Result<T> {
+        let v = if from_scale <= self.scale {
+            let shift = self.scale - from_scale;
+            let v = if shift > T::MAX_PRECISION {
+                None
+            } else {
+                v.checked_mul(decimal_pow10(shift))
+            };
+            v.ok_or_else(|| {
+                ErrorCode::Overflow(format!("Decimal overflow when cast to {}", self.sql_name()))
+            })?
+        } else {
+            let shift = from_scale - self.scale;
+            if shift > T::MAX_PRECISION {
+                T::default()
+            } else {
+                decimal_div_round(v, decimal_pow10(shift))
+            }
+        };
+        self.check_value(v)
+    }
+
+    pub fn to_f64<T: DecimalNativeType>(&self, v: T) -> f64 {
+        v.to_f64() / 10_f64.powi(self.scale as i32)
+    }
+
+    pub fn from_f64<T: DecimalNativeType>(&self, v: f64) -> Result<T> {
+        let scaled = (v * 10_f64.powi(self.scale as i32)).round();
+        if !scaled.is_finite() || scaled.abs() > self.max_value::<T>().to_f64() {
+            return Err(ErrorCode::Overflow(format!(
+                "Decimal overflow: {} is out of range of {}",
+                v,
+                self.sql_name()
+            )));
+        }
+        self.check_value(T::from_f64(scaled))
+    }
+
+    pub fn format_value<T: DecimalNativeType>(&self, v: T) -> String {
+        let text = v.to_string();
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", text.as_str()),
+        };
+        if self.scale == 0 {
+            return format!("{}{}", sign, digits);
+        }
+
+        let digits = format!("{:0>width$}", digits, width = self.scale + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - self.scale);
+        format!("{}{}.{}", sign, int_part, frac_part)
+    }
+
+    /// Parse text like `-12.345` or `1.2e3` into the unscaled value of this decimal.
+    pub fn parse_value<T: DecimalNativeType>(&self, text: &[u8]) -> Result<T> {
+        let bad_bytes = || {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse value:{:?} to {}",
+                String::from_utf8_lossy(text),
+                self.sql_name()
+            ))
+        };
+
+        let text = std::str::from_utf8(text).map_err(|_| bad_bytes())?.trim();
+        let (negative, text) = match text.as_bytes().first() {
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            _ => (false, text),
+        };
+        let (mantissa, exponent) = match text.find(|c| c == 'e' || c == 'E') {
+            Some(pos) => {
+                let exponent = text[pos + 1..].parse::<i32>().map_err(|_| bad_bytes())?;
+                (&text[..pos], exponent)
+            }
+            None => (text, 0),
+        };
+        let (int_part, frac_part) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+        if (int_part.is_empty() && frac_part.is_empty())
+            || !int_part.bytes().all(|c| c.is_ascii_digit())
+            || !frac_part.bytes().all(|c| c.is_ascii_digit())
+        {
+            return Err(bad_bytes());
+        }
+
+        // value = digits * 10^shift
+        let digits = format!("{}{}", int_part, frac_part);
+        let digits = digits.trim_start_matches('0');
+        let shift = self.scale as i64 + exponent as i64 - frac_part.len() as i64;
+
+        let overflow = || {
+            ErrorCode::Overflow(format!(
+                "Decimal overflow: {:?} is out of range of {}",
+                String::from_utf8_lossy(text.as_bytes()),
+                self.sql_name()
+            ))
+        };
+
+        let ten = T::from_i128(10);
+        let push_digit = |value: T, c: u8| {
+            value
+                .checked_mul(ten)
+                .and_then(|v| v.checked_add(T::from_i128((c - b'0') as i128)))
+        };
+
+        let mut value = T::default();
+        if shift >= 0 {
+            for c in digits.bytes() {
+                value = push_digit(value, c).ok_or_else(overflow)?;
+            }
+            if value != T::default() {
+                if shift as usize > T::MAX_PRECISION {
+                    return Err(overflow());
+                }
+                value = value
+                    .checked_mul(decimal_pow10(shift as usize))
+                    .ok_or_else(overflow)?;
+            }
+        } else {
+            let dropped = (-shift) as usize;
+            if dropped <= digits.len() {
+                let (kept, rest) = digits.split_at(digits.len() - dropped);
+                for c in kept.bytes() {
+                    value = push_digit(value, c).ok_or_else(overflow)?;
+                }
+                // round half away from zero
+                if rest.as_bytes()[0] >= b'5' {
+                    value = value + T::from_i128(1);
+                }
+            }
+        }
+
+        let value = if negative { -value } else { value };
+        self.check_value(value)
+    }
+}
+
+impl DataType for DecimalType {
+    fn data_type_id(&self) -> TypeID {
+        if self.is_decimal256() {
+            TypeID::Decimal256
+        } else {
+            TypeID::Decimal
+        }
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> String {
+        format!("Decimal({}, {})", self.precision, self.scale)
+    }
+
+    fn default_value(&self) -> DataValue {
+        if self.is_decimal256() {
+            DataValue::Int256(I256::ZERO)
+        } else {
+            DataValue::Int128(0)
+        }
+    }
+
+    fn random_value(&self) -> DataValue {
+        let mut rng = rand::rngs::SmallRng::from_entropy();
+        if self.is_decimal256() {
+            let modulus = self.max_value::<I256>() + I256::ONE;
+            DataValue::Int256(I256::from_words(rng.gen(), rng.gen()) % modulus)
+        } else {
+            let max = self.max_value::<i128>();
+            DataValue::Int128(rng.gen_range(-max..=max))
+        }
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        let column = self.create_column(std::slice::from_ref(data))?;
+        Ok(Arc::new(ConstColumn::new(column, size)))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        if self.is_decimal256() {
+            let value = data
+                .iter()
+                .map(|v| v.as_i256())
+                .collect::<Result<Vec<_>>>()?;
+            return Ok(Series::from_data(value));
+        }
+
+        let value = data
+            .iter()
+            .map(|v| v.as_i128())
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Series::from_data(&value))
+    }
+
+    /// Arrow has no 256 bits decimal yet, the wide decimal is kept in an extension type
+    /// of fixed size binary, see `ObjectType::to_arrow_array` of `I256`.
+    fn arrow_type(&self) -> ArrowType {
+        if self.is_decimal256() {
+            ArrowType::Extension(
+                "Decimal256".to_owned(),
+                Box::new(ArrowType::FixedSizeBinary(DECIMAL256_BYTE_SIZE)),
+                Some(format!("{},{}", self.precision, self.scale)),
+            )
+        } else {
+            ArrowType::Decimal(self.precision, self.scale)
+        }
+    }
+
+    fn custom_arrow_meta(&self) -> Option<BTreeMap<String, String>> {
+        if !self.is_decimal256() {
+            return None;
+        }
+        let mut mp = BTreeMap::new();
+        mp.insert(ARROW_EXTENSION_NAME.to_string(), "Decimal256".to_string());
+        mp.insert(
+            ARROW_EXTENSION_META.to_string(),
+            format!("{},{}", self.precision, self.scale),
+        );
+        Some(mp)
+    }
+
+    fn create_serializer_inner<'a>(&self, col: &'a ColumnRef) -> Result<TypeSerializerImpl<'a>> {
+        if self.is_decimal256() {
+            Ok(DecimalSerializer::<'a, I256>::try_create(self.clone(), col)?.into())
+        } else {
+            Ok(DecimalSerializer::<'a, i128>::try_create(self.clone(), col)?.into())
+        }
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> TypeDeserializerImpl {
+        if self.is_decimal256() {
+            DecimalDeserializer::<I256>::create(self.clone(), capacity).into()
+        } else {
+            DecimalDeserializer::<i128>::create(self.clone(), capacity).into()
+        }
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        if self.is_decimal256() {
+            return Box::new(MutableObjectColumn::<I256>::with_capacity(capacity));
+        }
+        Box::new(MutablePrimitiveColumn::<i128>::from_data(
+            self.clone().into(),
+            Vec::with_capacity(capacity),
+        ))
+    }
+}
+
+impl std::fmt::Debug for DecimalType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Decimal({}, {})", self.precision, self.scale)
+    }
+}
@@ -97,6 +97,10 @@ impl TypeFactory {
             return self.get(name).map(ArrayType::new_impl);
         }
 
+        if lowercase_name.starts_with("map(") {
+            return self.get_map(&origin_name[4..origin_name.len() - 1]);
+        }
+
         if lowercase_name.starts_with("tuple(") {
             let names = origin_name[6..origin_name.len() - 1].to_string();
             let bytes = names.as_bytes();
@@ -139,6 +143,16 @@ impl TypeFactory {
                             inner_names.push(names[start1..start2 - 1].to_string());
                         }
                         inner_data_types.push(self.get(&names[index - 5..sub_index + 1])?);
+                    } else if index >= 3 && names[index - 3..index].to_lowercase() == "map" {
+                        if start1 != start2 {
+                            inner_names.push(names[start1..start2 - 1].to_string());
+                        }
+                        inner_data_types.push(self.get(&names[index - 3..sub_index + 1])?);
+                    } else if index >= 7 && names[index - 7..index].to_lowercase() == "decimal" {
+                        if start1 != start2 {
+                            inner_names.push(names[start1..start2 - 1].to_string());
+                        }
+                        inner_data_types.push(self.get(&names[index - 7..sub_index + 1])?);
                     } else {
                         return Result::Err(ErrorCode::IllegalDataType(
                             "The names of tuple elements format error",
@@ -184,6 +198,10 @@ impl TypeFactory {
             }
         }
 
+        if lowercase_name == "decimal" || lowercase_name.starts_with("decimal(") {
+            return Self::get_decimal(&lowercase_name[7..]);
+        }
+
         self.case_insensitive_types
             .get(&lowercase_name)
             .cloned()
@@ -192,6 +210,59 @@ impl TypeFactory {
             })
     }
 
+    /// Parse the arguments of map, e.g. `String, Array(Int32)`.
+    fn get_map(&self, args: &str) -> Result<DataTypeImpl> {
+        let mut depth = 0;
+        let mut split = None;
+        for (index, c) in args.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    split = Some(index);
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        let index = split.ok_or_else(|| {
+            ErrorCode::IllegalDataType(format!("Unsupported data type: map({})", args))
+        })?;
+        let key_type = self.get(args[..index].trim())?;
+        let value_type = self.get(args[index + 1..].trim())?;
+        Ok(DataTypeImpl::Map(MapType::try_create(
+            key_type, value_type,
+        )?))
+    }
+
+    /// Parse the arguments of decimal, e.g. `(10, 2)`, `(10)` or empty.
+    fn get_decimal(args: &str) -> Result<DataTypeImpl> {
+        let args = args.trim();
+        if args.is_empty() {
+            return Ok(DecimalType::new_impl(
+                DECIMAL_DEFAULT_PRECISION,
+                DECIMAL_DEFAULT_SCALE,
+            ));
+        }
+
+        let error =
+            || ErrorCode::IllegalDataType(format!("Unsupported data type: decimal{}", args));
+        if !args.starts_with('(') || !args.ends_with(')') {
+            return Err(error());
+        }
+        let values = args[1..args.len() - 1]
+            .split(',')
+            .map(|v| v.trim().parse::<usize>().map_err(|_| error()))
+            .collect::<Result<Vec<_>>>()?;
+        let decimal = match values.as_slice() {
+            [precision] => DecimalType::try_create(*precision, DECIMAL_DEFAULT_SCALE)?,
+            [precision, scale] => DecimalType::try_create(*precision, *scale)?,
+            _ => return Err(error()),
+        };
+        Ok(DataTypeImpl::Decimal(decimal))
+    }
+
     pub fn register_names(&self) -> Vec<&str> {
         self.case_insensitive_types
             .keys()
@@ -33,6 +33,13 @@ pub enum TypeID {
     Float32,
     Float64,
 
+    /// A decimal number with fixed precision and scale,
+    /// its physical type is Int128 which stores the unscaled value
+    Decimal,
+    /// A decimal number whose precision is larger than 38,
+    /// its physical type is Int256 which stores the unscaled value
+    Decimal256,
+
     String,
 
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
@@ -50,6 +57,9 @@ pub enum TypeID {
 
     Array,
     Struct,
+    /// Map is a collection of key-value pairs,
+    /// it's physical type is Array of Struct(key, value)
+    Map,
 
     /// Variant is a tagged universal type, which can store values of any other type,
     /// including Object and Array, up to a maximum size of 16 MB.
@@ -110,6 +120,11 @@ impl TypeID {
         matches!(self, TypeID::Float32 | TypeID::Float64)
     }
 
+    #[inline]
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, TypeID::Decimal | TypeID::Decimal256)
+    }
+
     #[inline]
     pub fn is_date_or_date_time(&self) -> bool {
         matches!(self, TypeID::Date | TypeID::Timestamp)
@@ -160,6 +175,11 @@ impl TypeID {
         matches!(self, TypeID::Struct)
     }
 
+    #[inline]
+    pub fn is_map(&self) -> bool {
+        matches!(self, TypeID::Map)
+    }
+
     #[inline]
     pub fn is_quoted(&self) -> bool {
         matches!(self, TypeID::String | TypeID::Date | TypeID::Timestamp)
@@ -215,9 +235,11 @@ impl TypeID {
             UInt64 => PhysicalTypeID::UInt64,
             Float32 => PhysicalTypeID::Float32,
             Float64 => PhysicalTypeID::Float64,
+            Decimal => PhysicalTypeID::Int128,
+            Decimal256 => PhysicalTypeID::Int256,
 
             String => PhysicalTypeID::String,
-            Array => PhysicalTypeID::Array,
+            Array | Map => PhysicalTypeID::Array,
             Struct => PhysicalTypeID::Struct,
             Variant | VariantArray | VariantObject => PhysicalTypeID::Variant,
         }
@@ -253,6 +275,10 @@ pub enum PhysicalTypeID {
     Int32,
     /// A signed 64-bit integer.
     Int64,
+    /// A signed 128-bit integer.
+    Int128,
+    /// A signed 256-bit integer.
+    Int256,
     /// An unsigned 8-bit integer.
     UInt8,
     /// An unsigned 16-bit integer.
@@ -0,0 +1,192 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::Field;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::data_type::DataType;
+use super::data_type::DataTypeImpl;
+use super::type_id::TypeID;
+use crate::prelude::*;
+use crate::serializations::MapSerializer;
+use crate::serializations::TypeSerializerImpl;
+
+/// Map type stores the entries as an array of `Struct(key, value)`,
+/// a map value is `DataValue::Array` of `DataValue::Struct([key, value])`.
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct MapType {
+    key: Box<DataTypeImpl>,
+    value: Box<DataTypeImpl>,
+}
+
+impl MapType {
+    pub fn new_impl(key: DataTypeImpl, value: DataTypeImpl) -> DataTypeImpl {
+        DataTypeImpl::Map(Self::create(key, value))
+    }
+
+    pub fn create(key: DataTypeImpl, value: DataTypeImpl) -> Self {
+        MapType {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+    }
+
+    /// Keys of map must be not null scalar values, so they can be compared and hashed.
+    pub fn try_create(key: DataTypeImpl, value: DataTypeImpl) -> Result<Self> {
+        let key_type_id = key.data_type_id();
+        if !(key_type_id.is_numeric()
+            || key_type_id.is_string()
+            || key_type_id.is_date_or_date_time()
+            || key_type_id.is_decimal()
+            || key_type_id == TypeID::Boolean)
+        {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Map key type must be a not null scalar type, but got {}",
+                key.name()
+            )));
+        }
+        Ok(Self::create(key, value))
+    }
+
+    pub fn key_type(&self) -> &DataTypeImpl {
+        &self.key
+    }
+
+    pub fn value_type(&self) -> &DataTypeImpl {
+        &self.value
+    }
+
+    /// The type of the entries column.
+    pub fn entries_type(&self) -> DataTypeImpl {
+        StructType::new_impl(Some(vec!["key".to_string(), "value".to_string()]), vec![
+            *self.key.clone(),
+            *self.value.clone(),
+        ])
+    }
+
+    fn column_meta(&self) -> ColumnMeta {
+        ColumnMeta::Map {
+            key_type: *self.key.clone(),
+            value_type: *self.value.clone(),
+        }
+    }
+}
+
+impl DataType for MapType {
+    fn data_type_id(&self) -> TypeID {
+        TypeID::Map
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> String {
+        format!("Map({}, {})", self.key.name(), self.value.name())
+    }
+
+    fn sql_name(&self) -> String {
+        format!("MAP({}, {})", self.key.sql_name(), self.value.sql_name())
+    }
+
+    fn default_value(&self) -> DataValue {
+        DataValue::Array(vec![])
+    }
+
+    fn random_value(&self) -> DataValue {
+        DataValue::Array(vec![DataValue::Struct(vec![
+            self.key.random_value(),
+            self.value.random_value(),
+        ])])
+    }
+
+    fn create_constant_column(&self, data: &DataValue, size: usize) -> Result<ColumnRef> {
+        if let DataValue::Array(value) = data {
+            let entries_column = self.entries_type().create_column(value)?;
+            let offsets = vec![0, value.len() as i64];
+            let column = Arc::new(ArrayColumn::from_data(
+                DataTypeImpl::Map(self.clone()),
+                offsets.into(),
+                entries_column,
+            ));
+
+            return Ok(Arc::new(ConstColumn::new(column, size)));
+        }
+
+        Err(ErrorCode::BadDataValueType(format!(
+            "Unexpected type:{:?} to generate map column",
+            data.value_type()
+        )))
+    }
+
+    fn create_column(&self, data: &[DataValue]) -> Result<ColumnRef> {
+        let mut values: Vec<DataValue> = vec![];
+        let mut offsets: Vec<i64> = vec![0];
+        for v in data.iter() {
+            if let DataValue::Array(value) = v {
+                offsets.push(offsets.last().unwrap() + value.len() as i64);
+                values.extend_from_slice(value);
+            } else {
+                return Result::Err(ErrorCode::BadDataValueType(format!(
+                    "Unexpected type:{:?} to generate map column",
+                    v.value_type()
+                )));
+            }
+        }
+
+        let entries_column = self.entries_type().create_column(&values)?;
+
+        Ok(Arc::new(ArrayColumn::from_data(
+            DataTypeImpl::Map(self.clone()),
+            offsets.into(),
+            entries_column,
+        )))
+    }
+
+    fn arrow_type(&self) -> ArrowType {
+        let field = Field::new("entries", self.entries_type().arrow_type(), false);
+        ArrowType::Map(Box::new(field), false)
+    }
+
+    fn create_serializer_inner<'a>(&self, col: &'a ColumnRef) -> Result<TypeSerializerImpl<'a>> {
+        Ok(MapSerializer::try_create(col, &self.key, &self.value)?.into())
+    }
+
+    fn create_deserializer(&self, capacity: usize) -> TypeDeserializerImpl {
+        MapDeserializer {
+            key: Box::new(self.key.create_deserializer(capacity)),
+            value: Box::new(self.value.create_deserializer(capacity)),
+            builder: MutableArrayColumn::with_capacity_meta(capacity, self.column_meta()),
+        }
+        .into()
+    }
+
+    fn create_mutable(&self, capacity: usize) -> Box<dyn MutableColumn> {
+        Box::new(MutableArrayColumn::with_capacity_meta(
+            capacity,
+            self.column_meta(),
+        ))
+    }
+}
+
+impl std::fmt::Debug for MapType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -46,6 +46,10 @@ pub fn create_primitive_datatype<T: PrimitiveType>() -> DataTypeImpl {
         (true, false, 2) => DataTypeImpl::Int16(Int16Type { _t: PhantomData }),
         (true, false, 4) => DataTypeImpl::Int32(Int32Type { _t: PhantomData }),
         (true, false, 8) => DataTypeImpl::Int64(Int64Type { _t: PhantomData }),
+        // i128 is only used as the physical type of decimal
+        (true, false, 16) => {
+            DataTypeImpl::Decimal(DecimalType::create(DECIMAL128_MAX_PRECISION, 0))
+        }
 
         (true, true, 4) => DataTypeImpl::Float32(Float32Type { _t: PhantomData }),
         (true, true, 8) => DataTypeImpl::Float64(Float64Type { _t: PhantomData }),
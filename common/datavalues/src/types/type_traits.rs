@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::FixedSizeBinaryArray;
+use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::compute::arithmetics::basic::NativeArithmetics;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::ArrayRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use num::NumCast;
@@ -25,11 +30,16 @@ use crate::DFTryFrom;
 use crate::DataTypeImpl;
 use crate::DataValue;
 use crate::DateType;
+use crate::DecimalType;
+use crate::LargeBinaryArray;
 use crate::Scalar;
 use crate::TimestampType;
 use crate::TypeID;
 use crate::VariantType;
 use crate::VariantValue;
+use crate::DECIMAL256_BYTE_SIZE;
+use crate::DECIMAL_MAX_PRECISION;
+use crate::I256;
 
 pub trait PrimitiveType:
     NativeArithmetics
@@ -67,6 +77,7 @@ impl_primitive!(i8, i64, true, false, 1);
 impl_primitive!(i16, i64, true, false, 2);
 impl_primitive!(i32, i64, true, false, 4);
 impl_primitive!(i64, i64, true, false, 8);
+impl_primitive!(i128, i128, true, false, 16);
 impl_primitive!(f32, f64, true, true, 4);
 impl_primitive!(f64, f64, true, true, 8);
 
@@ -137,6 +148,63 @@ pub trait ObjectType:
     fn data_type() -> DataTypeImpl;
 
     fn column_name() -> &'static str;
+
+    /// Convert the values into arrow array of `arrow_type`, the values are kept as text by default.
+    fn to_arrow_array(values: &[Self], arrow_type: ArrowType) -> ArrayRef {
+        let mut offsets: Vec<i64> = Vec::with_capacity(values.len() + 1);
+        let mut bytes: Vec<u8> = Vec::with_capacity(values.len());
+
+        let mut offset: i64 = 0;
+        offsets.push(offset);
+        for val in values {
+            let v = val.to_string();
+            bytes.extend_from_slice(v.as_bytes());
+            offset += v.len() as i64;
+            offsets.push(offset);
+        }
+
+        Box::new(LargeBinaryArray::from_data(
+            arrow_type,
+            Buffer::from(offsets),
+            Buffer::from(bytes),
+            None,
+        ))
+    }
+
+    /// Read the values from the arrow array created by `to_arrow_array`, the null
+    /// values are read as the default value.
+    fn from_arrow_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = array
+            .as_any()
+            .downcast_ref::<LargeBinaryArray>()
+            .ok_or_else(|| {
+                ErrorCode::BadDataValueType(format!(
+                    "{} expects LargeBinary array, but got {:?}",
+                    Self::column_name(),
+                    array.data_type()
+                ))
+            })?;
+
+        let mut values = Vec::with_capacity(array.len());
+        for (i, value) in array.values_iter().enumerate() {
+            if !array.is_valid(i) {
+                values.push(Self::default());
+                continue;
+            }
+            let value = std::str::from_utf8(value)
+                .ok()
+                .and_then(|text| Self::from_str(text).ok())
+                .ok_or_else(|| {
+                    ErrorCode::BadBytes(format!(
+                        "Cannot parse value {:?} of {}",
+                        String::from_utf8_lossy(value),
+                        Self::column_name()
+                    ))
+                })?;
+            values.push(value);
+        }
+        Ok(values)
+    }
 }
 
 impl ObjectType for VariantValue {
@@ -149,6 +217,53 @@ impl ObjectType for VariantValue {
     }
 }
 
+impl ObjectType for I256 {
+    fn data_type() -> DataTypeImpl {
+        DecimalType::new_impl(DECIMAL_MAX_PRECISION, 0)
+    }
+
+    fn column_name() -> &'static str {
+        "Decimal256Column"
+    }
+
+    /// The values are kept as 32 bytes big-endian two's complement integers,
+    /// which is the layout of decimal stored as parquet `FIXED_LEN_BYTE_ARRAY(32)`.
+    fn to_arrow_array(values: &[Self], arrow_type: ArrowType) -> ArrayRef {
+        let mut bytes: Vec<u8> = Vec::with_capacity(values.len() * DECIMAL256_BYTE_SIZE);
+        for value in values {
+            bytes.extend_from_slice(&value.to_be_bytes());
+        }
+
+        Box::new(FixedSizeBinaryArray::from_data(
+            arrow_type,
+            Buffer::from(bytes),
+            None,
+        ))
+    }
+
+    fn from_arrow_array(array: &dyn Array) -> Result<Vec<Self>> {
+        let array = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .filter(|array| array.size() == DECIMAL256_BYTE_SIZE)
+            .ok_or_else(|| {
+                ErrorCode::BadDataValueType(format!(
+                    "{} expects FixedSizeBinary({}) array, but got {:?}",
+                    Self::column_name(),
+                    DECIMAL256_BYTE_SIZE,
+                    array.data_type()
+                ))
+            })?;
+
+        Ok(array
+            .values()
+            .as_slice()
+            .chunks_exact(DECIMAL256_BYTE_SIZE)
+            .map(|chunk| I256::from_be_bytes(chunk.try_into().unwrap()))
+            .collect())
+    }
+}
+
 pub trait LargePrimitive: Default + Sized + 'static {
     const BYTE_SIZE: usize;
     fn serialize_to(&self, _bytes: &mut [u8]);
@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::FixedSizeBinaryArray;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_io::prelude::FormatSettings;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_decimal_storage() -> Result<()> {
+    let narrow = DecimalType::try_create(38, 2)?;
+    assert!(!narrow.is_decimal256());
+    assert_eq!(narrow.data_type_id(), TypeID::Decimal);
+
+    let wide = DecimalType::try_create(50, 2)?;
+    assert!(wide.is_decimal256());
+    assert_eq!(wide.data_type_id(), TypeID::Decimal256);
+    assert_eq!(
+        wide.data_type_id().to_physical_type(),
+        PhysicalTypeID::Int256
+    );
+
+    assert!(DecimalType::try_create(DECIMAL_MAX_PRECISION, 0).is_ok());
+    assert!(DecimalType::try_create(DECIMAL_MAX_PRECISION + 1, 0).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_decimal256_values() -> Result<()> {
+    let decimal = DecimalType::create(50, 2);
+    let text = "123456789012345678901234567890123456789012.34";
+
+    let v: I256 = decimal.parse_value(text.as_bytes())?;
+    assert_eq!(
+        v,
+        "12345678901234567890123456789012345678901234"
+            .parse::<I256>()
+            .unwrap()
+    );
+    assert_eq!(decimal.format_value(v), text);
+    assert_eq!(decimal.format_value(-v), format!("-{}", text));
+
+    // Rounded half away from zero.
+    let v: I256 = DecimalType::create(50, 1).rescale(v, 2)?;
+    assert_eq!(
+        DecimalType::create(50, 1).format_value(v),
+        "123456789012345678901234567890123456789012.3"
+    );
+    let v: I256 = DecimalType::create(50, 0).parse_value(b"-2.5")?;
+    assert_eq!(v, I256::from(-3));
+
+    // 10^48 doesn't fit in DECIMAL(50, 2).
+    let overflow = format!("1{}", "0".repeat(48));
+    assert!(decimal.parse_value::<I256>(overflow.as_bytes()).is_err());
+    assert!(decimal.rescale(I256::from(1), 0).is_ok());
+    Ok(())
+}
+
+#[test]
+fn test_decimal256_column() -> Result<()> {
+    let data_type = DecimalType::new_impl(50, 2);
+    let column = data_type.create_constant_column(&DataValue::Int256(I256::from(1234)), 3)?;
+    assert_eq!(column.len(), 3);
+    assert_eq!(column.get(2), DataValue::Int256(I256::from(1234)));
+
+    let serializer = data_type.create_serializer(&column)?;
+    let format = FormatSettings::default();
+    assert_eq!(serializer.serialize_field(0, &format)?, "12.34");
+
+    // The precision and scale are kept in the arrow field.
+    let field = DataField::new("a", data_type.clone());
+    let arrow_field = field.to_arrow();
+    assert_eq!(DataField::from(&arrow_field), field);
+
+    // The values are kept as 32 bytes big-endian integers in arrow.
+    let column = Series::from_data(vec![I256::from(1), I256::from(-2)]);
+    let array = column.as_arrow_array(data_type.clone());
+    let binary = array
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    assert_eq!(binary.size(), DECIMAL256_BYTE_SIZE);
+    assert_eq!(binary.value(0)[31], 1);
+    assert_eq!(binary.value(1)[0], 0xff);
+    let column = array.into_column();
+    let values: &Decimal256Column = Series::check_get(&column)?;
+    assert_eq!(values.values(), &[I256::from(1), I256::from(-2)]);
+
+    // The array of other layout can not be read.
+    let array = LargeBinaryArray::from_slice(&["1", "-2"]);
+    assert!(Decimal256Column::try_from_arrow_array(&array).is_err());
+    Ok(())
+}
@@ -180,6 +180,7 @@ build_exceptions! {
     IllegalUserInfoFormat(2203),
     UnknownRole(2204),
     IllegalUserSettingFormat(2205),
+    UserQuotaExceeded(2206),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -214,6 +215,22 @@ build_exceptions! {
     UnknownUDF(2602),
     UdfAlreadyExists(2603),
 
+    // Row access policy error codes.
+    IllegalRowAccessPolicyFormat(2611),
+    UnknownRowAccessPolicy(2612),
+    RowAccessPolicyAlreadyExists(2613),
+
+    // Masking policy error codes.
+    IllegalMaskingPolicyFormat(2614),
+    UnknownMaskingPolicy(2615),
+    MaskingPolicyAlreadyExists(2616),
+
+    // Network policy error codes.
+    IllegalNetworkPolicyFormat(2617),
+    UnknownNetworkPolicy(2618),
+    NetworkPolicyAlreadyExists(2619),
+    NetworkPolicyIsUsedByUser(2620),
+
     // Database error codes.
     UnknownDatabaseEngine(2701),
     UnknownTableEngine(2702),
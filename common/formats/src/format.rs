@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::sync::Arc;
 
 use common_datablocks::DataBlock;
 use common_datavalues::TypeDeserializerImpl;
@@ -42,6 +43,24 @@ pub trait InputFormat: Send + Sync {
         Err(ErrorCode::UnImplement("Unimplement error"))
     }
 
+    /// Returns the size of the tail to read ahead of the file given the tail read so far,
+    /// for the formats keeping their metadata at the end of the file, e.g. ORC. The other
+    /// formats return 0.
+    fn tail_size(&self, _tail: &[u8]) -> Result<usize> {
+        Ok(0)
+    }
+
+    /// Sets the tail read ahead to the state, which reads the file from `offset`.
+    fn set_tail(
+        &self,
+        _state: &mut Box<dyn InputState>,
+        _file_size: usize,
+        _tail: Arc<Vec<u8>>,
+        _offset: usize,
+    ) -> Result<()> {
+        Ok(())
+    }
+
     fn deserialize_data(&self, state: &mut Box<dyn InputState>) -> Result<Vec<DataBlock>>;
 
     fn deserialize_complete_split(&self, split: FileSplit) -> Result<Vec<DataBlock>>;
@@ -0,0 +1,165 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::io::avro::read;
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FileSplit;
+use common_io::prelude::FormatSettings;
+
+use crate::FormatFactory;
+use crate::InputFormat;
+use crate::InputState;
+
+pub struct AvroInputState {
+    pub memory: Vec<u8>,
+}
+
+impl InputState for AvroInputState {
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Avro object container file, the avro schema is embedded in the file header.
+///
+/// The record fields are matched with the table columns by name, the unions of
+/// null and another type are mapped to the nullable type.
+pub struct AvroInputFormat {
+    schema: DataSchemaRef,
+}
+
+impl AvroInputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_input(
+            "avro",
+            Box::new(
+                |name: &str, schema: DataSchemaRef, _settings: FormatSettings| {
+                    AvroInputFormat::try_create(name, schema)
+                },
+            ),
+        )
+    }
+
+    pub fn try_create(_name: &str, schema: DataSchemaRef) -> Result<Arc<dyn InputFormat>> {
+        Ok(Arc::new(AvroInputFormat { schema }))
+    }
+
+    // Returns the index of each table column in the avro record.
+    fn projection(&self, avro_schema: &DataSchema) -> Result<Vec<usize>> {
+        let mut projection = Vec::with_capacity(self.schema.num_fields());
+        for field in self.schema.fields() {
+            let index = avro_schema.index_of(field.name()).map_err(|_| {
+                ErrorCode::BadBytes(format!(
+                    "avro schema mismatch, column {} is not found in the avro record",
+                    field.name()
+                ))
+            })?;
+
+            let avro_field = avro_schema.field(index);
+            let expected = remove_nullable(field.data_type());
+            let actual = remove_nullable(avro_field.data_type());
+            if expected != actual {
+                return Err(ErrorCode::BadBytes(format!(
+                    "avro schema mismatch, column {} expected type: {}, got: {}",
+                    field.name(),
+                    expected.name(),
+                    actual.name()
+                )));
+            }
+
+            projection.push(index);
+        }
+        Ok(projection)
+    }
+}
+
+impl InputFormat for AvroInputFormat {
+    fn create_state(&self) -> Box<dyn InputState> {
+        Box::new(AvroInputState { memory: vec![] })
+    }
+
+    fn deserialize_data(&self, state: &mut Box<dyn InputState>) -> Result<Vec<DataBlock>> {
+        let mut state = std::mem::replace(state, self.create_state());
+        let state = state.as_any().downcast_mut::<AvroInputState>().unwrap();
+        let memory = std::mem::take(&mut state.memory);
+        if memory.is_empty() {
+            return Ok(vec![]);
+        }
+        self.deserialize_complete_split(FileSplit {
+            path: None,
+            start_offset: 0,
+            start_row: 0,
+            buf: memory,
+        })
+    }
+
+    fn deserialize_complete_split(&self, split: FileSplit) -> Result<Vec<DataBlock>> {
+        let mut cursor = Cursor::new(&split.buf);
+        let (avro_schemas, arrow_schema, codec, file_marker) = read::read_metadata(&mut cursor)?;
+        let avro_schema = DataSchema::from(&arrow_schema);
+        let projection = self.projection(&avro_schema)?;
+
+        let reader = read::Reader::new(
+            read::Decompressor::new(read::BlockStreamIterator::new(cursor, file_marker), codec),
+            avro_schemas,
+            arrow_schema.fields,
+            None,
+        );
+
+        let mut data_blocks = vec![];
+        for chunk in reader {
+            let arrays = chunk?.into_arrays();
+            let mut columns = Vec::with_capacity(projection.len());
+            for (field, index) in self.schema.fields().iter().zip(projection.iter()) {
+                let array = arrays[*index].clone();
+                if !field.is_nullable() && array.null_count() > 0 {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "column {} is not nullable, but the avro record contains null values",
+                        field.name()
+                    )));
+                }
+                columns.push(array);
+            }
+            data_blocks.push(DataBlock::from_chunk(&self.schema, &Chunk::new(columns))?);
+        }
+
+        Ok(data_blocks)
+    }
+
+    fn read_buf(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<(usize, bool)> {
+        let state = state.as_any().downcast_mut::<AvroInputState>().unwrap();
+        state.memory.extend_from_slice(buf);
+        Ok((buf.len(), false))
+    }
+
+    fn take_buf(&self, state: &mut Box<dyn InputState>) -> Vec<u8> {
+        let state = state.as_any().downcast_mut::<AvroInputState>().unwrap();
+        std::mem::take(&mut state.memory)
+    }
+
+    fn skip_header(&self, _: &[u8], _: &mut Box<dyn InputState>, _: usize) -> Result<usize> {
+        Ok(0)
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::TypeDeserializer;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FileSplit;
+use common_io::prelude::FormatSettings;
+use serde_json::Value;
+
+use crate::format_ndjson::deserialize_json_object;
+use crate::format_ndjson::file_suffix;
+use crate::FormatFactory;
+use crate::InputFormat;
+use crate::InputState;
+
+pub struct JsonInputState {
+    pub memory: Vec<u8>,
+    pub file_name: Option<String>,
+}
+
+impl InputState for JsonInputState {
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A json array of objects, the whole file must be read before deserializing.
+pub struct JsonInputFormat {
+    schema: DataSchemaRef,
+    settings: FormatSettings,
+}
+
+impl JsonInputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_input(
+            "Json",
+            Box::new(
+                |name: &str, schema: DataSchemaRef, settings: FormatSettings| {
+                    JsonInputFormat::try_create(name, schema, settings)
+                },
+            ),
+        )
+    }
+
+    pub fn try_create(
+        _name: &str,
+        schema: DataSchemaRef,
+        settings: FormatSettings,
+    ) -> Result<Arc<dyn InputFormat>> {
+        Ok(Arc::new(JsonInputFormat { schema, settings }))
+    }
+}
+
+impl InputFormat for JsonInputFormat {
+    fn create_state(&self) -> Box<dyn InputState> {
+        Box::new(JsonInputState {
+            memory: vec![],
+            file_name: None,
+        })
+    }
+
+    fn set_state(
+        &self,
+        state: &mut Box<dyn InputState>,
+        file_name: String,
+        _start_row_index: usize,
+    ) -> Result<()> {
+        let state = state.as_any().downcast_mut::<JsonInputState>().unwrap();
+        state.file_name = Some(file_name);
+        Ok(())
+    }
+
+    fn deserialize_data(&self, state: &mut Box<dyn InputState>) -> Result<Vec<DataBlock>> {
+        let mut state = std::mem::replace(state, self.create_state());
+        let state = state.as_any().downcast_mut::<JsonInputState>().unwrap();
+        let memory = std::mem::take(&mut state.memory);
+        if memory.is_empty() {
+            return Ok(vec![]);
+        }
+        self.deserialize_complete_split(FileSplit {
+            path: state.file_name.clone(),
+            start_offset: 0,
+            start_row: 0,
+            buf: memory,
+        })
+    }
+
+    fn deserialize_complete_split(&self, split: FileSplit) -> Result<Vec<DataBlock>> {
+        let value = serde_json::from_slice::<Value>(&split.buf).map_err(|cause| {
+            ErrorCode::BadBytes(format!(
+                "Parse Json error{}: {}",
+                file_suffix(&split.path),
+                cause
+            ))
+        })?;
+
+        let rows = match value {
+            Value::Array(rows) => rows,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Parse Json error{}: expected a json array of objects",
+                    file_suffix(&split.path)
+                )));
+            }
+        };
+
+        let mut deserializers = self.schema.create_deserializers(rows.len());
+        for (row_index, row) in rows.iter().enumerate() {
+            deserialize_json_object(&self.schema, &self.settings, &mut deserializers, row)
+                .map_err(|cause| {
+                    cause.add_message(format!(
+                        "Parse Json error at row {}{}",
+                        row_index,
+                        file_suffix(&split.path)
+                    ))
+                })?;
+        }
+
+        let mut columns = Vec::with_capacity(deserializers.len());
+        for deserializer in &mut deserializers {
+            columns.push(deserializer.finish_to_column());
+        }
+
+        Ok(vec![DataBlock::create(self.schema.clone(), columns)])
+    }
+
+    fn read_buf(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<(usize, bool)> {
+        let state = state.as_any().downcast_mut::<JsonInputState>().unwrap();
+        state.memory.extend_from_slice(buf);
+        Ok((buf.len(), false))
+    }
+
+    fn take_buf(&self, state: &mut Box<dyn InputState>) -> Vec<u8> {
+        let state = state.as_any().downcast_mut::<JsonInputState>().unwrap();
+        std::mem::take(&mut state.memory)
+    }
+
+    fn skip_header(&self, _: &[u8], _: &mut Box<dyn InputState>, _: usize) -> Result<usize> {
+        Ok(0)
+    }
+}
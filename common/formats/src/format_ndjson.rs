@@ -0,0 +1,295 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::TypeDeserializer;
+use common_datavalues::TypeDeserializerImpl;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::position1;
+use common_io::prelude::FileSplit;
+use common_io::prelude::FormatSettings;
+use serde_json::Value;
+
+use crate::FormatFactory;
+use crate::InputFormat;
+use crate::InputState;
+
+pub struct NDJsonInputState {
+    pub memory: Vec<u8>,
+    pub accepted_rows: usize,
+    pub accepted_bytes: usize,
+    pub need_more_data: bool,
+    pub start_row_index: usize,
+    pub file_name: Option<String>,
+}
+
+impl InputState for NDJsonInputState {
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Newline delimited json, each line is a json object whose keys are the column names.
+pub struct NDJsonInputFormat {
+    schema: DataSchemaRef,
+    min_accepted_rows: usize,
+    min_accepted_bytes: usize,
+    settings: FormatSettings,
+}
+
+impl NDJsonInputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        for name in ["NDJson", "JsonEachRow"] {
+            factory.register_input(
+                name,
+                Box::new(
+                    |name: &str, schema: DataSchemaRef, settings: FormatSettings| {
+                        NDJsonInputFormat::try_create(
+                            name,
+                            schema,
+                            settings,
+                            8192,
+                            10 * 1024 * 1024,
+                        )
+                    },
+                ),
+            );
+        }
+    }
+
+    pub fn try_create(
+        _name: &str,
+        schema: DataSchemaRef,
+        settings: FormatSettings,
+        min_accepted_rows: usize,
+        min_accepted_bytes: usize,
+    ) -> Result<Arc<dyn InputFormat>> {
+        Ok(Arc::new(NDJsonInputFormat {
+            schema,
+            settings,
+            min_accepted_rows,
+            min_accepted_bytes,
+        }))
+    }
+
+    fn find_delimiter(&self, buf: &[u8], pos: usize, state: &mut NDJsonInputState) -> usize {
+        let position = pos + position1::<true, b'\n'>(&buf[pos..]);
+
+        if position != buf.len() {
+            state.accepted_rows += 1;
+            state.accepted_bytes += position - pos;
+
+            if state.accepted_rows >= self.min_accepted_rows
+                || state.accepted_bytes >= self.min_accepted_bytes
+            {
+                state.need_more_data = false;
+            }
+
+            return position + 1;
+        }
+
+        buf.len()
+    }
+}
+
+impl InputFormat for NDJsonInputFormat {
+    fn support_parallel(&self) -> bool {
+        true
+    }
+
+    fn create_state(&self) -> Box<dyn InputState> {
+        Box::new(NDJsonInputState {
+            memory: vec![],
+            accepted_rows: 0,
+            accepted_bytes: 0,
+            need_more_data: false,
+            start_row_index: 0,
+            file_name: None,
+        })
+    }
+
+    fn set_state(
+        &self,
+        state: &mut Box<dyn InputState>,
+        file_name: String,
+        start_row_index: usize,
+    ) -> Result<()> {
+        let state = state.as_any().downcast_mut::<NDJsonInputState>().unwrap();
+        state.file_name = Some(file_name);
+        state.start_row_index = start_row_index;
+        Ok(())
+    }
+
+    fn deserialize_data(&self, state: &mut Box<dyn InputState>) -> Result<Vec<DataBlock>> {
+        let mut state = std::mem::replace(state, self.create_state());
+        let state = state.as_any().downcast_mut::<NDJsonInputState>().unwrap();
+        let memory = std::mem::take(&mut state.memory);
+        self.deserialize_complete_split(FileSplit {
+            path: state.file_name.clone(),
+            start_offset: 0,
+            start_row: state.start_row_index,
+            buf: memory,
+        })
+    }
+
+    fn read_buf(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<(usize, bool)> {
+        let mut index = 0;
+        let state = state.as_any().downcast_mut::<NDJsonInputState>().unwrap();
+
+        state.need_more_data = true;
+        while index < buf.len() && state.need_more_data {
+            index = self.find_delimiter(buf, index, state);
+        }
+
+        state.memory.extend_from_slice(&buf[0..index]);
+        Ok((index, !state.need_more_data))
+    }
+
+    fn take_buf(&self, state: &mut Box<dyn InputState>) -> Vec<u8> {
+        let state = state.as_any().downcast_mut::<NDJsonInputState>().unwrap();
+        std::mem::take(&mut state.memory)
+    }
+
+    fn skip_header(
+        &self,
+        buf: &[u8],
+        state: &mut Box<dyn InputState>,
+        force: usize,
+    ) -> Result<usize> {
+        if force > 0 {
+            let mut index = 0;
+            let state = state.as_any().downcast_mut::<NDJsonInputState>().unwrap();
+
+            while index < buf.len() {
+                index = self.find_delimiter(buf, index, state);
+                if state.accepted_rows == force {
+                    return Ok(index);
+                }
+            }
+        }
+        Ok(0)
+    }
+
+    fn read_row_num(&self, state: &mut Box<dyn InputState>) -> Result<usize> {
+        let state = state.as_any().downcast_mut::<NDJsonInputState>().unwrap();
+        Ok(state.accepted_rows)
+    }
+
+    fn deserialize_complete_split(&self, split: FileSplit) -> Result<Vec<DataBlock>> {
+        let mut deserializers = self.schema.create_deserializers(self.min_accepted_rows);
+
+        let mut row_index = 0;
+        for line in split.buf.split(|b| *b == b'\n') {
+            if line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+
+            let line_number = row_index + split.start_row;
+            let value = serde_json::from_slice::<Value>(line).map_err(|cause| {
+                ErrorCode::BadBytes(format!(
+                    "Parse NDJson error at line {}{}: {}",
+                    line_number,
+                    file_suffix(&split.path),
+                    cause
+                ))
+            })?;
+
+            deserialize_json_object(&self.schema, &self.settings, &mut deserializers, &value)
+                .map_err(|cause| {
+                    cause.add_message(format!(
+                        "Parse NDJson error at line {}{}",
+                        line_number,
+                        file_suffix(&split.path)
+                    ))
+                })?;
+            row_index += 1;
+        }
+
+        let mut columns = Vec::with_capacity(deserializers.len());
+        for deserializer in &mut deserializers {
+            columns.push(deserializer.finish_to_column());
+        }
+
+        Ok(vec![DataBlock::create(self.schema.clone(), columns)])
+    }
+}
+
+/// Deserialize the json object into a row, the nested values are kept as they are for
+/// variant columns. The missing fields are filled with default values if `empty_as_default`
+/// is set, otherwise they are treated as null.
+pub(crate) fn deserialize_json_object(
+    schema: &DataSchemaRef,
+    settings: &FormatSettings,
+    deserializers: &mut [TypeDeserializerImpl],
+    value: &Value,
+) -> Result<()> {
+    let object = match value {
+        Value::Object(object) => object,
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Expected a json object, but got {}",
+                maybe_truncated(&other.to_string(), 1024)
+            )));
+        }
+    };
+
+    for (field, deserializer) in schema.fields().iter().zip(deserializers.iter_mut()) {
+        let value = match object.get(field.name()) {
+            Some(value) => value,
+            None if settings.empty_as_default => {
+                deserializer.de_default(settings);
+                continue;
+            }
+            None => &Value::Null,
+        };
+
+        deserializer.de_json(value, settings).map_err(|cause| {
+            ErrorCode::BadBytes(format!(
+                "Cannot parse column {}: type={}, err={}, value={}",
+                field.name(),
+                field.data_type().name(),
+                cause.message(),
+                maybe_truncated(&value.to_string(), 1024),
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn file_suffix(path: &Option<String>) -> String {
+    match path {
+        Some(path) => format!(" of file {}", path),
+        None => String::new(),
+    }
+}
+
+fn maybe_truncated(s: &str, limit: usize) -> Cow<'_, str> {
+    if s.len() > limit {
+        let mut end = limit;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        Cow::Owned(format!("(first {}B of {}B): {}", end, s.len(), &s[..end]))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
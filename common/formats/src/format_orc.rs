@@ -0,0 +1,828 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::io;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::sync::Arc;
+
+use common_arrow::arrow::array::Array;
+use common_arrow::arrow::array::BinaryArray;
+use common_arrow::arrow::array::BooleanArray;
+use common_arrow::arrow::array::PrimitiveArray;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::types::NativeType;
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::TypeID;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::FileSplit;
+use common_io::prelude::FormatSettings;
+use orc_format::proto::column_encoding::Kind as EncodingKind;
+use orc_format::proto::stream::Kind as StreamKind;
+use orc_format::proto::r#type::Kind as TypeKind;
+use orc_format::proto::Footer;
+use orc_format::proto::StripeFooter;
+use orc_format::read;
+use orc_format::read::decode;
+use orc_format::read::Column;
+
+use crate::FormatFactory;
+use crate::InputFormat;
+use crate::InputState;
+
+// The least size of the tail of orc file to read ahead, which holds the footer usually.
+const DEFAULT_TAIL_SIZE: usize = 16 * 1024;
+
+/// The state of reading an orc file.
+///
+/// If the tail of the file is read ahead, the stripes are known before the file is read
+/// from the start, each split is a stripe followed by the tail. Otherwise the whole file
+/// is read into one split. A split ends with the length of the tail and the file size,
+/// see `SplitReader`.
+pub struct OrcInputState {
+    pub memory: Vec<u8>,
+    /// The tail read ahead and the size of the file.
+    tail: Option<(Arc<Vec<u8>>, usize)>,
+    /// The offset in the file of `memory`.
+    offset: usize,
+    /// The end offsets of the stripes after `offset`.
+    stripe_ends: Vec<usize>,
+}
+
+impl InputState for OrcInputState {
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// ORC file, the schema and the stripe locations are read from the file footer.
+///
+/// The fields of the root struct are matched with the table columns by name, only
+/// the primitive types are supported, in both the RLE v1 and v2 encodings.
+pub struct OrcInputFormat {
+    schema: DataSchemaRef,
+}
+
+impl OrcInputFormat {
+    pub fn register(factory: &mut FormatFactory) {
+        factory.register_input(
+            "orc",
+            Box::new(
+                |name: &str, schema: DataSchemaRef, _settings: FormatSettings| {
+                    OrcInputFormat::try_create(name, schema)
+                },
+            ),
+        )
+    }
+
+    pub fn try_create(_name: &str, schema: DataSchemaRef) -> Result<Arc<dyn InputFormat>> {
+        Ok(Arc::new(OrcInputFormat { schema }))
+    }
+
+    // Returns the column id and the type kind in the orc file of each table column.
+    fn projection(&self, footer: &Footer) -> Result<Vec<(u32, TypeKind)>> {
+        let root = footer
+            .types
+            .get(0)
+            .ok_or_else(|| ErrorCode::BadBytes("orc file has no type"))?;
+        if root.kind() != TypeKind::Struct {
+            return Err(ErrorCode::BadBytes(
+                "the root type of orc file must be a struct",
+            ));
+        }
+
+        let mut projection = Vec::with_capacity(self.schema.num_fields());
+        for field in self.schema.fields() {
+            let position = root
+                .field_names
+                .iter()
+                .position(|name| name == field.name())
+                .ok_or_else(|| {
+                    ErrorCode::BadBytes(format!(
+                        "orc schema mismatch, column {} is not found in the orc file",
+                        field.name()
+                    ))
+                })?;
+            let column_id = root.subtypes[position];
+            let kind = footer
+                .types
+                .get(column_id as usize)
+                .ok_or_else(|| ErrorCode::BadBytes(format!("orc type {} not found", column_id)))?
+                .kind();
+
+            let expected = remove_nullable(field.data_type());
+            let matched = match kind {
+                TypeKind::Boolean => expected.data_type_id() == TypeID::Boolean,
+                TypeKind::Byte => expected.data_type_id() == TypeID::Int8,
+                TypeKind::Short => expected.data_type_id() == TypeID::Int16,
+                TypeKind::Int => expected.data_type_id() == TypeID::Int32,
+                TypeKind::Long => expected.data_type_id() == TypeID::Int64,
+                TypeKind::Float => expected.data_type_id() == TypeID::Float32,
+                TypeKind::Double => expected.data_type_id() == TypeID::Float64,
+                TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                    expected.data_type_id() == TypeID::String
+                }
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "orc type {:?} of column {} is not supported",
+                        kind,
+                        field.name()
+                    )));
+                }
+            };
+            if !matched {
+                return Err(ErrorCode::BadBytes(format!(
+                    "orc schema mismatch, column {} expected type: {}, got: {:?}",
+                    field.name(),
+                    expected.name(),
+                    kind
+                )));
+            }
+
+            projection.push((column_id, kind));
+        }
+        Ok(projection)
+    }
+
+    fn deserialize_column(
+        &self,
+        kind: TypeKind,
+        footer: &StripeFooter,
+        column_id: u32,
+        column: &Column,
+    ) -> Result<Box<dyn Array>> {
+        let validity = deserialize_validity(footer, column_id, column)?;
+        let array: Box<dyn Array> = match kind {
+            TypeKind::Boolean => Box::new(deserialize_bool(column, validity)?),
+            TypeKind::Byte => Box::new(deserialize_byte(column, validity)?),
+            TypeKind::Short => {
+                Box::new(deserialize_int::<i16>(ArrowType::Int16, column, validity)?)
+            }
+            TypeKind::Int => Box::new(deserialize_int::<i32>(ArrowType::Int32, column, validity)?),
+            TypeKind::Long => Box::new(deserialize_int::<i64>(ArrowType::Int64, column, validity)?),
+            TypeKind::Float => Box::new(deserialize_float::<f32>(
+                ArrowType::Float32,
+                column,
+                validity,
+            )?),
+            TypeKind::Double => Box::new(deserialize_float::<f64>(
+                ArrowType::Float64,
+                column,
+                validity,
+            )?),
+            _ => Box::new(deserialize_string(column, validity)?),
+        };
+        Ok(array)
+    }
+}
+
+impl InputFormat for OrcInputFormat {
+    fn create_state(&self) -> Box<dyn InputState> {
+        Box::new(OrcInputState {
+            memory: vec![],
+            tail: None,
+            offset: 0,
+            stripe_ends: vec![],
+        })
+    }
+
+    fn tail_size(&self, tail: &[u8]) -> Result<usize> {
+        // The last byte is the length of the postscript, which tells the length of the
+        // footer and the metadata before it.
+        let postscript_len = match tail.last() {
+            None => return Ok(DEFAULT_TAIL_SIZE),
+            Some(len) => *len as usize,
+        };
+        if tail.len() < postscript_len + 1 {
+            return Ok(postscript_len + 1);
+        }
+        let postscript = &tail[tail.len() - 1 - postscript_len..tail.len() - 1];
+        let (footer_len, metadata_len) = postscript_lengths(postscript)?;
+        Ok(DEFAULT_TAIL_SIZE.max(1 + postscript_len + footer_len + metadata_len))
+    }
+
+    fn set_tail(
+        &self,
+        state: &mut Box<dyn InputState>,
+        file_size: usize,
+        tail: Arc<Vec<u8>>,
+        offset: usize,
+    ) -> Result<()> {
+        let state = state.as_any().downcast_mut::<OrcInputState>().unwrap();
+        let mut reader = SplitReader::try_create(&[], 0, &tail, file_size)?;
+        let metadata = read::read_metadata(&mut reader).map_err(orc_error)?;
+        state.stripe_ends = metadata
+            .footer
+            .stripes
+            .iter()
+            .map(|stripe| {
+                (stripe.offset()
+                    + stripe.index_length()
+                    + stripe.data_length()
+                    + stripe.footer_length()) as usize
+            })
+            .filter(|end| *end > offset)
+            .collect();
+        state.offset = offset;
+        state.tail = Some((tail, file_size));
+        Ok(())
+    }
+
+    fn deserialize_data(&self, state: &mut Box<dyn InputState>) -> Result<Vec<DataBlock>> {
+        let mut state = std::mem::replace(state, self.create_state());
+        let start_offset = state
+            .as_any()
+            .downcast_mut::<OrcInputState>()
+            .unwrap()
+            .offset;
+        let buf = self.take_buf(&mut state);
+        if buf.is_empty() {
+            return Ok(vec![]);
+        }
+        self.deserialize_complete_split(FileSplit {
+            path: None,
+            start_offset,
+            start_row: 0,
+            buf,
+        })
+    }
+
+    fn deserialize_complete_split(&self, split: FileSplit) -> Result<Vec<DataBlock>> {
+        let mut reader = SplitReader::try_decode(&split.buf, split.start_offset)?;
+        let metadata = read::read_metadata(&mut reader).map_err(orc_error)?;
+        let projection = self.projection(&metadata.footer)?;
+
+        let mut data_blocks = vec![];
+        for (stripe, info) in metadata.footer.stripes.iter().enumerate() {
+            let start = info.offset();
+            let end = start + info.index_length() + info.data_length() + info.footer_length();
+            if !reader.contains(start, end) {
+                // The stripe is in another split.
+                continue;
+            }
+
+            let mut scratch = vec![];
+            let footer = read::read_stripe_footer(&mut reader, &metadata, stripe, &mut scratch)
+                .map_err(orc_error)?;
+
+            let mut columns = Vec::with_capacity(projection.len());
+            for (field, (column_id, kind)) in self.schema.fields().iter().zip(projection.iter()) {
+                let column = read::read_stripe_column(
+                    &mut reader,
+                    &metadata,
+                    stripe,
+                    footer.clone(),
+                    *column_id,
+                    vec![],
+                )
+                .map_err(orc_error)?;
+                let array = self.deserialize_column(*kind, &footer, *column_id, &column)?;
+                if !field.is_nullable() && array.null_count() > 0 {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "column {} is not nullable, but the orc file contains null values",
+                        field.name()
+                    )));
+                }
+                columns.push(array);
+            }
+            data_blocks.push(DataBlock::from_chunk(&self.schema, &Chunk::new(columns))?);
+        }
+
+        Ok(data_blocks)
+    }
+
+    fn read_buf(&self, buf: &[u8], state: &mut Box<dyn InputState>) -> Result<(usize, bool)> {
+        let state = state.as_any().downcast_mut::<OrcInputState>().unwrap();
+        if state.tail.is_none() {
+            state.memory.extend_from_slice(buf);
+            return Ok((buf.len(), false));
+        }
+
+        let position = state.offset + state.memory.len();
+        match state.stripe_ends.iter().find(|end| **end > position) {
+            Some(end) => {
+                let size = buf.len().min(end - position);
+                state.memory.extend_from_slice(&buf[..size]);
+                Ok((size, position + size == *end))
+            }
+            // The rest of the file is in the tail.
+            None => Ok((buf.len(), false)),
+        }
+    }
+
+    fn take_buf(&self, state: &mut Box<dyn InputState>) -> Vec<u8> {
+        let state = state.as_any().downcast_mut::<OrcInputState>().unwrap();
+        let mut buf = std::mem::take(&mut state.memory);
+        if buf.is_empty() {
+            return buf;
+        }
+
+        let (tail, file_size) = match &state.tail {
+            Some((tail, file_size)) => (tail.as_slice(), *file_size),
+            None => (&[][..], state.offset + buf.len()),
+        };
+        buf.extend_from_slice(tail);
+        buf.extend_from_slice(&(tail.len() as u64).to_le_bytes());
+        buf.extend_from_slice(&(file_size as u64).to_le_bytes());
+        buf
+    }
+
+    fn skip_header(&self, _: &[u8], _: &mut Box<dyn InputState>, _: usize) -> Result<usize> {
+        Ok(0)
+    }
+}
+
+fn orc_error(e: orc_format::error::Error) -> ErrorCode {
+    ErrorCode::BadBytes(format!("fail to read orc file: {:?}", e))
+}
+
+/// Reads a split of orc file as if it's the whole file, the split is a part of the file
+/// starting at `body_offset`, followed by the tail of the file.
+struct SplitReader<'a> {
+    body: &'a [u8],
+    body_offset: u64,
+    tail: &'a [u8],
+    file_size: u64,
+    position: u64,
+}
+
+impl<'a> SplitReader<'a> {
+    fn try_create(
+        body: &'a [u8],
+        body_offset: usize,
+        tail: &'a [u8],
+        file_size: usize,
+    ) -> Result<Self> {
+        if tail.len() > file_size || body_offset + body.len() > file_size {
+            return Err(ErrorCode::BadBytes("orc split is out of the file"));
+        }
+        Ok(SplitReader {
+            body,
+            body_offset: body_offset as u64,
+            tail,
+            file_size: file_size as u64,
+            position: 0,
+        })
+    }
+
+    // Decodes the split taken from `OrcInputState`, which ends with the length of the
+    // tail and the file size.
+    fn try_decode(buf: &'a [u8], body_offset: usize) -> Result<Self> {
+        let too_short = || ErrorCode::BadBytes("orc split is too short");
+        let data_len = buf.len().checked_sub(16).ok_or_else(too_short)?;
+        let (data, lengths) = buf.split_at(data_len);
+        let tail_len = u64::from_le_bytes(lengths[..8].try_into().unwrap()) as usize;
+        let file_size = u64::from_le_bytes(lengths[8..].try_into().unwrap()) as usize;
+        let body_len = data.len().checked_sub(tail_len).ok_or_else(too_short)?;
+        let (body, tail) = data.split_at(body_len);
+        Self::try_create(body, body_offset, tail, file_size)
+    }
+
+    // Whether the range of the file is in the body of the split.
+    fn contains(&self, start: u64, end: u64) -> bool {
+        start >= self.body_offset && end <= self.body_offset + self.body.len() as u64
+    }
+}
+
+impl Read for SplitReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let tail_offset = self.file_size - self.tail.len() as u64;
+        let (data, offset) = if self.position >= self.body_offset
+            && self.position < self.body_offset + self.body.len() as u64
+        {
+            (self.body, self.body_offset)
+        } else if self.position >= tail_offset && self.position < self.file_size {
+            (self.tail, tail_offset)
+        } else if self.position >= self.file_size {
+            return Ok(0);
+        } else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("offset {} is out of the orc split", self.position),
+            ));
+        };
+
+        let data = &data[(self.position - offset) as usize..];
+        let size = data.len().min(buf.len());
+        buf[..size].copy_from_slice(&data[..size]);
+        self.position += size as u64;
+        Ok(size)
+    }
+}
+
+impl Seek for SplitReader<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(n) => n as i64,
+            SeekFrom::End(n) => self.file_size as i64 + n,
+            SeekFrom::Current(n) => self.position as i64 + n,
+        };
+        if position < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "seek to a negative offset of orc split",
+            ));
+        }
+        self.position = position as u64;
+        Ok(self.position)
+    }
+}
+
+fn read_varint(buf: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut value = 0_u64;
+    let mut shift = 0;
+    loop {
+        let byte = *buf
+            .get(*pos)
+            .ok_or_else(|| ErrorCode::BadBytes("orc varint is too short"))?;
+        *pos += 1;
+        if shift >= 64 {
+            return Err(ErrorCode::BadBytes("orc varint is too long"));
+        }
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte < 0x80 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+}
+
+// Returns the footer length and the metadata length in the postscript, which are the
+// fields 1 and 5 of the protobuf message.
+fn postscript_lengths(postscript: &[u8]) -> Result<(usize, usize)> {
+    let mut footer_len = None;
+    let mut metadata_len = 0;
+    let mut pos = 0;
+    while pos < postscript.len() {
+        let key = read_varint(postscript, &mut pos)?;
+        match key & 0x7 {
+            0 => {
+                let value = read_varint(postscript, &mut pos)? as usize;
+                match key >> 3 {
+                    1 => footer_len = Some(value),
+                    5 => metadata_len = value,
+                    _ => {}
+                }
+            }
+            1 => pos += 8,
+            2 => pos += read_varint(postscript, &mut pos)? as usize,
+            5 => pos += 4,
+            wire_type => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid wire type {} in orc postscript",
+                    wire_type
+                )));
+            }
+        }
+    }
+    let footer_len =
+        footer_len.ok_or_else(|| ErrorCode::BadBytes("orc postscript has no footer length"))?;
+    Ok((footer_len, metadata_len))
+}
+
+// The PRESENT stream is omitted if the column has no nulls in the stripe.
+fn deserialize_validity(
+    footer: &StripeFooter,
+    column_id: u32,
+    column: &Column,
+) -> Result<Option<Bitmap>> {
+    if !footer
+        .streams
+        .iter()
+        .any(|stream| stream.column() == column_id && stream.kind() == StreamKind::Present)
+    {
+        return Ok(None);
+    }
+
+    let num_rows = column.number_of_rows();
+    let stream = column
+        .get_stream(StreamKind::Present, vec![])
+        .map_err(orc_error)?;
+    let mut validity = MutableBitmap::with_capacity(num_rows);
+    for is_valid in decode::BooleanIter::new(stream, num_rows) {
+        validity.push(is_valid.map_err(orc_error)?);
+    }
+    if validity.len() != num_rows {
+        return Err(ErrorCode::BadBytes("orc present stream is too short"));
+    }
+    Ok(validity.into())
+}
+
+fn num_values(column: &Column, validity: &Option<Bitmap>) -> usize {
+    match validity {
+        Some(validity) => validity.len() - validity.null_count(),
+        None => column.number_of_rows(),
+    }
+}
+
+// Places the non-null values at the rows they belong to.
+fn spread<T: Default + Clone>(values: Vec<T>, validity: &Option<Bitmap>) -> Result<Vec<T>> {
+    let validity = match validity {
+        None => return Ok(values),
+        Some(validity) => validity,
+    };
+
+    let mut values = values.into_iter();
+    let mut result = Vec::with_capacity(validity.len());
+    for is_valid in validity.iter() {
+        if is_valid {
+            let value = values
+                .next()
+                .ok_or_else(|| ErrorCode::BadBytes("orc data stream is too short"))?;
+            result.push(value);
+        } else {
+            result.push(T::default());
+        }
+    }
+    Ok(result)
+}
+
+fn check_encoding(column: &Column, supported: &[EncodingKind]) -> Result<()> {
+    let encoding = column.encoding().kind();
+    if supported.contains(&encoding) {
+        Ok(())
+    } else {
+        Err(ErrorCode::BadBytes(format!(
+            "orc column encoding {:?} is not supported",
+            encoding
+        )))
+    }
+}
+
+// The integers of the DIRECT and DICTIONARY encodings (written before Hive 0.12) are
+// encoded by the RLE v1: a header byte `h` < 128 is followed by a delta byte and a base
+// varint of `h + 3` values, otherwise it's followed by `256 - h` literal varints.
+fn read_rle_v1(column: &Column, kind: StreamKind, length: usize, signed: bool) -> Result<Vec<i64>> {
+    let mut stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    let mut buf = vec![];
+    stream
+        .read_to_end(&mut buf)
+        .map_err(|e| ErrorCode::BadBytes(format!("fail to read orc file: {}", e)))?;
+
+    let read_value = |pos: &mut usize| -> Result<i64> {
+        let value = read_varint(&buf, pos)?;
+        if signed {
+            // zigzag encoded
+            Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+        } else {
+            Ok(value as i64)
+        }
+    };
+
+    let mut values = Vec::with_capacity(length);
+    let mut pos = 0;
+    while values.len() < length {
+        let header = *buf
+            .get(pos)
+            .ok_or_else(|| ErrorCode::BadBytes("orc data stream is too short"))?;
+        pos += 1;
+        if header < 128 {
+            let delta = *buf
+                .get(pos)
+                .ok_or_else(|| ErrorCode::BadBytes("orc data stream is too short"))?
+                as i8 as i64;
+            pos += 1;
+            let base = read_value(&mut pos)?;
+            for i in 0..header as i64 + 3 {
+                values.push(base.wrapping_add(delta.wrapping_mul(i)));
+            }
+        } else {
+            for _ in 0..256 - header as usize {
+                values.push(read_value(&mut pos)?);
+            }
+        }
+    }
+    values.truncate(length);
+    Ok(values)
+}
+
+fn is_rle_v1(column: &Column) -> bool {
+    matches!(
+        column.encoding().kind(),
+        EncodingKind::Direct | EncodingKind::Dictionary
+    )
+}
+
+fn read_signed(column: &Column, kind: StreamKind, length: usize) -> Result<Vec<i64>> {
+    if is_rle_v1(column) {
+        return read_rle_v1(column, kind, length, true);
+    }
+    let stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    let mut values = Vec::with_capacity(length);
+    for value in decode::SignedRleV2Iter::new(stream, length, vec![]) {
+        values.push(value.map_err(orc_error)?);
+    }
+    Ok(values)
+}
+
+fn read_unsigned(column: &Column, kind: StreamKind, length: usize) -> Result<Vec<u64>> {
+    if is_rle_v1(column) {
+        let values = read_rle_v1(column, kind, length, false)?;
+        return Ok(values.into_iter().map(|v| v as u64).collect());
+    }
+    let stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    let mut values = Vec::with_capacity(length);
+    for value in decode::UnsignedRleV2Iter::new(stream, length, vec![]) {
+        values.push(value.map_err(orc_error)?);
+    }
+    Ok(values)
+}
+
+fn read_bytes(column: &Column, kind: StreamKind, length: usize) -> Result<Vec<u8>> {
+    let mut stream = column.get_stream(kind, vec![]).map_err(orc_error)?;
+    let mut bytes = vec![0; length];
+    stream
+        .read_exact(&mut bytes)
+        .map_err(|e| ErrorCode::BadBytes(format!("fail to read orc file: {}", e)))?;
+    Ok(bytes)
+}
+
+fn deserialize_bool(column: &Column, validity: Option<Bitmap>) -> Result<BooleanArray> {
+    let length = num_values(column, &validity);
+    let stream = column
+        .get_stream(StreamKind::Data, vec![])
+        .map_err(orc_error)?;
+    let mut values = Vec::with_capacity(length);
+    for value in decode::BooleanIter::new(stream, length) {
+        values.push(value.map_err(orc_error)?);
+    }
+    let values = spread(values, &validity)?;
+    Ok(BooleanArray::try_new(
+        ArrowType::Boolean,
+        Bitmap::from_iter(values),
+        validity,
+    )?)
+}
+
+// The bytes are encoded by the byte RLE: a header byte `h` < 128 is followed by one byte
+// repeated `h + 3` times, otherwise it's followed by `256 - h` literal bytes.
+fn deserialize_byte(column: &Column, validity: Option<Bitmap>) -> Result<PrimitiveArray<i8>> {
+    let length = num_values(column, &validity);
+    let mut stream = column
+        .get_stream(StreamKind::Data, vec![])
+        .map_err(orc_error)?;
+    let mut buf = vec![];
+    stream
+        .read_to_end(&mut buf)
+        .map_err(|e| ErrorCode::BadBytes(format!("fail to read orc file: {}", e)))?;
+
+    let too_short = || ErrorCode::BadBytes("orc data stream is too short");
+    let mut values = Vec::with_capacity(length);
+    let mut pos = 0;
+    while values.len() < length {
+        let header = *buf.get(pos).ok_or_else(too_short)?;
+        pos += 1;
+        if header < 128 {
+            let value = *buf.get(pos).ok_or_else(too_short)? as i8;
+            pos += 1;
+            values.extend(std::iter::repeat(value).take(header as usize + 3));
+        } else {
+            let n = 256 - header as usize;
+            let literals = buf.get(pos..pos + n).ok_or_else(too_short)?;
+            pos += n;
+            values.extend(literals.iter().map(|v| *v as i8));
+        }
+    }
+    values.truncate(length);
+    let values = spread(values, &validity)?;
+    Ok(PrimitiveArray::try_new(
+        ArrowType::Int8,
+        values.into(),
+        validity,
+    )?)
+}
+
+fn deserialize_int<T>(
+    data_type: ArrowType,
+    column: &Column,
+    validity: Option<Bitmap>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Default + TryFrom<i64>,
+{
+    check_encoding(column, &[EncodingKind::Direct, EncodingKind::DirectV2])?;
+    let length = num_values(column, &validity);
+    let values = read_signed(column, StreamKind::Data, length)?
+        .into_iter()
+        .map(|v| {
+            T::try_from(v)
+                .map_err(|_| ErrorCode::BadBytes(format!("orc value {} is out of range", v)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let values = spread(values, &validity)?;
+    Ok(PrimitiveArray::try_new(data_type, values.into(), validity)?)
+}
+
+fn deserialize_float<T>(
+    data_type: ArrowType,
+    column: &Column,
+    validity: Option<Bitmap>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: NativeType + Default + decode::Float,
+{
+    let length = num_values(column, &validity);
+    let mut stream = column
+        .get_stream(StreamKind::Data, vec![])
+        .map_err(orc_error)?;
+    let mut values = Vec::with_capacity(length);
+    for value in decode::FloatIter::<T, _>::new(&mut stream, length) {
+        values.push(value.map_err(orc_error)?);
+    }
+    let values = spread(values, &validity)?;
+    Ok(PrimitiveArray::try_new(data_type, values.into(), validity)?)
+}
+
+// Strings are either stored directly with their lengths, or as indexes into a
+// dictionary of the distinct values of the stripe.
+fn deserialize_string(column: &Column, validity: Option<Bitmap>) -> Result<BinaryArray<i64>> {
+    check_encoding(column, &[
+        EncodingKind::Direct,
+        EncodingKind::Dictionary,
+        EncodingKind::DirectV2,
+        EncodingKind::DictionaryV2,
+    ])?;
+    let length = num_values(column, &validity);
+
+    let (lengths, bytes, indexes) = match column.encoding().kind() {
+        EncodingKind::Direct | EncodingKind::DirectV2 => {
+            let lengths = read_unsigned(column, StreamKind::Length, length)?;
+            let total = lengths.iter().sum::<u64>() as usize;
+            let bytes = read_bytes(column, StreamKind::Data, total)?;
+            (lengths, bytes, None)
+        }
+        _ => {
+            let dictionary_size = column.encoding().dictionary_size() as usize;
+            let lengths = read_unsigned(column, StreamKind::Length, dictionary_size)?;
+            let total = lengths.iter().sum::<u64>() as usize;
+            let bytes = read_bytes(column, StreamKind::DictionaryData, total)?;
+            let indexes = read_unsigned(column, StreamKind::Data, length)?;
+            (lengths, bytes, Some(indexes))
+        }
+    };
+
+    let mut starts = Vec::with_capacity(lengths.len() + 1);
+    starts.push(0_usize);
+    for len in lengths.iter() {
+        starts.push(starts[starts.len() - 1] + *len as usize);
+    }
+    let value_at = |i: usize| -> Result<&[u8]> {
+        match starts.get(i + 1) {
+            Some(end) => Ok(&bytes[starts[i]..*end]),
+            None => Err(ErrorCode::BadBytes(format!(
+                "orc string index {} is out of range",
+                i
+            ))),
+        }
+    };
+
+    let mut values = Vec::with_capacity(bytes.len());
+    let mut offsets = Vec::with_capacity(column.number_of_rows() + 1);
+    offsets.push(0_i64);
+    let mut next_value = 0;
+    for row in 0..column.number_of_rows() {
+        let is_valid = match &validity {
+            Some(validity) => validity.get_bit(row),
+            None => true,
+        };
+        if is_valid {
+            let i = match &indexes {
+                Some(indexes) => *indexes
+                    .get(next_value)
+                    .ok_or_else(|| ErrorCode::BadBytes("orc data stream is too short"))?
+                    as usize,
+                None => next_value,
+            };
+            values.extend_from_slice(value_at(i)?);
+            next_value += 1;
+        }
+        offsets.push(values.len() as i64);
+    }
+
+    Ok(BinaryArray::try_new(
+        ArrowType::LargeBinary,
+        offsets.into(),
+        values.into(),
+        validity,
+    )?)
+}
@@ -13,9 +13,13 @@
 // limitations under the License.
 
 pub mod format;
+mod format_avro;
 pub mod format_csv;
 mod format_diagnostic;
 mod format_factory;
+pub mod format_json;
+pub mod format_ndjson;
+mod format_orc;
 mod format_parquet;
 pub mod format_tsv;
 pub mod output_format;
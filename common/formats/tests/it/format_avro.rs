@@ -0,0 +1,123 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::Int64Type;
+use common_datavalues::NullableType;
+use common_datavalues::StringType;
+use common_exception::Result;
+use common_formats::FormatFactory;
+use common_io::prelude::FormatSettings;
+
+const SYNC_MARKER: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+fn write_long(buf: &mut Vec<u8>, value: i64) {
+    let mut n = ((value << 1) ^ (value >> 63)) as u64;
+    while n >= 0x80 {
+        buf.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+fn write_string(buf: &mut Vec<u8>, value: &str) {
+    write_long(buf, value.len() as i64);
+    buf.extend_from_slice(value.as_bytes());
+}
+
+// An uncompressed object container file of record {a: long, b: [null, string]}.
+fn avro_file(rows: &[(i64, Option<&str>)]) -> Vec<u8> {
+    let schema = r#"{"type":"record","name":"t","fields":[{"name":"a","type":"long"},{"name":"b","type":["null","string"]}]}"#;
+
+    let mut file = b"Obj\x01".to_vec();
+    write_long(&mut file, 1);
+    write_string(&mut file, "avro.schema");
+    write_string(&mut file, schema);
+    write_long(&mut file, 0);
+    file.extend_from_slice(&SYNC_MARKER);
+
+    let mut block = vec![];
+    for (a, b) in rows {
+        write_long(&mut block, *a);
+        match b {
+            None => write_long(&mut block, 0),
+            Some(b) => {
+                write_long(&mut block, 1);
+                write_string(&mut block, b);
+            }
+        }
+    }
+
+    write_long(&mut file, rows.len() as i64);
+    write_long(&mut file, block.len() as i64);
+    file.extend_from_slice(&block);
+    file.extend_from_slice(&SYNC_MARKER);
+    file
+}
+
+#[test]
+fn test_deserialize_avro() -> Result<()> {
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("b", NullableType::new_impl(StringType::new_impl())),
+        DataField::new("a", Int64Type::new_impl()),
+    ]));
+    let input_format =
+        FormatFactory::instance().get_input("Avro", schema, FormatSettings::default())?;
+    assert!(!input_format.support_parallel());
+
+    let file = avro_file(&[(1, Some("x")), (2, None), (3, Some("z"))]);
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file[..10], &mut input_state)?;
+    input_format.read_buf(&file[10..], &mut input_state)?;
+    assert_blocks_eq(
+        vec![
+            "+------+---+",
+            "| b    | a |",
+            "+------+---+",
+            "| x    | 1 |",
+            "| NULL | 2 |",
+            "| z    | 3 |",
+            "+------+---+",
+        ],
+        &input_format.deserialize_data(&mut input_state)?,
+    );
+
+    // Null values can't be loaded into not null column.
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "b",
+        StringType::new_impl(),
+    )]));
+    let input_format =
+        FormatFactory::instance().get_input("Avro", schema, FormatSettings::default())?;
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file, &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    // The column is not found in the avro record.
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "c",
+        Int64Type::new_impl(),
+    )]));
+    let input_format =
+        FormatFactory::instance().get_input("Avro", schema, FormatSettings::default())?;
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file, &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::type_primitive::UInt32Type;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::NullableType;
+use common_datavalues::StringType;
+use common_datavalues::VariantType;
+use common_exception::Result;
+use common_formats::format_json::JsonInputFormat;
+use common_formats::format_ndjson::NDJsonInputFormat;
+use common_formats::format_ndjson::NDJsonInputState;
+use common_formats::FormatFactory;
+use common_io::prelude::FormatSettings;
+
+fn test_schema() -> DataSchemaRef {
+    Arc::new(DataSchema::new(vec![
+        DataField::new("a", UInt32Type::new_impl()),
+        DataField::new("b", NullableType::new_impl(StringType::new_impl())),
+        DataField::new("c", VariantType::new_impl()),
+    ]))
+}
+
+#[test]
+fn test_accepted_lines() -> Result<()> {
+    let input_format = NDJsonInputFormat::try_create(
+        "ndjson",
+        test_schema(),
+        FormatSettings::default(),
+        2,
+        10 * 1024 * 1024,
+    )?;
+
+    let mut input_state = input_format.create_state();
+
+    let bytes = "{\"a\": 1}\n{\"a\": 2".as_bytes();
+    assert_eq!((16, false), input_format.read_buf(bytes, &mut input_state)?);
+
+    let bytes = "}\n{\"a\": 3}\n".as_bytes();
+    assert_eq!((2, true), input_format.read_buf(bytes, &mut input_state)?);
+    assert_eq!(
+        "{\"a\": 1}\n{\"a\": 2}\n".as_bytes(),
+        input_state
+            .as_any()
+            .downcast_mut::<NDJsonInputState>()
+            .unwrap()
+            .memory
+    );
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_ndjson() -> Result<()> {
+    let settings = FormatSettings {
+        empty_as_default: true,
+        ..Default::default()
+    };
+    let input_format = FormatFactory::instance().get_input("NDJson", test_schema(), settings)?;
+
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(
+        concat!(
+            "{\"a\": 1, \"b\": \"x\", \"c\": {\"k\": [1, 2]}}\n",
+            "\n",
+            "{\"a\": 2, \"c\": 3}\n",
+            "{\"b\": null, \"c\": true}\n",
+        )
+        .as_bytes(),
+        &mut input_state,
+    )?;
+    assert_blocks_eq(
+        vec![
+            "+---+------+-------------+",
+            "| a | b    | c           |",
+            "+---+------+-------------+",
+            "| 1 | x    | {\"k\":[1,2]} |",
+            "| 2 | NULL | 3           |",
+            "| 0 | NULL | true        |",
+            "+---+------+-------------+",
+        ],
+        &input_format.deserialize_data(&mut input_state)?,
+    );
+
+    // Missing field of not null column can't be filled without empty_as_default.
+    let input_format =
+        FormatFactory::instance().get_input("NDJson", test_schema(), FormatSettings::default())?;
+    let mut input_state = input_format.create_state();
+    input_format.read_buf("{\"b\": \"x\", \"c\": 1}\n".as_bytes(), &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_json() -> Result<()> {
+    let input_format =
+        JsonInputFormat::try_create("json", test_schema(), FormatSettings::default())?;
+    assert!(!input_format.support_parallel());
+
+    let mut input_state = input_format.create_state();
+    input_format.read_buf("[{\"a\": 1, \"b\": \"x\", ".as_bytes(), &mut input_state)?;
+    input_format.read_buf(
+        "\"c\": [1]}, {\"a\": 2, \"b\": null, \"c\": null}]".as_bytes(),
+        &mut input_state,
+    )?;
+    assert_blocks_eq(
+        vec![
+            "+---+------+------+",
+            "| a | b    | c    |",
+            "+---+------+------+",
+            "| 1 | x    | [1]  |",
+            "| 2 | NULL | null |",
+            "+---+------+------+",
+        ],
+        &input_format.deserialize_data(&mut input_state)?,
+    );
+
+    let mut input_state = input_format.create_state();
+    input_format.read_buf("{\"a\": 1}".as_bytes(), &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    Ok(())
+}
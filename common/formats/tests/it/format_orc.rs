@@ -0,0 +1,338 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::assert_blocks_eq;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::Int32Type;
+use common_datavalues::Int64Type;
+use common_datavalues::NullableType;
+use common_datavalues::StringType;
+use common_exception::Result;
+use common_formats::FormatFactory;
+use common_io::prelude::FileSplit;
+use common_io::prelude::FormatSettings;
+
+fn write_varint(buf: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        buf.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    buf.push(n as u8);
+}
+
+fn write_uint_field(buf: &mut Vec<u8>, field: u64, value: u64) {
+    write_varint(buf, field << 3);
+    write_varint(buf, value);
+}
+
+fn write_bytes_field(buf: &mut Vec<u8>, field: u64, value: &[u8]) {
+    write_varint(buf, (field << 3) | 2);
+    write_varint(buf, value.len() as u64);
+    buf.extend_from_slice(value);
+}
+
+fn write_packed_field(buf: &mut Vec<u8>, field: u64, values: &[u64]) {
+    let mut packed = vec![];
+    for value in values {
+        write_varint(&mut packed, *value);
+    }
+    write_bytes_field(buf, field, &packed);
+}
+
+// RLE v2 DIRECT run of 8 bits values, the signed values are zigzag encoded.
+fn rle_v2(values: &[u8]) -> Vec<u8> {
+    let len = values.len() - 1;
+    let mut buf = vec![0x40 | (7 << 1) | (len >> 8) as u8, len as u8];
+    buf.extend_from_slice(values);
+    buf
+}
+
+// RLE v1 run of `len` values starting from `base` with `delta`, the signed values are
+// zigzag encoded.
+fn rle_v1_run(len: u8, delta: i8, base: u8) -> Vec<u8> {
+    vec![len - 3, delta as u8, base]
+}
+
+// RLE v1 literals of one byte varints, the signed values are zigzag encoded.
+fn rle_v1_literals(values: &[u8]) -> Vec<u8> {
+    let mut buf = vec![(256 - values.len()) as u8];
+    buf.extend_from_slice(values);
+    buf
+}
+
+const STREAM_PRESENT: u64 = 0;
+const STREAM_DATA: u64 = 1;
+const STREAM_LENGTH: u64 = 2;
+const STREAM_DICTIONARY_DATA: u64 = 3;
+
+const ENCODING_DIRECT: u64 = 0;
+const ENCODING_DIRECT_V2: u64 = 2;
+const ENCODING_DICTIONARY_V2: u64 = 3;
+
+const TYPE_INT: u64 = 3;
+const TYPE_LONG: u64 = 4;
+const TYPE_STRING: u64 = 7;
+
+struct Stripe {
+    rows: u64,
+    // (column, kind, data)
+    streams: Vec<(u64, u64, Vec<u8>)>,
+    // (kind, dictionary size) of each column
+    encodings: Vec<(u64, u64)>,
+}
+
+// An uncompressed orc file of the struct of the given fields of (name, type kind).
+fn write_orc_file(fields: &[(&str, u64)], stripes: Vec<Stripe>) -> Vec<u8> {
+    let mut file = b"ORC".to_vec();
+    let mut stripe_infos = vec![];
+    let mut total_rows = 0;
+    for stripe in stripes {
+        let mut data = vec![];
+        let mut stripe_footer = vec![];
+        for (column, kind, stream_data) in stripe.streams {
+            let mut stream = vec![];
+            write_uint_field(&mut stream, 1, kind);
+            write_uint_field(&mut stream, 2, column);
+            write_uint_field(&mut stream, 3, stream_data.len() as u64);
+            write_bytes_field(&mut stripe_footer, 1, &stream);
+            data.extend_from_slice(&stream_data);
+        }
+        for (kind, dictionary_size) in stripe.encodings {
+            let mut encoding = vec![];
+            write_uint_field(&mut encoding, 1, kind);
+            if dictionary_size > 0 {
+                write_uint_field(&mut encoding, 2, dictionary_size);
+            }
+            write_bytes_field(&mut stripe_footer, 2, &encoding);
+        }
+
+        let mut stripe_info = vec![];
+        write_uint_field(&mut stripe_info, 1, file.len() as u64);
+        write_uint_field(&mut stripe_info, 2, 0);
+        write_uint_field(&mut stripe_info, 3, data.len() as u64);
+        write_uint_field(&mut stripe_info, 4, stripe_footer.len() as u64);
+        write_uint_field(&mut stripe_info, 5, stripe.rows);
+        stripe_infos.push(stripe_info);
+        total_rows += stripe.rows;
+
+        file.extend_from_slice(&data);
+        file.extend_from_slice(&stripe_footer);
+    }
+
+    let mut footer = vec![];
+    write_uint_field(&mut footer, 1, 3);
+    write_uint_field(&mut footer, 2, file.len() as u64);
+    for stripe_info in stripe_infos {
+        write_bytes_field(&mut footer, 3, &stripe_info);
+    }
+    let mut root = vec![];
+    write_uint_field(&mut root, 1, 12);
+    let subtypes = (1..=fields.len() as u64).collect::<Vec<_>>();
+    write_packed_field(&mut root, 2, &subtypes);
+    for (name, _) in fields {
+        write_bytes_field(&mut root, 3, name.as_bytes());
+    }
+    write_bytes_field(&mut footer, 4, &root);
+    for (_, kind) in fields {
+        let mut column = vec![];
+        write_uint_field(&mut column, 1, *kind);
+        write_bytes_field(&mut footer, 4, &column);
+    }
+    write_uint_field(&mut footer, 6, total_rows);
+
+    let mut postscript = vec![];
+    write_uint_field(&mut postscript, 1, footer.len() as u64);
+    write_uint_field(&mut postscript, 2, 0);
+    write_packed_field(&mut postscript, 4, &[0, 12]);
+    write_uint_field(&mut postscript, 5, 0);
+    write_bytes_field(&mut postscript, 8000, b"ORC");
+
+    file.extend_from_slice(&footer);
+    file.extend_from_slice(&postscript);
+    file.push(postscript.len() as u8);
+    file
+}
+
+// An uncompressed orc file of struct<a: bigint, b: string, c: string> with one stripe:
+// (1, 'x', 'p'), (2, NULL, 'q'), (3, 'z', 'p'), `c` is dictionary encoded.
+fn orc_file() -> Vec<u8> {
+    write_orc_file(
+        &[("a", TYPE_LONG), ("b", TYPE_STRING), ("c", TYPE_STRING)],
+        vec![Stripe {
+            rows: 3,
+            streams: vec![
+                (1, STREAM_DATA, rle_v2(&[2, 4, 6])),
+                (2, STREAM_PRESENT, vec![0xff, 0b1010_0000]),
+                (2, STREAM_LENGTH, rle_v2(&[1, 1])),
+                (2, STREAM_DATA, b"xz".to_vec()),
+                (3, STREAM_DATA, rle_v2(&[0, 1, 0])),
+                (3, STREAM_DICTIONARY_DATA, b"pq".to_vec()),
+                (3, STREAM_LENGTH, rle_v2(&[1, 1])),
+            ],
+            encodings: vec![
+                (ENCODING_DIRECT, 0),
+                (ENCODING_DIRECT_V2, 0),
+                (ENCODING_DIRECT_V2, 0),
+                (ENCODING_DICTIONARY_V2, 2),
+            ],
+        }],
+    )
+}
+
+// An uncompressed orc file of struct<a: int> written by the RLE v1, with two stripes:
+// (1), (2), (3) and (10), (NULL), (30).
+fn orc_file_rle_v1() -> Vec<u8> {
+    write_orc_file(&[("a", TYPE_INT)], vec![
+        Stripe {
+            rows: 3,
+            streams: vec![(1, STREAM_DATA, rle_v1_run(3, 1, 2))],
+            encodings: vec![(ENCODING_DIRECT, 0), (ENCODING_DIRECT, 0)],
+        },
+        Stripe {
+            rows: 3,
+            streams: vec![
+                (1, STREAM_PRESENT, vec![0xff, 0b1010_0000]),
+                (1, STREAM_DATA, rle_v1_literals(&[20, 60])),
+            ],
+            encodings: vec![(ENCODING_DIRECT, 0), (ENCODING_DIRECT, 0)],
+        },
+    ])
+}
+
+#[test]
+fn test_deserialize_orc() -> Result<()> {
+    let schema = Arc::new(DataSchema::new(vec![
+        DataField::new("c", StringType::new_impl()),
+        DataField::new("b", NullableType::new_impl(StringType::new_impl())),
+        DataField::new("a", Int64Type::new_impl()),
+    ]));
+    let input_format =
+        FormatFactory::instance().get_input("Orc", schema, FormatSettings::default())?;
+    assert!(!input_format.support_parallel());
+
+    let file = orc_file();
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file[..10], &mut input_state)?;
+    input_format.read_buf(&file[10..], &mut input_state)?;
+    assert_blocks_eq(
+        vec![
+            "+---+------+---+",
+            "| c | b    | a |",
+            "+---+------+---+",
+            "| p | x    | 1 |",
+            "| q | NULL | 2 |",
+            "| p | z    | 3 |",
+            "+---+------+---+",
+        ],
+        &input_format.deserialize_data(&mut input_state)?,
+    );
+
+    // Null values can't be loaded into not null column.
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "b",
+        StringType::new_impl(),
+    )]));
+    let input_format =
+        FormatFactory::instance().get_input("Orc", schema, FormatSettings::default())?;
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file, &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    // The column is not found in the orc file.
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "d",
+        Int64Type::new_impl(),
+    )]));
+    let input_format =
+        FormatFactory::instance().get_input("Orc", schema, FormatSettings::default())?;
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file, &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    // The type of the column mismatches.
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        Int32Type::new_impl(),
+    )]));
+    let input_format =
+        FormatFactory::instance().get_input("Orc", schema, FormatSettings::default())?;
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file, &mut input_state)?;
+    assert!(input_format.deserialize_data(&mut input_state).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_deserialize_orc_by_stripe() -> Result<()> {
+    let schema = Arc::new(DataSchema::new(vec![DataField::new(
+        "a",
+        NullableType::new_impl(Int32Type::new_impl()),
+    )]));
+    let input_format =
+        FormatFactory::instance().get_input("Orc", schema, FormatSettings::default())?;
+    let file = orc_file_rle_v1();
+    let expected = vec![
+        "+------+", "| a    |", "+------+", "| 1    |", "| 2    |", "| 3    |", "| 10   |",
+        "| NULL |", "| 30   |", "+------+",
+    ];
+
+    // The whole file is read into one split without the tail read ahead.
+    let mut input_state = input_format.create_state();
+    input_format.read_buf(&file, &mut input_state)?;
+    let blocks = input_format.deserialize_data(&mut input_state)?;
+    assert_eq!(blocks.len(), 2);
+    assert_blocks_eq(expected.clone(), &blocks);
+
+    // Read the tail ahead as the file splitter does.
+    let mut tail = vec![];
+    let mut tail_size = input_format.tail_size(&tail)?;
+    while tail_size > tail.len() && tail.len() < file.len() {
+        tail = file[file.len().saturating_sub(tail_size)..].to_vec();
+        tail_size = input_format.tail_size(&tail)?;
+    }
+    let tail = Arc::new(tail);
+
+    // Each stripe is a split.
+    let mut blocks = vec![];
+    let mut input_state = input_format.create_state();
+    input_format.set_tail(&mut input_state, file.len(), tail.clone(), 0)?;
+    let (mut offset, mut start_offset) = (0, 0);
+    while offset < file.len() {
+        let (size, is_full) = input_format.read_buf(&file[offset..], &mut input_state)?;
+        offset += size;
+        if is_full {
+            let split = FileSplit {
+                path: None,
+                start_offset,
+                start_row: 0,
+                buf: input_format.take_buf(&mut input_state),
+            };
+            let split_blocks = input_format.deserialize_complete_split(split)?;
+            assert_eq!(split_blocks.len(), 1);
+            blocks.extend(split_blocks);
+
+            start_offset = offset;
+            input_state = input_format.create_state();
+            input_format.set_tail(&mut input_state, file.len(), tail.clone(), offset)?;
+        }
+    }
+    assert!(input_format.take_buf(&mut input_state).is_empty());
+    assert_blocks_eq(expected, &blocks);
+
+    Ok(())
+}
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod format_avro;
 mod format_csv;
 mod format_factory;
+mod format_ndjson;
+mod format_orc;
 mod output_format_json_each_row;
 mod output_format_tcsv;
 mod output_format_utils;
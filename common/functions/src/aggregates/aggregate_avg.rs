@@ -29,6 +29,7 @@ use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 
+use super::aggregate_decimal256::AggregateDecimal256Function;
 use super::aggregate_sum::sum_primitive;
 use super::StateAddr;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
@@ -65,7 +66,8 @@ where T: std::ops::AddAssign + PrimitiveType
 #[derive(Clone)]
 pub struct AggregateAvgFunction<T, SumT> {
     display_name: String,
-    _arguments: Vec<DataField>,
+    arguments: Vec<DataField>,
+    return_type: DataTypeImpl,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -80,7 +82,7 @@ where
     }
 
     fn return_type(&self) -> Result<DataTypeImpl> {
-        Ok(f64::to_data_type())
+        Ok(self.return_type.clone())
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -172,6 +174,15 @@ where
     fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
         let state = place.get::<AggregateAvgState<SumT>>();
 
+        if let DataTypeImpl::Decimal(decimal) = &self.return_type {
+            let builder: &mut MutablePrimitiveColumn<i128> =
+                Series::check_get_mutable_column(array)?;
+            let sum: i128 = NumCast::from(state.value).unwrap_or_default();
+            let val = avg_decimal::<i128>(decimal, sum, self.decimal_scale(), state.count)?;
+            builder.append_value(val);
+            return Ok(());
+        }
+
         let builder: &mut MutablePrimitiveColumn<f64> = Series::check_get_mutable_column(array)?;
         let v: f64 = NumCast::from(state.value).unwrap_or_default();
         let val = v / state.count as f64;
@@ -198,14 +209,44 @@ where
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create_with_return_type(display_name, arguments, f64::to_data_type())
+    }
+
+    pub fn try_create_with_return_type(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        return_type: DataTypeImpl,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_string(),
-            _arguments: arguments,
+            arguments,
+            return_type,
             t: PhantomData,
             sum_t: PhantomData,
         }))
     }
+
+    fn decimal_scale(&self) -> usize {
+        match self.arguments[0].data_type() {
+            DataTypeImpl::Decimal(decimal) => decimal.scale(),
+            _ => 0,
+        }
+    }
+}
+
+/// The average of decimals in the scale of `result`, rounded half away from zero.
+pub(super) fn avg_decimal<T: DecimalNativeType>(
+    result: &DecimalType,
+    sum: T,
+    scale: usize,
+    count: u64,
+) -> Result<T> {
+    if count == 0 {
+        return Ok(T::default());
+    }
+    let sum = DecimalType::create(T::MAX_PRECISION, result.scale()).rescale(sum, scale)?;
+    Ok(decimal_div_round(sum, T::from_i128(count as i128)))
 }
 
 pub fn try_create_aggregate_avg_function(
@@ -220,6 +261,20 @@ pub fn try_create_aggregate_avg_function(
         return AggregateAvgFunction::<u8, u64>::try_create(display_name, arguments);
     }
 
+    // The average of decimals keeps at least DECIMAL_MIN_ADJUSTED_SCALE digits after the point.
+    if let DataTypeImpl::Decimal(decimal) = data_type {
+        if decimal.is_decimal256() {
+            return AggregateDecimal256Function::try_create_avg(display_name, decimal);
+        }
+        let scale = std::cmp::max(decimal.scale(), DECIMAL_MIN_ADJUSTED_SCALE);
+        let return_type = DecimalType::new_impl(DECIMAL128_MAX_PRECISION, scale);
+        return AggregateAvgFunction::<i128, i128>::try_create_with_return_type(
+            display_name,
+            arguments,
+            return_type,
+        );
+    }
+
     let mut phid = data_type.data_type_id();
     // null use dummy func, it's already covered in `AggregateNullResultFunction`
     if data_type.is_null() {
@@ -0,0 +1,196 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_io::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_avg::avg_decimal;
+use super::StateAddr;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+#[derive(Serialize, Deserialize)]
+struct AggregateDecimal256State {
+    pub sum: I256,
+    pub count: u64,
+}
+
+impl AggregateDecimal256State {
+    #[inline(always)]
+    fn add(&mut self, value: I256, count: u64) -> Result<()> {
+        self.sum = self.sum.checked_add(value).ok_or_else(|| {
+            ErrorCode::Overflow("Decimal overflow in the sum of Decimal256".to_string())
+        })?;
+        self.count += count;
+        Ok(())
+    }
+}
+
+/// The sum and average of decimals with precision above 38, which are stored in i256.
+#[derive(Clone)]
+pub struct AggregateDecimal256Function {
+    display_name: String,
+    is_avg: bool,
+    arg_scale: usize,
+    return_type: DecimalType,
+}
+
+impl AggregateDecimal256Function {
+    pub fn try_create_sum(
+        display_name: &str,
+        decimal: &DecimalType,
+    ) -> Result<AggregateFunctionRef> {
+        let return_type = DecimalType::create(DECIMAL_MAX_PRECISION, decimal.scale());
+        Self::try_create(display_name, false, decimal, return_type)
+    }
+
+    pub fn try_create_avg(
+        display_name: &str,
+        decimal: &DecimalType,
+    ) -> Result<AggregateFunctionRef> {
+        let scale = std::cmp::max(decimal.scale(), DECIMAL_MIN_ADJUSTED_SCALE);
+        let return_type = DecimalType::create(DECIMAL_MAX_PRECISION, scale);
+        Self::try_create(display_name, true, decimal, return_type)
+    }
+
+    fn try_create(
+        display_name: &str,
+        is_avg: bool,
+        decimal: &DecimalType,
+        return_type: DecimalType,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            is_avg,
+            arg_scale: decimal.scale(),
+            return_type,
+        }))
+    }
+}
+
+impl AggregateFunction for AggregateDecimal256Function {
+    fn name(&self) -> &str {
+        "AggregateDecimal256Function"
+    }
+
+    fn return_type(&self) -> Result<DataTypeImpl> {
+        Ok(DataTypeImpl::Decimal(self.return_type.clone()))
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| AggregateDecimal256State {
+            sum: I256::ZERO,
+            count: 0,
+        });
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateDecimal256State>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[ColumnRef],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        let values = I256::column_values(&columns[0])?;
+        match validity {
+            Some(validity) => {
+                for (v, valid) in values.iter().zip(validity.iter()) {
+                    if valid {
+                        state.add(*v, 1)?;
+                    }
+                }
+            }
+            None => {
+                for v in values {
+                    state.add(*v, 1)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[ColumnRef],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let values = I256::column_values(&columns[0])?;
+        for (v, place) in values.iter().zip(places.iter()) {
+            let state = place.next(offset).get::<AggregateDecimal256State>();
+            state.add(*v, 1)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[ColumnRef], row: usize) -> Result<()> {
+        let values = I256::column_values(&columns[0])?;
+        let state = place.get::<AggregateDecimal256State>();
+        state.add(values[row], 1)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut BytesMut) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        serialize_into_buf(writer, state)
+    }
+
+    fn deserialize(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        *state = deserialize_from_slice(reader)?;
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        let rhs = rhs.get::<AggregateDecimal256State>();
+        state.add(rhs.sum, rhs.count)
+    }
+
+    fn merge_result(&self, place: StateAddr, array: &mut dyn MutableColumn) -> Result<()> {
+        let state = place.get::<AggregateDecimal256State>();
+        let value = if self.is_avg {
+            avg_decimal(&self.return_type, state.sum, self.arg_scale, state.count)?
+        } else {
+            self.return_type.check_value(state.sum)?
+        };
+        let builder: &mut MutableObjectColumn<I256> = Series::check_get_mutable_column(array)?;
+        builder.append_value(value);
+        Ok(())
+    }
+
+    fn convert_const_to_full(&self) -> bool {
+        true
+    }
+}
+
+impl fmt::Display for AggregateDecimal256Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
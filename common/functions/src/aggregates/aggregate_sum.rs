@@ -28,6 +28,7 @@ use num::traits::AsPrimitive;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+use super::aggregate_decimal256::AggregateDecimal256Function;
 use super::aggregate_function::AggregateFunction;
 use super::aggregate_function::AggregateFunctionRef;
 use super::aggregate_function_factory::AggregateFunctionDescription;
@@ -61,6 +62,7 @@ where T: std::ops::AddAssign + Serialize + DeserializeOwned + Copy + Clone
 pub struct AggregateSumFunction<T, SumT> {
     display_name: String,
     _arguments: Vec<DataField>,
+    return_type: DataTypeImpl,
     t: PhantomData<T>,
     sum_t: PhantomData<SumT>,
 }
@@ -75,7 +77,7 @@ where
     }
 
     fn return_type(&self) -> Result<DataTypeImpl> {
-        Ok(SumT::to_data_type())
+        Ok(self.return_type.clone())
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -194,10 +196,19 @@ where
     pub fn try_create(
         display_name: &str,
         arguments: Vec<DataField>,
+    ) -> Result<AggregateFunctionRef> {
+        Self::try_create_with_return_type(display_name, arguments, SumT::to_data_type())
+    }
+
+    pub fn try_create_with_return_type(
+        display_name: &str,
+        arguments: Vec<DataField>,
+        return_type: DataTypeImpl,
     ) -> Result<AggregateFunctionRef> {
         Ok(Arc::new(Self {
             display_name: display_name.to_owned(),
             _arguments: arguments,
+            return_type,
             t: PhantomData,
             sum_t: PhantomData,
         }))
@@ -216,6 +227,19 @@ pub fn try_create_aggregate_sum_function(
         return AggregateSumFunction::<u8, u64>::try_create(display_name, arguments);
     }
 
+    // The sum of decimals keeps the scale and uses the max precision of its storage.
+    if let DataTypeImpl::Decimal(decimal) = data_type {
+        if decimal.is_decimal256() {
+            return AggregateDecimal256Function::try_create_sum(display_name, decimal);
+        }
+        let return_type = DecimalType::new_impl(DECIMAL128_MAX_PRECISION, decimal.scale());
+        return AggregateSumFunction::<i128, i128>::try_create_with_return_type(
+            display_name,
+            arguments,
+            return_type,
+        );
+    }
+
     let mut phid = data_type.data_type_id();
     // null use dummy func, it's already covered in `AggregateNullResultFunction`
     if data_type.is_null() {
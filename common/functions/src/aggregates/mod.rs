@@ -32,6 +32,7 @@ mod aggregate_combinator;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
 mod aggregate_covariance;
+mod aggregate_decimal256;
 mod aggregate_min_max;
 mod aggregate_null_result;
 mod aggregate_retention;
@@ -46,6 +47,7 @@ pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
+pub use aggregate_decimal256::AggregateDecimal256Function;
 pub use aggregate_function::AggregateFunction;
 pub use aggregate_function::AggregateFunctionRef;
 pub use aggregate_function_factory::AggregateFunctionFactory;
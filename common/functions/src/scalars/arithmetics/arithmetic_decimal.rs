@@ -0,0 +1,257 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::fmt;
+use std::sync::Arc;
+
+use common_datavalues::prelude::*;
+use common_datavalues::type_coercion::decimal_arithmetic_coercion;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::scalar_binary_op;
+use crate::scalars::EvalContext;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+
+/// Binary arithmetic between decimals, or between decimal and other numbers.
+///
+/// Integers are treated as decimals with zero scale, the arithmetic is done on
+/// the unscaled i128 values, or i256 ones if the result precision is above 38.
+/// The result type follows `decimal_arithmetic_coercion`.
+/// If one of the arguments is floating, both are casted to Float64.
+#[derive(Clone)]
+pub struct DecimalArithmeticFunction {
+    op: DataValueBinaryOperator,
+    lhs_type: DataTypeImpl,
+    rhs_type: DataTypeImpl,
+    result_type: DataTypeImpl,
+}
+
+impl DecimalArithmeticFunction {
+    pub fn try_create_func(
+        op: DataValueBinaryOperator,
+        args: &[&DataTypeImpl],
+    ) -> Result<Box<dyn Function>> {
+        let result_type = decimal_arithmetic_coercion(&op, args[0], args[1])?;
+        let (lhs_type, rhs_type) = match result_type.data_type_id() {
+            // Wide results are computed on i256, so are the operands.
+            TypeID::Decimal256 => (
+                Self::decimal256_type(args[0])?,
+                Self::decimal256_type(args[1])?,
+            ),
+            TypeID::Decimal => (Self::decimal_type(args[0])?, Self::decimal_type(args[1])?),
+            _ => (result_type.clone(), result_type.clone()),
+        };
+
+        Ok(Box::new(Self {
+            op,
+            lhs_type,
+            rhs_type,
+            result_type,
+        }))
+    }
+
+    fn decimal_type(data_type: &DataTypeImpl) -> Result<DataTypeImpl> {
+        if data_type.data_type_id().is_decimal() {
+            return Ok(data_type.clone());
+        }
+        match DecimalType::from_integer_type(data_type.data_type_id()) {
+            Some(decimal) => Ok(DataTypeImpl::Decimal(decimal)),
+            None => Err(ErrorCode::BadDataValueType(format!(
+                "Can't construct decimal type from {:?}",
+                data_type
+            ))),
+        }
+    }
+
+    fn decimal256_type(data_type: &DataTypeImpl) -> Result<DataTypeImpl> {
+        let decimal: DecimalType = Self::decimal_type(data_type)?.try_into()?;
+        Ok(DataTypeImpl::Decimal(decimal.to_decimal256()))
+    }
+
+    fn eval_float(&self, lhs: &ColumnRef, rhs: &ColumnRef) -> Result<ColumnRef> {
+        let mut ctx = EvalContext::default();
+        let col = match self.op {
+            DataValueBinaryOperator::Plus => {
+                scalar_binary_op::<f64, f64, f64, _>(lhs, rhs, |a, b, _| a + b, &mut ctx)
+            }
+            DataValueBinaryOperator::Minus => {
+                scalar_binary_op::<f64, f64, f64, _>(lhs, rhs, |a, b, _| a - b, &mut ctx)
+            }
+            DataValueBinaryOperator::Mul => {
+                scalar_binary_op::<f64, f64, f64, _>(lhs, rhs, |a, b, _| a * b, &mut ctx)
+            }
+            DataValueBinaryOperator::Div => {
+                scalar_binary_op::<f64, f64, f64, _>(lhs, rhs, |a, b, _| a / b, &mut ctx)
+            }
+            _ => return Err(self.unsupported_error()),
+        }?;
+        Ok(Arc::new(col))
+    }
+
+    fn eval_decimal<T: DecimalNativeType + Scalar>(
+        &self,
+        lhs: &ColumnRef,
+        rhs: &ColumnRef,
+    ) -> Result<ColumnRef> {
+        let l: DecimalType = self.lhs_type.to_owned().try_into()?;
+        let r: DecimalType = self.rhs_type.to_owned().try_into()?;
+        let result: DecimalType = self.result_type.to_owned().try_into()?;
+        let mut ctx = EvalContext::default();
+
+        let col = match self.op {
+            DataValueBinaryOperator::Plus | DataValueBinaryOperator::Minus => {
+                let is_plus = matches!(self.op, DataValueBinaryOperator::Plus);
+                let scale = cmp::max(l.scale(), r.scale());
+                let l_factor = decimal_pow10::<T>(scale - l.scale());
+                let r_factor = decimal_pow10::<T>(scale - r.scale());
+                scalar_binary_op::<T, T, T, _>(
+                    lhs,
+                    rhs,
+                    |a, b, ctx| {
+                        let (a, b) = (a.to_owned_scalar(), b.to_owned_scalar());
+                        let v = a
+                            .checked_mul(l_factor)
+                            .zip(b.checked_mul(r_factor))
+                            .and_then(|(a, b)| {
+                                if is_plus {
+                                    a.checked_add(b)
+                                } else {
+                                    a.checked_sub(b)
+                                }
+                            });
+                        decimal_result(&result, v, scale, ctx)
+                    },
+                    &mut ctx,
+                )
+            }
+            DataValueBinaryOperator::Mul => {
+                let scale = l.scale() + r.scale();
+                scalar_binary_op::<T, T, T, _>(
+                    lhs,
+                    rhs,
+                    |a, b, ctx| {
+                        let (a, b) = (a.to_owned_scalar(), b.to_owned_scalar());
+                        decimal_result(&result, a.checked_mul(b), scale, ctx)
+                    },
+                    &mut ctx,
+                )
+            }
+            DataValueBinaryOperator::Div => {
+                // a / b in result scale s is (a * 10^(s + s2 - s1)) / b
+                let shift = (result.scale() + r.scale()) as i64 - l.scale() as i64;
+                scalar_binary_op::<T, T, T, _>(
+                    lhs,
+                    rhs,
+                    |a, b, ctx| {
+                        let (a, b) = (a.to_owned_scalar(), b.to_owned_scalar());
+                        if b == T::default() {
+                            ctx.set_error(ErrorCode::BadArguments("Division by zero"));
+                            return T::default();
+                        }
+                        let a = if shift > T::MAX_PRECISION as i64 {
+                            None
+                        } else if shift >= 0 {
+                            a.checked_mul(decimal_pow10(shift as usize))
+                        } else if -shift > T::MAX_PRECISION as i64 {
+                            Some(T::default())
+                        } else {
+                            Some(a / decimal_pow10((-shift) as usize))
+                        };
+                        let v = a.map(|a| decimal_div_round(a, b));
+                        decimal_result(&result, v, result.scale(), ctx)
+                    },
+                    &mut ctx,
+                )
+            }
+            _ => return Err(self.unsupported_error()),
+        }?;
+        Ok(Arc::new(col))
+    }
+
+    fn unsupported_error(&self) -> ErrorCode {
+        ErrorCode::BadDataValueType(format!(
+            "DataValue Error: Unsupported arithmetic ({:?}) {} ({:?})",
+            self.lhs_type, self.op, self.rhs_type
+        ))
+    }
+}
+
+#[inline]
+fn decimal_result<T: DecimalNativeType>(
+    result: &DecimalType,
+    v: Option<T>,
+    scale: usize,
+    ctx: &mut EvalContext,
+) -> T {
+    let v = match v {
+        Some(v) => result.rescale(v, scale),
+        None => Err(ErrorCode::Overflow(format!(
+            "Decimal overflow in arithmetic of {}",
+            result.name()
+        ))),
+    };
+    match v {
+        Ok(v) => v,
+        Err(e) => {
+            ctx.set_error(e);
+            T::default()
+        }
+    }
+}
+
+impl Function for DecimalArithmeticFunction {
+    fn name(&self) -> &str {
+        "DecimalArithmeticFunction"
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        self.result_type.clone()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        _input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let lhs = cast_column_field(
+            &columns[0],
+            columns[0].data_type(),
+            &self.lhs_type,
+            &func_ctx,
+        )?;
+        let rhs = cast_column_field(
+            &columns[1],
+            columns[1].data_type(),
+            &self.rhs_type,
+            &func_ctx,
+        )?;
+
+        match self.result_type.data_type_id() {
+            TypeID::Decimal256 => self.eval_decimal::<I256>(&lhs, &rhs),
+            TypeID::Decimal => self.eval_decimal::<i128>(&lhs, &rhs),
+            _ => self.eval_float(&lhs, &rhs),
+        }
+    }
+}
+
+impl fmt::Display for DecimalArithmeticFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.op)
+    }
+}
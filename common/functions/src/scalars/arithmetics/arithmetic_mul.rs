@@ -22,6 +22,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingMul;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -55,6 +56,10 @@ impl ArithmeticMulFunction {
     ) -> Result<Box<dyn Function>> {
         let op = DataValueBinaryOperator::Mul;
 
+        if args[0].data_type_id().is_decimal() || args[1].data_type_id().is_decimal() {
+            return DecimalArithmeticFunction::try_create_func(op, args);
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             with_match_primitive_types_error!(args[1].data_type_id(), |$D| {
                 let result_type = <($T, $D) as ResultTypeOfBinary>::AddMul::to_data_type();
@@ -33,6 +33,10 @@ where O: PrimitiveType + Neg<Output = O> {
     -l.as_()
 }
 
+fn neg_i256(l: &I256, _ctx: &mut EvalContext) -> I256 {
+    -*l
+}
+
 fn wrapping_neg<O>(l: impl AsPrimitive<O>, _ctx: &mut EvalContext) -> O
 where O: IntegerType + WrappingNeg {
     l.as_().wrapping_neg()
@@ -47,6 +51,22 @@ impl ArithmeticNegateFunction {
     ) -> Result<Box<dyn Function>> {
         let op = DataValueUnaryOperator::Negate;
 
+        if args[0].data_type_id() == TypeID::Decimal256 {
+            return UnaryArithmeticFunction::<I256, I256, _>::try_create_func(
+                op,
+                args[0].clone(),
+                neg_i256,
+            );
+        }
+
+        if args[0].data_type_id().is_decimal() {
+            return UnaryArithmeticFunction::<i128, i128, _>::try_create_func(
+                op,
+                args[0].clone(),
+                neg,
+            );
+        }
+
         with_match_primitive_types_error!(args[0].data_type_id(), |$T| {
             let result_type = <$T as ResultTypeOfUnary>::Negate::to_data_type();
             match result_type.data_type_id() {
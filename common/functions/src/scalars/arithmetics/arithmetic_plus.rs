@@ -24,6 +24,7 @@ use num::traits::AsPrimitive;
 use num_traits::WrappingAdd;
 
 use crate::scalars::BinaryArithmeticFunction;
+use crate::scalars::DecimalArithmeticFunction;
 use crate::scalars::EvalContext;
 use crate::scalars::Function;
 use crate::scalars::FunctionDescription;
@@ -60,6 +61,10 @@ impl ArithmeticPlusFunction {
         let left_type = args[0].data_type_id();
         let right_type = args[1].data_type_id();
 
+        if left_type.is_decimal() || right_type.is_decimal() {
+            return DecimalArithmeticFunction::try_create_func(op, args);
+        }
+
         let error_fn = || -> Result<Box<dyn Function>> {
             Err(ErrorCode::BadDataValueType(format!(
                 "DataValue Error: Unsupported arithmetic ({:?}) {} ({:?})",
@@ -117,14 +117,17 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
         display_name: &str,
         args: &[&DataTypeImpl],
     ) -> Result<Box<dyn Function>> {
-        // expect array & struct
-        let has_array_struct = args
-            .iter()
-            .any(|arg| matches!(arg.data_type_id(), TypeID::Struct | TypeID::Array));
+        // expect array & struct & map
+        let has_array_struct = args.iter().any(|arg| {
+            matches!(
+                arg.data_type_id(),
+                TypeID::Struct | TypeID::Array | TypeID::Map
+            )
+        });
 
         if has_array_struct {
             return Err(ErrorCode::BadArguments(format!(
-                "Illegal types {:?} of argument of function {}, can not be struct or array",
+                "Illegal types {:?} of argument of function {}, can not be struct, array or map",
                 args, display_name
             )));
         }
@@ -155,6 +158,14 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
                         let func = Arc::new(ComparisonScalarImpl::<Vu8, Vu8, _>::new(args[0].clone(), false, T::eval_binary));
                         ComparisonFunction::try_create_func(display_name, func)
                     },
+                    TypeID::Decimal => {
+                        let func = Arc::new(ComparisonPrimitiveImpl::<i128, _>::new(args[0].clone(), false, T::eval_simd::<i128>));
+                        ComparisonFunction::try_create_func(display_name, func)
+                    },
+                    TypeID::Decimal256 => {
+                        let func = Arc::new(ComparisonScalarImpl::<I256, I256, _>::new(args[0].clone(), false, T::eval_i256));
+                        ComparisonFunction::try_create_func(display_name, func)
+                    },
                     _ => Err(ErrorCode::IllegalDataType(format!(
                         "Can not compare {:?} with {:?}",
                         args[0], args[1]
@@ -186,6 +197,15 @@ impl<T: ComparisonImpl> ComparisonFunctionCreator<T> {
                     let func = Arc::new(ComparisonScalarImpl::<Vu8, Vu8, _>::new(least_supertype, true, T::eval_binary));
                     ComparisonFunction::try_create_func(display_name, func)
                 },
+                // decimals are compared after rescaled to the same scale
+                TypeID::Decimal => {
+                    let func = Arc::new(ComparisonPrimitiveImpl::<i128, _>::new(least_supertype, true, T::eval_simd::<i128>));
+                    ComparisonFunction::try_create_func(display_name, func)
+                },
+                TypeID::Decimal256 => {
+                    let func = Arc::new(ComparisonScalarImpl::<I256, I256, _>::new(least_supertype, true, T::eval_i256));
+                    ComparisonFunction::try_create_func(display_name, func)
+                },
                 _ => Err(ErrorCode::IllegalDataType(format!(
                     "Can not compare {:?} with {:?}",
                     args[0], args[1]
@@ -261,6 +281,8 @@ pub trait ComparisonImpl: Sync + Send + Clone + 'static {
     fn eval_binary(_l: &[u8], _r: &[u8], _ctx: &mut EvalContext) -> bool;
 
     fn eval_variant(_l: &VariantValue, _r: &VariantValue, _ctx: &mut EvalContext) -> bool;
+
+    fn eval_i256(_l: &I256, _r: &I256, _ctx: &mut EvalContext) -> bool;
 }
 
 pub trait ComparisonExpression: Sync + Send {
@@ -58,6 +58,10 @@ impl ComparisonImpl for ComparisonGtImpl {
     fn eval_variant(l: &VariantValue, r: &VariantValue, _ctx: &mut EvalContext) -> bool {
         l.cmp(r) == Ordering::Greater
     }
+
+    fn eval_i256(l: &I256, r: &I256, _ctx: &mut EvalContext) -> bool {
+        l.cmp(r) == Ordering::Greater
+    }
 }
 
 #[derive(Clone)]
@@ -58,6 +58,11 @@ impl ComparisonImpl for ComparisonGtEqImpl {
         let result = l.cmp(r);
         result == Ordering::Equal || result == Ordering::Greater
     }
+
+    fn eval_i256(l: &I256, r: &I256, _ctx: &mut EvalContext) -> bool {
+        let result = l.cmp(r);
+        result == Ordering::Equal || result == Ordering::Greater
+    }
 }
 
 #[derive(Clone)]
@@ -58,6 +58,10 @@ impl ComparisonImpl for ComparisonLtImpl {
     fn eval_variant(l: &VariantValue, r: &VariantValue, _ctx: &mut EvalContext) -> bool {
         l.cmp(r) == Ordering::Less
     }
+
+    fn eval_i256(l: &I256, r: &I256, _ctx: &mut EvalContext) -> bool {
+        l.cmp(r) == Ordering::Less
+    }
 }
 
 #[derive(Clone)]
@@ -56,6 +56,10 @@ impl ComparisonImpl for ComparisonNotEqImpl {
     fn eval_variant(l: &VariantValue, r: &VariantValue, _ctx: &mut EvalContext) -> bool {
         l.cmp(r) != Ordering::Equal
     }
+
+    fn eval_i256(l: &I256, r: &I256, _ctx: &mut EvalContext) -> bool {
+        l.cmp(r) != Ordering::Equal
+    }
 }
 
 #[derive(Clone)]
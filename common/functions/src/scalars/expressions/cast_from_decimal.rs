@@ -0,0 +1,135 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_datavalues::prelude::*;
+use common_datavalues::with_match_integer_type_id;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::build_decimal_column;
+use super::cast_with_type::CastOptions;
+use crate::scalars::FunctionContext;
+
+pub fn cast_from_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypeImpl,
+    data_type: &DataTypeImpl,
+    cast_options: &CastOptions,
+    func_ctx: &FunctionContext,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: DecimalType = from_type.to_owned().try_into()?;
+    let c = Series::remove_nullable(column);
+    if decimal.is_decimal256() {
+        let values = I256::column_values(&c)?;
+        cast_decimal_values(
+            values,
+            &decimal,
+            from_type,
+            data_type,
+            cast_options,
+            func_ctx,
+        )
+    } else {
+        let values = i128::column_values(&c)?;
+        cast_decimal_values(
+            values,
+            &decimal,
+            from_type,
+            data_type,
+            cast_options,
+            func_ctx,
+        )
+    }
+}
+
+fn cast_decimal_values<T: DecimalNativeType>(
+    values: &[T],
+    decimal: &DecimalType,
+    from_type: &DataTypeImpl,
+    data_type: &DataTypeImpl,
+    cast_options: &CastOptions,
+    func_ctx: &FunctionContext,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let size = values.len();
+
+    match data_type.data_type_id() {
+        TypeID::String => {
+            let mut builder = ColumnBuilder::<Vu8>::with_capacity(size);
+            for v in values {
+                builder.append(decimal.format_value(*v).as_bytes());
+            }
+            Ok((builder.build(size), None))
+        }
+
+        TypeID::Decimal | TypeID::Decimal256 => {
+            let target: DecimalType = data_type.to_owned().try_into()?;
+            let values = values
+                .iter()
+                .map(|v| target.rescale(v.to_i256(), decimal.scale()).ok())
+                .collect();
+            Ok(build_decimal_column(&target, values))
+        }
+
+        TypeID::Boolean => {
+            let it = values.iter().map(|v| *v != T::default());
+            Ok((Arc::new(BooleanColumn::from_iterator(it)), None))
+        }
+
+        TypeID::Float32 | TypeID::Float64 => {
+            let it = values.iter().map(|v| decimal.to_f64(*v));
+            let column: ColumnRef = Arc::new(Float64Column::from_iterator(it));
+            arrow_cast_compute(
+                &column,
+                &f64::to_data_type(),
+                data_type,
+                cast_options,
+                func_ctx,
+            )
+        }
+
+        type_id => {
+            // Round to the integral part first, the out of range values are null.
+            let integral = DecimalType::create(DECIMAL_MAX_PRECISION, 0);
+            with_match_integer_type_id!(type_id, |$T| {
+                let mut builder = NullableColumnBuilder::<$T>::with_capacity(size);
+                for v in values {
+                    let v = integral
+                        .rescale(v.to_i256(), decimal.scale())
+                        .ok()
+                        .and_then(|v| <$T>::try_from(v).ok());
+                    match v {
+                        Some(v) => builder.append(v, true),
+                        None => builder.append_null(),
+                    }
+                }
+                let column = builder.build(size);
+                let nullable_column: &NullableColumn = Series::check_get(&column)?;
+                Ok((
+                    nullable_column.inner().clone(),
+                    Some(nullable_column.ensure_validity().clone()),
+                ))
+            }, {
+                Err(ErrorCode::BadDataValueType(format!(
+                    "Cast error happens in casting from {} to {}",
+                    from_type.name(),
+                    data_type.name()
+                )))
+            })
+        }
+    }
+}
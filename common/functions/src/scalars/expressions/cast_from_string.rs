@@ -23,6 +23,7 @@ use common_datavalues::prelude::*;
 use common_exception::Result;
 
 use super::cast_with_type::arrow_cast_compute;
+use super::cast_with_type::build_decimal_column;
 use super::cast_with_type::CastOptions;
 use crate::scalars::FunctionContext;
 
@@ -92,6 +93,14 @@ pub fn cast_from_string(
                 Some(nullable_column.ensure_validity().clone()),
             ))
         }
+        TypeID::Decimal | TypeID::Decimal256 => {
+            let decimal: DecimalType = data_type.to_owned().try_into()?;
+            let values = str_column
+                .iter()
+                .map(|v| decimal.parse_value::<I256>(v).ok())
+                .collect();
+            Ok(build_decimal_column(&decimal, values))
+        }
         TypeID::Interval => todo!(),
         _ => arrow_cast_compute(column, from_type, data_type, cast_options, func_ctx),
     }
@@ -20,12 +20,14 @@ use common_arrow::arrow::compute::cast;
 use common_arrow::arrow::compute::cast::CastOptions as ArrowOption;
 use common_arrow::ArrayRef;
 use common_datavalues::prelude::*;
+use common_datavalues::with_match_integer_type_id;
 use common_datavalues::with_match_primitive_type_id;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
 
 use super::cast_from_datetimes::cast_from_date;
+use super::cast_from_decimal::cast_from_decimal;
 use super::cast_from_string::cast_from_string;
 use super::cast_from_variant::cast_from_variant;
 use crate::scalars::expressions::cast_from_datetimes::cast_from_timestamp;
@@ -100,8 +102,9 @@ pub fn cast_with_type(
     cast_options: &CastOptions,
     func_ctx: &FunctionContext,
 ) -> Result<ColumnRef> {
-    // they are pyhsically the same type
-    if &column.data_type() == target_type {
+    // they are pyhsically the same type,
+    // decimal columns don't carry the precision and scale, so they must be rescaled.
+    if &column.data_type() == target_type && !target_type.data_type_id().is_decimal() {
         return Ok(column.clone());
     }
 
@@ -151,6 +154,13 @@ pub fn cast_with_type(
             cast_options,
             func_ctx,
         ),
+        TypeID::Decimal | TypeID::Decimal256 => cast_from_decimal(
+            column,
+            &nonull_from_type,
+            &nonull_data_type,
+            cast_options,
+            func_ctx,
+        ),
         TypeID::Variant | TypeID::VariantArray | TypeID::VariantObject => {
             cast_from_variant(column, &nonull_data_type, func_ctx)
         }
@@ -176,6 +186,7 @@ pub fn cast_with_type(
         }
     } else if nonull_data_type.data_type_id() == TypeID::Array
         || nonull_data_type.data_type_id() == TypeID::Struct
+        || nonull_data_type.data_type_id() == TypeID::Map
     {
         return Err(ErrorCode::BadDataValueType(format!(
             "Cast error happens in casting from {} to {}",
@@ -301,6 +312,78 @@ pub fn cast_to_timestamp(
     return Ok((builder.build(size), None));
 }
 
+pub fn cast_to_decimal(
+    column: &ColumnRef,
+    from_type: &DataTypeImpl,
+    data_type: &DataTypeImpl,
+) -> Result<(ColumnRef, Option<Bitmap>)> {
+    let decimal: DecimalType = data_type.to_owned().try_into()?;
+    let column = Series::remove_nullable(column);
+
+    let values: Vec<Option<I256>> = with_match_integer_type_id!(from_type.data_type_id(), |$T| {
+        let col: &PrimitiveColumn<$T> = Series::check_get(&column)?;
+        col.iter()
+            .map(|v| decimal.rescale(I256::from(*v), 0).ok())
+            .collect()
+    }, {
+        match from_type.data_type_id() {
+            TypeID::Float32 | TypeID::Float64 => {
+                let column = default_column_cast(&column, &f64::to_data_type())?;
+                let col: &Float64Column = Series::check_get(&column)?;
+                col.iter().map(|v| decimal.from_f64(*v).ok()).collect()
+            }
+            TypeID::Boolean => {
+                let col: &BooleanColumn = Series::check_get(&column)?;
+                col.iter()
+                    .map(|v| decimal.rescale(I256::from(v as i128), 0).map(Some))
+                    .collect::<Result<_>>()?
+            }
+            _ => {
+                return Err(ErrorCode::BadDataValueType(format!(
+                    "Cast error happens in casting from {} to {}.",
+                    from_type.data_type_id(),
+                    data_type.name()
+                )));
+            }
+        }
+    });
+
+    Ok(build_decimal_column(&decimal, values))
+}
+
+/// Build the column of `decimal` from the unscaled values which are already checked
+/// against its precision, `None` values are marked invalid in the returned bitmap.
+pub fn build_decimal_column(
+    decimal: &DecimalType,
+    values: Vec<Option<I256>>,
+) -> (ColumnRef, Option<Bitmap>) {
+    if decimal.is_decimal256() {
+        build_decimal_values::<I256>(values)
+    } else {
+        build_decimal_values::<i128>(values)
+    }
+}
+
+fn build_decimal_values<T: DecimalNativeType>(
+    values: Vec<Option<I256>>,
+) -> (ColumnRef, Option<Bitmap>) {
+    let mut validity = MutableBitmap::with_capacity(values.len());
+    let values = values
+        .into_iter()
+        .map(|v| match v.and_then(T::try_from_i256) {
+            Some(v) => {
+                validity.push(true);
+                v
+            }
+            None => {
+                validity.push(false);
+                T::default()
+            }
+        })
+        .collect();
+    (T::new_column(values), Some(validity.into()))
+}
+
 // cast using arrow's cast compute
 pub fn arrow_cast_compute(
     column: &ColumnRef,
@@ -313,6 +396,8 @@ pub fn arrow_cast_compute(
         return cast_to_variant(column, from_type, data_type, func_ctx);
     } else if data_type.data_type_id() == TypeID::Timestamp {
         return cast_to_timestamp(column, from_type);
+    } else if data_type.data_type_id().is_decimal() {
+        return cast_to_decimal(column, from_type, data_type);
     } else if data_type.data_type_id() == TypeID::Date {
         return arrow_cast_compute(
             column,
@@ -30,6 +30,7 @@ use super::FunctionAdapter;
 use super::FunctionFeatures;
 use super::HashesFunction;
 use super::LogicFunction;
+use super::MapFunction;
 use super::MathsFunction;
 use super::OtherFunction;
 use super::SemiStructuredFunction;
@@ -77,6 +78,7 @@ static FUNCTION_FACTORY: Lazy<Arc<FunctionFactory>> = Lazy::new(|| {
     ComparisonFunction::register(&mut function_factory);
     ContextFunction::register(&mut function_factory);
     SemiStructuredFunction::register(&mut function_factory);
+    MapFunction::register(&mut function_factory);
     StringFunction::register(&mut function_factory);
     HashesFunction::register(&mut function_factory);
     ConditionalFunction::register(&mut function_factory);
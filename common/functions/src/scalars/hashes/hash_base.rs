@@ -122,6 +122,20 @@ macro_rules! integer_impl {
 
 for_all_integer_types! { integer_impl}
 
+impl DFHash for i128 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(self, state);
+    }
+}
+
+impl<'a> DFHash for &'a I256 {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(*self, state);
+    }
+}
+
 impl DFHash for f32 {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
@@ -220,6 +234,8 @@ impl DFHash for DataValue {
             DataValue::Int64(v) => DFHash::hash(v, state),
             DataValue::UInt64(v) => DFHash::hash(v, state),
             DataValue::Float64(v) => DFHash::hash(v, state),
+            DataValue::Int128(v) => DFHash::hash(v, state),
+            DataValue::Int256(v) => Hash::hash(v, state),
             DataValue::String(vals) => {
                 for v in vals {
                     DFHash::hash(v, state);
@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+use crate::scalars::FunctionDescription;
+use crate::scalars::FunctionFeatures;
+
+#[derive(Clone)]
+pub struct MapContainsFunction {
+    map_type: MapType,
+    display_name: String,
+}
+
+impl MapContainsFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        let data_type = args[0];
+        let key_type = args[1];
+
+        if !data_type.data_type_id().is_map() || key_type.data_type_id().is_array() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                display_name.to_uppercase(),
+                data_type.data_type_id(),
+                key_type.data_type_id()
+            )));
+        }
+
+        let map_type: MapType = data_type.clone().try_into()?;
+        Ok(Box::new(MapContainsFunction {
+            map_type,
+            display_name: display_name.to_string(),
+        }))
+    }
+
+    pub fn desc() -> FunctionDescription {
+        FunctionDescription::creator(Box::new(Self::try_create))
+            .features(FunctionFeatures::default().deterministic().num_arguments(2))
+    }
+}
+
+impl Function for MapContainsFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        BooleanType::new_impl()
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let entries: &StructColumn = Series::check_get(map_column.values())?;
+        let keys = &entries.values()[0];
+
+        let key_column = cast_column_field(
+            &columns[1],
+            columns[1].data_type(),
+            self.map_type.key_type(),
+            &func_ctx,
+        )?;
+
+        let offsets = map_column.offsets();
+        let it = (0..input_rows).map(|row| {
+            let key = key_column.get(row);
+            let start = offsets[row] as usize;
+            let end = offsets[row + 1] as usize;
+            (start..end).any(|i| keys.get(i) == key)
+        });
+
+        Ok(BooleanColumn::from_iterator(it).arc())
+    }
+}
+
+impl fmt::Display for MapContainsFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
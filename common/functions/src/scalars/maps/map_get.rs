@@ -0,0 +1,103 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::scalars::cast_column_field;
+use crate::scalars::Function;
+use crate::scalars::FunctionContext;
+
+/// Get the value of the key in map, returns NULL if the key does not exist.
+/// It's created by `GET` function, which is used by `m['key']`.
+#[derive(Clone)]
+pub struct MapGetFunction {
+    map_type: MapType,
+    display_name: String,
+}
+
+impl MapGetFunction {
+    pub fn try_create(display_name: &str, args: &[&DataTypeImpl]) -> Result<Box<dyn Function>> {
+        let data_type = args[0];
+        let key_type = args[1];
+
+        if !data_type.data_type_id().is_map() || key_type.data_type_id().is_array() {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Invalid argument types for function '{}': ({:?}, {:?})",
+                display_name.to_uppercase(),
+                data_type.data_type_id(),
+                key_type.data_type_id()
+            )));
+        }
+
+        let map_type: MapType = data_type.clone().try_into()?;
+        Ok(Box::new(MapGetFunction {
+            map_type,
+            display_name: display_name.to_string(),
+        }))
+    }
+}
+
+impl Function for MapGetFunction {
+    fn name(&self) -> &str {
+        &*self.display_name
+    }
+
+    fn return_type(&self) -> DataTypeImpl {
+        wrap_nullable(self.map_type.value_type())
+    }
+
+    fn eval(
+        &self,
+        func_ctx: FunctionContext,
+        columns: &ColumnsWithField,
+        input_rows: usize,
+    ) -> Result<ColumnRef> {
+        let column = columns[0].column().convert_full_column();
+        let map_column: &ArrayColumn = Series::check_get(&column)?;
+        let entries: &StructColumn = Series::check_get(map_column.values())?;
+        let (keys, values) = (&entries.values()[0], &entries.values()[1]);
+
+        let key_column = cast_column_field(
+            &columns[1],
+            columns[1].data_type(),
+            self.map_type.key_type(),
+            &func_ctx,
+        )?;
+
+        let offsets = map_column.offsets();
+        let mut result = Vec::with_capacity(input_rows);
+        for row in 0..input_rows {
+            let key = key_column.get(row);
+            let start = offsets[row] as usize;
+            let end = offsets[row + 1] as usize;
+            let value = (start..end)
+                .find(|i| keys.get(*i) == key)
+                .map(|i| values.get(i))
+                .unwrap_or(DataValue::Null);
+            result.push(value);
+        }
+
+        self.return_type().create_column(&result)
+    }
+}
+
+impl fmt::Display for MapGetFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name.to_uppercase())
+    }
+}
@@ -28,6 +28,7 @@ mod function_monotonic;
 mod geos;
 mod hashes;
 mod logics;
+mod maps;
 mod maths;
 mod others;
 mod semi_structureds;
@@ -51,6 +52,7 @@ pub use function_monotonic::Monotonicity;
 pub use geos::*;
 pub use hashes::*;
 pub use logics::*;
+pub use maps::*;
 pub use maths::*;
 pub use others::*;
 pub use semi_structureds::*;
@@ -50,16 +50,13 @@ pub struct WindowFrame {
     pub end_bound: WindowFrameBound,
 }
 
-impl TryFrom<ast::WindowFrame> for WindowFrame {
-    type Error = ErrorCode;
-
-    fn try_from(value: ast::WindowFrame) -> Result<Self, Self::Error> {
-        let start_bound = value.start_bound.into();
-        let end_bound = value
-            .end_bound
-            .map(WindowFrameBound::from)
-            .unwrap_or(WindowFrameBound::CurrentRow);
-
+impl WindowFrame {
+    /// Create a window frame, checking that the bounds are well-formed.
+    pub fn try_new(
+        units: WindowFrameUnits,
+        start_bound: WindowFrameBound,
+        end_bound: WindowFrameBound,
+    ) -> Result<Self, ErrorCode> {
         if let WindowFrameBound::Following(None) = start_bound {
             Err(ErrorCode::LogicalError(
                 "Invalid window frame: start bound cannot be unbounded following".to_owned(),
@@ -74,7 +71,6 @@ impl TryFrom<ast::WindowFrame> for WindowFrame {
                 start_bound, end_bound
             )))
         } else {
-            let units = value.units.into();
             Ok(Self {
                 units,
                 start_bound,
@@ -84,6 +80,20 @@ impl TryFrom<ast::WindowFrame> for WindowFrame {
     }
 }
 
+impl TryFrom<ast::WindowFrame> for WindowFrame {
+    type Error = ErrorCode;
+
+    fn try_from(value: ast::WindowFrame) -> Result<Self, Self::Error> {
+        let start_bound = value.start_bound.into();
+        let end_bound = value
+            .end_bound
+            .map(WindowFrameBound::from)
+            .unwrap_or(WindowFrameBound::CurrentRow);
+
+        Self::try_new(value.units.into(), start_bound, end_bound)
+    }
+}
+
 impl Default for WindowFrame {
     fn default() -> Self {
         WindowFrame {
@@ -13,15 +13,19 @@
 // limitations under the License.
 
 use common_config::QueryConfig;
+use common_exception::Result;
 
 use crate::caches::memory_cache::new_bytes_cache;
 use crate::caches::memory_cache::BloomIndexCache;
 use crate::caches::memory_cache::BloomIndexMetaCache;
 use crate::caches::memory_cache::BytesCache;
 use crate::caches::new_item_cache;
+use crate::caches::ColumnDataCache;
+use crate::caches::ColumnDataDiskCache;
 use crate::caches::ItemCache;
 use crate::caches::SegmentInfoCache;
 use crate::caches::TableSnapshotCache;
+use crate::caches::TenantLabel;
 
 // default number of index meta cached, default 3000 items
 static DEFAULT_BLOOM_INDEX_META_CACHE_ITEMS: u64 = 3000;
@@ -34,6 +38,7 @@ pub struct CacheManager {
     segment_info_cache: Option<SegmentInfoCache>,
     bloom_index_cache: Option<BloomIndexCache>,
     bloom_index_meta_cache: Option<BloomIndexMetaCache>,
+    column_data_disk_cache: Option<ColumnDataDiskCache>,
     cluster_id: String,
     tenant_id: String,
 }
@@ -42,30 +47,46 @@ impl CacheManager {
     /// Initialize the caches according to the relevant configurations.
     ///
     /// For convenience, ids of cluster and tenant are also kept
-    pub fn init(config: &QueryConfig) -> CacheManager {
+    pub fn init(config: &QueryConfig) -> Result<CacheManager> {
+        // the disk cache is opt-in, it is independent of the in-memory caches
+        let column_data_disk_cache = if config.table_disk_cache_enabled {
+            Self::new_column_data_disk_cache(
+                &config.table_disk_cache_root,
+                config.table_disk_cache_mb_size * 1024 * 1024,
+                TenantLabel {
+                    tenant_id: config.tenant_id.clone(),
+                    cluster_id: config.cluster_id.clone(),
+                },
+            )?
+        } else {
+            None
+        };
+
         if !config.table_cache_enabled {
-            Self {
+            Ok(Self {
                 table_snapshot_cache: None,
                 segment_info_cache: None,
                 bloom_index_cache: None,
                 bloom_index_meta_cache: None,
+                column_data_disk_cache,
                 cluster_id: config.cluster_id.clone(),
                 tenant_id: config.tenant_id.clone(),
-            }
+            })
         } else {
             let table_snapshot_cache = Self::new_item_cache(config.table_cache_snapshot_count);
             let segment_info_cache = Self::new_item_cache(config.table_cache_segment_count);
             let bloom_index_cache = Self::new_bytes_cache(DEFAULT_BLOOM_INDEX_META_CACHE_ITEMS);
             let bloom_index_meta_cache =
                 Self::new_item_cache(DEFAULT_BLOOM_INDEX_COLUMN_CACHE_SIZE);
-            Self {
+            Ok(Self {
                 table_snapshot_cache,
                 segment_info_cache,
                 bloom_index_cache,
                 bloom_index_meta_cache,
+                column_data_disk_cache,
                 cluster_id: config.cluster_id.clone(),
                 tenant_id: config.tenant_id.clone(),
-            }
+            })
         }
     }
 
@@ -85,6 +106,10 @@ impl CacheManager {
         self.bloom_index_meta_cache.clone()
     }
 
+    pub fn get_column_data_disk_cache(&self) -> Option<ColumnDataDiskCache> {
+        self.column_data_disk_cache.clone()
+    }
+
     pub fn get_tenant_id(&self) -> &str {
         self.tenant_id.as_str()
     }
@@ -108,4 +133,20 @@ impl CacheManager {
             None
         }
     }
+
+    fn new_column_data_disk_cache(
+        root: &str,
+        capacity: u64,
+        tenant_label: TenantLabel,
+    ) -> Result<Option<ColumnDataDiskCache>> {
+        if capacity > 0 {
+            Ok(Some(ColumnDataCache::try_create(
+                root,
+                capacity,
+                tenant_label,
+            )?))
+        } else {
+            Ok(None)
+        }
+    }
 }
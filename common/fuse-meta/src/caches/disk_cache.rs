@@ -0,0 +1,188 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::Read;
+use std::path::PathBuf;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::base::tokio;
+use common_cache::LruDiskCache;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parking_lot::Mutex;
+
+use crate::caches::metrics::metrics_inc_disk_cache_access;
+use crate::caches::TenantLabel;
+
+// number of shards of the disk cache, each of them is guarded by its own lock
+const DISK_CACHE_SHARDS: usize = 16;
+
+/// Snapshot of the state of a cache, used by `system.caches`.
+#[derive(Clone, Debug, Default)]
+pub struct CacheStatistics {
+    pub num_items: u64,
+    pub size: u64,
+    pub capacity: u64,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Column chunks of fuse data blocks cached on local disk, evicted in LRU order.
+///
+/// Chunks are keyed by the location of the block and the id of the column,
+/// blocks are immutable once written, so there is no need to invalidate them.
+pub type ColumnDataDiskCache = Arc<ColumnDataCache>;
+
+/// The cache is split into shards by the hash of the key, the locks of the shards
+/// only guard the bookkeeping of the LRU, contents of the chunks are read and
+/// written outside of them, in the blocking threads of the runtime.
+pub struct ColumnDataCache {
+    shards: Vec<Mutex<LruDiskCache>>,
+    // chunks are written here first, then moved into the shard
+    tmp_dir: PathBuf,
+    next_tmp_id: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    tenant_label: TenantLabel,
+}
+
+impl ColumnDataCache {
+    pub fn try_create(
+        root: &str,
+        capacity: u64,
+        tenant_label: TenantLabel,
+    ) -> Result<ColumnDataDiskCache> {
+        let root = PathBuf::from(root);
+        let tmp_dir = root.join("tmp");
+        // leftovers of the writes interrupted by a restart
+        if tmp_dir.exists() {
+            fs::remove_dir_all(&tmp_dir)?;
+        }
+        fs::create_dir_all(&tmp_dir)?;
+
+        let num_shards = DISK_CACHE_SHARDS as u64;
+        let shards = (0..num_shards)
+            .map(|i| {
+                let shard_capacity = capacity / num_shards + u64::from(i < capacity % num_shards);
+                let shard = LruDiskCache::new(root.join(format!("shard_{}", i)), shard_capacity)?;
+                Ok(Mutex::new(shard))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(ColumnDataCache {
+            shards,
+            tmp_dir,
+            next_tmp_id: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            tenant_label,
+        }))
+    }
+
+    pub fn cache_key(location: &str, column_id: u32) -> String {
+        format!("{}/{}", location.trim_start_matches('/'), column_id)
+    }
+
+    /// Returns the cached chunk, a broken cache file is treated as a miss.
+    pub async fn get(self: &Arc<Self>, key: &str) -> Option<Vec<u8>> {
+        let cache = self.clone();
+        let key = key.to_owned();
+        let chunk = tokio::task::spawn_blocking(move || cache.get_blocking(&key))
+            .await
+            .unwrap_or(None);
+
+        match &chunk {
+            Some(chunk) => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                metrics_inc_disk_cache_access(&self.tenant_label, true, chunk.len() as u64);
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                metrics_inc_disk_cache_access(&self.tenant_label, false, 0);
+            }
+        }
+        chunk
+    }
+
+    pub async fn put(self: &Arc<Self>, key: &str, chunk: Vec<u8>) -> Result<()> {
+        let cache = self.clone();
+        let key = key.to_owned();
+        tokio::task::spawn_blocking(move || cache.put_blocking(&key, &chunk))
+            .await
+            .map_err(|e| ErrorCode::TokioError(e.to_string()))?
+    }
+
+    pub fn statistics(&self) -> CacheStatistics {
+        let mut statistics = CacheStatistics {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            ..CacheStatistics::default()
+        };
+        for shard in &self.shards {
+            let shard = shard.lock();
+            statistics.num_items += shard.len() as u64;
+            statistics.size += shard.size();
+            statistics.capacity += shard.capacity();
+        }
+        statistics
+    }
+
+    fn shard(&self, key: &str) -> &Mutex<LruDiskCache> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+
+    fn get_blocking(&self, key: &str) -> Option<Vec<u8>> {
+        // only the file is opened under the lock, an opened file is still readable
+        // if it is evicted meanwhile
+        let mut file = self.shard(key).lock().get_file(key).ok()?;
+        let mut chunk = vec![];
+        file.read_to_end(&mut chunk).ok().map(|_| chunk)
+    }
+
+    fn put_blocking(&self, key: &str, chunk: &[u8]) -> Result<()> {
+        let shard = self.shard(key);
+        {
+            let shard = shard.lock();
+            if !shard.can_store(chunk.len() as u64) || shard.contains_key(key) {
+                return Ok(());
+            }
+        }
+
+        let tmp_id = self.next_tmp_id.fetch_add(1, Ordering::Relaxed);
+        let tmp_path = self.tmp_dir.join(tmp_id.to_string());
+        fs::write(&tmp_path, chunk)?;
+
+        let mut shard = shard.lock();
+        let result = if shard.contains_key(key) {
+            // cached by a concurrent read of the same chunk
+            Ok(())
+        } else {
+            shard.insert_file(key, &tmp_path).map_err(ErrorCode::from)
+        };
+        drop(shard);
+
+        if tmp_path.exists() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        result
+    }
+}
@@ -19,6 +19,9 @@ const CACHE_READ_BYTES_FROM_REMOTE: &str = "cache_read_bytes_from_remote";
 const CACHE_READ_BYTES_FROM_LOCAL: &str = "cache_read_bytes_from_local";
 const CACHE_ACCESS_COUNT: &str = "cache_access_count";
 const CACHE_ACCESS_HIT_COUNT: &str = "cache_access_hit_count";
+const DISK_CACHE_HIT_COUNT: &str = "disk_cache_hit_count";
+const DISK_CACHE_MISS_COUNT: &str = "disk_cache_miss_count";
+const DISK_CACHE_READ_BYTES: &str = "disk_cache_read_bytes";
 
 pub struct TenantLabel {
     pub tenant_id: String,
@@ -56,3 +59,15 @@ impl Drop for CacheDeferMetrics {
         }
     }
 }
+
+pub fn metrics_inc_disk_cache_access(tenant_label: &TenantLabel, cache_hit: bool, read_bytes: u64) {
+    let tenant_id = &tenant_label.tenant_id;
+    let cluster_id = &tenant_label.cluster_id;
+
+    if cache_hit {
+        label_counter(DISK_CACHE_HIT_COUNT, tenant_id, cluster_id);
+        label_counter_with_val(DISK_CACHE_READ_BYTES, read_bytes, tenant_id, cluster_id);
+    } else {
+        label_counter(DISK_CACHE_MISS_COUNT, tenant_id, cluster_id);
+    }
+}
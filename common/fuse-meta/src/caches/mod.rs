@@ -13,10 +13,14 @@
 // limitations under the License.
 
 mod cache;
+mod disk_cache;
 mod memory_cache;
 mod metrics;
 
 pub use cache::CacheManager;
+pub use disk_cache::CacheStatistics;
+pub use disk_cache::ColumnDataCache;
+pub use disk_cache::ColumnDataDiskCache;
 pub use memory_cache::new_item_cache;
 pub use memory_cache::ItemCache;
 pub use memory_cache::SegmentInfoCache;
@@ -15,8 +15,13 @@
 pub use v0::ColumnMeta;
 pub use v1::BlockBloomFilterIndex;
 pub use v1::BlockMeta;
+pub use v1::ColumnDistribution;
+pub use v1::DistributionsOfColumns;
+pub use v1::Histogram;
+pub use v1::HistogramBucket;
 pub use v1::SegmentInfo;
 pub use v1::TableSnapshot;
+pub use v1::TableSnapshotStatistics;
 
 use super::v0;
 use super::v1;
@@ -15,8 +15,14 @@
 mod index;
 mod segment;
 mod snapshot;
+mod table_snapshot_statistics;
 
 pub use index::BlockBloomFilterIndex;
 pub use segment::BlockMeta;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
+pub use table_snapshot_statistics::ColumnDistribution;
+pub use table_snapshot_statistics::DistributionsOfColumns;
+pub use table_snapshot_statistics::Histogram;
+pub use table_snapshot_statistics::HistogramBucket;
+pub use table_snapshot_statistics::TableSnapshotStatistics;
@@ -0,0 +1,90 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::DataValue;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::meta::common::FormatVersion;
+use crate::meta::ColumnId;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+/// Statistics of the column distributions, collected by `ANALYZE TABLE`.
+///
+/// They are much larger than the summary of a snapshot, so they are kept in a separate
+/// file, which is referenced by the snapshots. The statistics are not maintained by the
+/// writes to the table, they are collected on the snapshot of `snapshot_id`, and are
+/// carried forward to the later snapshots until the table is analyzed again.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of the statistics
+    format_version: FormatVersion,
+
+    /// id of the snapshot on which the statistics are collected
+    pub snapshot_id: SnapshotId,
+
+    /// Distributions of the analyzed columns, keyed by the ids of the columns
+    pub column_distributions: DistributionsOfColumns,
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(snapshot_id: SnapshotId, column_distributions: DistributionsOfColumns) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id,
+            column_distributions,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+}
+
+pub type DistributionsOfColumns = HashMap<ColumnId, ColumnDistribution>;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnDistribution {
+    /// Estimated number of the distinct values, NULLs are excluded
+    pub ndv: u64,
+    pub histogram: Histogram,
+}
+
+/// An equi-height histogram, the non-null values of a column are split into buckets of
+/// (approximately) the same number of values.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct Histogram {
+    /// Buckets ordered by the bounds, the bounds of different buckets never overlap
+    pub buckets: Vec<HistogramBucket>,
+}
+
+impl Histogram {
+    /// Total number of the values in the histogram.
+    pub fn num_values(&self) -> u64 {
+        self.buckets.iter().map(|bucket| bucket.num_values).sum()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    /// Min value of the bucket, inclusive
+    pub lower_bound: DataValue,
+    /// Max value of the bucket, inclusive
+    pub upper_bound: DataValue,
+    pub num_values: u64,
+    pub num_distinct: u64,
+}
@@ -25,8 +25,10 @@ pub use hash_table_iter::HashTableIter;
 pub use hash_table_iter::HashTableIteratorKind;
 pub use hash_table_iter::TwoLevelHashTableIter;
 pub use hash_table_key::HashTableKeyable;
+pub use two_level_hash_table::get_bucket_from_hash;
 pub use two_level_hash_table::HashTableKind;
 pub use two_level_hash_table::TwoLevelHashTable;
+pub use two_level_hash_table::NUM_BUCKETS;
 
 mod hash_table;
 #[allow(clippy::missing_safety_doc, clippy::not_unsafe_ptr_arg_deref)]
@@ -20,9 +20,15 @@ use crate::HashTableGrower;
 use crate::HashTableIteratorKind;
 use crate::HashTableKeyable;
 
-static BITS_FOR_BUCKET: u8 = 8;
-static NUM_BUCKETS: usize = 1 << BITS_FOR_BUCKET;
-static MAX_BUCKECT: usize = NUM_BUCKETS - 1;
+const BITS_FOR_BUCKET: u8 = 8;
+pub const NUM_BUCKETS: usize = 1 << BITS_FOR_BUCKET;
+const MAX_BUCKECT: usize = NUM_BUCKETS - 1;
+
+/// Returns the bucket of the hash value in two-level hash table.
+#[inline(always)]
+pub fn get_bucket_from_hash(hash_value: u64) -> usize {
+    ((hash_value >> (64 - BITS_FOR_BUCKET)) & (MAX_BUCKECT as u64)) as usize
+}
 
 pub enum HashTableKind<
     Key: HashTableKeyable,
@@ -180,6 +186,6 @@ impl<Key: HashTableKeyable, Entity: HashTableEntity<Key>, Grower: HashTableGrowe
 
     #[inline(always)]
     fn get_bucket_from_hash(&self, hash_value: &u64) -> usize {
-        ((hash_value >> (64 - BITS_FOR_BUCKET)) & (MAX_BUCKECT as u64)) as usize
+        get_bucket_from_hash(*hash_value)
     }
 }
@@ -13,8 +13,12 @@
 // limitations under the License.
 
 mod cluster;
+mod masking_policy;
+mod network_policy;
+mod policy;
 mod quota;
 mod role;
+mod row_access_policy;
 mod serde;
 mod setting;
 mod stage;
@@ -23,10 +27,17 @@ mod user;
 
 pub use cluster::ClusterApi;
 pub use cluster::ClusterMgr;
+pub use masking_policy::MaskingPolicyMgr;
+pub use network_policy::NetworkPolicyApi;
+pub use network_policy::NetworkPolicyMgr;
+pub use policy::Policy;
+pub use policy::PolicyApi;
+pub use policy::PolicyMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
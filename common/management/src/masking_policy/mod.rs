@@ -0,0 +1,64 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::udfs::UDFParser;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::MaskingPolicy;
+
+use crate::policy::Policy;
+use crate::policy::PolicyMgr;
+
+pub type MaskingPolicyMgr = PolicyMgr<MaskingPolicy>;
+
+impl Policy for MaskingPolicy {
+    const KEY_PREFIX: &'static str = "__fd_masking_policies";
+    const KIND: &'static str = "masking policy";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn validate(&self) -> Result<()> {
+        // The only parameter is the value of the masked column, and the masked value
+        // replaces it in the queries, so they must be of the same type.
+        if self.parameters.len() != 1 || self.parameter_types.len() != 1 {
+            return Err(ErrorCode::IllegalMaskingPolicyFormat(format!(
+                "Masking policy {} must have exactly one parameter, but got {}",
+                self.name,
+                self.parameters.len()
+            )));
+        }
+        if self.parameter_types[0] != self.return_type {
+            return Err(ErrorCode::IllegalMaskingPolicyFormat(format!(
+                "Masking policy {} must return the type of its parameter {}, but got {}",
+                self.name, self.parameter_types[0], self.return_type
+            )));
+        }
+
+        // The policy body follows the same rules as the UDF definition: it can only
+        // refer to the declared parameters, and all of them must be used.
+        let mut udf_parser = UDFParser::default();
+        udf_parser.parse(&self.name, &self.parameters, &self.definition)?;
+        Ok(())
+    }
+
+    fn already_exists(message: String) -> ErrorCode {
+        ErrorCode::MaskingPolicyAlreadyExists(message)
+    }
+
+    fn unknown(message: String) -> ErrorCode {
+        ErrorCode::UnknownMaskingPolicy(message)
+    }
+}
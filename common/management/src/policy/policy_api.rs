@@ -0,0 +1,55 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::SeqV;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The kinds of policies kept by tenant in the meta, such as the row access
+/// policies and the masking policies.
+pub trait Policy: Clone + Debug + Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// The prefix of the keys of the policies in the meta.
+    const KEY_PREFIX: &'static str;
+
+    /// The kind of the policy in the messages, such as `row access policy`.
+    const KIND: &'static str;
+
+    fn name(&self) -> &str;
+
+    /// Check the policy before it is added.
+    fn validate(&self) -> Result<()>;
+
+    fn already_exists(message: String) -> ErrorCode;
+
+    fn unknown(message: String) -> ErrorCode;
+}
+
+#[async_trait::async_trait]
+pub trait PolicyApi<P: Policy>: Sync + Send {
+    // Add a policy to /tenant/policy-name.
+    async fn add_policy(&self, policy: P) -> Result<u64>;
+
+    // Get policy by name.
+    async fn get_policy(&self, policy_name: &str, seq: Option<u64>) -> Result<SeqV<P>>;
+
+    // Get all the policies for a tenant.
+    async fn get_policies(&self) -> Result<Vec<P>>;
+
+    // Drop the tenant's policy by name.
+    async fn drop_policy(&self, policy_name: &str, seq: Option<u64>) -> Result<()>;
+}
@@ -0,0 +1,57 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::udfs::UDFParser;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+
+use crate::policy::Policy;
+use crate::policy::PolicyMgr;
+
+pub type RowAccessPolicyMgr = PolicyMgr<RowAccessPolicy>;
+
+impl Policy for RowAccessPolicy {
+    const KEY_PREFIX: &'static str = "__fd_row_access_policies";
+    const KIND: &'static str = "row access policy";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.parameters.len() != self.parameter_types.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicyFormat(format!(
+                "Row access policy {} has {} parameters, but {} parameter types",
+                self.name,
+                self.parameters.len(),
+                self.parameter_types.len()
+            )));
+        }
+
+        // The policy body follows the same rules as the UDF definition: it can only
+        // refer to the declared parameters, and all of them must be used.
+        let mut udf_parser = UDFParser::default();
+        udf_parser.parse(&self.name, &self.parameters, &self.definition)?;
+        Ok(())
+    }
+
+    fn already_exists(message: String) -> ErrorCode {
+        ErrorCode::RowAccessPolicyAlreadyExists(message)
+    }
+
+    fn unknown(message: String) -> ErrorCode {
+        ErrorCode::UnknownRowAccessPolicy(message)
+    }
+}
@@ -20,6 +20,7 @@ use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 use common_meta_types::UserOption;
 use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserQuota;
 
 #[async_trait::async_trait]
 pub trait UserApi: Sync + Send {
@@ -37,6 +38,13 @@ pub trait UserApi: Sync + Send {
         seq: Option<u64>,
     ) -> Result<Option<u64>>;
 
+    async fn update_user_quota(
+        &self,
+        user: UserIdentity,
+        quota: UserQuota,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>>;
+
     async fn grant_privileges(
         &self,
         user: UserIdentity,
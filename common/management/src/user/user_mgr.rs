@@ -30,6 +30,7 @@ use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 use common_meta_types::UserOption;
 use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserQuota;
 
 use crate::serde::deserialize_struct;
 use crate::serde::serialize_struct;
@@ -164,6 +165,19 @@ impl UserApi for UserMgr {
         Ok(Some(seq))
     }
 
+    async fn update_user_quota(
+        &self,
+        user: UserIdentity,
+        quota: UserQuota,
+        seq: Option<u64>,
+    ) -> Result<Option<u64>> {
+        let user_val_seq = self.get_user(user, seq);
+        let mut user_info = user_val_seq.await?.data;
+        user_info.quota = quota;
+        let seq = self.upsert_user_info(&user_info, seq).await?;
+        Ok(Some(seq))
+    }
+
     async fn grant_privileges(
         &self,
         user: UserIdentity,
@@ -57,6 +57,8 @@ use common_meta_app::schema::UndropDatabaseReply;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReply;
@@ -1701,6 +1703,65 @@ impl<KV: KVApi> SchemaApi for KV {
         }
     }
 
+    #[tracing::instrument(level = "debug", ret, err, skip_all)]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, MetaError> {
+        debug!(req = debug(&req), "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut condition = Vec::with_capacity(req.update_table_metas.len());
+            let mut if_then = Vec::with_capacity(req.update_table_metas.len());
+
+            for update_req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: update_req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_struct_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(MetaError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update_req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update_req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(MetaError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update_req.table_id,
+                            update_req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                // tb_id -> tb_meta
+                if_then.push(txn_op_put(
+                    &tbid,
+                    serialize_struct(&update_req.new_table_meta)?,
+                ));
+            }
+
+            let txn_req = TxnRequest {
+                condition,
+                if_then,
+                else_then: vec![],
+            };
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(succ = display(succ), "update_multi_table_meta");
+
+            if succ {
+                return Ok(UpdateMultiTableMetaReply {});
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", ret, err, skip_all)]
     async fn gc_dropped_data(
         &self,
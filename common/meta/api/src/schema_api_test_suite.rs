@@ -52,6 +52,7 @@ use common_meta_app::schema::TableNameIdent;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableOptionReq;
 use common_meta_types::GCDroppedDataReq;
@@ -1876,6 +1877,82 @@ impl SchemaApiTestSuite {
                 assert_eq!(ErrorCode::table_version_mismatched_code(), err.code());
             }
         }
+
+        info!("--- update multi table meta");
+        {
+            let req = CreateTableReq {
+                if_not_exists: false,
+                name_ident: TableNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                    table_name: "tb3".to_string(),
+                },
+                table_meta: table_meta(Utc::now()),
+            };
+            mt.create_table(req).await?;
+
+            info!("--- update multi table meta, normal case");
+            {
+                let table2 = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                let table3 = mt.get_table((tenant, "db1", "tb3").into()).await?;
+
+                let mut new_table_meta2 = table2.meta.clone();
+                new_table_meta2.statistics.number_of_rows = 2;
+                let mut new_table_meta3 = table3.meta.clone();
+                new_table_meta3.statistics.number_of_rows = 3;
+                mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        UpdateTableMetaReq {
+                            table_id: table2.ident.table_id,
+                            seq: MatchSeq::Exact(table2.ident.seq),
+                            new_table_meta: new_table_meta2.clone(),
+                        },
+                        UpdateTableMetaReq {
+                            table_id: table3.ident.table_id,
+                            seq: MatchSeq::Exact(table3.ident.seq),
+                            new_table_meta: new_table_meta3.clone(),
+                        },
+                    ],
+                })
+                .await?;
+
+                let table2 = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                let table3 = mt.get_table((tenant, "db1", "tb3").into()).await?;
+                assert_eq!(table2.meta, new_table_meta2);
+                assert_eq!(table3.meta, new_table_meta3);
+            }
+
+            info!("--- update multi table meta: version mismatch, none is updated");
+            {
+                let table2 = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                let table3 = mt.get_table((tenant, "db1", "tb3").into()).await?;
+
+                let mut new_table_meta2 = table2.meta.clone();
+                new_table_meta2.statistics.number_of_rows = 20;
+                let res = mt
+                    .update_multi_table_meta(UpdateMultiTableMetaReq {
+                        update_table_metas: vec![
+                            UpdateTableMetaReq {
+                                table_id: table2.ident.table_id,
+                                seq: MatchSeq::Exact(table2.ident.seq),
+                                new_table_meta: new_table_meta2,
+                            },
+                            UpdateTableMetaReq {
+                                table_id: table3.ident.table_id,
+                                seq: MatchSeq::Exact(table3.ident.seq + 1),
+                                new_table_meta: table3.meta.clone(),
+                            },
+                        ],
+                    })
+                    .await;
+
+                let err = ErrorCode::from(res.unwrap_err());
+                assert_eq!(ErrorCode::table_version_mismatched_code(), err.code());
+
+                let got = mt.get_table((tenant, "db1", "tb2").into()).await?;
+                assert_eq!(got.meta, table2.meta);
+            }
+        }
         Ok(())
     }
 
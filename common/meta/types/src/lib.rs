@@ -25,6 +25,7 @@ mod endpoint;
 mod errors;
 mod kv_message;
 mod log_entry;
+mod masking_policy;
 mod match_seq;
 mod message;
 mod meta_errors;
@@ -33,10 +34,12 @@ mod meta_network_errors;
 mod meta_raft_errors;
 mod meta_result_error;
 mod meta_storage_errors;
+mod network_policy;
 mod operation;
 mod raft_txid;
 mod raft_types;
 mod role_info;
+mod row_access_policy;
 mod seq_num;
 mod seq_value;
 mod tenant_quota;
@@ -106,6 +109,7 @@ pub use kv_message::MGetKVReq;
 pub use kv_message::UpsertKVReply;
 pub use kv_message::UpsertKVReq;
 pub use log_entry::LogEntry;
+pub use masking_policy::MaskingPolicy;
 pub use match_seq::MatchSeq;
 pub use match_seq::MatchSeqExt;
 pub use message::ForwardRequest;
@@ -126,6 +130,7 @@ pub use meta_raft_errors::RetryableError;
 pub use meta_result_error::MetaResultError;
 pub use meta_storage_errors::MetaStorageError;
 pub use meta_storage_errors::MetaStorageResult;
+pub use network_policy::NetworkPolicy;
 pub use operation::GCDroppedDataReply;
 pub use operation::GCDroppedDataReq;
 pub use operation::MetaId;
@@ -154,6 +159,7 @@ pub use raft_types::LogIndex;
 pub use raft_types::NodeId;
 pub use raft_types::Term;
 pub use role_info::RoleInfo;
+pub use row_access_policy::RowAccessPolicy;
 pub use seq_num::SeqNum;
 pub use seq_value::IntoSeqV;
 pub use seq_value::KVMeta;
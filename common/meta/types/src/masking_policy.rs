@@ -0,0 +1,69 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A masking policy is an expression over a single parameter, which is bound to a column
+/// by `ALTER TABLE ... MODIFY COLUMN ... SET MASKING POLICY`. The queries on that table
+/// read the result of the expression instead of the column value.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct MaskingPolicy {
+    pub name: String,
+    pub parameters: Vec<String>,
+    pub parameter_types: Vec<String>,
+    pub return_type: String,
+
+    pub description: String,
+    pub definition: String,
+}
+
+impl MaskingPolicy {
+    pub fn new(
+        name: &str,
+        parameters: Vec<String>,
+        parameter_types: Vec<String>,
+        return_type: &str,
+        definition: &str,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            parameters,
+            parameter_types,
+            return_type: return_type.to_string(),
+            definition: definition.to_string(),
+            description: description.to_string(),
+        }
+    }
+}
+
+impl TryFrom<Vec<u8>> for MaskingPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalMaskingPolicyFormat(format!(
+                "Cannot deserialize masking policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
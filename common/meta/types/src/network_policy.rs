@@ -0,0 +1,146 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::convert::TryFrom;
+use std::net::IpAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A network policy restricts the client addresses a user can log in from, it is
+/// assigned to users by `ALTER USER ... WITH SET NETWORK POLICY`.
+///
+/// The entries of both lists are IP addresses or CIDR ranges such as `10.0.0.0/8`.
+/// An address is allowed if it matches the allowed list and does not match the
+/// blocked list.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+#[serde(default)]
+pub struct NetworkPolicy {
+    pub name: String,
+    pub allowed_ip_list: Vec<String>,
+    pub blocked_ip_list: Vec<String>,
+    pub comment: String,
+}
+
+impl NetworkPolicy {
+    pub fn new(
+        name: &str,
+        allowed_ip_list: Vec<String>,
+        blocked_ip_list: Vec<String>,
+        comment: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            allowed_ip_list,
+            blocked_ip_list,
+            comment: comment.to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.allowed_ip_list.is_empty() {
+            return Err(ErrorCode::IllegalNetworkPolicyFormat(format!(
+                "Network policy {} must have at least one allowed ip",
+                self.name
+            )));
+        }
+        for range in self
+            .allowed_ip_list
+            .iter()
+            .chain(self.blocked_ip_list.iter())
+        {
+            parse_ip_range(range)?;
+        }
+        Ok(())
+    }
+
+    pub fn is_allowed(&self, ip: &IpAddr) -> Result<bool> {
+        for range in &self.blocked_ip_list {
+            if ip_in_range(ip, range)? {
+                return Ok(false);
+            }
+        }
+        for range in &self.allowed_ip_list {
+            if ip_in_range(ip, range)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+}
+
+fn parse_ip_range(range: &str) -> Result<(IpAddr, u32)> {
+    let illegal = || {
+        ErrorCode::IllegalNetworkPolicyFormat(format!(
+            "Invalid ip address or CIDR range: {}",
+            range
+        ))
+    };
+
+    let (addr, prefix) = match range.split_once('/') {
+        Some((addr, prefix)) => (addr, Some(prefix)),
+        None => (range, None),
+    };
+    let addr = addr.trim().parse::<IpAddr>().map_err(|_| illegal())?;
+    let max_prefix = match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+    let prefix = match prefix {
+        Some(prefix) => prefix.trim().parse::<u32>().map_err(|_| illegal())?,
+        None => max_prefix,
+    };
+    if prefix > max_prefix {
+        return Err(illegal());
+    }
+    Ok((addr, prefix))
+}
+
+fn ip_in_range(ip: &IpAddr, range: &str) -> Result<bool> {
+    let (addr, prefix) = parse_ip_range(range)?;
+
+    // The peer address of an IPv4 client may be reported as an IPv4-mapped IPv6 address.
+    let ip = match ip {
+        IpAddr::V6(v6) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(*ip),
+        IpAddr::V4(_) => *ip,
+    };
+
+    Ok(match (addr, ip) {
+        (IpAddr::V4(addr), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(addr) & mask == u32::from(ip) & mask
+        }
+        (IpAddr::V6(addr), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(addr) & mask == u128::from(ip) & mask
+        }
+        _ => false,
+    })
+}
+
+impl TryFrom<Vec<u8>> for NetworkPolicy {
+    type Error = ErrorCode;
+
+    fn try_from(value: Vec<u8>) -> Result<Self> {
+        match serde_json::from_slice(&value) {
+            Ok(policy) => Ok(policy),
+            Err(serialize_error) => Err(ErrorCode::IllegalNetworkPolicyFormat(format!(
+                "Cannot deserialize network policy from bytes. cause {}",
+                serialize_error
+            ))),
+        }
+    }
+}
@@ -26,11 +26,14 @@ pub enum GrantObject {
     Global,
     Database(String, String),
     Table(String, String, String),
+    /// Columns of a table, only `SELECT` is available on it.
+    Column(String, String, String, Vec<String>),
 }
 
 impl GrantObject {
     /// Comparing the grant objects, the Database object contains all the Table objects inside it.
-    /// Global object contains all the Database objects.
+    /// Global object contains all the Database objects. The Table object contains all the Column
+    /// objects of it.
     pub fn contains(&self, object: &GrantObject) -> bool {
         match (self, object) {
             (GrantObject::Global, _) => true,
@@ -41,11 +44,53 @@ impl GrantObject {
             (GrantObject::Database(lcat, ldb), GrantObject::Table(rcat, rdb, _)) => {
                 lcat == rcat && ldb == rdb
             }
+            (GrantObject::Database(lcat, ldb), GrantObject::Column(rcat, rdb, _, _)) => {
+                lcat == rcat && ldb == rdb
+            }
             (
                 GrantObject::Table(lcat, lhs_db, lhs_table),
                 GrantObject::Table(rcat, rhs_db, rhs_table),
             ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            (
+                GrantObject::Table(lcat, lhs_db, lhs_table),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
             (GrantObject::Table(_, _, _), _) => false,
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, lhs_columns),
+                GrantObject::Column(rcat, rhs_db, rhs_table, rhs_columns),
+            ) => {
+                lcat == rcat
+                    && (lhs_db == rhs_db)
+                    && (lhs_table == rhs_table)
+                    && rhs_columns.iter().all(|c| lhs_columns.contains(c))
+            }
+            (GrantObject::Column(_, _, _, _), _) => false,
+        }
+    }
+
+    fn columns(&self) -> Option<&Vec<String>> {
+        match self {
+            GrantObject::Column(_, _, _, columns) => Some(columns),
+            _ => None,
+        }
+    }
+
+    fn columns_mut(&mut self) -> Option<&mut Vec<String>> {
+        match self {
+            GrantObject::Column(_, _, _, columns) => Some(columns),
+            _ => None,
+        }
+    }
+
+    /// Returns true if both of the objects are the columns of the same table.
+    fn is_columns_of_same_table(&self, object: &GrantObject) -> bool {
+        match (self, object) {
+            (
+                GrantObject::Column(lcat, lhs_db, lhs_table, _),
+                GrantObject::Column(rcat, rhs_db, rhs_table, _),
+            ) => lcat == rcat && (lhs_db == rhs_db) && (lhs_table == rhs_table),
+            _ => false,
         }
     }
 
@@ -55,6 +100,7 @@ impl GrantObject {
             GrantObject::Global => UserPrivilegeSet::available_privileges_on_global(),
             GrantObject::Database(_, _) => UserPrivilegeSet::available_privileges_on_database(),
             GrantObject::Table(_, _, _) => UserPrivilegeSet::available_privileges_on_table(),
+            GrantObject::Column(_, _, _, _) => UserPrivilegeSet::available_privileges_on_column(),
         }
     }
 }
@@ -67,6 +113,9 @@ impl fmt::Display for GrantObject {
             GrantObject::Table(ref cat, ref db, ref table) => {
                 write!(f, "'{}'.'{}'.'{}'", cat, db, table)
             }
+            GrantObject::Column(ref cat, ref db, ref table, ref columns) => {
+                write!(f, "'{}'.'{}'.'{}'({})", cat, db, table, columns.join(", "))
+            }
         }
     }
 }
@@ -118,7 +167,17 @@ impl fmt::Display for GrantEntry {
         } else {
             privileges.to_string()
         };
-        write!(f, "GRANT {} ON {}", &privileges_str, self.object)
+        match &self.object {
+            // GRANT SELECT(a, b) ON 'default'.'db'.'t'
+            GrantObject::Column(cat, db, table, columns) => write!(
+                f,
+                "GRANT {}({}) ON {}",
+                privileges,
+                columns.join(", "),
+                GrantObject::Table(cat.clone(), db.clone(), table.clone())
+            ),
+            _ => write!(f, "GRANT {} ON {}", &privileges_str, self.object),
+        }
     }
 }
 
@@ -162,6 +221,26 @@ impl UserGrantSet {
             .any(|e| e.verify_privilege(object, privilege))
     }
 
+    /// Returns the columns of the table on which the privilege is granted by the column
+    /// level grants.
+    pub fn granted_columns(
+        &self,
+        table: &GrantObject,
+        privilege: UserPrivilegeType,
+    ) -> Vec<String> {
+        self.entries
+            .iter()
+            .filter(|e| e.privileges.contains(privilege))
+            .filter_map(|e| match &e.object {
+                GrantObject::Column(_, _, _, columns) if table.contains(&e.object) => {
+                    Some(columns.clone())
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
     pub fn grant_privileges(&mut self, object: &GrantObject, privileges: UserPrivilegeSet) {
         let privileges: BitFlags<UserPrivilegeType> = privileges.into();
         let mut new_entries: Vec<GrantEntry> = vec![];
@@ -172,6 +251,20 @@ impl UserGrantSet {
             if entry.matches_entry(object) {
                 entry.privileges |= privileges;
                 changed = true;
+            } else if entry.privileges == privileges
+                && entry.object.is_columns_of_same_table(object)
+            {
+                // Merge the columns granted on the same table
+                if let (Some(columns), Some(new_columns)) =
+                    (entry.object.columns_mut(), object.columns())
+                {
+                    for column in new_columns {
+                        if !columns.contains(column) {
+                            columns.push(column.clone());
+                        }
+                    }
+                }
+                changed = true;
             }
             new_entries.push(entry);
         }
@@ -193,11 +286,27 @@ impl UserGrantSet {
                     let mut e = e.clone();
                     e.privileges ^= privileges;
                     e
+                } else if e.object.is_columns_of_same_table(object)
+                    && privileges.contains(e.privileges)
+                {
+                    // Revoke the privileges on some columns of the table
+                    let mut e = e.clone();
+                    if let (Some(columns), Some(revoked)) =
+                        (e.object.columns_mut(), object.columns())
+                    {
+                        columns.retain(|column| !revoked.contains(column));
+                    }
+                    e
                 } else {
                     e.clone()
                 }
             })
             .filter(|e| e.privileges != BitFlags::empty())
+            .filter(|e| {
+                e.object
+                    .columns()
+                    .map_or(true, |columns| !columns.is_empty())
+            })
             .collect::<Vec<_>>();
         self.entries = new_entries;
     }
@@ -94,11 +94,20 @@ impl TryFrom<Vec<u8>> for UserInfo {
 #[serde(default)]
 pub struct UserOption {
     flags: BitFlags<UserOptionFlag>,
+    network_policy: Option<String>,
 }
 
 impl UserOption {
     pub fn new(flags: BitFlags<UserOptionFlag>) -> Self {
-        Self { flags }
+        Self {
+            flags,
+            network_policy: None,
+        }
+    }
+
+    pub fn with_network_policy(mut self, network_policy: Option<String>) -> Self {
+        self.network_policy = network_policy;
+        self
     }
 
     pub fn flags(&self) -> &BitFlags<UserOptionFlag> {
@@ -120,6 +129,14 @@ impl UserOption {
     pub fn has_option_flag(&self, flag: UserOptionFlag) -> bool {
         self.flags.contains(flag)
     }
+
+    pub fn network_policy(&self) -> Option<&String> {
+        self.network_policy.as_ref()
+    }
+
+    pub fn set_network_policy(&mut self, network_policy: Option<String>) {
+        self.network_policy = network_policy;
+    }
 }
 
 #[bitflags]
@@ -13,7 +13,10 @@
 //  limitations under the License.
 
 mod cluster;
+mod masking_policy;
 mod match_seq;
+mod network_policy;
+mod row_access_policy;
 mod user_defined_function;
 mod user_grant;
 mod user_info;
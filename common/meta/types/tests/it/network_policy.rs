@@ -0,0 +1,87 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use common_exception::exception::Result;
+use common_meta_types::NetworkPolicy;
+
+fn ip(s: &str) -> IpAddr {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_network_policy() -> Result<()> {
+    let policy = NetworkPolicy::new(
+        "np",
+        vec!["10.0.0.0/8".to_string(), "192.168.1.1".to_string()],
+        vec!["10.1.0.0/16".to_string()],
+        "this is a comment",
+    );
+    let ser = serde_json::to_string(&policy)?;
+
+    let de = NetworkPolicy::try_from(ser.into_bytes())?;
+    assert_eq!(policy, de);
+
+    Ok(())
+}
+
+#[test]
+fn test_network_policy_is_allowed() -> Result<()> {
+    let policy = NetworkPolicy::new(
+        "np",
+        vec![
+            "10.0.0.0/8".to_string(),
+            "192.168.1.1".to_string(),
+            "fd00::/8".to_string(),
+        ],
+        vec!["10.1.0.0/16".to_string()],
+        "",
+    );
+    policy.validate()?;
+
+    assert!(policy.is_allowed(&ip("10.2.3.4"))?);
+    assert!(policy.is_allowed(&ip("192.168.1.1"))?);
+    assert!(policy.is_allowed(&ip("::ffff:10.2.3.4"))?);
+    assert!(policy.is_allowed(&ip("fd12::1"))?);
+    assert!(!policy.is_allowed(&ip("10.1.2.3"))?);
+    assert!(!policy.is_allowed(&ip("192.168.1.2"))?);
+    assert!(!policy.is_allowed(&ip("127.0.0.1"))?);
+
+    let allow_all = NetworkPolicy::new("all", vec!["0.0.0.0/0".to_string()], vec![], "");
+    assert!(allow_all.is_allowed(&ip("1.2.3.4"))?);
+
+    Ok(())
+}
+
+#[test]
+fn test_network_policy_validate() -> Result<()> {
+    for (allowed, blocked) in [
+        (vec![], vec![]),
+        (vec!["10.0.0.0/33"], vec![]),
+        (vec!["10.0.0"], vec![]),
+        (vec!["10.0.0.0/8"], vec!["localhost"]),
+    ] {
+        let policy = NetworkPolicy::new(
+            "np",
+            allowed.into_iter().map(|s| s.to_string()).collect(),
+            blocked.into_iter().map(|s| s.to_string()).collect(),
+            "",
+        );
+        let err = policy.validate().unwrap_err();
+        assert_eq!(err.code(), 2617);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::exception::Result;
+use common_meta_types::RowAccessPolicy;
+
+#[test]
+fn test_row_access_policy() -> Result<()> {
+    let policy = RowAccessPolicy::new(
+        "customer_policy",
+        vec!["customer_id".to_string()],
+        vec!["String".to_string()],
+        "customer_id = 'c1'",
+        "this is a description",
+    );
+    let ser = serde_json::to_string(&policy)?;
+
+    let de = RowAccessPolicy::try_from(ser.into_bytes())?;
+    assert_eq!(policy, de);
+
+    Ok(())
+}
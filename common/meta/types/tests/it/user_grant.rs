@@ -77,6 +77,29 @@ fn test_grant_object_contains() -> Result<()> {
             rhs: GrantObject::Database("default".into(), "db1".into()),
             expect: false,
         },
+        Test {
+            lhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), vec!["a".into()]),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), vec![
+                "a".into(),
+                "b".into(),
+            ]),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), vec!["b".into()]),
+            expect: true,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), vec!["a".into()]),
+            rhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), vec!["b".into()]),
+            expect: false,
+        },
+        Test {
+            lhs: GrantObject::Column("default".into(), "db1".into(), "c".into(), vec!["a".into()]),
+            rhs: GrantObject::Table("default".into(), "db1".into(), "c".into()),
+            expect: false,
+        },
     ];
     for t in tests {
         assert_eq!(
@@ -200,3 +223,52 @@ fn test_user_grant_set() -> Result<()> {
     ));
     Ok(())
 }
+
+#[test]
+fn test_user_grant_set_with_columns() -> Result<()> {
+    let mut grants = UserGrantSet::empty();
+    let table = GrantObject::Table("default".into(), "db1".into(), "table1".into());
+    let columns = |columns: &[&str]| {
+        GrantObject::Column(
+            "default".into(),
+            "db1".into(),
+            "table1".into(),
+            columns.iter().map(|c| c.to_string()).collect(),
+        )
+    };
+
+    grants.grant_privileges(
+        &columns(&["a", "b"]),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    grants.grant_privileges(
+        &columns(&["b", "c"]),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(1, grants.entries().len());
+    assert_eq!(
+        "GRANT SELECT(a, b, c) ON 'default'.'db1'.'table1'",
+        grants.entries()[0].to_string()
+    );
+    assert!(grants.verify_privilege(&columns(&["a", "c"]), UserPrivilegeType::Select));
+    assert!(!grants.verify_privilege(&columns(&["d"]), UserPrivilegeType::Select));
+    assert!(!grants.verify_privilege(&table, UserPrivilegeType::Select));
+    assert_eq!(
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        grants.granted_columns(&table, UserPrivilegeType::Select)
+    );
+
+    grants.revoke_privileges(
+        &columns(&["a"]),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert!(!grants.verify_privilege(&columns(&["a"]), UserPrivilegeType::Select));
+    assert!(grants.verify_privilege(&columns(&["b", "c"]), UserPrivilegeType::Select));
+
+    grants.revoke_privileges(
+        &columns(&["b", "c"]),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    assert_eq!(0, grants.entries().len());
+    Ok(())
+}
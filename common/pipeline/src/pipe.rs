@@ -17,6 +17,8 @@ use std::sync::Arc;
 use crate::processors::port::InputPort;
 use crate::processors::port::OutputPort;
 use crate::processors::processor::ProcessorPtr;
+use crate::processors::profile::ProcessorProfile;
+use crate::processors::profile::ProcessorProfileRef;
 
 #[derive(Clone)]
 pub enum Pipe {
@@ -60,6 +62,49 @@ impl Pipe {
             Pipe::ResizePipe { processor, .. } => processor.clone(),
         }
     }
+
+    /// Attaches a profile to each processor of the pipe and to the ports of it, the
+    /// profiles are filled while the pipe is being executed.
+    pub fn attach_profiles(&self) -> Vec<ProcessorProfileRef> {
+        unsafe {
+            match self {
+                Pipe::SimplePipe {
+                    processors,
+                    inputs_port,
+                    outputs_port,
+                } => processors
+                    .iter()
+                    .enumerate()
+                    .map(|(index, processor)| {
+                        let profile = ProcessorProfile::create();
+                        processor.set_profile(profile.clone());
+                        if let Some(input_port) = inputs_port.get(index) {
+                            input_port.set_profile(profile.clone());
+                        }
+                        if let Some(output_port) = outputs_port.get(index) {
+                            output_port.set_profile(profile.clone());
+                        }
+                        profile
+                    })
+                    .collect(),
+                Pipe::ResizePipe {
+                    processor,
+                    inputs_port,
+                    outputs_port,
+                } => {
+                    let profile = ProcessorProfile::create();
+                    processor.set_profile(profile.clone());
+                    for input_port in inputs_port {
+                        input_port.set_profile(profile.clone());
+                    }
+                    for output_port in outputs_port {
+                        output_port.set_profile(profile.clone());
+                    }
+                    vec![profile]
+                }
+            }
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -14,6 +14,7 @@
 
 pub mod port;
 pub mod processor;
+pub mod profile;
 
 mod port_trigger;
 mod resize_processor;
@@ -24,4 +25,7 @@ pub use port_trigger::UpdateList;
 pub use port_trigger::UpdateTrigger;
 pub use processor::Processor;
 pub use processor::Processors;
+pub use profile::ProcessorProfile;
+pub use profile::ProcessorProfileRef;
+pub use profile::ProcessorProfileValues;
 pub use resize_processor::ResizeProcessor;
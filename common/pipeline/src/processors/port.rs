@@ -20,6 +20,7 @@ use common_datablocks::DataBlock;
 use common_exception::Result;
 use common_io::prelude::FileSplit;
 
+use crate::processors::profile::ProcessorProfileRef;
 use crate::processors::UpdateTrigger;
 use crate::unsafe_cell_wrap::UnSafeCellWrap;
 
@@ -115,6 +116,7 @@ impl SharedStatus {
 pub struct InputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    profile: UnSafeCellWrap<Option<ProcessorProfileRef>>,
 }
 
 impl InputPort {
@@ -122,6 +124,7 @@ impl InputPort {
         Arc::new(InputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            profile: UnSafeCellWrap::create(None),
         })
     }
 
@@ -174,6 +177,9 @@ impl InputPort {
                 address if address.is_null() => None,
                 address => {
                     if let SharedData::Data(block) = *Box::from_raw(address) {
+                        if let (Some(profile), Ok(block)) = (self.profile.as_ref(), &block) {
+                            profile.add_input(block);
+                        }
                         Some(block)
                     } else {
                         unreachable!()
@@ -214,11 +220,19 @@ impl InputPort {
     pub unsafe fn set_trigger(&self, update_trigger: *mut UpdateTrigger) {
         self.update_trigger.set_value(update_trigger)
     }
+
+    /// # Safety
+    ///
+    /// Method is thread unsafe and require thread safe call
+    pub unsafe fn set_profile(&self, profile: ProcessorProfileRef) {
+        self.profile.set_value(Some(profile))
+    }
 }
 
 pub struct OutputPort {
     shared: UnSafeCellWrap<Arc<SharedStatus>>,
     update_trigger: UnSafeCellWrap<*mut UpdateTrigger>,
+    profile: UnSafeCellWrap<Option<ProcessorProfileRef>>,
 }
 
 impl OutputPort {
@@ -226,6 +240,7 @@ impl OutputPort {
         Arc::new(OutputPort {
             shared: UnSafeCellWrap::create(SharedStatus::create()),
             update_trigger: UnSafeCellWrap::create(std::ptr::null_mut()),
+            profile: UnSafeCellWrap::create(None),
         })
     }
 
@@ -234,6 +249,9 @@ impl OutputPort {
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
+            if let (Some(profile), Ok(block)) = (self.profile.as_ref(), &data) {
+                profile.add_output(block);
+            }
             let data = Box::into_raw(Box::new(SharedData::Data(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
         }
@@ -284,6 +302,13 @@ impl OutputPort {
     pub unsafe fn set_trigger(&self, update_trigger: *mut UpdateTrigger) {
         self.update_trigger.set_value(update_trigger)
     }
+
+    /// # Safety
+    ///
+    /// Method is thread unsafe and require thread safe call
+    pub unsafe fn set_profile(&self, profile: ProcessorProfileRef) {
+        self.profile.set_value(Some(profile))
+    }
 }
 
 /// Connect input and output ports.
@@ -23,6 +23,8 @@ use futures::FutureExt;
 use petgraph::graph::node_index;
 use petgraph::prelude::NodeIndex;
 
+use crate::processors::profile::ProcessorProfileRef;
+
 pub enum Event {
     NeedData,
     NeedConsume,
@@ -55,6 +57,7 @@ pub trait Processor: Send {
 #[derive(Clone)]
 pub struct ProcessorPtr {
     id: Arc<UnsafeCell<NodeIndex>>,
+    profile: Arc<UnsafeCell<Option<ProcessorProfileRef>>>,
     inner: Arc<UnsafeCell<Box<dyn Processor>>>,
 }
 
@@ -66,6 +69,7 @@ impl ProcessorPtr {
     pub fn create(inner: Box<dyn Processor>) -> ProcessorPtr {
         ProcessorPtr {
             id: Arc::new(UnsafeCell::new(node_index(0))),
+            profile: Arc::new(UnsafeCell::new(None)),
             inner: Arc::new(UnsafeCell::new(inner)),
         }
     }
@@ -85,6 +89,16 @@ impl ProcessorPtr {
         *self.id.get() = id;
     }
 
+    /// # Safety
+    pub unsafe fn profile(&self) -> Option<&ProcessorProfileRef> {
+        (*self.profile.get()).as_ref()
+    }
+
+    /// # Safety
+    pub unsafe fn set_profile(&self, profile: ProcessorProfileRef) {
+        *self.profile.get() = Some(profile);
+    }
+
     /// # Safety
     pub unsafe fn name(&self) -> &'static str {
         (*self.inner.get()).name()
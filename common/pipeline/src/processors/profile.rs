@@ -0,0 +1,106 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_datablocks::DataBlock;
+
+pub type ProcessorProfileRef = Arc<ProcessorProfile>;
+
+/// Runtime statistics of a processor.
+///
+/// The rows and bytes are recorded by the ports of the processor, the time is recorded
+/// by the pipeline executor. Nothing is recorded unless a profile is attached to the
+/// processor, e.g. by `EXPLAIN ANALYZE`.
+#[derive(Debug, Default)]
+pub struct ProcessorProfile {
+    rows_in: AtomicUsize,
+    bytes_in: AtomicUsize,
+    rows_out: AtomicUsize,
+    bytes_out: AtomicUsize,
+    wall_time_ns: AtomicU64,
+    cpu_time_ns: AtomicU64,
+}
+
+impl ProcessorProfile {
+    pub fn create() -> ProcessorProfileRef {
+        Arc::new(ProcessorProfile::default())
+    }
+
+    pub fn add_input(&self, block: &DataBlock) {
+        self.rows_in.fetch_add(block.num_rows(), Ordering::Relaxed);
+        self.bytes_in
+            .fetch_add(block.memory_size(), Ordering::Relaxed);
+    }
+
+    pub fn add_output(&self, block: &DataBlock) {
+        self.rows_out.fetch_add(block.num_rows(), Ordering::Relaxed);
+        self.bytes_out
+            .fetch_add(block.memory_size(), Ordering::Relaxed);
+    }
+
+    pub fn add_wall_time(&self, wall_time: Duration) {
+        self.wall_time_ns
+            .fetch_add(wall_time.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_cpu_time(&self, cpu_time: Duration) {
+        self.cpu_time_ns
+            .fetch_add(cpu_time.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn get_values(&self) -> ProcessorProfileValues {
+        ProcessorProfileValues {
+            rows_in: self.rows_in.load(Ordering::Relaxed),
+            bytes_in: self.bytes_in.load(Ordering::Relaxed),
+            rows_out: self.rows_out.load(Ordering::Relaxed),
+            bytes_out: self.bytes_out.load(Ordering::Relaxed),
+            wall_time: Duration::from_nanos(self.wall_time_ns.load(Ordering::Relaxed)),
+            cpu_time: Duration::from_nanos(self.cpu_time_ns.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProcessorProfileValues {
+    pub rows_in: usize,
+    pub bytes_in: usize,
+    pub rows_out: usize,
+    pub bytes_out: usize,
+    pub wall_time: Duration,
+    pub cpu_time: Duration,
+}
+
+/// Returns the CPU time consumed by the current thread so far.
+#[cfg(unix)]
+pub fn thread_cpu_time() -> Duration {
+    let mut ts = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    match unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut ts) } {
+        0 => Duration::new(ts.tv_sec as u64, ts.tv_nsec as u32),
+        _ => Duration::ZERO,
+    }
+}
+
+/// Returns the CPU time consumed by the current thread so far.
+#[cfg(not(unix))]
+pub fn thread_cpu_time() -> Duration {
+    Duration::ZERO
+}
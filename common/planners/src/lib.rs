@@ -44,6 +44,11 @@ mod plan_kill;
 mod plan_limit;
 mod plan_limit_by;
 mod plan_list;
+mod plan_masking_policy_create;
+mod plan_masking_policy_drop;
+mod plan_merge;
+mod plan_network_policy_create;
+mod plan_network_policy_drop;
 mod plan_node;
 mod plan_node_builder;
 mod plan_node_display;
@@ -64,6 +69,8 @@ mod plan_role_create;
 mod plan_role_drop;
 mod plan_role_grant;
 mod plan_role_revoke;
+mod plan_row_access_policy_create;
+mod plan_row_access_policy_drop;
 mod plan_select;
 mod plan_setting;
 mod plan_show;
@@ -81,17 +88,26 @@ mod plan_show_users;
 mod plan_sink;
 mod plan_sort;
 mod plan_subqueries_set;
+mod plan_table_add_column;
+mod plan_table_add_row_access_policy;
 mod plan_table_alter_cluster_key;
+mod plan_table_analyze;
 mod plan_table_create;
 mod plan_table_describe;
 mod plan_table_drop;
 mod plan_table_drop_cluster_key;
+mod plan_table_drop_column;
+mod plan_table_drop_row_access_policy;
 mod plan_table_exists;
 mod plan_table_optimize;
 mod plan_table_rename;
+mod plan_table_rename_column;
+mod plan_table_set_column_masking_policy;
 mod plan_table_show_create;
 mod plan_table_truncate;
 mod plan_table_undrop;
+mod plan_table_unset_column_masking_policy;
+mod plan_update;
 mod plan_use_database;
 mod plan_user_alter;
 mod plan_user_create;
@@ -173,6 +189,14 @@ pub use plan_kill::KillPlan;
 pub use plan_limit::LimitPlan;
 pub use plan_limit_by::LimitByPlan;
 pub use plan_list::ListPlan;
+pub use plan_masking_policy_create::CreateMaskingPolicyPlan;
+pub use plan_masking_policy_drop::DropMaskingPolicyPlan;
+pub use plan_merge::MergeMatchedClause;
+pub use plan_merge::MergeOperation;
+pub use plan_merge::MergePlan;
+pub use plan_merge::MergeUnmatchedClause;
+pub use plan_network_policy_create::CreateNetworkPolicyPlan;
+pub use plan_network_policy_drop::DropNetworkPolicyPlan;
 pub use plan_node::PlanNode;
 pub use plan_node_builder::PlanBuilder;
 pub use plan_node_extras::Extras;
@@ -181,6 +205,7 @@ pub use plan_node_rewriter::RewriteHelper;
 pub use plan_node_stage::StageKind;
 pub use plan_node_stage::StagePlan;
 pub use plan_node_stage_table::StageTableInfo;
+pub use plan_node_statistics::PruningStatistics;
 pub use plan_node_statistics::Statistics;
 pub use plan_node_visitor::PlanVisitor;
 pub use plan_partition::PartInfo;
@@ -198,6 +223,8 @@ pub use plan_role_create::CreateRolePlan;
 pub use plan_role_drop::DropRolePlan;
 pub use plan_role_grant::GrantRolePlan;
 pub use plan_role_revoke::RevokeRolePlan;
+pub use plan_row_access_policy_create::CreateRowAccessPolicyPlan;
+pub use plan_row_access_policy_drop::DropRowAccessPolicyPlan;
 pub use plan_select::SelectPlan;
 pub use plan_setting::SettingPlan;
 pub use plan_setting::VarValue;
@@ -218,20 +245,29 @@ pub use plan_sink::SinkPlan;
 pub use plan_sink::SINK_SCHEMA;
 pub use plan_sort::SortPlan;
 pub use plan_subqueries_set::SubQueriesSetPlan;
+pub use plan_table_add_column::AddTableColumnPlan;
+pub use plan_table_add_row_access_policy::AddTableRowAccessPolicyPlan;
 pub use plan_table_alter_cluster_key::AlterTableClusterKeyPlan;
+pub use plan_table_analyze::AnalyzeTablePlan;
 pub use plan_table_create::CreateTablePlan;
 pub use plan_table_create::TableOptions;
 pub use plan_table_describe::DescribeTablePlan;
 pub use plan_table_drop::DropTablePlan;
 pub use plan_table_drop_cluster_key::DropTableClusterKeyPlan;
+pub use plan_table_drop_column::DropTableColumnPlan;
+pub use plan_table_drop_row_access_policy::DropTableRowAccessPolicyPlan;
 pub use plan_table_exists::ExistsTablePlan;
 pub use plan_table_optimize::OptimizeTableAction;
 pub use plan_table_optimize::OptimizeTablePlan;
 pub use plan_table_rename::RenameTableEntity;
 pub use plan_table_rename::RenameTablePlan;
+pub use plan_table_rename_column::RenameTableColumnPlan;
+pub use plan_table_set_column_masking_policy::SetTableColumnMaskingPolicyPlan;
 pub use plan_table_show_create::ShowCreateTablePlan;
 pub use plan_table_truncate::TruncateTablePlan;
 pub use plan_table_undrop::UndropTablePlan;
+pub use plan_table_unset_column_masking_policy::UnsetTableColumnMaskingPolicyPlan;
+pub use plan_update::UpdatePlan;
 pub use plan_use_database::UseDatabasePlan;
 pub use plan_user_alter::AlterUserPlan;
 pub use plan_user_create::CreateUserPlan;
@@ -29,10 +29,16 @@ use crate::ExpressionVisitor;
 use crate::PlanNode;
 
 static OP_SET: Lazy<HashSet<&'static str>> = Lazy::new(|| {
-    ["database", "version", "current_user", "user"]
-        .iter()
-        .copied()
-        .collect()
+    [
+        "database",
+        "version",
+        "current_user",
+        "user",
+        "current_role",
+    ]
+    .iter()
+    .copied()
+    .collect()
 });
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq)]
@@ -0,0 +1,73 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_datavalues::DataSchemaRef;
+use common_meta_app::schema::TableIdent;
+
+use crate::Expression;
+
+/// Merges the rows of the source into the target table.
+///
+/// The target table is joined with the source by a query, `target RIGHT OUTER JOIN source`,
+/// whose rows are the target columns, a boolean column which is true if the source row
+/// matches a target row and NULL otherwise, and the source columns. The expressions of
+/// the plan are evaluated over the joined rows without the boolean column, whose schema
+/// is the `target_columns` followed by the fields of `source_schema`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct MergePlan {
+    pub catalog_name: String,
+    pub database_name: String,
+    pub table_name: String,
+    pub table_id: TableIdent,
+    /// The names of the target columns in the joined rows, in the order of the table schema.
+    pub target_columns: Vec<String>,
+    pub source_schema: DataSchemaRef,
+    /// The target sides of the equi conditions of the join, the blocks of the table
+    /// are pruned by the values of them in the matched rows.
+    pub target_keys: Vec<Expression>,
+    pub matched_clauses: Vec<MergeMatchedClause>,
+    pub unmatched_clauses: Vec<MergeUnmatchedClause>,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct MergeMatchedClause {
+    pub selection: Option<Expression>,
+    pub operation: MergeOperation,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub enum MergeOperation {
+    /// The indexes of the columns being updated, and the new values of them, which have
+    /// been cast to the types of the columns.
+    Update(Vec<(usize, Expression)>),
+    Delete,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct MergeUnmatchedClause {
+    /// Evaluated over the source rows.
+    pub selection: Option<Expression>,
+    /// The values of all the columns of the target table, which have been cast to
+    /// the types of the columns.
+    pub values: Vec<Expression>,
+}
+
+impl MergePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
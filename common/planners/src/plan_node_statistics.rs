@@ -24,6 +24,8 @@ pub struct Statistics {
     pub partitions_total: usize,
     /// Is the statistics exact.
     pub is_exact: bool,
+    /// Statistics of the partition pruning, None if the partitions are not pruned.
+    pub pruning: Option<PruningStatistics>,
 }
 
 impl Statistics {
@@ -39,6 +41,7 @@ impl Statistics {
             partitions_scanned,
             partitions_total,
             is_exact: false,
+            pruning: None,
         }
     }
 
@@ -54,6 +57,7 @@ impl Statistics {
             partitions_scanned,
             partitions_total,
             is_exact: true,
+            pruning: None,
         }
     }
 
@@ -68,3 +72,25 @@ impl Statistics {
         *self = Self::default();
     }
 }
+
+/// The number of the segments and blocks before and after each step of the pruning.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug, Default)]
+pub struct PruningStatistics {
+    pub segments_range_pruning_before: usize,
+    pub segments_range_pruning_after: usize,
+    pub blocks_range_pruning_before: usize,
+    pub blocks_range_pruning_after: usize,
+    pub blocks_bloom_pruning_before: usize,
+    pub blocks_bloom_pruning_after: usize,
+}
+
+impl PruningStatistics {
+    pub fn merge(&mut self, other: &PruningStatistics) {
+        self.segments_range_pruning_before += other.segments_range_pruning_before;
+        self.segments_range_pruning_after += other.segments_range_pruning_after;
+        self.blocks_range_pruning_before += other.blocks_range_pruning_before;
+        self.blocks_range_pruning_after += other.blocks_range_pruning_after;
+        self.blocks_bloom_pruning_before += other.blocks_bloom_pruning_before;
+        self.blocks_bloom_pruning_after += other.blocks_bloom_pruning_after;
+    }
+}
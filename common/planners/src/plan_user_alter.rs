@@ -19,6 +19,7 @@ use common_datavalues::DataSchemaRef;
 use common_meta_types::AuthInfo;
 use common_meta_types::UserIdentity;
 use common_meta_types::UserOption;
+use common_meta_types::UserQuota;
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct AlterUserPlan {
@@ -26,6 +27,7 @@ pub struct AlterUserPlan {
     // None means no change to make
     pub auth_info: Option<AuthInfo>,
     pub user_option: Option<UserOption>,
+    pub user_quota: Option<UserQuota>,
 }
 
 impl AlterUserPlan {
@@ -64,6 +64,7 @@ impl Test {
             partitions_scanned: 8,
             partitions_total: 8,
             is_exact: true,
+            pruning: None,
         };
 
         Ok(PlanNode::ReadSource(ReadDataSourcePlan {
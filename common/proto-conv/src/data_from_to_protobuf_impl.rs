@@ -113,6 +113,7 @@ impl FromToProto<pb::DataType> for dv::DataTypeImpl {
             Dt::Uint64Type(_) => Ok(dv::DataTypeImpl::UInt64(dv::UInt64Type::default())),
             Dt::Float32Type(_) => Ok(dv::DataTypeImpl::Float32(dv::Float32Type::default())),
             Dt::Float64Type(_) => Ok(dv::DataTypeImpl::Float64(dv::Float64Type::default())),
+            Dt::DecimalType(x) => Ok(dv::DataTypeImpl::Decimal(dv::DecimalType::from_pb(x)?)),
             Dt::DateType(_) => Ok(dv::DataTypeImpl::Date(dv::DateType {})),
             Dt::TimestampType(x) => Ok(dv::DataTypeImpl::Timestamp(dv::TimestampType::from_pb(x)?)),
             Dt::StringType(_) => Ok(dv::DataTypeImpl::String(dv::StringType {})),
@@ -120,6 +121,9 @@ impl FromToProto<pb::DataType> for dv::DataTypeImpl {
             Dt::ArrayType(x) => Ok(dv::DataTypeImpl::Array(dv::ArrayType::from_pb(
                 x.as_ref().clone(),
             )?)),
+            Dt::MapType(x) => Ok(dv::DataTypeImpl::Map(dv::MapType::from_pb(
+                x.as_ref().clone(),
+            )?)),
             Dt::VariantType(_) => Ok(dv::DataTypeImpl::Variant(dv::VariantType {})),
             Dt::VariantArrayType(_) => Ok(dv::DataTypeImpl::VariantArray(dv::VariantArrayType {})),
             Dt::VariantObjectType(_) => {
@@ -232,6 +236,16 @@ impl FromToProto<pb::DataType> for dv::DataTypeImpl {
                 };
                 Ok(v)
             }
+            dv::DataTypeImpl::Decimal(x) => {
+                let inn = x.to_pb()?;
+
+                let v = pb::DataType {
+                    ver: VER,
+                    min_compatible: MIN_COMPATIBLE_VER,
+                    dt: Some(Dt::DecimalType(inn)),
+                };
+                Ok(v)
+            }
             dv::DataTypeImpl::Date(_x) => {
                 let v = pb::DataType {
                     ver: VER,
@@ -278,6 +292,16 @@ impl FromToProto<pb::DataType> for dv::DataTypeImpl {
                 };
                 Ok(v)
             }
+            dv::DataTypeImpl::Map(x) => {
+                let inn = x.to_pb()?;
+
+                let v = pb::DataType {
+                    ver: VER,
+                    min_compatible: MIN_COMPATIBLE_VER,
+                    dt: Some(Dt::MapType(Box::new(inn))),
+                };
+                Ok(v)
+            }
             dv::DataTypeImpl::Variant(x) => {
                 let inn = x.to_pb()?;
 
@@ -350,6 +374,29 @@ impl FromToProto<pb::NullableType> for dv::NullableType {
     }
 }
 
+impl FromToProto<pb::Decimal> for dv::DecimalType {
+    fn from_pb(p: pb::Decimal) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.ver, p.min_compatible)?;
+        dv::DecimalType::try_create(p.precision as usize, p.scale as usize).map_err(|e| {
+            Incompatible {
+                reason: format!("invalid Decimal: {}", e.message()),
+            }
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Decimal, Incompatible> {
+        let p = pb::Decimal {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            precision: self.precision() as u64,
+            scale: self.scale() as u64,
+        };
+
+        Ok(p)
+    }
+}
+
 impl FromToProto<pb::Timestamp> for dv::TimestampType {
     fn from_pb(p: pb::Timestamp) -> Result<Self, Incompatible>
     where Self: Sized {
@@ -442,6 +489,37 @@ impl FromToProto<pb::Array> for dv::ArrayType {
     }
 }
 
+impl FromToProto<pb::Map> for dv::MapType {
+    fn from_pb(p: pb::Map) -> Result<Self, Incompatible>
+    where Self: Sized {
+        check_ver(p.ver, p.min_compatible)?;
+
+        let key = p.key.ok_or_else(|| Incompatible {
+            reason: "Map.key can not be None".to_string(),
+        })?;
+        let value = p.value.ok_or_else(|| Incompatible {
+            reason: "Map.value can not be None".to_string(),
+        })?;
+
+        let key_dt = dv::DataTypeImpl::from_pb(key.as_ref().clone())?;
+        let value_dt = dv::DataTypeImpl::from_pb(value.as_ref().clone())?;
+        Ok(dv::MapType::create(key_dt, value_dt))
+    }
+
+    fn to_pb(&self) -> Result<pb::Map, Incompatible> {
+        let key = self.key_type().to_pb()?;
+        let value = self.value_type().to_pb()?;
+
+        let p = pb::Map {
+            ver: VER,
+            min_compatible: MIN_COMPATIBLE_VER,
+            key: Some(Box::new(key)),
+            value: Some(Box::new(value)),
+        };
+        Ok(p)
+    }
+}
+
 impl FromToProto<pb::VariantArray> for dv::VariantArrayType {
     fn from_pb(p: pb::VariantArray) -> Result<Self, Incompatible>
     where Self: Sized {
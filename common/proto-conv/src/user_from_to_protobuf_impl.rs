@@ -83,7 +83,7 @@ impl FromToProto<pb::UserOption> for mt::UserOption {
 
         let flags = BitFlags::<mt::UserOptionFlag, u64>::from_bits(p.flags);
         match flags {
-            Ok(flags) => Ok(mt::UserOption::new(flags)),
+            Ok(flags) => Ok(mt::UserOption::new(flags).with_network_policy(p.network_policy)),
             Err(e) => Err(Incompatible {
                 reason: format!("UserOptionFlag error: {}", e),
             }),
@@ -95,6 +95,7 @@ impl FromToProto<pb::UserOption> for mt::UserOption {
             ver: VER,
             min_compatible: MIN_COMPATIBLE_VER,
             flags: self.flags().bits(),
+            network_policy: self.network_policy().cloned(),
         })
     }
 }
@@ -140,6 +141,12 @@ impl FromToProto<pb::GrantObject> for mt::GrantObject {
                 db,
                 table,
             })) => Ok(mt::GrantObject::Table(catalog, db, table)),
+            Some(pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                catalog,
+                db,
+                table,
+                columns,
+            })) => Ok(mt::GrantObject::Column(catalog, db, table, columns)),
             _ => Err(Incompatible {
                 reason: "GrantObject cannot be None".to_string(),
             }),
@@ -164,6 +171,14 @@ impl FromToProto<pb::GrantObject> for mt::GrantObject {
                     table: table.clone(),
                 },
             )),
+            mt::GrantObject::Column(catalog, db, table, columns) => Some(
+                pb::grant_object::Object::Column(pb::grant_object::GrantColumnObject {
+                    catalog: catalog.clone(),
+                    db: db.clone(),
+                    table: table.clone(),
+                    columns: columns.clone(),
+                }),
+            ),
         };
         Ok(pb::GrantObject {
             ver: VER,
@@ -181,6 +181,25 @@ fn test_pb_from_to() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_data_type_pb_from_to() -> anyhow::Result<()> {
+    let decimal: dv::DataTypeImpl = dv::DecimalType::create(10, 2).into();
+    let p = decimal.to_pb()?;
+    let got = dv::DataTypeImpl::from_pb(p)?;
+    assert_eq!(decimal, got);
+
+    let map: dv::DataTypeImpl = dv::MapType::create(
+        dv::StringType::default().into(),
+        dv::NullableType::create(dv::Int64Type::default().into()).into(),
+    )
+    .into();
+    let p = map.to_pb()?;
+    let got = dv::DataTypeImpl::from_pb(p)?;
+    assert_eq!(map, got);
+
+    Ok(())
+}
+
 #[test]
 fn test_incompatible() -> anyhow::Result<()> {
     let db_meta = new_db_meta();
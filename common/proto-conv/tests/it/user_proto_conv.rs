@@ -99,6 +99,34 @@ fn test_user_pb_from_to() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_user_network_policy_pb_from_to() -> anyhow::Result<()> {
+    let mut test_user_info = test_user_info();
+    test_user_info
+        .option
+        .set_network_policy(Some(s("office_network")));
+    let test_user_info_pb = test_user_info.to_pb()?;
+    let got = mt::UserInfo::from_pb(test_user_info_pb)?;
+    assert_eq!(got, test_user_info);
+    assert_eq!(got.option.network_policy(), Some(&s("office_network")));
+
+    Ok(())
+}
+
+#[test]
+fn test_user_grant_column_pb_from_to() -> anyhow::Result<()> {
+    let mut test_user_info = test_user_info();
+    test_user_info.grants.grant_privileges(
+        &mt::GrantObject::Column(s("default"), s("db1"), s("t1"), vec![s("a"), s("b")]),
+        make_bitflags!(UserPrivilegeType::{Select}).into(),
+    );
+    let test_user_info_pb = test_user_info.to_pb()?;
+    let got = mt::UserInfo::from_pb(test_user_info_pb)?;
+    assert_eq!(got, test_user_info);
+
+    Ok(())
+}
+
 #[test]
 fn test_user_stage_pb_from_to() -> anyhow::Result<()> {
     let test_user_stage_info = test_user_stage_info();
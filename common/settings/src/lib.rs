@@ -66,6 +66,9 @@ pub struct Settings {
     //#[allow(dead_code)]
     // session_ctx: Arc<T>,
     tenant: String,
+    // Upper bound of max_threads for the holder of this handle only, the
+    // values in `settings` are shared with the session and are not changed.
+    max_threads_limit: Option<u64>,
 }
 
 impl Settings {
@@ -180,6 +183,33 @@ impl Settings {
                 level: ScopeLevel::Session,
                 desc: "The threshold of keys to open two-level aggregation, default value: 10000",
             },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "group_by_spill_threshold_bytes",
+                    DataValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The memory threshold in bytes to spill group by states to disk, default value: 0 (disabled)",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(0),
+                user_setting: UserSetting::create(
+                    "sort_spill_threshold_bytes",
+                    DataValue::UInt64(0),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The memory threshold in bytes to spill sorted runs to disk, default value: 0 (disabled)",
+            },
+            SettingValue {
+                default_value: DataValue::UInt64(1073741824),
+                user_setting: UserSetting::create(
+                    "max_merge_source_bytes",
+                    DataValue::UInt64(1073741824),
+                ),
+                level: ScopeLevel::Session,
+                desc: "The max bytes of the rows being merged by MERGE INTO kept in memory, default value: 1GB",
+            },
             SettingValue {
                 default_value: DataValue::UInt64(0),
                 user_setting: UserSetting::create("enable_async_insert", DataValue::UInt64(0)),
@@ -225,6 +255,7 @@ impl Settings {
                 settings,
                 user_api,
                 tenant,
+                max_threads_limit: None,
             };
             for global_setting in global_settings {
                 let name = global_setting.name;
@@ -257,7 +288,18 @@ impl Settings {
     // Get max_threads.
     pub fn get_max_threads(&self) -> Result<u64> {
         let key = "max_threads";
-        self.try_get_u64(key)
+        let max_threads = self.try_get_u64(key)?;
+        match self.max_threads_limit {
+            Some(limit) => Ok(std::cmp::min(max_threads, limit)),
+            None => Ok(max_threads),
+        }
+    }
+
+    // Cap max_threads read through this handle, e.g. by the cpu quota of the user.
+    pub fn with_max_threads_limit(&self, limit: u64) -> Settings {
+        let mut settings = self.clone();
+        settings.max_threads_limit = Some(limit);
+        settings
     }
 
     // Set max_threads.
@@ -334,6 +376,36 @@ impl Settings {
         self.try_set_u64(key, val, false)
     }
 
+    // Get group by spill threshold in bytes
+    pub fn get_group_by_spill_threshold_bytes(&self) -> Result<u64> {
+        let key = "group_by_spill_threshold_bytes";
+        self.try_get_u64(key)
+    }
+
+    // Set group by spill threshold in bytes
+    pub fn set_group_by_spill_threshold_bytes(&self, val: u64) -> Result<()> {
+        let key = "group_by_spill_threshold_bytes";
+        self.try_set_u64(key, val, false)
+    }
+
+    // Get sort spill threshold in bytes
+    pub fn get_sort_spill_threshold_bytes(&self) -> Result<u64> {
+        let key = "sort_spill_threshold_bytes";
+        self.try_get_u64(key)
+    }
+
+    // Set sort spill threshold in bytes
+    pub fn set_sort_spill_threshold_bytes(&self, val: u64) -> Result<()> {
+        let key = "sort_spill_threshold_bytes";
+        self.try_set_u64(key, val, false)
+    }
+
+    // Get the max bytes of the rows being merged by MERGE INTO
+    pub fn get_max_merge_source_bytes(&self) -> Result<u64> {
+        let key = "max_merge_source_bytes";
+        self.try_get_u64(key)
+    }
+
     pub fn get_enable_async_insert(&self) -> Result<u64> {
         let key = "enable_async_insert";
         self.try_get_u64(key)
@@ -465,6 +537,7 @@ impl Settings {
             settings: new_settings,
             user_api: self.user_api.clone(),
             tenant: self.tenant.clone(),
+            max_threads_limit: None,
         }
     }
 
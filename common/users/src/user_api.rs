@@ -16,6 +16,11 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_grpc::RpcClientConf;
+use common_management::NetworkPolicyApi;
+use common_management::NetworkPolicyMgr;
+use common_management::Policy;
+use common_management::PolicyApi;
+use common_management::PolicyMgr;
 use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
@@ -59,6 +64,20 @@ impl UserApiProvider {
         Ok(Arc::new(UdfMgr::create(self.client.clone(), tenant)?))
     }
 
+    pub fn get_policy_api_client<P: Policy>(&self, tenant: &str) -> Result<Arc<dyn PolicyApi<P>>> {
+        Ok(Arc::new(PolicyMgr::<P>::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_network_policy_api_client(&self, tenant: &str) -> Result<Arc<dyn NetworkPolicyApi>> {
+        Ok(Arc::new(NetworkPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_tenant_quota_api_client(&self, tenant: &str) -> Result<Arc<dyn QuotaApi>> {
         Ok(Arc::new(QuotaMgr::create(self.client.clone(), tenant)?))
     }
@@ -20,6 +20,7 @@ use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 use common_meta_types::UserOption;
 use common_meta_types::UserPrivilegeSet;
+use common_meta_types::UserQuota;
 
 use crate::UserApiProvider;
 
@@ -200,4 +201,19 @@ impl UserApiProvider {
             Err(e) => Err(e.add_message_back("(while alter user).")),
         }
     }
+
+    // Update the quota of a user by name and hostname.
+    pub async fn update_user_quota(
+        &self,
+        tenant: &str,
+        user: UserIdentity,
+        quota: UserQuota,
+    ) -> Result<Option<u64>> {
+        let client = self.get_user_api_client(tenant)?;
+        let update_user_quota = client.update_user_quota(user, quota, None);
+        match update_user_quota.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while alter user quota).")),
+        }
+    }
 }
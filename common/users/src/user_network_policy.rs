@@ -0,0 +1,160 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::UserInfo;
+use tracing::warn;
+
+use crate::UserApiProvider;
+
+/// Network policy operations.
+impl UserApiProvider {
+    // Add a new network policy.
+    pub async fn add_network_policy(
+        &self,
+        tenant: &str,
+        info: NetworkPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        let add_policy = policy_api_client.add_policy(info);
+        match add_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::network_policy_already_exists_code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a network policy by name.
+    pub async fn get_network_policy(
+        &self,
+        tenant: &str,
+        policy_name: &str,
+    ) -> Result<NetworkPolicy> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        let get_policy = policy_api_client.get_policy(policy_name, None);
+        Ok(get_policy.await?.data)
+    }
+
+    // Get all network policies for the tenant.
+    pub async fn get_network_policies(&self, tenant: &str) -> Result<Vec<NetworkPolicy>> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        let get_policies = policy_api_client.get_policies();
+
+        match get_policies.await {
+            Err(e) => Err(e.add_message_back("(while get network policies).")),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Get the users the network policy is set to.
+    pub async fn get_network_policy_users(
+        &self,
+        tenant: &str,
+        policy_name: &str,
+    ) -> Result<Vec<UserInfo>> {
+        let users = self.get_users(tenant).await?;
+        Ok(users
+            .into_iter()
+            .filter(|user| user.option.network_policy().map(|p| p.as_str()) == Some(policy_name))
+            .collect())
+    }
+
+    // Drop a network policy by name, the policy can not be dropped while it is set to users.
+    pub async fn drop_network_policy(
+        &self,
+        tenant: &str,
+        policy_name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let policy_api_client = self.get_network_policy_api_client(tenant)?;
+        let seq = match policy_api_client.get_policy(policy_name, None).await {
+            Ok(policy) => policy.seq,
+            Err(e) if if_exists && e.code() == ErrorCode::unknown_network_policy_code() => {
+                return Ok(());
+            }
+            Err(e) => return Err(e.add_message_back("(while drop network policy)")),
+        };
+
+        let users = self.get_network_policy_users(tenant, policy_name).await?;
+        if !users.is_empty() {
+            let users = users
+                .iter()
+                .map(|user| user.identity().to_string())
+                .collect::<Vec<_>>();
+            return Err(ErrorCode::NetworkPolicyIsUsedByUser(format!(
+                "Network policy {} is used by users: {}",
+                policy_name,
+                users.join(", ")
+            )));
+        }
+
+        // only the version of the policy checked above is dropped
+        let drop_policy = policy_api_client.drop_policy(policy_name, Some(seq));
+        match drop_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => Err(e.add_message_back("(while drop network policy)")),
+        }
+    }
+
+    // Check the client address against the network policy of the user, if any.
+    pub async fn check_network_policy(
+        &self,
+        tenant: &str,
+        user: &UserInfo,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
+        let policy_name = match user.option.network_policy() {
+            None => return Ok(()),
+            Some(policy_name) => policy_name,
+        };
+
+        // The policy may be dropped while an ALTER USER setting it is in flight, in which
+        // case the dangling policy is ignored instead of locking the user out.
+        let policy = match self.get_network_policy(tenant, policy_name).await {
+            Ok(policy) => policy,
+            Err(e) if e.code() == ErrorCode::unknown_network_policy_code() => {
+                warn!(
+                    "network policy {} of user {} does not exist, ignore it",
+                    policy_name,
+                    user.identity()
+                );
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let allowed = match client_ip.and_then(|ip| ip.parse::<IpAddr>().ok()) {
+            Some(ip) => policy.is_allowed(&ip)?,
+            None => false,
+        };
+        if !allowed {
+            return Err(ErrorCode::AuthenticateFailure(format!(
+                "client address {} is not allowed by network policy {} of user {}",
+                client_ip.unwrap_or("unknown"),
+                policy_name,
+                user.identity()
+            )));
+        }
+        Ok(())
+    }
+}
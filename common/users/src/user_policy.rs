@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_management::Policy;
+
+use crate::UserApiProvider;
+
+/// Row access policy and masking policy operations.
+impl UserApiProvider {
+    // Add a new policy.
+    pub async fn add_policy<P: Policy>(
+        &self,
+        tenant: &str,
+        info: P,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let policy_api_client = self.get_policy_api_client::<P>(tenant)?;
+        let add_policy = policy_api_client.add_policy(info);
+        match add_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == P::already_exists(String::new()).code() {
+                    Ok(u64::MIN)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a policy by name.
+    pub async fn get_policy<P: Policy>(&self, tenant: &str, policy_name: &str) -> Result<P> {
+        let policy_api_client = self.get_policy_api_client::<P>(tenant)?;
+        let get_policy = policy_api_client.get_policy(policy_name, None);
+        Ok(get_policy.await?.data)
+    }
+
+    // Get all policies of the kind for the tenant.
+    pub async fn get_policies<P: Policy>(&self, tenant: &str) -> Result<Vec<P>> {
+        let policy_api_client = self.get_policy_api_client::<P>(tenant)?;
+        let get_policies = policy_api_client.get_policies();
+
+        match get_policies.await {
+            Err(e) => Err(e.add_message_back(format!("(while get {}s).", P::KIND))),
+            Ok(policies) => Ok(policies),
+        }
+    }
+
+    // Drop a policy by name.
+    pub async fn drop_policy<P: Policy>(
+        &self,
+        tenant: &str,
+        policy_name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let policy_api_client = self.get_policy_api_client::<P>(tenant)?;
+        let drop_policy = policy_api_client.drop_policy(policy_name, None);
+        match drop_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(format!("(while drop {})", P::KIND)))
+                }
+            }
+        }
+    }
+}
@@ -24,6 +24,7 @@ use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 use common_meta_types::UserPrivilegeSet;
 use common_meta_types::UserPrivilegeType;
+use common_meta_types::UserQuota;
 use common_users::User;
 use common_users::UserApiProvider;
 use pretty_assertions::assert_eq;
@@ -239,7 +240,23 @@ async fn test_user_manager() -> Result<()> {
             )
             .await;
         // ErrorCode::UnknownUser
-        assert_eq!(not_exist.err().unwrap().code(), 2201)
+        assert_eq!(not_exist.err().unwrap().code(), 2201);
+
+        // alter quota
+        let quota = UserQuota {
+            max_cpu: 2,
+            max_memory_in_bytes: 1024,
+            max_storage_in_bytes: 0,
+        };
+        user_mgr
+            .update_user_quota(tenant, user_info.identity(), quota.clone())
+            .await?;
+        let new_user = user_mgr.get_user(tenant, user_info.identity()).await?;
+        assert_eq!(new_user.quota, quota);
+        assert_eq!(
+            new_user.auth_info.get_password().unwrap(),
+            Vec::from(new_new_pwd)
+        );
     }
     Ok(())
 }
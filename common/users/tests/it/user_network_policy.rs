@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use common_grpc::RpcClientConf;
+use common_meta_types::AuthInfo;
+use common_meta_types::NetworkPolicy;
+use common_meta_types::UserInfo;
+use common_users::UserApiProvider;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_network_policy() -> Result<()> {
+    let conf = RpcClientConf::default();
+    let tenant = "test";
+    let user_mgr = UserApiProvider::create_global(conf).await?;
+
+    let policy = NetworkPolicy::new(
+        "np",
+        vec!["10.0.0.0/8".to_string()],
+        vec!["10.1.0.0/16".to_string()],
+        "",
+    );
+    user_mgr.add_network_policy(tenant, policy, false).await?;
+
+    let mut user = UserInfo::new("u1", "%", AuthInfo::None);
+    user_mgr.check_network_policy(tenant, &user, None).await?;
+
+    user.option.set_network_policy(Some("np".to_string()));
+    user_mgr.add_user(tenant, user.clone(), false).await?;
+
+    // check the client address.
+    {
+        user_mgr
+            .check_network_policy(tenant, &user, Some("10.2.3.4"))
+            .await?;
+
+        for client_ip in [Some("10.1.2.3"), Some("192.168.0.1"), Some("%"), None] {
+            let res = user_mgr
+                .check_network_policy(tenant, &user, client_ip)
+                .await;
+            assert_eq!(res.unwrap_err().code(), 1051);
+        }
+    }
+
+    // drop the policy in use.
+    {
+        let mut user2 = UserInfo::new("u2", "%", AuthInfo::None);
+        user2.option.set_network_policy(Some("np".to_string()));
+        user_mgr.add_user(tenant, user2.clone(), false).await?;
+        assert_eq!(
+            user_mgr.get_network_policy_users(tenant, "np").await?.len(),
+            2
+        );
+
+        let err = user_mgr
+            .drop_network_policy(tenant, "np", false)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), 2620);
+        assert!(err.message().contains("'u1'@'%'"));
+        assert!(err.message().contains("'u2'@'%'"));
+
+        // if exists does not bypass the check.
+        let res = user_mgr.drop_network_policy(tenant, "np", true).await;
+        assert_eq!(res.unwrap_err().code(), 2620);
+
+        user_mgr.drop_user(tenant, user.identity(), false).await?;
+        let res = user_mgr.drop_network_policy(tenant, "np", false).await;
+        assert_eq!(res.unwrap_err().code(), 2620);
+
+        user_mgr.drop_user(tenant, user2.identity(), false).await?;
+        user_mgr.drop_network_policy(tenant, "np", false).await?;
+        assert!(user_mgr.get_network_policies(tenant).await?.is_empty());
+    }
+
+    // drop the unknown policy.
+    {
+        let res = user_mgr.drop_network_policy(tenant, "np", false).await;
+        assert_eq!(res.unwrap_err().code(), 2618);
+        user_mgr.drop_network_policy(tenant, "np", true).await?;
+    }
+
+    // the dropped policy of a user is ignored.
+    {
+        user_mgr
+            .check_network_policy(tenant, &user, Some("192.168.0.1"))
+            .await?;
+    }
+
+    Ok(())
+}
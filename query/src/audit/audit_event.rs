@@ -0,0 +1,315 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use chrono::NaiveDateTime;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Plan;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventType {
+    Login,
+    Ddl,
+    Privilege,
+    UserManagement,
+    DataTransfer,
+}
+
+impl AuditEventType {
+    /// Classify a statement, `None` means the statement is not audited.
+    pub fn from_plan(plan: &Plan) -> Option<AuditEventType> {
+        match plan {
+            Plan::CreateDatabase(_)
+            | Plan::DropDatabase(_)
+            | Plan::UndropDatabase(_)
+            | Plan::RenameDatabase(_)
+            | Plan::CreateTable(_)
+            | Plan::DropTable(_)
+            | Plan::UndropTable(_)
+            | Plan::RenameTable(_)
+            | Plan::AlterTableClusterKey(_)
+            | Plan::DropTableClusterKey(_)
+            | Plan::AddTableColumn(_)
+            | Plan::DropTableColumn(_)
+            | Plan::RenameTableColumn(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_)
+            | Plan::SetTableColumnMaskingPolicy(_)
+            | Plan::UnsetTableColumnMaskingPolicy(_)
+            | Plan::TruncateTable(_)
+            | Plan::CreateView(_)
+            | Plan::AlterView(_)
+            | Plan::DropView(_)
+            | Plan::CreateUDF(_)
+            | Plan::AlterUDF(_)
+            | Plan::DropUDF(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::CreateMaskingPolicy(_)
+            | Plan::DropMaskingPolicy(_)
+            | Plan::CreateNetworkPolicy(_)
+            | Plan::DropNetworkPolicy(_)
+            | Plan::CreateStage(_)
+            | Plan::DropStage(_) => Some(AuditEventType::Ddl),
+
+            Plan::GrantRole(_) | Plan::GrantPriv(_) | Plan::RevokePriv(_) | Plan::RevokeRole(_) => {
+                Some(AuditEventType::Privilege)
+            }
+
+            Plan::CreateUser(_)
+            | Plan::AlterUser(_)
+            | Plan::DropUser(_)
+            | Plan::CreateRole(_)
+            | Plan::DropRole(_) => Some(AuditEventType::UserManagement),
+
+            Plan::Copy(_) | Plan::Presign(_) => Some(AuditEventType::DataTransfer),
+
+            _ => None,
+        }
+    }
+}
+
+impl Display for AuditEventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditEventType::Login => write!(f, "login"),
+            AuditEventType::Ddl => write!(f, "ddl"),
+            AuditEventType::Privilege => write!(f, "privilege"),
+            AuditEventType::UserManagement => write!(f, "user_management"),
+            AuditEventType::DataTransfer => write!(f, "data_transfer"),
+        }
+    }
+}
+
+fn datetime_str<S>(dt: &i64, s: S) -> std::result::Result<S::Ok, S::Error>
+where S: Serializer {
+    let t = NaiveDateTime::from_timestamp(
+        dt / 1_000_000,
+        u32::try_from((dt % 1_000_000) * 1000).unwrap_or(0),
+    );
+    s.serialize_str(t.format("%Y-%m-%d %H:%M:%S%.6f").to_string().as_str())
+}
+
+/// One record of the audit log, written as a JSON line to the audit log file
+/// and, when configured, as a row of the audit log table.
+#[derive(Clone, Debug, Serialize)]
+pub struct AuditEvent {
+    pub event_type: AuditEventType,
+    #[serde(serialize_with = "datetime_str")]
+    pub event_time: i64,
+
+    // User.
+    pub tenant_id: String,
+    pub cluster_id: String,
+    pub user: String,
+
+    // Client.
+    pub handler_type: String,
+    pub client_address: String,
+    pub auth_type: String,
+
+    // Query.
+    pub query_id: String,
+    pub query_kind: String,
+    pub query_text: String,
+
+    // Result.
+    pub success: bool,
+    pub exception_code: i32,
+    pub exception: String,
+}
+
+fn now_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_micros() as i64
+}
+
+fn error_fields(err: Option<&ErrorCode>) -> (bool, i32, String) {
+    match err {
+        None => (true, 0, "".to_string()),
+        Some(e) => (false, e.code().into(), e.message()),
+    }
+}
+
+impl AuditEvent {
+    pub fn login(
+        tenant_id: String,
+        cluster_id: String,
+        user: String,
+        handler_type: String,
+        client_address: String,
+        auth_type: &str,
+        err: Option<&ErrorCode>,
+    ) -> AuditEvent {
+        let (success, exception_code, exception) = error_fields(err);
+        AuditEvent {
+            event_type: AuditEventType::Login,
+            event_time: now_micros(),
+            tenant_id,
+            cluster_id,
+            user,
+            handler_type,
+            client_address,
+            auth_type: auth_type.to_string(),
+            query_id: "".to_string(),
+            query_kind: "".to_string(),
+            query_text: "".to_string(),
+            success,
+            exception_code,
+            exception,
+        }
+    }
+
+    pub fn statement(
+        ctx: &QueryContext,
+        event_type: AuditEventType,
+        query_kind: &str,
+        err: Option<&ErrorCode>,
+    ) -> AuditEvent {
+        let (success, exception_code, exception) = error_fields(err);
+        let session = ctx.get_current_session();
+        let user = match ctx.get_current_user() {
+            Ok(user) => user.identity().to_string(),
+            Err(_) => "".to_string(),
+        };
+        let client_address = match ctx.get_client_address() {
+            Some(addr) => addr.to_string(),
+            None => "".to_string(),
+        };
+
+        AuditEvent {
+            event_type,
+            event_time: now_micros(),
+            tenant_id: ctx.get_tenant(),
+            cluster_id: ctx.get_config().query.cluster_id,
+            user,
+            handler_type: session.get_type().to_string(),
+            client_address,
+            auth_type: "".to_string(),
+            query_id: ctx.get_id(),
+            query_kind: query_kind.to_string(),
+            query_text: ctx.get_query_str(),
+            success,
+            exception_code,
+            exception,
+        }
+    }
+
+    /// The schema the audit log table must provide. Extra columns of the table
+    /// are filled with their default values.
+    pub fn schema() -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("event_type", Vu8::to_data_type()),
+            DataField::new("event_time", TimestampType::new_impl(6)),
+            // User.
+            DataField::new("tenant_id", Vu8::to_data_type()),
+            DataField::new("cluster_id", Vu8::to_data_type()),
+            DataField::new("user", Vu8::to_data_type()),
+            // Client.
+            DataField::new("handler_type", Vu8::to_data_type()),
+            DataField::new("client_address", Vu8::to_data_type()),
+            DataField::new("auth_type", Vu8::to_data_type()),
+            // Query.
+            DataField::new("query_id", Vu8::to_data_type()),
+            DataField::new("query_kind", Vu8::to_data_type()),
+            DataField::new("query_text", Vu8::to_data_type()),
+            // Result.
+            DataField::new("success", bool::to_data_type()),
+            DataField::new("exception_code", i32::to_data_type()),
+            DataField::new("exception", Vu8::to_data_type()),
+        ])
+    }
+
+    pub fn to_data_block(events: &[AuditEvent]) -> DataBlock {
+        let strings = |f: fn(&AuditEvent) -> &str| events.iter().map(f).collect::<Vec<_>>();
+        let event_types = events
+            .iter()
+            .map(|e| e.event_type.to_string())
+            .collect::<Vec<_>>();
+        DataBlock::create(AuditEvent::schema(), vec![
+            Series::from_data(event_types.iter().map(|t| t.as_str()).collect::<Vec<_>>()),
+            Series::from_data(events.iter().map(|e| e.event_time).collect::<Vec<_>>()),
+            // User.
+            Series::from_data(strings(|e| &e.tenant_id)),
+            Series::from_data(strings(|e| &e.cluster_id)),
+            Series::from_data(strings(|e| &e.user)),
+            // Client.
+            Series::from_data(strings(|e| &e.handler_type)),
+            Series::from_data(strings(|e| &e.client_address)),
+            Series::from_data(strings(|e| &e.auth_type)),
+            // Query.
+            Series::from_data(strings(|e| &e.query_id)),
+            Series::from_data(strings(|e| &e.query_kind)),
+            Series::from_data(strings(|e| &e.query_text)),
+            // Result.
+            Series::from_data(events.iter().map(|e| e.success).collect::<Vec<_>>()),
+            Series::from_data(events.iter().map(|e| e.exception_code).collect::<Vec<_>>()),
+            Series::from_data(strings(|e| &e.exception)),
+        ])
+    }
+
+    /// Project the events onto the columns of the audit log table, in the order of
+    /// the table schema. The extra columns of the table are left out.
+    pub fn to_table_block(
+        events: &[AuditEvent],
+        table_schema: &DataSchemaRef,
+    ) -> Result<DataBlock> {
+        let schema = AuditEvent::schema();
+        for field in schema.fields() {
+            match table_schema.field_with_name(field.name()) {
+                Ok(f)
+                    if remove_nullable(f.data_type()).data_type_id()
+                        == field.data_type().data_type_id() => {}
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "audit log table must have a column {} of type {}",
+                        field.name(),
+                        field.data_type().name()
+                    )));
+                }
+            }
+        }
+
+        let block = AuditEvent::to_data_block(events);
+        let mut fields = Vec::with_capacity(schema.fields().len());
+        let mut columns = Vec::with_capacity(schema.fields().len());
+        for field in table_schema.fields() {
+            if let Ok(column) = block.try_column_by_name(field.name()) {
+                let column = if field.is_nullable() {
+                    NullableColumn::wrap_inner(column.clone(), None)
+                } else {
+                    column.clone()
+                };
+                fields.push(field.clone());
+                columns.push(column);
+            }
+        }
+        Ok(DataBlock::create(DataSchemaRefExt::create(fields), columns))
+    }
+}
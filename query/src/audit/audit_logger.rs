@@ -0,0 +1,250 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::mpsc;
+use common_base::base::tokio::sync::mpsc::error::TrySendError;
+use common_base::base::tokio::time::interval;
+use common_config::QueryConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use parking_lot::Mutex;
+use tracing::error;
+
+use crate::audit::AuditEvent;
+use crate::audit::RotatingFileWriter;
+use crate::catalogs::CATALOG_DEFAULT;
+use crate::interpreters::append2table;
+use crate::interpreters::commit2table;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::BlocksSource;
+use crate::pipelines::Pipeline;
+use crate::pipelines::SourcePipeBuilder;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+// events are appended into the audit log table once this many are pending
+const AUDIT_LOG_FLUSH_EVENTS: usize = 1000;
+// or once this long has passed since the last append
+const AUDIT_LOG_FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+// events beyond this are dropped, both from the file and the table
+const AUDIT_LOG_QUEUE_SIZE: usize = 100000;
+
+/// Durable audit trail of logins and privileged statements.
+///
+/// Every event is written as a JSON line to a rotating file under `audit_log_dir`.
+/// If `audit_log_table` is set, the events are also appended into that fuse table
+/// in batches, the table must contain the columns of [`AuditEvent::schema`]. Both are
+/// written by a single background task, the audited operations only queue the events.
+pub struct AuditLogger {
+    enabled: bool,
+    table: Option<(String, String)>,
+    // the writer is moved into the background task, which is started by the first event
+    writer: Mutex<Option<RotatingFileWriter>>,
+    queue: Mutex<Option<mpsc::Sender<AuditEvent>>>,
+}
+
+impl AuditLogger {
+    pub fn try_create(conf: &QueryConfig) -> Result<AuditLogger> {
+        if !conf.audit_log_enabled {
+            return Ok(AuditLogger {
+                enabled: false,
+                table: None,
+                writer: Mutex::new(None),
+                queue: Mutex::new(None),
+            });
+        }
+
+        let table = match conf.audit_log_table.as_str() {
+            "" => None,
+            name => match name.split_once('.') {
+                Some((database, table)) if !database.is_empty() && !table.is_empty() => {
+                    Some((database.to_string(), table.to_string()))
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "audit_log_table must be in the form of `database.table`, but got: {}",
+                        name
+                    )));
+                }
+            },
+        };
+
+        let writer = RotatingFileWriter::create(
+            &conf.audit_log_dir,
+            conf.audit_log_max_file_size_mb * 1024 * 1024,
+            conf.audit_log_max_files,
+        );
+
+        Ok(AuditLogger {
+            enabled: true,
+            table,
+            writer: Mutex::new(Some(writer)),
+            queue: Mutex::new(None),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Record the event, failures are reported to the server log and never
+    /// fail the audited operation.
+    pub fn log(&self, session_mgr: &Arc<SessionManager>, event: AuditEvent) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut queue = self.queue.lock();
+        if queue.is_none() {
+            let writer = match self.writer.lock().take() {
+                Some(writer) => writer,
+                None => return,
+            };
+            let (tx, rx) = mpsc::channel(AUDIT_LOG_QUEUE_SIZE);
+            session_mgr.get_storage_runtime().spawn(Self::write_events(
+                session_mgr.clone(),
+                writer,
+                self.table.clone(),
+                rx,
+            ));
+            *queue = Some(tx);
+        }
+
+        let queue = match queue.as_ref() {
+            Some(queue) => queue,
+            None => return,
+        };
+        match queue.try_send(event) {
+            Ok(_) => {}
+            Err(TrySendError::Full(_)) => error!("audit log queue is full, event dropped"),
+            Err(TrySendError::Closed(_)) => error!("audit log task is stopped, event dropped"),
+        }
+    }
+
+    /// Record a login attempt of the session, `err` is the authentication failure if any.
+    pub fn log_login(
+        session: &Arc<Session>,
+        user: String,
+        client_address: String,
+        auth_type: &str,
+        err: Option<&ErrorCode>,
+    ) {
+        let session_mgr = session.get_session_manager();
+        let audit_logger = session_mgr.get_audit_logger();
+        if audit_logger.is_enabled() {
+            let event = AuditEvent::login(
+                session.get_current_tenant(),
+                session_mgr.get_conf().query.cluster_id,
+                user,
+                session.get_type().to_string(),
+                client_address,
+                auth_type,
+                err,
+            );
+            audit_logger.log(&session_mgr, event);
+        }
+    }
+
+    // Write the queued events into the file one by one, and append them into the
+    // table by size or by time.
+    async fn write_events(
+        session_mgr: Arc<SessionManager>,
+        mut writer: RotatingFileWriter,
+        table: Option<(String, String)>,
+        mut queue: mpsc::Receiver<AuditEvent>,
+    ) {
+        let mut events = Vec::with_capacity(AUDIT_LOG_FLUSH_EVENTS);
+        let mut ticker = interval(AUDIT_LOG_FLUSH_INTERVAL);
+        loop {
+            let closed = tokio::select! {
+                event = queue.recv() => match event {
+                    Some(event) => {
+                        match serde_json::to_string(&event) {
+                            Ok(line) => {
+                                if let Err(cause) = writer.write_line(&line) {
+                                    error!("fail to write audit log: {:?}", cause);
+                                }
+                            }
+                            Err(cause) => error!("fail to serialize audit event: {:?}", cause),
+                        }
+                        if table.is_none() {
+                            continue;
+                        }
+                        events.push(event);
+                        if events.len() < AUDIT_LOG_FLUSH_EVENTS {
+                            continue;
+                        }
+                        false
+                    }
+                    None => true,
+                },
+                _ = ticker.tick() => false,
+            };
+
+            if let Some((database, table)) = &table {
+                if !events.is_empty() {
+                    let batch =
+                        std::mem::replace(&mut events, Vec::with_capacity(AUDIT_LOG_FLUSH_EVENTS));
+                    if let Err(cause) =
+                        Self::append_to_table(session_mgr.clone(), database, table, batch).await
+                    {
+                        error!(
+                            "fail to append audit log into {}.{}: {:?}",
+                            database, table, cause
+                        );
+                    }
+                }
+            }
+
+            if closed {
+                return;
+            }
+        }
+    }
+
+    async fn append_to_table(
+        session_mgr: Arc<SessionManager>,
+        database: &str,
+        table: &str,
+        events: Vec<AuditEvent>,
+    ) -> Result<()> {
+        let session = session_mgr.create_session(SessionType::Dummy).await?;
+        let ctx = session.create_query_context().await?;
+        let table = ctx.get_table(CATALOG_DEFAULT, database, table).await?;
+
+        // the columns missing from the block are filled by their default values
+        let block = AuditEvent::to_table_block(&events, &table.schema())?;
+        let schema = block.schema().clone();
+
+        let output = OutputPort::create();
+        let blocks = Arc::new(Mutex::new(VecDeque::from(vec![block])));
+        let mut builder = SourcePipeBuilder::create();
+        builder.add_source(
+            output.clone(),
+            BlocksSource::create(ctx.clone(), output, blocks)?,
+        );
+        let mut pipeline = Pipeline::create();
+        pipeline.add_pipe(builder.finalize());
+
+        append2table(ctx.clone(), table.clone(), schema, pipeline)?;
+        commit2table(ctx, table, false).await
+    }
+}
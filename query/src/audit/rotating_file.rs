@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+use common_exception::Result;
+
+pub const AUDIT_LOG_FILE_NAME: &str = "audit.log";
+
+/// Appends lines to `<dir>/audit.log`, rotating it to `audit.log.1`, `audit.log.2`, ...
+/// once it grows beyond `max_file_size` bytes. At most `max_files` rotated files are kept.
+pub struct RotatingFileWriter {
+    dir: PathBuf,
+    max_file_size: u64,
+    max_files: u64,
+    file: Option<File>,
+    file_size: u64,
+}
+
+impl RotatingFileWriter {
+    pub fn create(dir: impl Into<PathBuf>, max_file_size: u64, max_files: u64) -> Self {
+        RotatingFileWriter {
+            dir: dir.into(),
+            max_file_size,
+            max_files,
+            file: None,
+            file_size: 0,
+        }
+    }
+
+    pub fn write_line(&mut self, line: &str) -> Result<()> {
+        let len = line.len() as u64 + 1;
+        if self.file.is_none() {
+            self.open()?;
+        }
+        if self.file_size > 0 && self.file_size + len > self.max_file_size {
+            self.rotate()?;
+        }
+
+        if let Some(file) = self.file.as_mut() {
+            file.write_all(line.as_bytes())?;
+            file.write_all(b"\n")?;
+            file.flush()?;
+            self.file_size += len;
+        }
+        Ok(())
+    }
+
+    fn path(&self, index: u64) -> PathBuf {
+        match index {
+            0 => self.dir.join(AUDIT_LOG_FILE_NAME),
+            _ => self.dir.join(format!("{}.{}", AUDIT_LOG_FILE_NAME, index)),
+        }
+    }
+
+    fn open(&mut self) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.path(0))?;
+        self.file_size = file.metadata()?.len();
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn rotate(&mut self) -> Result<()> {
+        self.file = None;
+
+        // Drop the oldest file, then shift every remaining file one slot up.
+        let oldest = self.path(self.max_files);
+        if oldest.exists() {
+            fs::remove_file(oldest)?;
+        }
+        for index in (0..self.max_files).rev() {
+            let from = self.path(index);
+            if from.exists() {
+                fs::rename(from, self.path(index + 1))?;
+            }
+        }
+        self.open()
+    }
+}
@@ -23,6 +23,7 @@ use common_users::JwtAuthenticator;
 use common_users::UserApiProvider;
 use jwtk::Claims;
 
+use crate::audit::AuditLogger;
 use crate::sessions::SessionRef;
 pub use crate::Config;
 
@@ -52,7 +53,30 @@ impl AuthMgr {
     }
 
     pub async fn auth(&self, session: SessionRef, credential: &Credential) -> Result<()> {
-        let user_info = match credential {
+        let res = self.do_auth(&session, credential).await;
+
+        let (user, hostname, auth_type) = match credential {
+            Credential::Jwt { hostname, .. } => (None, hostname, "jwt"),
+            Credential::Password { name, hostname, .. } => (Some(name), hostname, "password"),
+        };
+        let user = match &res {
+            Ok(user_info) => user_info.identity().to_string(),
+            Err(_) => user.cloned().unwrap_or_default(),
+        };
+        AuditLogger::log_login(
+            &session,
+            user,
+            hostname.clone().unwrap_or_default(),
+            auth_type,
+            res.as_ref().err(),
+        );
+
+        session.set_current_user(res?);
+        Ok(())
+    }
+
+    async fn do_auth(&self, session: &SessionRef, credential: &Credential) -> Result<UserInfo> {
+        let (user_info, hostname) = match credential {
             Credential::Jwt {
                 token: t,
                 hostname: h,
@@ -63,15 +87,17 @@ impl AuthMgr {
                     .ok_or_else(|| ErrorCode::AuthenticateFailure("jwt auth not configured."))?;
                 let parsed_jwt = jwt_auth.parse_jwt(t.as_str()).await?;
                 let (tenant, user_name) = self
-                    .process_jwt_claims(&session, parsed_jwt.claims())
+                    .process_jwt_claims(session, parsed_jwt.claims())
                     .await?;
-                self.user_mgr
+                let user = self
+                    .user_mgr
                     .get_user_with_client_ip(
                         &tenant,
                         &user_name,
                         h.as_ref().unwrap_or(&"%".to_string()),
                     )
-                    .await?
+                    .await?;
+                (user, h)
             }
             Credential::Password {
                 name: n,
@@ -83,7 +109,7 @@ impl AuthMgr {
                     .user_mgr
                     .get_user_with_client_ip(&tenant, n, h.as_ref().unwrap_or(&"%".to_string()))
                     .await?;
-                match &user.auth_info {
+                let user = match &user.auth_info {
                     AuthInfo::None => Ok(user),
                     AuthInfo::Password {
                         hash_value: h,
@@ -99,11 +125,19 @@ impl AuthMgr {
                         }
                     },
                     _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
-                }?
+                }?;
+                (user, h)
             }
         };
-        session.set_current_user(user_info);
-        Ok(())
+
+        self.user_mgr
+            .check_network_policy(
+                &session.get_current_tenant(),
+                &user_info,
+                hostname.as_deref(),
+            )
+            .await?;
+        Ok(user_info)
     }
 
     async fn process_jwt_claims(
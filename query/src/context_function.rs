@@ -49,6 +49,12 @@ impl ContextFunction {
             "user" | "currentuser" | "current_user" => vec![Expression::create_literal(
                 DataValue::String(ctx.get_current_user()?.identity().to_string().into_bytes()),
             )],
+            "current_role" => vec![Expression::create_literal(DataValue::String(
+                ctx.get_current_session()
+                    .get_current_role()?
+                    .unwrap_or_default()
+                    .into_bytes(),
+            ))],
             "connection_id" => vec![Expression::create_literal(DataValue::String(
                 ctx.get_connection_id().into_bytes(),
             ))],
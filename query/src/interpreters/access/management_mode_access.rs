@@ -99,6 +99,16 @@ impl ManagementModeAccess {
                 | Plan::CreateUDF(_)
                 | Plan::AlterUDF(_)
                 | Plan::DropUDF(_)
+
+                // Row access policy
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+
+                // Masking policy
+                | Plan::CreateMaskingPolicy(_)
+                | Plan::DropMaskingPolicy(_)
+                | Plan::CreateNetworkPolicy(_)
+                | Plan::DropNetworkPolicy(_)
                 | Plan::UseDatabase(_)
                 | Plan::Call(_) => true,
                 _ => false
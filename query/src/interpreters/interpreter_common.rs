@@ -89,6 +89,35 @@ pub async fn commit2table(
     }
 }
 
+/// Check the storage quota of current user before writing data, the storage used by
+/// a user is the total size of the tables owned by the user in the catalog.
+pub async fn validate_user_storage_quota(
+    ctx: &Arc<QueryContext>,
+    catalog_name: &str,
+) -> Result<()> {
+    let user = ctx.get_current_user()?;
+    let max_storage_in_bytes = user.quota.max_storage_in_bytes;
+    if max_storage_in_bytes == 0 {
+        return Ok(());
+    }
+
+    let owner = user.identity().to_string();
+    let storage_used = ctx
+        .get_current_session()
+        .get_session_manager()
+        .get_user_storage_usage_cache()
+        .get_usage(ctx, catalog_name, &owner)
+        .await?;
+
+    if storage_used >= max_storage_in_bytes {
+        return Err(ErrorCode::UserQuotaExceeded(format!(
+            "Storage limit exceeded for user {}: storage used {} bytes, max storage quota {} bytes",
+            owner, storage_used, max_storage_in_bytes
+        )));
+    }
+    Ok(())
+}
+
 pub async fn validate_grant_object_exists(
     ctx: &Arc<QueryContext>,
     object: &GrantObject,
@@ -120,6 +149,21 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Column(catalog_name, database_name, table_name, columns) => {
+            let catalog = ctx.get_catalog(catalog_name)?;
+            let table = catalog
+                .get_table(tenant.as_str(), database_name, table_name)
+                .await?;
+            let schema = table.schema();
+            for column in columns {
+                if !schema.has_field(column) {
+                    return Err(common_exception::ErrorCode::UnknownColumn(format!(
+                        "column {} not exists in table {}.{}",
+                        column, database_name, table_name,
+                    )));
+                }
+            }
+        }
         GrantObject::Global => (),
     }
 
@@ -30,6 +30,7 @@ use tracing::info;
 
 use super::append2table;
 use super::commit2table;
+use super::interpreter_common::validate_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreterV2;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -247,6 +248,8 @@ impl Interpreter for CopyInterpreterV2 {
                 from,
                 ..
             } => {
+                validate_user_storage_quota(&self.ctx, catalog_name).await?;
+
                 let mut files = self.list_files(from, files).await?;
 
                 // Pattern match check.
@@ -24,9 +24,16 @@ use common_streams::SendableDataBlockStream;
 use super::fragments::Fragmenter;
 use super::QueryFragmentsActions;
 use crate::interpreters::Interpreter;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::EmptySink;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipelines::SinkPipeBuilder;
 use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 use crate::sql::executor::PhysicalPlanBuilder;
 use crate::sql::executor::PipelineBuilder;
+use crate::sql::executor::PlanProfiles;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Plan;
 use crate::sql::MetadataRef;
@@ -67,6 +74,14 @@ impl Interpreter for ExplainInterpreterV2 {
                     return Err(ErrorCode::UnImplement("Unsupported EXPLAIN statement"));
                 }
             },
+            ExplainKind::Analyze => match &self.plan {
+                Plan::Query {
+                    s_expr, metadata, ..
+                } => self.explain_analyze(s_expr.clone(), metadata.clone())?,
+                _ => {
+                    return Err(ErrorCode::UnImplement("Unsupported EXPLAIN statement"));
+                }
+            },
             ExplainKind::Graph => {
                 return Err(ErrorCode::UnImplement("ExplainKind graph is unimplemented"));
             }
@@ -139,6 +154,55 @@ impl ExplainInterpreterV2 {
         Ok(blocks)
     }
 
+    /// Executes the query with the processors profiled, the results of the query are
+    /// discarded.
+    fn explain_analyze(&self, s_expr: SExpr, metadata: MetadataRef) -> Result<Vec<DataBlock>> {
+        let plan = PhysicalPlanBuilder::new(metadata).build(&s_expr)?;
+        if plan.is_distributed_plan() {
+            return Err(ErrorCode::UnImplement(
+                "EXPLAIN ANALYZE of distributed query is unimplemented",
+            ));
+        }
+
+        let plan_profiles = PlanProfiles::create();
+        let pipeline_builder =
+            PipelineBuilder::create_with_profiles(self.ctx.clone(), plan_profiles.clone());
+        let mut build_res = pipeline_builder.finalize(&plan)?;
+
+        let mut sink_pipeline_builder = SinkPipeBuilder::create();
+        for _index in 0..build_res.main_pipeline.output_len() {
+            let input_port = InputPort::create();
+            sink_pipeline_builder.add_sink(input_port.clone(), EmptySink::create(input_port));
+        }
+        build_res
+            .main_pipeline
+            .add_pipe(sink_pipeline_builder.finalize());
+        build_res.set_max_threads(self.ctx.get_settings().get_max_threads()? as usize);
+
+        let PipelineBuildResult {
+            main_pipeline,
+            sources_pipelines: mut pipelines,
+        } = build_res;
+        pipelines.push(main_pipeline);
+        let executor = PipelineCompleteExecutor::from_pipelines(
+            self.ctx.get_storage_runtime(),
+            self.ctx.query_need_abort(),
+            pipelines,
+        )?;
+        executor.execute()?;
+
+        let display_string = plan.format_analyze(&plan_profiles).to_string();
+        let formatted_plan = Series::from_data(
+            display_string
+                .lines()
+                .map(|s| s.as_bytes())
+                .collect::<Vec<_>>(),
+        );
+        Ok(vec![DataBlock::create(self.schema.clone(), vec![
+            formatted_plan,
+        ])])
+    }
+
     fn explain_fragments(&self, s_expr: SExpr, metadata: MetadataRef) -> Result<Vec<DataBlock>> {
         let ctx = self.ctx.clone();
         let plan = PhysicalPlanBuilder::new(metadata).build(&s_expr)?;
@@ -12,10 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::time::SystemTime;
 
 use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::PlanNode;
 use common_streams::ErrorStream;
@@ -23,6 +26,8 @@ use common_streams::ProgressStream;
 use common_streams::SendableDataBlockStream;
 use parking_lot::Mutex;
 
+use crate::audit::AuditEvent;
+use crate::audit::AuditEventType;
 use crate::interpreters::access::ManagementModeAccess;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -40,6 +45,9 @@ pub struct InterceptorInterpreter {
     query_log: InterpreterQueryLog,
     source_pipe_builder: Mutex<Option<SourcePipeBuilder>>,
     management_mode_access: ManagementModeAccess,
+    query_kind: String,
+    audit_event_type: Option<AuditEventType>,
+    audited: AtomicBool,
 }
 
 impl InterceptorInterpreter {
@@ -50,14 +58,36 @@ impl InterceptorInterpreter {
         new_plan: Option<Plan>,
         query_kind: String,
     ) -> Self {
+        let audit_event_type = new_plan.as_ref().and_then(AuditEventType::from_plan);
         InterceptorInterpreter {
             ctx: ctx.clone(),
             plan,
             new_plan,
             inner,
-            query_log: InterpreterQueryLog::create(ctx.clone(), query_kind),
+            query_log: InterpreterQueryLog::create(ctx.clone(), query_kind.clone()),
             source_pipe_builder: Mutex::new(None),
             management_mode_access: ManagementModeAccess::create(ctx),
+            query_kind,
+            audit_event_type,
+            audited: AtomicBool::new(false),
+        }
+    }
+
+    // Audit the statement once, either when it fails to execute or when it finishes.
+    fn audit(&self, err: Option<&ErrorCode>) {
+        let event_type = match self.audit_event_type {
+            None => return,
+            Some(event_type) => event_type,
+        };
+        if self.audited.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let session_mgr = self.ctx.get_current_session().get_session_manager();
+        let audit_logger = session_mgr.get_audit_logger();
+        if audit_logger.is_enabled() {
+            let event = AuditEvent::statement(&self.ctx, event_type, &self.query_kind, err);
+            audit_logger.log(&session_mgr, event);
         }
     }
 }
@@ -86,6 +116,7 @@ impl Interpreter for InterceptorInterpreter {
             Ok(s) => s,
             Err(e) => {
                 self.ctx.set_error(e.clone());
+                self.audit(Some(&e));
                 return Err(e);
             }
         };
@@ -121,6 +152,7 @@ impl Interpreter for InterceptorInterpreter {
                 .query_finish(now)
         }
         let error = self.ctx.get_error_value();
+        self.audit(error.as_ref());
         self.query_log.log_finish(now, error).await
     }
 
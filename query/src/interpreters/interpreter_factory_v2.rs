@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use common_exception::Result;
+use common_meta_types::MaskingPolicy;
+use common_meta_types::RowAccessPolicy;
 use common_planners::EmptyPlan;
 use common_planners::PlanNode;
 
@@ -128,12 +130,37 @@ impl InterpreterFactoryV2 {
             Plan::DropTableClusterKey(drop_table_cluster_key) => Ok(Arc::new(
                 DropTableClusterKeyInterpreter::try_create(ctx, *drop_table_cluster_key.clone())?,
             )),
+            Plan::AddTableColumn(add_table_column) => Ok(Arc::new(
+                AddTableColumnInterpreter::try_create(ctx, *add_table_column.clone())?,
+            )),
+            Plan::DropTableColumn(drop_table_column) => Ok(Arc::new(
+                DropTableColumnInterpreter::try_create(ctx, *drop_table_column.clone())?,
+            )),
+            Plan::RenameTableColumn(rename_table_column) => Ok(Arc::new(
+                RenameTableColumnInterpreter::try_create(ctx, *rename_table_column.clone())?,
+            )),
+            Plan::AddTableRowAccessPolicy(add_policy) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *add_policy.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(drop_policy) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *drop_policy.clone())?,
+            )),
+            Plan::SetTableColumnMaskingPolicy(set_policy) => Ok(Arc::new(
+                SetTableColumnMaskingPolicyInterpreter::try_create(ctx, *set_policy.clone())?,
+            )),
+            Plan::UnsetTableColumnMaskingPolicy(unset_policy) => Ok(Arc::new(
+                UnsetTableColumnMaskingPolicyInterpreter::try_create(ctx, *unset_policy.clone())?,
+            )),
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
             Plan::OptimizeTable(optimize_table) => Ok(Arc::new(
                 OptimizeTableInterpreter::try_create(ctx, *optimize_table.clone())?,
             )),
+            Plan::AnalyzeTable(analyze_table) => Ok(Arc::new(AnalyzeTableInterpreter::try_create(
+                ctx,
+                *analyze_table.clone(),
+            )?)),
             Plan::ExistsTable(exists_table) => Ok(Arc::new(ExistsTableInterpreter::try_create(
                 ctx,
                 *exists_table.clone(),
@@ -174,6 +201,18 @@ impl InterpreterFactoryV2 {
                 *delete.clone(),
             )?)),
 
+            Plan::Update(update) => Ok(Arc::new(UpdateInterpreter::try_create(
+                ctx,
+                *update.clone(),
+            )?)),
+
+            Plan::Merge(merge) => Ok(Arc::new(MergeInterpreter::try_create(ctx, *merge.clone())?)),
+
+            // Transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Rollback => Ok(Arc::new(RollbackInterpreter::try_create(ctx)?)),
+
             // Roles
             Plan::CreateRole(create_role) => Ok(Arc::new(CreateRoleInterpreter::try_create(
                 ctx,
@@ -236,6 +275,48 @@ impl InterpreterFactoryV2 {
                 *drop_udf.clone(),
             )?)),
 
+            // Row access policies
+            Plan::CreateRowAccessPolicy(create_policy) => {
+                Ok(Arc::new(CreatePolicyInterpreter::try_create(
+                    ctx,
+                    create_policy.if_not_exists,
+                    create_policy.policy.clone(),
+                )?))
+            }
+            Plan::DropRowAccessPolicy(drop_policy) => {
+                let interpreter = DropPolicyInterpreter::<RowAccessPolicy>::try_create(
+                    ctx,
+                    drop_policy.if_exists,
+                    drop_policy.name.clone(),
+                )?;
+                Ok(Arc::new(interpreter))
+            }
+
+            // Masking policies
+            Plan::CreateMaskingPolicy(create_policy) => {
+                Ok(Arc::new(CreatePolicyInterpreter::try_create(
+                    ctx,
+                    create_policy.if_not_exists,
+                    create_policy.policy.clone(),
+                )?))
+            }
+            Plan::DropMaskingPolicy(drop_policy) => {
+                let interpreter = DropPolicyInterpreter::<MaskingPolicy>::try_create(
+                    ctx,
+                    drop_policy.if_exists,
+                    drop_policy.name.clone(),
+                )?;
+                Ok(Arc::new(interpreter))
+            }
+
+            // Network policies
+            Plan::CreateNetworkPolicy(create_policy) => Ok(Arc::new(
+                CreateNetworkPolicyInterpreter::try_create(ctx, *create_policy.clone())?,
+            )),
+            Plan::DropNetworkPolicy(drop_policy) => Ok(Arc::new(
+                DropNetworkPolicyInterpreter::try_create(ctx, *drop_policy.clone())?,
+            )),
+
             Plan::Presign(presign) => Ok(Arc::new(PresignInterpreter::try_create(
                 ctx,
                 *presign.clone(),
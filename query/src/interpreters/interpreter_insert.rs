@@ -29,6 +29,7 @@ use parking_lot::Mutex;
 
 use super::commit2table;
 use super::interpreter_common::append2table;
+use super::interpreter_common::validate_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::processors::port::OutputPort;
@@ -90,6 +91,7 @@ impl Interpreter for InsertInterpreter {
             .ctx
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
+        validate_user_storage_quota(&self.ctx, &plan.catalog).await?;
         let mut pipeline = self.create_new_pipeline().await?;
         let mut builder = SourcePipeBuilder::create();
         if self.async_insert {
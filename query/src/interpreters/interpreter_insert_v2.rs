@@ -25,6 +25,7 @@ use parking_lot::Mutex;
 
 use super::commit2table;
 use super::interpreter_common::append2table;
+use super::interpreter_common::validate_user_storage_quota;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::interpreters::SelectInterpreterV2;
@@ -67,6 +68,7 @@ impl InsertInterpreterV2 {
             .ctx
             .get_table(&plan.catalog, &plan.database, &plan.table)
             .await?;
+        validate_user_storage_quota(&self.ctx, &plan.catalog).await?;
 
         let mut pipeline = self.create_new_pipeline().await?;
         let mut builder = SourcePipeBuilder::create();
@@ -0,0 +1,130 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::MergeOperation;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::interpreter_common::validate_user_storage_quota;
+use crate::interpreters::Interpreter;
+use crate::interpreters::SelectInterpreterV2;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::plans::Merge;
+use crate::sql::plans::Plan;
+
+/// interprets Merge
+pub struct MergeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: Merge,
+}
+
+impl MergeInterpreter {
+    /// Create the MergeInterpreter from Merge
+    pub fn try_create(ctx: Arc<QueryContext>, plan: Merge) -> Result<Self> {
+        Ok(MergeInterpreter { ctx, plan })
+    }
+
+    fn required_privileges(&self) -> Vec<UserPrivilegeType> {
+        let plan = &self.plan.plan;
+        let mut privileges = vec![];
+        for clause in &plan.matched_clauses {
+            let privilege = match clause.operation {
+                MergeOperation::Update(_) => UserPrivilegeType::Update,
+                MergeOperation::Delete => UserPrivilegeType::Delete,
+            };
+            if !privileges.contains(&privilege) {
+                privileges.push(privilege);
+            }
+        }
+        if !plan.unmatched_clauses.is_empty() {
+            privileges.push(UserPrivilegeType::Insert);
+        }
+        privileges
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for MergeInterpreter {
+    /// Get the name of current interpreter
+    fn name(&self) -> &str {
+        "MergeInterpreter"
+    }
+
+    /// Get the schema of Merge
+    fn schema(&self) -> DataSchemaRef {
+        self.plan.schema()
+    }
+
+    #[tracing::instrument(level = "debug", name = "merge_interpreter_execute", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan.plan;
+        let catalog_name = plan.catalog_name.as_str();
+        let db_name = plan.database_name.as_str();
+        let tbl_name = plan.table_name.as_str();
+        for privilege in self.required_privileges() {
+            self.ctx
+                .get_current_session()
+                .validate_privilege(
+                    &GrantObject::Table(
+                        catalog_name.to_string(),
+                        db_name.to_string(),
+                        tbl_name.to_string(),
+                    ),
+                    privilege,
+                )
+                .await?;
+        }
+
+        validate_user_storage_quota(&self.ctx, catalog_name).await?;
+
+        // the join of the target and the source is planned by the binder, its rows are
+        // fed to the table, which applies the clauses of the plan to them
+        let select_interpreter = match self.plan.join.as_ref() {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => SelectInterpreterV2::try_create(
+                self.ctx.clone(),
+                *bind_context.clone(),
+                s_expr.clone(),
+                metadata.clone(),
+            )?,
+            _ => {
+                return Err(ErrorCode::LogicalError(
+                    "the join of MERGE INTO should be a query",
+                ));
+            }
+        };
+        let joined = select_interpreter.execute().await?;
+
+        let tbl = self.ctx.get_table(catalog_name, db_name, tbl_name).await?;
+        tbl.merge(self.ctx.clone(), plan.clone(), joined).await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use common_datavalues::DataSchema;
+use common_exception::Result;
+use common_management::Policy;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Drops a row access policy or a masking policy.
+#[derive(Debug)]
+pub struct DropPolicyInterpreter<P: Policy> {
+    ctx: Arc<QueryContext>,
+    if_exists: bool,
+    name: String,
+    _policy: PhantomData<P>,
+}
+
+impl<P: Policy> DropPolicyInterpreter<P> {
+    pub fn try_create(ctx: Arc<QueryContext>, if_exists: bool, name: String) -> Result<Self> {
+        Ok(DropPolicyInterpreter {
+            ctx,
+            if_exists,
+            name,
+            _policy: PhantomData,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<P: Policy> Interpreter for DropPolicyInterpreter<P> {
+    fn name(&self) -> &str {
+        "DropPolicyInterpreter"
+    }
+
+    #[tracing::instrument(level = "info", skip(self), fields(ctx.id = self.ctx.get_id().as_str()))]
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        self.ctx
+            .get_current_session()
+            .validate_privilege(&GrantObject::Global, UserPrivilegeType::Super)
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = self.ctx.get_user_manager();
+        user_mgr
+            .drop_policy::<P>(&tenant, &self.name, self.if_exists)
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            Arc::new(DataSchema::empty()),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -37,14 +37,14 @@ use crate::sql::MetadataRef;
 pub struct SelectInterpreterV2 {
     ctx: Arc<QueryContext>,
     s_expr: SExpr,
-    bind_context: BindContext,
+    bind_context: BindContext<'static>,
     metadata: MetadataRef,
 }
 
 impl SelectInterpreterV2 {
     pub fn try_create(
         ctx: Arc<QueryContext>,
-        bind_context: BindContext,
+        bind_context: BindContext<'static>,
         s_expr: SExpr,
         metadata: MetadataRef,
     ) -> Result<Self> {
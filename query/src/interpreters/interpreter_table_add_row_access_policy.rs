@@ -0,0 +1,148 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataType;
+use common_datavalues::TypeFactory;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::GrantObject;
+use common_meta_types::MatchSeq;
+use common_meta_types::RowAccessPolicy;
+use common_meta_types::UserPrivilegeType;
+use common_planners::AddTableRowAccessPolicyPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::get_row_access_policy;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY;
+use crate::sql::OPT_KEY_ROW_ACCESS_POLICY_COLUMNS;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(
+                    plan.catalog.clone(),
+                    plan.database.clone(),
+                    plan.table.clone(),
+                ),
+                UserPrivilegeType::Alter,
+            )
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        if let Some((policy, _)) = get_row_access_policy(table_info.options()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Table {} already has row access policy {}, drop it first",
+                plan.table, policy
+            )));
+        }
+
+        let policy = self
+            .ctx
+            .get_user_manager()
+            .get_policy::<RowAccessPolicy>(&tenant, &plan.policy)
+            .await?;
+        if policy.parameters.len() != plan.columns.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Row access policy {} requires {} columns, but got: {}",
+                plan.policy,
+                policy.parameters.len(),
+                plan.columns.len()
+            )));
+        }
+
+        // The columns are bound to the parameters as they are, so their types must match.
+        let schema = table.schema();
+        for (column, parameter_type) in plan.columns.iter().zip(policy.parameter_types.iter()) {
+            let field = schema
+                .fields()
+                .iter()
+                .find(|field| &field.name().to_lowercase() == column)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "Unknown column {} of table {}",
+                        column, plan.table
+                    ))
+                })?;
+            let parameter_type = TypeFactory::instance().get(parameter_type)?;
+            if remove_nullable(field.data_type()).data_type_id()
+                != remove_nullable(&parameter_type).data_type_id()
+            {
+                return Err(ErrorCode::IllegalDataType(format!(
+                    "Column {} of type {} can't be bound to the parameter of type {} in row access policy {}",
+                    column,
+                    field.data_type().name(),
+                    parameter_type.name(),
+                    plan.policy
+                )));
+            }
+        }
+
+        let mut options = HashMap::new();
+        options.insert(
+            OPT_KEY_ROW_ACCESS_POLICY.to_string(),
+            Some(plan.policy.clone()),
+        );
+        options.insert(
+            OPT_KEY_ROW_ACCESS_POLICY_COLUMNS.to_string(),
+            Some(plan.columns.join(",")),
+        );
+        catalog
+            .upsert_table_option(UpsertTableOptionReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                options,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
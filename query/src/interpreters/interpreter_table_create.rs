@@ -18,6 +18,7 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchemaRefExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
 use common_meta_types::GrantObject;
 use common_meta_types::UserPrivilegeType;
 use common_planners::CreateTablePlan;
@@ -31,6 +32,7 @@ use super::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_CREATED_BY;
 use crate::storages::StorageDescription;
 
 pub struct CreateTableInterpreter {
@@ -125,7 +127,7 @@ impl CreateTableInterpreter {
         let catalog = self.ctx.get_catalog(&self.plan.catalog)?;
 
         // TODO: maybe the table creation and insertion should be a transaction, but it may require create_table support 2pc.
-        catalog.create_table(self.plan.clone().into()).await?;
+        catalog.create_table(self.build_request()?).await?;
         let table = catalog
             .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
             .await?;
@@ -170,7 +172,7 @@ impl CreateTableInterpreter {
 
     async fn create_table(&self) -> Result<SendableDataBlockStream> {
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str())?;
-        catalog.create_table(self.plan.clone().into()).await?;
+        catalog.create_table(self.build_request()?).await?;
 
         Ok(Box::pin(DataBlockStream::create(
             self.plan.schema(),
@@ -178,4 +180,14 @@ impl CreateTableInterpreter {
             vec![],
         )))
     }
+
+    fn build_request(&self) -> Result<CreateTableReq> {
+        let mut req: CreateTableReq = self.plan.clone().into();
+        // Record the owner of the table to account the storage quota of the user.
+        let user = self.ctx.get_current_user()?;
+        req.table_meta
+            .options
+            .insert(OPT_KEY_CREATED_BY.to_string(), user.identity().to_string());
+        Ok(req)
+    }
 }
@@ -0,0 +1,85 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_types::GrantObject;
+use common_meta_types::UserPrivilegeType;
+use common_planners::RenameTableColumnPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::masking_policy_opt_key;
+
+pub struct RenameTableColumnInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RenameTableColumnPlan,
+}
+
+impl RenameTableColumnInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RenameTableColumnPlan) -> Result<Self> {
+        Ok(RenameTableColumnInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RenameTableColumnInterpreter {
+    fn name(&self) -> &str {
+        "RenameTableColumnInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(
+                    plan.catalog.clone(),
+                    plan.database.clone(),
+                    plan.table.clone(),
+                ),
+                UserPrivilegeType::Alter,
+            )
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+
+        let table = catalog
+            .get_table(tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        // The masking policy is attached to the column by name, renaming the column would
+        // silently detach it and expose the unmasked values.
+        let opt_key = masking_policy_opt_key(&plan.old_column.to_lowercase());
+        if let Some(policy) = table.get_table_info().options().get(&opt_key) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} of table {} has masking policy {}, unset it before renaming",
+                plan.old_column, plan.table, policy
+            )));
+        }
+
+        table.rename_column(self.ctx.clone(), plan.clone()).await?;
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
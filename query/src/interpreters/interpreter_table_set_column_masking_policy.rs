@@ -0,0 +1,140 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataType;
+use common_datavalues::TypeFactory;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::GrantObject;
+use common_meta_types::MaskingPolicy;
+use common_meta_types::MatchSeq;
+use common_meta_types::UserPrivilegeType;
+use common_planners::SetTableColumnMaskingPolicyPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::masking_policy_opt_key;
+
+pub struct SetTableColumnMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetTableColumnMaskingPolicyPlan,
+}
+
+impl SetTableColumnMaskingPolicyInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: SetTableColumnMaskingPolicyPlan,
+    ) -> Result<Self> {
+        Ok(SetTableColumnMaskingPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetTableColumnMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "SetTableColumnMaskingPolicyInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(
+                    plan.catalog.clone(),
+                    plan.database.clone(),
+                    plan.table.clone(),
+                ),
+                UserPrivilegeType::Alter,
+            )
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        let schema = table.schema();
+        let field = schema
+            .fields()
+            .iter()
+            .find(|field| field.name().to_lowercase() == plan.column)
+            .ok_or_else(|| {
+                ErrorCode::UnknownColumn(format!(
+                    "Unknown column {} of table {}",
+                    plan.column, plan.table
+                ))
+            })?;
+
+        let table_info = table.get_table_info();
+        let opt_key = masking_policy_opt_key(&plan.column);
+        if let Some(policy) = table_info.options().get(&opt_key) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} of table {} already has masking policy {}, unset it first",
+                plan.column, plan.table, policy
+            )));
+        }
+
+        let policy = self
+            .ctx
+            .get_user_manager()
+            .get_policy::<MaskingPolicy>(&tenant, &plan.policy)
+            .await?;
+
+        // The column is bound to the parameter as it is, so their types must match.
+        let parameter_type = policy.parameter_types.first().ok_or_else(|| {
+            ErrorCode::IllegalMaskingPolicyFormat(format!(
+                "Masking policy {} has no parameter",
+                plan.policy
+            ))
+        })?;
+        let parameter_type = TypeFactory::instance().get(parameter_type)?;
+        if remove_nullable(field.data_type()).data_type_id()
+            != remove_nullable(&parameter_type).data_type_id()
+        {
+            return Err(ErrorCode::IllegalDataType(format!(
+                "Column {} of type {} can't be bound to the parameter of type {} in masking policy {}",
+                plan.column,
+                field.data_type().name(),
+                parameter_type.name(),
+                plan.policy
+            )));
+        }
+
+        let mut options = HashMap::new();
+        options.insert(opt_key, Some(plan.policy.clone()));
+        catalog
+            .upsert_table_option(UpsertTableOptionReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                options,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::GrantObject;
+use common_meta_types::MatchSeq;
+use common_meta_types::UserPrivilegeType;
+use common_planners::UnsetTableColumnMaskingPolicyPlan;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use super::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::masking_policy_opt_key;
+
+pub struct UnsetTableColumnMaskingPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: UnsetTableColumnMaskingPolicyPlan,
+}
+
+impl UnsetTableColumnMaskingPolicyInterpreter {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        plan: UnsetTableColumnMaskingPolicyPlan,
+    ) -> Result<Self> {
+        Ok(UnsetTableColumnMaskingPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for UnsetTableColumnMaskingPolicyInterpreter {
+    fn name(&self) -> &str {
+        "UnsetTableColumnMaskingPolicyInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let plan = &self.plan;
+        self.ctx
+            .get_current_session()
+            .validate_privilege(
+                &GrantObject::Table(
+                    plan.catalog.clone(),
+                    plan.database.clone(),
+                    plan.table.clone(),
+                ),
+                UserPrivilegeType::Alter,
+            )
+            .await?;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&plan.catalog)?;
+        let table = catalog
+            .get_table(tenant.as_str(), &plan.database, &plan.table)
+            .await?;
+
+        let table_info = table.get_table_info();
+        let opt_key = masking_policy_opt_key(&plan.column);
+        if !table_info.options().contains_key(&opt_key) {
+            return Err(ErrorCode::UnknownMaskingPolicy(format!(
+                "Column {} of table {} has no masking policy",
+                plan.column, plan.table
+            )));
+        }
+
+        let mut options = HashMap::new();
+        options.insert(opt_key, None);
+        catalog
+            .upsert_table_option(UpsertTableOptionReq {
+                table_id: table_info.ident.table_id,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                options,
+            })
+            .await?;
+
+        Ok(Box::pin(DataBlockStream::create(
+            self.plan.schema(),
+            None,
+            vec![],
+        )))
+    }
+}
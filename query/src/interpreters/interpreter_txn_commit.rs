@@ -0,0 +1,98 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_catalog::txn::TxnTableWrites;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+
+use crate::interpreters::purge_txn_writes;
+use crate::interpreters::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        // The transaction is ended anyway, it is rolled back if the commit fails.
+        let writes = self.ctx.get_txn_manager().commit();
+        if let Err(e) = self.commit(&writes).await {
+            purge_txn_writes(self.ctx.clone(), writes).await;
+            return Err(e);
+        }
+
+        // e.g. the history of the transient tables is purged once the writes are committed
+        for write in writes {
+            write.table.txn_committed(self.ctx.clone()).await?;
+        }
+
+        let schema = Arc::new(DataSchema::empty());
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+    }
+}
+
+impl CommitInterpreter {
+    async fn commit(&self, writes: &[TxnTableWrites]) -> Result<()> {
+        let mut update_table_metas = BTreeMap::<&str, Vec<_>>::new();
+        for write in writes {
+            update_table_metas
+                .entry(write.catalog_name.as_str())
+                .or_default()
+                .push(write.update_table_meta_req());
+        }
+        if update_table_metas.len() > 1 {
+            return Err(ErrorCode::UnImplement(
+                "Transaction across catalogs is not supported, the transaction is rolled back",
+            ));
+        }
+
+        // All the tables are committed by a single meta transaction, which fails if
+        // any of the tables has been changed by a concurrent commit.
+        for (catalog_name, update_table_metas) in update_table_metas {
+            let catalog = self.ctx.get_catalog(catalog_name)?;
+            let req = UpdateMultiTableMetaReq { update_table_metas };
+            catalog.update_multi_table_meta(req).await.map_err(|e| {
+                if e.code() == ErrorCode::table_version_mismatched_code() {
+                    ErrorCode::TableVersionMismatched(format!(
+                        "Transaction conflicts with a concurrent commit and is rolled back, please retry it. {}",
+                        e.message()
+                    ))
+                } else {
+                    e
+                }
+            })?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_catalog::txn::TxnTableWrites;
+use common_datavalues::DataSchema;
+use common_exception::Result;
+use common_streams::DataBlockStream;
+use common_streams::SendableDataBlockStream;
+use tracing::warn;
+
+use crate::interpreters::Interpreter;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::storages::Table;
+
+pub struct RollbackInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl RollbackInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(RollbackInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RollbackInterpreter {
+    fn name(&self) -> &str {
+        "RollbackInterpreter"
+    }
+
+    async fn execute(&self) -> Result<SendableDataBlockStream> {
+        let writes = self.ctx.get_txn_manager().rollback();
+        purge_txn_writes(self.ctx.clone(), writes).await;
+        let schema = Arc::new(DataSchema::empty());
+        Ok(Box::pin(DataBlockStream::create(schema, None, vec![])))
+    }
+}
+
+/// Purges the data written by a transaction which is rolled back, they are never
+/// committed and can't be collected by the GC of the tables. The transaction has
+/// been ended, so the errors are only logged.
+pub async fn purge_txn_writes(ctx: Arc<QueryContext>, writes: Vec<TxnTableWrites>) {
+    for write in writes {
+        if let Err(e) = write
+            .base_table
+            .purge_txn_writes(ctx.clone(), &write.written_locations)
+            .await
+        {
+            warn!(
+                "failed to purge the data written by the rolled back transaction, table {}, locations {:?}, the error: {}",
+                write.base_table.name(),
+                write.written_locations,
+                e
+            );
+        }
+    }
+}
@@ -38,6 +38,11 @@ mod interpreter_insert;
 mod interpreter_insert_v2;
 mod interpreter_kill;
 mod interpreter_list;
+mod interpreter_merge;
+mod interpreter_network_policy_create;
+mod interpreter_network_policy_drop;
+mod interpreter_policy_create;
+mod interpreter_policy_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
@@ -60,16 +65,28 @@ mod interpreter_show_stages;
 mod interpreter_show_tables;
 mod interpreter_show_tables_status;
 mod interpreter_show_users;
+mod interpreter_table_add_column;
+mod interpreter_table_add_row_access_policy;
+mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_create_v2;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
+mod interpreter_table_drop_column;
+mod interpreter_table_drop_row_access_policy;
 mod interpreter_table_exists;
 mod interpreter_table_optimize;
 mod interpreter_table_rename;
+mod interpreter_table_rename_column;
+mod interpreter_table_set_column_masking_policy;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
 mod interpreter_table_undrop;
+mod interpreter_table_unset_column_masking_policy;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
+mod interpreter_txn_rollback;
+mod interpreter_update;
 mod interpreter_use_database;
 mod interpreter_user_alter;
 mod interpreter_user_create;
@@ -117,6 +134,11 @@ pub use interpreter_insert::InsertInterpreter;
 pub use interpreter_insert_v2::InsertInterpreterV2;
 pub use interpreter_kill::KillInterpreter;
 pub use interpreter_list::ListInterpreter;
+pub use interpreter_merge::MergeInterpreter;
+pub use interpreter_network_policy_create::CreateNetworkPolicyInterpreter;
+pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
+pub use interpreter_policy_create::CreatePolicyInterpreter;
+pub use interpreter_policy_drop::DropPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
 pub use interpreter_query_log::InterpreterQueryLog;
@@ -140,15 +162,28 @@ pub use interpreter_show_stages::ShowStagesInterpreter;
 pub use interpreter_show_tables::ShowTablesInterpreter;
 pub use interpreter_show_tables_status::ShowTablesStatusInterpreter;
 pub use interpreter_show_users::ShowUsersInterpreter;
+pub use interpreter_table_add_column::AddTableColumnInterpreter;
+pub use interpreter_table_add_row_access_policy::AddTableRowAccessPolicyInterpreter;
+pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_drop_column::DropTableColumnInterpreter;
+pub use interpreter_table_drop_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
+pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_set_column_masking_policy::SetTableColumnMaskingPolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
+pub use interpreter_table_unset_column_masking_policy::UnsetTableColumnMaskingPolicyInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
+pub use interpreter_txn_rollback::purge_txn_writes;
+pub use interpreter_txn_rollback::RollbackInterpreter;
+pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
 pub use interpreter_user_alter::AlterUserInterpreter;
 pub use interpreter_user_create::CreateUserInterpreter;
@@ -25,6 +25,7 @@
 #![feature(result_option_inspect)]
 
 pub mod api;
+pub mod audit;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
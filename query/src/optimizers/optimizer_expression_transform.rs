@@ -361,6 +361,7 @@ impl PlanRewriter for ExprTransformImpl {
                     partitions_scanned: 0,
                     partitions_total: 0,
                     is_exact: true,
+                    pruning: None,
                 },
                 description: format!("(Read from {} table)", plan.source_info.desc()),
                 tbl_args: plan.tbl_args.clone(),
@@ -15,10 +15,12 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_base::base::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_pipeline::processors::profile::thread_cpu_time;
 use petgraph::prelude::NodeIndex;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
@@ -79,7 +81,16 @@ impl ExecutorWorkerContext {
     }
 
     unsafe fn execute_sync_task(&mut self, processor: ProcessorPtr) -> Result<Option<NodeIndex>> {
-        processor.process()?;
+        match processor.profile() {
+            None => processor.process()?,
+            Some(profile) => {
+                let instant = Instant::now();
+                let cpu_time = thread_cpu_time();
+                processor.process()?;
+                profile.add_cpu_time(thread_cpu_time().saturating_sub(cpu_time));
+                profile.add_wall_time(instant.elapsed());
+            }
+        }
         Ok(Some(processor.id()))
     }
 
@@ -18,6 +18,7 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::thread::JoinHandle;
 
+use common_base::base::MemoryTracker;
 use common_base::base::Runtime;
 use common_base::base::Thread;
 use common_exception::ErrorCode;
@@ -218,6 +219,7 @@ impl PipelineExecutor {
     pub unsafe fn execute_single_thread(&self, thread_num: usize) -> Result<()> {
         let workers_condvar = self.workers_condvar.clone();
         let mut context = ExecutorWorkerContext::create(thread_num, workers_condvar);
+        let memory_tracker = MemoryTracker::current();
 
         while !self.global_tasks_queue.is_finished() && !self.need_abort() {
             // When there are not enough tasks, the thread will be blocked, so we need loop check.
@@ -232,6 +234,11 @@ impl PipelineExecutor {
                     let schedule_queue = self.graph.schedule_queue(executed_pid)?;
                     schedule_queue.schedule(&self.global_tasks_queue, &mut context);
                 }
+
+                // Abort the query if it uses more memory than the quota of the user.
+                if let Some(memory_tracker) = &memory_tracker {
+                    memory_tracker.check_memory_limit()?;
+                }
             }
         }
 
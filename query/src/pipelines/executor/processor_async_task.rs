@@ -17,10 +17,12 @@ use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Context;
 use std::task::Poll;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_pipeline::processors::processor::ProcessorPtr;
+use common_pipeline::processors::profile::thread_cpu_time;
 use futures_util::future::BoxFuture;
 use futures_util::future::Either;
 use futures_util::FutureExt;
@@ -34,6 +36,9 @@ pub struct ProcessorAsyncTask {
     processor: ProcessorPtr,
     queue: Arc<ExecutorTasksQueue>,
     workers_condvar: Arc<WorkersCondvar>,
+    // The time when the task is spawned, the wall time of the processor is until
+    // the task is completed.
+    instant: Instant,
     inner: BoxFuture<'static, Result<()>>,
 }
 
@@ -63,6 +68,7 @@ impl ProcessorAsyncTask {
             processor,
             queue,
             workers_condvar,
+            instant: Instant::now(),
             inner: inner.boxed(),
         }
     }
@@ -76,12 +82,22 @@ impl Future for ProcessorAsyncTask {
             return Poll::Ready(());
         }
 
+        let profile = unsafe { self.processor.profile().cloned() };
+        let cpu_time = profile.as_ref().map(|_| thread_cpu_time());
         let inner = self.inner.as_mut();
         let try_result =
             std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || -> Poll<Result<()>> {
                 inner.poll(cx)
             }));
 
+        if let (Some(profile), Some(cpu_time)) = (&profile, cpu_time) {
+            // Only the time spent in polling is the CPU time of the processor
+            profile.add_cpu_time(thread_cpu_time().saturating_sub(cpu_time));
+            if !matches!(try_result, Ok(Poll::Pending)) {
+                profile.add_wall_time(self.instant.elapsed());
+            }
+        }
+
         match try_result {
             Ok(Poll::Pending) => Poll::Pending,
             Ok(Poll::Ready(res)) => {
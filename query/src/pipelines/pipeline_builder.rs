@@ -57,6 +57,7 @@ use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformLimitBy;
 use crate::pipelines::processors::TransformSortMerge;
 use crate::pipelines::processors::TransformSortPartial;
+use crate::pipelines::processors::TransformSortSpill;
 use crate::pipelines::Pipe;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -399,6 +400,26 @@ impl PlanVisitor for QueryPipelineBuilder {
                 )
             })?;
 
+        // processor1 sorted block --
+        //                             \
+        // processor2 sorted block ----> processor  --> spill sorted runs and merge them
+        //                             /
+        // processor3 sorted block --
+        if self.ctx.get_settings().get_sort_spill_threshold_bytes()? != 0 {
+            self.main_pipeline.resize(1)?;
+            return self
+                .main_pipeline
+                .add_transform(|transform_input_port, transform_output_port| {
+                    TransformSortSpill::try_create(
+                        self.ctx.clone(),
+                        transform_input_port,
+                        transform_output_port,
+                        rows_limit,
+                        get_sort_descriptions(&plan.schema, &plan.order_by)?,
+                    )
+                });
+        }
+
         // processor 1: [sorted blocks ...] ---> merge to one sorted block
         // processor 2: [sorted blocks ...] ---> merge to one sorted block
         // processor 3: [sorted blocks ...] ---> merge to one sorted block
@@ -41,6 +41,8 @@ pub use sources::SyncReceiverSource;
 pub use sources::SyncSource;
 pub use sources::SyncSourcer;
 pub use transforms::AggregatorParams;
+pub use transforms::AggregatorSpiller;
+pub use transforms::AggregatorState;
 pub use transforms::AggregatorTransformParams;
 pub use transforms::BlockCompactor;
 pub use transforms::ExpressionTransform;
@@ -56,11 +58,14 @@ pub use transforms::KeyU512HashTable;
 pub use transforms::KeyU64HashTable;
 pub use transforms::KeyU8HashTable;
 pub use transforms::MarkJoinCompactor;
+pub use transforms::PolymorphicKeysHelper;
 pub use transforms::ProjectionTransform;
+pub use transforms::RightJoinCompactor;
 pub use transforms::SerializerHashTable;
 pub use transforms::SinkBuildHashTable;
 pub use transforms::SortMergeCompactor;
 pub use transforms::TransformAddOn;
+pub use transforms::TransformAggregateExpand;
 pub use transforms::TransformAggregator;
 pub use transforms::TransformBlockCompact;
 pub use transforms::TransformCastSchema;
@@ -74,3 +79,6 @@ pub use transforms::TransformLimit;
 pub use transforms::TransformLimitBy;
 pub use transforms::TransformSortMerge;
 pub use transforms::TransformSortPartial;
+pub use transforms::TransformSortSpill;
+pub use transforms::TransformWindow;
+pub use transforms::WindowCompactor;
@@ -54,6 +54,8 @@ pub struct FileSplitterCore {
 
     input_format: Arc<dyn InputFormat>,
     format_state: Box<dyn InputState>,
+    /// The file size and the tail of the file read ahead, see `InputFormat::tail_size`.
+    tail: Option<(usize, Arc<Vec<u8>>)>,
 }
 
 pub struct FileSplitterCompressed {
@@ -107,10 +109,10 @@ impl Splitter for FileSplitterCore {
             if skip_rows == self.rows_to_skip as usize {
                 self.rows_to_skip = 0;
                 self.input_format.take_buf(&mut self.format_state);
-                self.format_state = self.input_format.create_state();
                 self.start_offset += skip_size;
                 self.offset += skip_size;
                 self.start_offset = self.offset;
+                self.format_state = self.create_state()?;
                 data_slice = &data_slice[skip_size..];
                 self.start_row = skip_rows;
             } else {
@@ -129,7 +131,7 @@ impl Splitter for FileSplitterCore {
             if is_full {
                 let buf = self.input_format.take_buf(&mut self.format_state);
                 let rows = self.input_format.read_row_num(&mut self.format_state)?;
-                self.format_state = self.input_format.create_state();
+                self.format_state = self.create_state()?;
                 let split = FileSplit {
                     path: self.path.clone(),
                     start_offset: self.start_offset,
@@ -159,6 +161,18 @@ impl Splitter for FileSplitterCore {
     }
 }
 
+impl FileSplitterCore {
+    // The state reading the file from the current offset.
+    fn create_state(&self) -> Result<Box<dyn InputState>> {
+        let mut state = self.input_format.create_state();
+        if let Some((file_size, tail)) = &self.tail {
+            self.input_format
+                .set_tail(&mut state, *file_size, tail.clone(), self.offset)?;
+        }
+        Ok(state)
+    }
+}
+
 impl Splitter for FileSplitterCompressed {
     fn split(&mut self, data: &[u8], output_splits: &mut VecDeque<FileSplit>) -> Result<()> {
         let decoder = &mut self.decoder;
@@ -216,6 +230,7 @@ impl FileSplitter {
             rows_to_skip: format_settings.skip_header,
             input_format: input_format.clone(),
             format_state: input_format.create_state(),
+            tail: None,
         };
         let inner = match compress_algorithm {
             None => Splitters::Simple(core),
@@ -233,6 +248,20 @@ impl FileSplitter {
         }
     }
 
+    /// Sets the tail of the file read ahead, see `InputFormat::tail_size`.
+    pub fn set_tail(&mut self, file_size: usize, tail: Vec<u8>) -> Result<()> {
+        match &mut self.inner {
+            Splitters::Simple(core) => {
+                core.tail = Some((file_size, Arc::new(tail)));
+                core.format_state = core.create_state()?;
+                Ok(())
+            }
+            Splitters::Compressed(_) => Err(ErrorCode::LogicalError(
+                "The tail of compressed file can't be read ahead",
+            )),
+        }
+    }
+
     pub fn state(&self) -> FileSplitterState {
         self.state
     }
@@ -27,6 +27,7 @@ use common_meta_types::StageFileCompression;
 use common_storage::init_operator;
 use common_storage::StorageParams;
 use opendal::io_util::CompressAlgorithm;
+use opendal::Object;
 use opendal::Operator;
 use parking_lot::Mutex;
 
@@ -95,17 +96,48 @@ impl MultiFileSplitter {
             }
         };
         let object = op.object(path);
+        let compression_algo = self.get_compression_algo(path)?;
+        // The compressed file can only be read from the start.
+        let tail = match compression_algo {
+            None => self.read_tail(&object).await?,
+            Some(_) => None,
+        };
         let reader = object.reader().await?;
-        self.current_file = Some(FileSplitter::create(
+        let mut splitter = FileSplitter::create(
             reader,
             Some(path.to_string()),
             self.input_format.clone(),
             self.format_settings.clone(),
-            self.get_compression_algo(path)?,
-        ));
+            compression_algo,
+        );
+        if let Some((file_size, tail)) = tail {
+            splitter.set_tail(file_size, tail)?;
+        }
+        self.current_file = Some(splitter);
         Ok(())
     }
 
+    // Reads the tail of the file ahead for the formats keeping their metadata at the end
+    // of the file, see `InputFormat::tail_size`.
+    async fn read_tail(&self, object: &Object) -> Result<Option<(usize, Vec<u8>)>> {
+        let mut tail_size = self.input_format.tail_size(&[])?;
+        if tail_size == 0 {
+            return Ok(None);
+        }
+        let file_size = object.metadata().await?.content_length() as usize;
+        if file_size == 0 {
+            return Ok(None);
+        }
+
+        let mut tail = vec![];
+        while tail_size > tail.len() && tail.len() < file_size {
+            let start = file_size.saturating_sub(tail_size);
+            tail = object.range_read(start as u64..file_size as u64).await?;
+            tail_size = self.input_format.tail_size(&tail)?;
+        }
+        Ok(Some((file_size, tail)))
+    }
+
     fn get_compression_algo(&self, path: &str) -> Result<Option<CompressAlgorithm>> {
         let compression_algo = match self.compress_option {
             StageFileCompression::Auto => CompressAlgorithm::from_path(path),
@@ -25,15 +25,19 @@ use common_datablocks::HashMethodKeysU512;
 use common_datablocks::HashMethodKeysU64;
 use common_datablocks::HashMethodKeysU8;
 use common_datablocks::HashMethodSerializer;
+use common_datavalues::DataSchemaRef;
 use common_datavalues::DataType;
 use common_datavalues::MutableColumn;
 use common_datavalues::ScalarColumn;
 use common_datavalues::Series;
 use common_datavalues::StringColumn;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::StateAddr;
 use common_functions::aggregates::StateAddrs;
+use common_hashtable::NUM_BUCKETS;
 
+use crate::pipelines::processors::transforms::aggregator::aggregator_spiller::AggregatorSpiller;
 use crate::pipelines::processors::transforms::group_by::AggregatorState;
 use crate::pipelines::processors::transforms::group_by::GroupColumnsBuilder;
 use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
@@ -71,6 +75,12 @@ pub struct FinalAggregator<
     // used for deserialization only, so we can reuse it during the loop
     temp_place: Option<StateAddr>,
     ctx: Arc<QueryContext>,
+
+    spiller: AggregatorSpiller,
+    // schema of the partial aggregated blocks, used to spill states
+    input_schema: Option<DataSchemaRef>,
+    // the next spilled bucket to merge
+    spilled_bucket: usize,
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + Send>
@@ -87,6 +97,7 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
         } else {
             state.alloc_layout2(&params)
         };
+        let spiller = AggregatorSpiller::create(&ctx)?;
 
         Ok(Self {
             is_generated: false,
@@ -96,8 +107,58 @@ impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + S
             params,
             temp_place,
             ctx,
+            spiller,
+            input_schema: None,
+            spilled_bucket: 0,
         })
     }
+
+    fn reset_state(&mut self) {
+        self.drop_states();
+        self.state = self.method.aggregate_state();
+        self.states_dropped = false;
+        self.temp_place = if self.params.aggregate_functions.is_empty() {
+            None
+        } else {
+            self.state.alloc_layout2(&self.params)
+        };
+    }
+
+    fn spill_states(&mut self) -> Result<()> {
+        let schema = self.input_schema.clone().ok_or_else(|| {
+            ErrorCode::LogicalError("Cannot spill aggregate states without input schema")
+        })?;
+        self.spiller
+            .spill(&self.method, &self.state, &self.params, &schema)?;
+        self.reset_state();
+        Ok(())
+    }
+
+    /// Spill the states to disk if the memory used by them exceeds the threshold.
+    fn try_spill_states(&mut self, block: &DataBlock) -> Result<()> {
+        if self.spiller.should_spill(self.state.allocated_bytes()) {
+            self.input_schema = Some(block.schema().clone());
+            self.spill_states()?;
+        }
+        Ok(())
+    }
+
+    /// Read the spilled states of the next non-empty bucket. The in-memory states are
+    /// spilled first, so that all the states of the same group are in the same bucket.
+    fn next_spilled_bucket(&mut self) -> Result<Option<Vec<DataBlock>>> {
+        if self.spilled_bucket == 0 && self.state.len() != 0 {
+            self.spill_states()?;
+        }
+
+        while self.spilled_bucket < NUM_BUCKETS {
+            let blocks = self.spiller.read_bucket(self.spilled_bucket)?;
+            self.spilled_bucket += 1;
+            if !blocks.is_empty() {
+                return Ok(Some(blocks));
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<true, Method> {
@@ -137,6 +198,42 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     const NAME: &'static str = "GroupByFinalTransform";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        self.merge_block(&block)?;
+        self.try_spill_states(&block)
+    }
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.spiller.is_spilled() {
+            // Merge the spilled states bucket by bucket.
+            while let Some(blocks) = self.next_spilled_bucket()? {
+                for block in blocks.iter() {
+                    self.merge_block(block)?;
+                }
+
+                if self.state.len() != 0 {
+                    let block = self.build_block()?;
+                    self.reset_state();
+                    return Ok(Some(block));
+                }
+            }
+            return Ok(None);
+        }
+
+        match self.state.len() == 0 || self.is_generated {
+            true => {
+                self.drop_states();
+                Ok(None)
+            }
+            false => {
+                self.is_generated = true;
+                Ok(Some(self.build_block()?))
+            }
+        }
+    }
+}
+
+impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<true, Method> {
+    fn merge_block(&mut self, block: &DataBlock) -> Result<()> {
         // 1.1 and 1.2.
         let aggregate_function_len = self.params.aggregate_functions.len();
         let keys_column = block.column(aggregate_function_len);
@@ -179,55 +276,45 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
         Ok(())
     }
 
-    fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.state.len() == 0 || self.is_generated {
-            true => {
-                self.drop_states();
-                Ok(None)
-            }
-            false => {
-                self.is_generated = true;
-                let mut group_columns_builder = self
-                    .method
-                    .group_columns_builder(self.state.len(), &self.params);
-
-                let aggregate_functions = &self.params.aggregate_functions;
-                let offsets_aggregate_states = &self.params.offsets_aggregate_states;
-
-                let mut aggregates_column_builder: Vec<Box<dyn MutableColumn>> = {
-                    let mut values = vec![];
-                    for aggregate_function in aggregate_functions {
-                        let builder = aggregate_function.return_type()?.create_mutable(1024);
-                        values.push(builder)
-                    }
-                    values
-                };
+    fn build_block(&self) -> Result<DataBlock> {
+        let mut group_columns_builder = self
+            .method
+            .group_columns_builder(self.state.len(), &self.params);
 
-                for group_entity in self.state.iter() {
-                    let place: StateAddr = (*group_entity.get_state_value()).into();
+        let aggregate_functions = &self.params.aggregate_functions;
+        let offsets_aggregate_states = &self.params.offsets_aggregate_states;
 
-                    for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
-                        let arg_place = place.next(offsets_aggregate_states[idx]);
-                        let builder: &mut dyn MutableColumn =
-                            aggregates_column_builder[idx].borrow_mut();
-                        aggregate_function.merge_result(arg_place, builder)?;
-                    }
+        let mut aggregates_column_builder: Vec<Box<dyn MutableColumn>> = {
+            let mut values = vec![];
+            for aggregate_function in aggregate_functions {
+                let builder = aggregate_function.return_type()?.create_mutable(1024);
+                values.push(builder)
+            }
+            values
+        };
 
-                    group_columns_builder.append_value(group_entity.get_state_key());
-                }
+        for group_entity in self.state.iter() {
+            let place: StateAddr = (*group_entity.get_state_value()).into();
 
-                // Build final state block.
-                let fields_len = self.params.schema.fields().len();
-                let mut columns = Vec::with_capacity(fields_len);
+            for (idx, aggregate_function) in aggregate_functions.iter().enumerate() {
+                let arg_place = place.next(offsets_aggregate_states[idx]);
+                let builder: &mut dyn MutableColumn = aggregates_column_builder[idx].borrow_mut();
+                aggregate_function.merge_result(arg_place, builder)?;
+            }
 
-                for mut array in aggregates_column_builder {
-                    columns.push(array.to_column());
-                }
+            group_columns_builder.append_value(group_entity.get_state_key());
+        }
 
-                columns.extend_from_slice(&group_columns_builder.finish()?);
-                Ok(Some(DataBlock::create(self.params.schema.clone(), columns)))
-            }
+        // Build final state block.
+        let fields_len = self.params.schema.fields().len();
+        let mut columns = Vec::with_capacity(fields_len);
+
+        for mut array in aggregates_column_builder {
+            columns.push(array.to_column());
         }
+
+        columns.extend_from_slice(&group_columns_builder.finish()?);
+        Ok(DataBlock::create(self.params.schema.clone(), columns))
     }
 }
 
@@ -237,6 +324,39 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
     const NAME: &'static str = "GroupByFinalTransform";
 
     fn consume(&mut self, block: DataBlock) -> Result<()> {
+        self.merge_block(&block)?;
+        self.try_spill_states(&block)
+    }
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.spiller.is_spilled() {
+            // Merge the spilled keys bucket by bucket.
+            while let Some(blocks) = self.next_spilled_bucket()? {
+                for block in blocks.iter() {
+                    self.merge_block(block)?;
+                }
+
+                if self.state.len() != 0 {
+                    let block = self.build_block()?;
+                    self.reset_state();
+                    return Ok(Some(block));
+                }
+            }
+            return Ok(None);
+        }
+
+        match self.state.len() == 0 || self.is_generated {
+            true => Ok(None),
+            false => {
+                self.is_generated = true;
+                Ok(Some(self.build_block()?))
+            }
+        }
+    }
+}
+
+impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> FinalAggregator<false, Method> {
+    fn merge_block(&mut self, block: &DataBlock) -> Result<()> {
         let key_array = block.column(0);
         let keys_iter = self.method.keys_iter_from_column(key_array)?;
 
@@ -254,22 +374,16 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
         Ok(())
     }
 
-    fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.state.len() == 0 || self.is_generated {
-            true => Ok(None),
-            false => {
-                self.is_generated = true;
-                let mut columns_builder = self
-                    .method
-                    .group_columns_builder(self.state.len(), &self.params);
-                for group_entity in self.state.iter() {
-                    columns_builder.append_value(group_entity.get_state_key());
-                }
-
-                let columns = columns_builder.finish()?;
-                Ok(Some(DataBlock::create(self.params.schema.clone(), columns)))
-            }
+    fn build_block(&self) -> Result<DataBlock> {
+        let mut columns_builder = self
+            .method
+            .group_columns_builder(self.state.len(), &self.params);
+        for group_entity in self.state.iter() {
+            columns_builder.append_value(group_entity.get_state_key());
         }
+
+        let columns = columns_builder.finish()?;
+        Ok(DataBlock::create(self.params.schema.clone(), columns))
     }
 }
 
@@ -32,7 +32,9 @@ use common_datavalues::ScalarColumnBuilder;
 use common_exception::Result;
 use common_functions::aggregates::StateAddr;
 use common_functions::aggregates::StateAddrs;
+use common_hashtable::NUM_BUCKETS;
 
+use crate::pipelines::processors::transforms::aggregator::aggregator_spiller::AggregatorSpiller;
 use crate::pipelines::processors::transforms::group_by::AggregatorState;
 use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
 use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
@@ -73,21 +75,60 @@ pub struct PartialAggregator<
     state: Method::State,
     params: Arc<AggregatorParams>,
     ctx: Arc<QueryContext>,
+
+    spiller: AggregatorSpiller,
+    // the next spilled bucket to output
+    spilled_bucket: usize,
 }
 
 impl<const HAS_AGG: bool, Method: HashMethod + PolymorphicKeysHelper<Method> + Send>
     PartialAggregator<HAS_AGG, Method>
 {
-    pub fn create(ctx: Arc<QueryContext>, method: Method, params: Arc<AggregatorParams>) -> Self {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        method: Method,
+        params: Arc<AggregatorParams>,
+    ) -> Result<Self> {
         let state = method.aggregate_state();
-        Self {
+        let spiller = AggregatorSpiller::create(&ctx)?;
+        Ok(Self {
             is_generated: false,
             states_dropped: false,
             state,
             method,
             params,
             ctx,
+            spiller,
+            spilled_bucket: 0,
+        })
+    }
+
+    /// Spill the states to disk if the memory used by them exceeds the threshold,
+    /// they will be output bucket by bucket after the in-memory states are generated.
+    fn try_spill_states(&mut self) -> Result<()> {
+        if self.spiller.should_spill(self.state.allocated_bytes()) {
+            self.spiller
+                .spill(&self.method, &self.state, &self.params, &self.params.schema)?;
+            self.drop_states();
+            self.state = self.method.aggregate_state();
+            self.states_dropped = false;
         }
+        Ok(())
+    }
+
+    fn generate_spilled(&mut self) -> Result<Option<DataBlock>> {
+        if !self.spiller.is_spilled() {
+            return Ok(None);
+        }
+
+        while self.spilled_bucket < NUM_BUCKETS {
+            let blocks = self.spiller.read_bucket(self.spilled_bucket)?;
+            self.spilled_bucket += 1;
+            if !blocks.is_empty() {
+                return Ok(Some(DataBlock::concat_blocks(&blocks)?));
+            }
+        }
+        Ok(None)
     }
 
     #[inline(always)]
@@ -249,11 +290,15 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
         }
 
         let places = Self::lookup_state(&self.params, group_keys_iter, &mut self.state);
-        Self::execute(&self.params, &block, &places)
+        Self::execute(&self.params, &block, &places)?;
+        self.try_spill_states()
     }
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        self.generate_data()
+        match self.generate_data()? {
+            Some(block) => Ok(Some(block)),
+            None => self.generate_spilled(),
+        }
     }
 }
 
@@ -278,14 +323,14 @@ impl<Method: HashMethod + PolymorphicKeysHelper<Method> + Send> Aggregator
         }
 
         Self::lookup_key(group_keys_iter, &mut self.state);
-        Ok(())
+        self.try_spill_states()
     }
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
         match self.state.len() == 0 || self.is_generated {
             true => {
                 self.drop_states();
-                Ok(None)
+                self.generate_spilled()
             }
             false => {
                 self.is_generated = true;
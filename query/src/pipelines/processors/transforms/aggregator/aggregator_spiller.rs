@@ -0,0 +1,217 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use common_base::base::Runtime;
+use common_datablocks::DataBlock;
+use common_datablocks::HashMethod;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::MutableColumn;
+use common_datavalues::MutableStringColumn;
+use common_exception::Result;
+use common_functions::aggregates::StateAddr;
+use common_hashtable::NUM_BUCKETS;
+use opendal::Operator;
+use tracing::info;
+use tracing::warn;
+
+use crate::pipelines::processors::transforms::group_by::AggregatorState;
+use crate::pipelines::processors::transforms::group_by::KeysColumnBuilder;
+use crate::pipelines::processors::transforms::group_by::PolymorphicKeysHelper;
+use crate::pipelines::processors::transforms::group_by::StateEntity;
+use crate::pipelines::processors::transforms::spill_file::SpillReader;
+use crate::pipelines::processors::transforms::spill_file::SpillWriter;
+use crate::pipelines::processors::AggregatorParams;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Spill serialized aggregate states to the storage of the query when the memory
+/// used by the group by state exceeds `group_by_spill_threshold_bytes`.
+///
+/// The states are partitioned by the bucket of two-level hash table, each spill
+/// writes the states of a bucket into a new object under the directory of the
+/// bucket, so that they can be merged bucket by bucket later.
+pub struct AggregatorSpiller {
+    spill_threshold: usize,
+    operator: Operator,
+    runtime: Arc<Runtime>,
+    directory: String,
+    schema: Option<DataSchemaRef>,
+    // the spilled objects of each bucket, in the order they are written
+    locations: Vec<Vec<String>>,
+    is_spilled: bool,
+}
+
+impl AggregatorSpiller {
+    pub fn create(ctx: &Arc<QueryContext>) -> Result<Self> {
+        let spill_threshold = ctx.get_settings().get_group_by_spill_threshold_bytes()? as usize;
+        let directory = format!("_spill/{}/{}", ctx.get_id(), uuid::Uuid::new_v4().simple());
+
+        Ok(AggregatorSpiller {
+            spill_threshold,
+            operator: ctx.get_storage_operator()?,
+            runtime: ctx.get_storage_runtime(),
+            directory,
+            schema: None,
+            locations: vec![vec![]; NUM_BUCKETS],
+            is_spilled: false,
+        })
+    }
+
+    #[inline(always)]
+    pub fn should_spill(&self, allocated_bytes: usize) -> bool {
+        self.spill_threshold != 0 && allocated_bytes >= self.spill_threshold
+    }
+
+    #[inline(always)]
+    pub fn is_spilled(&self) -> bool {
+        self.is_spilled
+    }
+
+    /// Serialize all the states into blocks with the same layout as the output of
+    /// partial aggregator(aggregate states followed by group keys), and write them
+    /// into the objects of their buckets.
+    pub fn spill<Method>(
+        &mut self,
+        method: &Method,
+        state: &Method::State,
+        params: &AggregatorParams,
+        schema: &DataSchemaRef,
+    ) -> Result<()>
+    where
+        Method: HashMethod + PolymorphicKeysHelper<Method>,
+    {
+        let mut buckets = vec![vec![]; NUM_BUCKETS];
+        for group_entity in state.iter() {
+            let bucket = state.bucket(group_entity.get_state_key());
+            buckets[bucket].push(group_entity);
+        }
+
+        let funcs = &params.aggregate_functions;
+        let offsets_aggregate_states = &params.offsets_aggregate_states;
+
+        let mut objects = vec![];
+        let mut bytes = BytesMut::new();
+        for (bucket, entities) in buckets.into_iter().enumerate() {
+            if entities.is_empty() {
+                continue;
+            }
+
+            let mut state_builders: Vec<MutableStringColumn> = (0..funcs.len())
+                .map(|_| MutableStringColumn::with_capacity(entities.len() * 4))
+                .collect();
+            let mut group_key_builder = method.keys_column_builder(entities.len());
+
+            for group_entity in entities {
+                let place: StateAddr = (*group_entity.get_state_value()).into();
+
+                for (idx, func) in funcs.iter().enumerate() {
+                    let arg_place = place.next(offsets_aggregate_states[idx]);
+                    func.serialize(arg_place, &mut bytes)?;
+                    state_builders[idx].append_value(&bytes[..]);
+                    bytes.clear();
+                }
+
+                group_key_builder.append_value(group_entity.get_state_key());
+            }
+
+            let mut columns: Vec<ColumnRef> = Vec::with_capacity(funcs.len() + 1);
+            for mut builder in state_builders {
+                columns.push(builder.to_column());
+            }
+            columns.push(group_key_builder.finish());
+
+            let block = DataBlock::create(schema.clone(), columns);
+            let mut writer = SpillWriter::from_writer(vec![]);
+            writer.write_block(&block)?;
+            let location = format!(
+                "{}/bucket_{}/{}",
+                self.directory,
+                bucket,
+                self.locations[bucket].len()
+            );
+            self.locations[bucket].push(location.clone());
+            objects.push((location, writer.finish()?));
+        }
+
+        let operator = self.operator.clone();
+        self.runtime.block_on(async move {
+            for (location, data) in objects {
+                operator.object(&location).write(data).await?;
+            }
+            Result::Ok(())
+        })?;
+
+        info!(
+            "Spilled {} groups of aggregate states into {}",
+            state.len(),
+            self.directory
+        );
+        self.schema = Some(schema.clone());
+        self.is_spilled = true;
+        Ok(())
+    }
+
+    /// Read back all the spilled blocks of the bucket.
+    pub fn read_bucket(&self, bucket: usize) -> Result<Vec<DataBlock>> {
+        let schema = match &self.schema {
+            None => return Ok(vec![]),
+            Some(schema) => schema.clone(),
+        };
+
+        let operator = self.operator.clone();
+        let locations = self.locations[bucket].clone();
+        let objects = self.runtime.block_on(async move {
+            let mut objects = Vec::with_capacity(locations.len());
+            for location in locations {
+                objects.push(operator.object(&location).read().await?);
+            }
+            Result::Ok(objects)
+        })?;
+
+        let mut blocks = vec![];
+        for data in objects {
+            let mut reader = SpillReader::from_reader(Cursor::new(data), schema.clone());
+            while let Some(block) = reader.read_block()? {
+                blocks.push(block);
+            }
+        }
+        Ok(blocks)
+    }
+}
+
+impl Drop for AggregatorSpiller {
+    fn drop(&mut self) {
+        if !self.is_spilled {
+            return;
+        }
+
+        let operator = self.operator.clone();
+        let locations = std::mem::take(&mut self.locations);
+        self.runtime.block_on(async move {
+            for location in locations.into_iter().flatten() {
+                if let Err(cause) = operator.object(&location).delete().await {
+                    warn!(
+                        "Cannot remove spilled object {}, cause: {:?}",
+                        location, cause
+                    );
+                }
+            }
+        });
+    }
+}
@@ -16,6 +16,7 @@ mod aggregator_final;
 mod aggregator_params;
 mod aggregator_partial;
 mod aggregator_single_key;
+mod aggregator_spiller;
 
 pub use aggregator_final::FinalAggregator;
 pub use aggregator_final::KeysU128FinalAggregator;
@@ -40,3 +41,4 @@ pub use aggregator_partial::SerializerPartialAggregator;
 pub use aggregator_single_key::FinalSingleStateAggregator;
 pub use aggregator_single_key::PartialSingleStateAggregator;
 pub use aggregator_single_key::SingleStateAggregator;
+pub use aggregator_spiller::AggregatorSpiller;
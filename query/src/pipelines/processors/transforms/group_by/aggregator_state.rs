@@ -21,11 +21,13 @@ use common_datablocks::HashMethodFixedKeys;
 use common_datablocks::HashMethodSerializer;
 use common_datavalues::prelude::*;
 use common_functions::aggregates::StateAddr;
+use common_hashtable::get_bucket_from_hash;
 use common_hashtable::HashMapIteratorKind;
 use common_hashtable::HashMapKind;
 use common_hashtable::HashTableEntity;
 use common_hashtable::HashTableKeyable;
 use common_hashtable::KeyValueEntity;
+use common_hashtable::NUM_BUCKETS;
 
 use crate::pipelines::processors::transforms::group_by::aggregator_state_entity::ShortFixedKeyable;
 use crate::pipelines::processors::transforms::group_by::aggregator_state_entity::ShortFixedKeysStateEntity;
@@ -86,6 +88,13 @@ pub trait AggregatorState<Method: HashMethod>: Sync + Send {
     }
 
     fn convert_to_two_level(&mut self) {}
+
+    /// The bucket of the key in two-level hash table, states are partitioned
+    /// by it when spilling to disk.
+    fn bucket(&self, key: &Self::Key) -> usize;
+
+    /// Approximate memory size in bytes used by the states and keys.
+    fn allocated_bytes(&self) -> usize;
 }
 
 /// The fixed length array is used as the data structure to locate the key by subscript
@@ -196,6 +205,18 @@ where
     fn convert_to_two_level(&mut self) {
         self.two_level_flag = true;
     }
+
+    #[inline(always)]
+    fn bucket(&self, key: &Self::Key) -> usize {
+        key.lookup() as usize % NUM_BUCKETS
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        // the fixed array is allocated once with the max size, only the filled entities
+        // are counted, otherwise the threshold of spilling may be always exceeded
+        self.area.allocated_bytes()
+            + self.size * std::mem::size_of::<ShortFixedKeysStateEntity<T>>()
+    }
 }
 
 pub struct LongerFixedKeysAggregatorState<T: HashTableKeyable> {
@@ -272,6 +293,15 @@ where
         }
         self.two_level_flag = true;
     }
+
+    #[inline(always)]
+    fn bucket(&self, key: &Self::Key) -> usize {
+        get_bucket_from_hash(key.fast_hash())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.area.allocated_bytes() + self.data.len() * std::mem::size_of::<Self::Entity>()
+    }
 }
 
 pub struct SerializedKeysAggregatorState {
@@ -360,4 +390,15 @@ impl AggregatorState<HashMethodSerializer> for SerializedKeysAggregatorState {
         }
         self.two_level_flag = true;
     }
+
+    #[inline(always)]
+    fn bucket(&self, keys_ref: &KeysRef) -> usize {
+        get_bucket_from_hash(keys_ref.fast_hash())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.keys_area.allocated_bytes()
+            + self.state_area.allocated_bytes()
+            + self.data_state_map.len() * std::mem::size_of::<Self::Entity>()
+    }
 }
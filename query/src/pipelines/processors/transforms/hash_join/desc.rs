@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_datavalues::DataSchemaRef;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
 use parking_lot::RwLock;
@@ -32,6 +33,8 @@ pub struct HashJoinDesc {
     pub(crate) marker_join_desc: MarkJoinDesc,
     /// Whether the Join are derived from correlated subquery.
     pub(crate) from_correlated_subquery: bool,
+    /// Schema of probe side, used to pad unmatched build rows with NULLs in RIGHT and FULL join.
+    pub(crate) probe_schema: DataSchemaRef,
 }
 
 impl HashJoinDesc {
@@ -51,6 +54,7 @@ impl HashJoinDesc {
                 marker_index: join.marker_index,
             },
             from_correlated_subquery: join.from_correlated_subquery,
+            probe_schema: join.probe.output_schema()?,
         })
     }
 
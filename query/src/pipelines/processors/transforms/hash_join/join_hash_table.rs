@@ -14,9 +14,12 @@
 
 use std::borrow::BorrowMut;
 use std::fmt::Debug;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
 use common_base::base::tokio::sync::Notify;
 use common_datablocks::DataBlock;
@@ -26,6 +29,7 @@ use common_datablocks::HashMethodKind;
 use common_datablocks::HashMethodSerializer;
 use common_datavalues::combine_validities_2;
 use common_datavalues::combine_validities_3;
+use common_datavalues::wrap_nullable;
 use common_datavalues::BooleanColumn;
 use common_datavalues::BooleanType;
 use common_datavalues::Column;
@@ -35,7 +39,9 @@ use common_datavalues::DataField;
 use common_datavalues::DataSchema;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_datavalues::NullableType;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -130,6 +136,11 @@ pub struct JoinHashTable {
     pub(crate) row_space: RowSpace,
     pub(crate) hash_join_desc: HashJoinDesc,
     pub(crate) row_ptrs: RwLock<Vec<RowPtr>>,
+    /// Whether the build side rows have been matched by probe side rows,
+    /// indexed by chunk index and row index. Only used by RIGHT and FULL join.
+    /// The map is built once the build side finishes, then the probe threads
+    /// mark the rows concurrently under the read lock.
+    pub(crate) outer_scan_map: RwLock<Vec<Vec<AtomicBool>>>,
     finished_notify: Arc<Notify>,
 }
 
@@ -227,6 +238,7 @@ impl JoinHashTable {
     ) -> Result<Self> {
         if hash_join_desc.join_type == JoinType::Left
             || hash_join_desc.join_type == JoinType::Single
+            || hash_join_desc.join_type == JoinType::Full
         {
             let mut nullable_field = Vec::with_capacity(build_data_schema.fields().len());
             for field in build_data_schema.fields().iter() {
@@ -245,6 +257,7 @@ impl JoinHashTable {
             ctx,
             hash_table: RwLock::new(hash_table),
             row_ptrs: RwLock::new(vec![]),
+            outer_scan_map: RwLock::new(vec![]),
             finished_notify: Arc::new(Notify::new()),
         })
     }
@@ -422,6 +435,8 @@ impl HashJoinState for JoinHashTable {
             | JoinType::Semi
             | JoinType::Anti
             | JoinType::Left
+            | JoinType::Right
+            | JoinType::Full
             | Mark
             | JoinType::Single => self.probe_join(input, probe_state),
             JoinType::Cross => self.probe_cross_join(input, probe_state),
@@ -481,6 +496,20 @@ impl HashJoinState for JoinHashTable {
         }
 
         let mut chunks = self.row_space.chunks.write().unwrap();
+        if matches!(
+            self.hash_join_desc.join_type,
+            JoinType::Right | JoinType::Full
+        ) {
+            let mut outer_scan_map = self.outer_scan_map.write();
+            *outer_scan_map = chunks
+                .iter()
+                .map(|chunk| {
+                    (0..chunk.num_rows())
+                        .map(|_| AtomicBool::new(false))
+                        .collect()
+                })
+                .collect();
+        }
         for chunk_index in 0..chunks.len() {
             let chunk = &mut chunks[chunk_index];
             let mut columns = Vec::with_capacity(chunk.cols.len());
@@ -655,4 +684,58 @@ impl HashJoinState for JoinHashTable {
         let build_block = self.row_space.gather(&row_ptrs)?;
         Ok(vec![self.merge_eq_block(&marker_block, &build_block)?])
     }
+
+    fn right_join_blocks(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        let unmatched_build_indexs = {
+            let outer_scan_map = self.outer_scan_map.read();
+            let mut row_ptrs = vec![];
+            for (chunk_index, chunk_map) in outer_scan_map.iter().enumerate() {
+                for (row_index, matched) in chunk_map.iter().enumerate() {
+                    if !matched.load(Ordering::Relaxed) {
+                        row_ptrs.push(RowPtr {
+                            chunk_index: chunk_index as u32,
+                            row_index: row_index as u32,
+                            marker: None,
+                        });
+                    }
+                }
+            }
+            row_ptrs
+        };
+
+        let mut results = blocks.to_vec();
+        if unmatched_build_indexs.is_empty() {
+            return Ok(results);
+        }
+
+        let num_rows = unmatched_build_indexs.len();
+        let mut build_block = self.row_space.gather(&unmatched_build_indexs)?;
+        if self.hash_join_desc.join_type == JoinType::Full {
+            // The build side of FULL join is nullable
+            let validity: Bitmap = MutableBitmap::from_len_set(num_rows).into();
+            let nullable_columns = build_block
+                .columns()
+                .iter()
+                .map(|c| Self::set_validity(c, &validity))
+                .collect::<Result<Vec<_>>>()?;
+            build_block = DataBlock::create(self.row_space.data_schema.clone(), nullable_columns);
+        }
+
+        // Pad the probe side with NULLs
+        let probe_schema = &self.hash_join_desc.probe_schema;
+        let mut null_probe_fields = Vec::with_capacity(probe_schema.fields().len());
+        let mut null_probe_columns = Vec::with_capacity(probe_schema.fields().len());
+        for field in probe_schema.fields().iter() {
+            let data_type = wrap_nullable(field.data_type());
+            null_probe_columns.push(data_type.create_constant_column(&DataValue::Null, num_rows)?);
+            null_probe_fields.push(DataField::new(field.name(), data_type));
+        }
+        let null_probe_block = DataBlock::create(
+            DataSchemaRefExt::create(null_probe_fields),
+            null_probe_columns,
+        );
+
+        results.push(self.merge_eq_block(&build_block, &null_probe_block)?);
+        Ok(results)
+    }
 }
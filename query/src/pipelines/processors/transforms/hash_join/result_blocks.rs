@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::iter::TrustedLen;
+use std::sync::atomic::Ordering;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_arrow::arrow::bitmap::MutableBitmap;
@@ -21,6 +22,7 @@ use common_datavalues::BooleanColumn;
 use common_datavalues::BooleanViewer;
 use common_datavalues::Column;
 use common_datavalues::ColumnRef;
+use common_datavalues::DataField;
 use common_datavalues::DataType;
 use common_datavalues::DataTypeImpl;
 use common_datavalues::DataValue;
@@ -139,7 +141,9 @@ impl JoinHashTable {
             }
 
             // Single join is similar to left join, but the result is a single row.
-            JoinType::Left | JoinType::Single => {
+            // Full join is also similar to left join, the unmatched build side rows
+            // will be emitted after all probe side rows are consumed.
+            JoinType::Left | JoinType::Single | JoinType::Full => {
                 if self.hash_join_desc.other_predicate.is_none() {
                     let result = self.left_or_single_join::<false, _, _>(
                         hash_table,
@@ -158,6 +162,10 @@ impl JoinHashTable {
                     return Ok(vec![result]);
                 }
             }
+            JoinType::Right => {
+                let result = self.right_join(hash_table, probe_state, keys_iter, input)?;
+                return Ok(vec![result]);
+            }
             Mark => {
                 results.push(DataBlock::empty());
                 // Three cases will produce Mark join:
@@ -377,6 +385,57 @@ impl JoinHashTable {
         DataBlock::filter_block(probe_block, &predicate)
     }
 
+    fn right_join<Key, IT>(
+        &self,
+        hash_table: &HashMap<Key, Vec<RowPtr>>,
+        probe_state: &mut ProbeState,
+        keys_iter: IT,
+        input: &DataBlock,
+    ) -> Result<DataBlock>
+    where
+        Key: HashTableKeyable + Clone + 'static,
+        IT: Iterator<Item = Key> + TrustedLen,
+    {
+        let probe_indexs = &mut probe_state.probe_indexs;
+        let build_indexs = &mut probe_state.build_indexs;
+        let valids = &probe_state.valids;
+
+        for (i, key) in keys_iter.enumerate() {
+            if let Some(v) = Self::probe_key(hash_table, key, valids, i) {
+                let probe_result_ptrs = v.get_value();
+                build_indexs.extend_from_slice(probe_result_ptrs);
+                probe_indexs.extend(std::iter::repeat(i as u32).take(probe_result_ptrs.len()));
+            }
+        }
+
+        let build_block = self.row_space.gather(build_indexs)?;
+        let probe_block = DataBlock::block_take_by_indices(input, probe_indexs)?;
+        // The probe side of right join is nullable
+        let probe_block = Self::nullable_block(&probe_block)?;
+        let merged_block = self.merge_eq_block(&build_block, &probe_block)?;
+
+        match &self.hash_join_desc.other_predicate {
+            Some(other_predicate) => {
+                let (bm, all_true, all_false) =
+                    self.get_other_filters(&merged_block, other_predicate)?;
+                let bm = match (bm, all_true, all_false) {
+                    (Some(b), _, _) => b,
+                    (_, true, _) => MutableBitmap::from_len_set(merged_block.num_rows()).into(),
+                    (_, _, true) => Bitmap::new_zeroed(merged_block.num_rows()),
+                    // must be one of above
+                    _ => unreachable!(),
+                };
+                self.mark_matched_build_rows(build_indexs, Some(&bm));
+                let predicate = BooleanColumn::from_arrow_data(bm).arc();
+                DataBlock::filter_block(merged_block, &predicate)
+            }
+            None => {
+                self.mark_matched_build_rows(build_indexs, None);
+                Ok(merged_block)
+            }
+        }
+    }
+
     fn left_or_single_join<const WITH_OTHER_CONJUNCT: bool, Key, IT>(
         &self,
         hash_table: &HashMap<Key, Vec<RowPtr>>,
@@ -442,6 +501,11 @@ impl JoinHashTable {
         }
 
         let validity: Bitmap = validity.into();
+        let is_full_join = self.hash_join_desc.join_type == JoinType::Full;
+        if is_full_join && !WITH_OTHER_CONJUNCT {
+            self.mark_matched_build_rows(build_indexs, Some(&validity));
+        }
+
         let build_block = if !self.hash_join_desc.from_correlated_subquery
             && self.hash_join_desc.join_type == JoinType::Single
             && validity.unset_bits() == input.num_rows()
@@ -482,7 +546,11 @@ impl JoinHashTable {
         };
         let nullable_build_block =
             DataBlock::create(self.row_space.data_schema.clone(), nullable_columns.clone());
-        let probe_block = DataBlock::block_take_by_indices(input, probe_indexs)?;
+        let mut probe_block = DataBlock::block_take_by_indices(input, probe_indexs)?;
+        if is_full_join {
+            // The probe side of full join is nullable
+            probe_block = Self::nullable_block(&probe_block)?;
+        }
         let merged_block = self.merge_eq_block(&nullable_build_block, &probe_block)?;
 
         if !WITH_OTHER_CONJUNCT {
@@ -495,16 +563,26 @@ impl JoinHashTable {
         )?;
 
         if all_true {
+            if is_full_join {
+                self.mark_matched_build_rows(build_indexs, Some(&validity));
+            }
             return Ok(merged_block);
         }
 
-        let validity = match (bm, all_false) {
+        let filter_validity = match (bm, all_false) {
             (Some(b), _) => b,
             (None, true) => Bitmap::new_zeroed(merged_block.num_rows()),
             // must be one of above
             _ => unreachable!(),
         };
 
+        if is_full_join {
+            // Dummy rows of unmatched probe rows should not be marked.
+            let matched = (&filter_validity) & (&validity);
+            self.mark_matched_build_rows(build_indexs, Some(&matched));
+        }
+        let validity = filter_validity;
+
         let nullable_columns = nullable_columns
             .iter()
             .map(|c| Self::set_validity(c, &validity))
@@ -645,6 +723,31 @@ impl JoinHashTable {
         }
     }
 
+    // Wrap all the columns of the block into nullable columns
+    fn nullable_block(block: &DataBlock) -> Result<DataBlock> {
+        let validity: Bitmap = MutableBitmap::from_len_set(block.num_rows()).into();
+        let mut nullable_block = DataBlock::empty();
+        for (col, field) in block.columns().iter().zip(block.schema().fields().iter()) {
+            nullable_block = nullable_block.add_column(
+                Self::set_validity(col, &validity)?,
+                DataField::new_nullable(field.name(), field.data_type().clone()),
+            )?;
+        }
+        Ok(nullable_block)
+    }
+
+    // Mark the build side rows which have been matched by probe side rows,
+    // `valids` indicates whether the row in `build_indexs` is a real match.
+    fn mark_matched_build_rows(&self, build_indexs: &[RowPtr], valids: Option<&Bitmap>) {
+        let outer_scan_map = self.outer_scan_map.read();
+        for (idx, row_ptr) in build_indexs.iter().enumerate() {
+            if valids.map(|v| v.get_bit(idx)).unwrap_or(true) {
+                outer_scan_map[row_ptr.chunk_index as usize][row_ptr.row_index as usize]
+                    .store(true, Ordering::Relaxed);
+            }
+        }
+    }
+
     #[inline]
     fn probe_key<Key: HashTableKeyable>(
         hash_table: &HashMap<Key, Vec<RowPtr>>,
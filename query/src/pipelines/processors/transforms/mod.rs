@@ -14,8 +14,10 @@
 
 mod aggregator;
 pub(crate) mod hash_join;
+mod spill_file;
 mod transform;
 mod transform_addon;
+mod transform_aggregate_expand;
 mod transform_aggregator;
 mod transform_block_compact;
 mod transform_cast_schema;
@@ -33,14 +35,20 @@ mod transform_limit_by;
 mod transform_mark_join;
 mod transform_project;
 mod transform_rename;
+mod transform_right_join;
 mod transform_sort_merge;
 mod transform_sort_partial;
+mod transform_sort_spill;
+mod transform_window;
 mod transform_window_func;
 
 pub mod group_by;
 
 pub use aggregator::AggregatorParams;
+pub use aggregator::AggregatorSpiller;
 pub use aggregator::AggregatorTransformParams;
+pub use group_by::AggregatorState;
+pub use group_by::PolymorphicKeysHelper;
 pub use hash_join::HashJoinDesc;
 pub use hash_join::HashJoinState;
 pub use hash_join::HashTable;
@@ -54,6 +62,7 @@ pub use hash_join::KeyU64HashTable;
 pub use hash_join::KeyU8HashTable;
 pub use hash_join::SerializerHashTable;
 pub use transform_addon::TransformAddOn;
+pub use transform_aggregate_expand::TransformAggregateExpand;
 pub use transform_aggregator::TransformAggregator;
 pub use transform_block_compact::BlockCompactor;
 pub use transform_block_compact::TransformBlockCompact;
@@ -78,9 +87,14 @@ pub use transform_mark_join::MarkJoinCompactor;
 pub use transform_mark_join::TransformMarkJoin;
 pub use transform_project::TransformProject;
 pub use transform_rename::TransformRename;
+pub use transform_right_join::RightJoinCompactor;
+pub use transform_right_join::TransformRightJoin;
 pub use transform_sort_merge::SortMergeCompactor;
 pub use transform_sort_merge::TransformSortMerge;
 pub use transform_sort_partial::get_sort_descriptions;
 pub use transform_sort_partial::TransformSortPartial;
+pub use transform_sort_spill::TransformSortSpill;
+pub use transform_window::TransformWindow;
+pub use transform_window::WindowCompactor;
 pub use transform_window_func::TransformWindowFunc;
 pub use transform_window_func::WindowFuncCompact;
@@ -0,0 +1,179 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use byteorder::BigEndian;
+use byteorder::ReadBytesExt;
+use byteorder::WriteBytesExt;
+use common_arrow::arrow::datatypes::Field;
+use common_arrow::arrow::io::flight::deserialize_batch;
+use common_arrow::arrow::io::flight::serialize_batch;
+use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::WriteOptions;
+use common_arrow::arrow::io::ipc::IpcSchema;
+use common_arrow::arrow_format::flight::data::FlightData;
+use common_datablocks::DataBlock;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tracing::warn;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Returns a new directory(not created yet) to spill the data of a processor in the query.
+pub fn spill_directory(ctx: &QueryContext) -> PathBuf {
+    std::env::temp_dir()
+        .join("databend_spill")
+        .join(ctx.get_id())
+        .join(uuid::Uuid::new_v4().simple().to_string())
+}
+
+/// Remove the spill directory and all the files in it.
+pub fn remove_spill_directory(directory: &Path) {
+    if directory.exists() {
+        if let Err(cause) = std::fs::remove_dir_all(directory) {
+            warn!(
+                "Cannot remove spill directory {:?}, cause: {:?}",
+                directory, cause
+            );
+        }
+    }
+}
+
+/// Write data blocks into a spill file, each block is serialized as a length-prefixed
+/// arrow flight data header followed by the length-prefixed body.
+pub struct SpillWriter<W: Write = BufWriter<File>> {
+    writer: W,
+}
+
+impl SpillWriter {
+    /// Create a local spill file.
+    pub fn create(path: &Path) -> Result<Self> {
+        if let Some(directory) = path.parent() {
+            std::fs::create_dir_all(directory)?;
+        }
+
+        Ok(SpillWriter {
+            writer: BufWriter::new(File::create(path)?),
+        })
+    }
+}
+
+impl<W: Write> SpillWriter<W> {
+    pub fn from_writer(writer: W) -> Self {
+        SpillWriter { writer }
+    }
+
+    pub fn write_block(&mut self, block: &DataBlock) -> Result<()> {
+        let arrow_schema = block.schema().to_arrow();
+        let options = WriteOptions { compression: None };
+        let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let chunks = block.clone().try_into()?;
+        let (dicts, flight_data) = serialize_batch(&chunks, &ipc_fields, &options)?;
+
+        if !dicts.is_empty() {
+            return Err(ErrorCode::UnImplement(
+                "DatabendQuery does not implement dicts.",
+            ));
+        }
+
+        self.writer
+            .write_u64::<BigEndian>(flight_data.data_header.len() as u64)?;
+        self.writer.write_all(&flight_data.data_header)?;
+        self.writer
+            .write_u64::<BigEndian>(flight_data.data_body.len() as u64)?;
+        self.writer.write_all(&flight_data.data_body)?;
+        Ok(())
+    }
+
+    /// Flush the written blocks, returns the underlying writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Read back the data blocks written by `SpillWriter` one by one.
+pub struct SpillReader<R: Read = BufReader<File>> {
+    schema: DataSchemaRef,
+    fields: Vec<Field>,
+    ipc_schema: IpcSchema,
+    reader: R,
+}
+
+impl SpillReader {
+    /// Open a local spill file, returns None if it doesn't exist.
+    pub fn try_open(path: &Path, schema: DataSchemaRef) -> Result<Option<Self>> {
+        let file = match File::open(path) {
+            Ok(file) => file,
+            Err(cause) if cause.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(cause) => return Err(cause.into()),
+        };
+        Ok(Some(SpillReader::from_reader(BufReader::new(file), schema)))
+    }
+}
+
+impl<R: Read> SpillReader<R> {
+    pub fn from_reader(reader: R, schema: DataSchemaRef) -> Self {
+        let fields = schema.to_arrow().fields;
+        let ipc_schema = IpcSchema {
+            fields: default_ipc_fields(&fields),
+            is_little_endian: true,
+        };
+
+        SpillReader {
+            schema,
+            fields,
+            ipc_schema,
+            reader,
+        }
+    }
+
+    pub fn read_block(&mut self) -> Result<Option<DataBlock>> {
+        let header_len = match self.reader.read_u64::<BigEndian>() {
+            Ok(len) => len as usize,
+            Err(cause) if cause.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(cause) => return Err(cause.into()),
+        };
+        let mut data_header = vec![0; header_len];
+        self.reader.read_exact(&mut data_header)?;
+
+        let body_len = self.reader.read_u64::<BigEndian>()? as usize;
+        let mut data_body = vec![0; body_len];
+        self.reader.read_exact(&mut data_body)?;
+
+        let flight_data = FlightData {
+            app_metadata: vec![],
+            data_header,
+            data_body,
+            flight_descriptor: None,
+        };
+        let chunk = deserialize_batch(
+            &flight_data,
+            &self.fields,
+            &self.ipc_schema,
+            &Default::default(),
+        )?;
+        Ok(Some(DataBlock::from_chunk(&self.schema, &chunk)?))
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::transform::Transform;
+use crate::pipelines::processors::transforms::transform::Transformer;
+
+/// Expands each input block into one block for each grouping set, the group by
+/// columns which are not in the grouping set are replaced with NULLs, and the
+/// position of the grouping set is appended as the grouping id column.
+pub struct TransformAggregateExpand {
+    // Positions of the group by columns in the input block
+    group_by_positions: Vec<usize>,
+    // Whether each group by column is in the grouping set, for each grouping set
+    grouping_sets: Vec<Vec<bool>>,
+    output_schema: DataSchemaRef,
+}
+
+impl TransformAggregateExpand {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+        group_bys: &[String],
+        grouping_sets: &[Vec<usize>],
+    ) -> Result<ProcessorPtr> {
+        let group_by_positions = group_bys
+            .iter()
+            .map(|name| input_schema.index_of(name))
+            .collect::<Result<Vec<_>>>()?;
+        let grouping_sets = grouping_sets
+            .iter()
+            .map(|set| (0..group_bys.len()).map(|i| set.contains(&i)).collect())
+            .collect();
+
+        Ok(Transformer::create(input, output, Self {
+            group_by_positions,
+            grouping_sets,
+            output_schema,
+        }))
+    }
+}
+
+impl Transform for TransformAggregateExpand {
+    const NAME: &'static str = "AggregateExpandTransform";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let num_rows = data.num_rows();
+        let mut blocks = Vec::with_capacity(self.grouping_sets.len());
+        for (grouping_id, grouping_set) in self.grouping_sets.iter().enumerate() {
+            let mut columns = data.columns().to_vec();
+            for (position, in_set) in self.group_by_positions.iter().zip(grouping_set) {
+                let column = columns[*position].convert_full_column();
+                columns[*position] = if !*in_set {
+                    wrap_nullable(&column.data_type())
+                        .create_constant_column(&DataValue::Null, num_rows)?
+                        .convert_full_column()
+                } else if !column.data_type().can_inside_nullable() {
+                    column
+                } else {
+                    NullableColumn::wrap_inner(column, None)
+                };
+            }
+            columns.push(Series::from_data(vec![grouping_id as u32; num_rows]));
+            blocks.push(DataBlock::create(self.output_schema.clone(), columns));
+        }
+        DataBlock::concat_blocks(&blocks)
+    }
+}
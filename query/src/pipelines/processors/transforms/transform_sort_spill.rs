@@ -0,0 +1,368 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::DataSchemaRef;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use tracing::info;
+
+use crate::pipelines::processors::port::InputPort;
+use crate::pipelines::processors::port::OutputPort;
+use crate::pipelines::processors::processor::Event;
+use crate::pipelines::processors::processor::ProcessorPtr;
+use crate::pipelines::processors::transforms::spill_file::remove_spill_directory;
+use crate::pipelines::processors::transforms::spill_file::spill_directory;
+use crate::pipelines::processors::transforms::spill_file::SpillReader;
+use crate::pipelines::processors::transforms::spill_file::SpillWriter;
+use crate::pipelines::processors::Processor;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+enum SortSpillStage {
+    Consume,
+    PrepareMerge,
+    Merge,
+    Finished,
+}
+
+/// External merge sort, it must be placed after `TransformSortPartial` with single input.
+///
+/// The sorted input blocks are buffered in memory, once the buffered bytes exceed
+/// `sort_spill_threshold_bytes`, they are merged into a sorted run and written into
+/// a local spill file. When all the input is consumed, the runs are k-way merged back.
+pub struct TransformSortSpill {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    stage: SortSpillStage,
+    input_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+
+    limit: Option<usize>,
+    sort_columns_descriptions: Vec<SortColumnDescription>,
+    spill_threshold: usize,
+    max_block_size: usize,
+
+    buffered_blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+
+    schema: Option<DataSchemaRef>,
+    directory: PathBuf,
+    runs: Vec<PathBuf>,
+    readers: Vec<SpillReader>,
+    // The current unmerged block of each run, None if the run is exhausted.
+    heads: Vec<Option<DataBlock>>,
+    output_rows: usize,
+}
+
+impl TransformSortSpill {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        limit: Option<usize>,
+        sort_columns_descriptions: Vec<SortColumnDescription>,
+    ) -> Result<ProcessorPtr> {
+        let settings = ctx.get_settings();
+        let spill_threshold = settings.get_sort_spill_threshold_bytes()? as usize;
+        let max_block_size = settings.get_max_block_size()? as usize;
+
+        // Nothing is output with `LIMIT 0`, the input is not consumed at all.
+        let stage = match limit {
+            Some(0) => SortSpillStage::Finished,
+            _ => SortSpillStage::Consume,
+        };
+
+        Ok(ProcessorPtr::create(Box::new(TransformSortSpill {
+            input,
+            output,
+            stage,
+            input_data: None,
+            output_data: VecDeque::new(),
+            limit,
+            sort_columns_descriptions,
+            spill_threshold,
+            max_block_size,
+            buffered_blocks: vec![],
+            buffered_bytes: 0,
+            schema: None,
+            directory: spill_directory(&ctx),
+            runs: vec![],
+            readers: vec![],
+            heads: vec![],
+            output_rows: 0,
+        })))
+    }
+
+    fn consume(&mut self, block: DataBlock) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        if self.schema.is_none() {
+            self.schema = Some(block.schema().clone());
+        }
+
+        self.buffered_bytes += block.memory_size();
+        self.buffered_blocks.push(block);
+
+        if self.spill_threshold != 0 && self.buffered_bytes >= self.spill_threshold {
+            self.spill_run()?;
+        }
+        Ok(())
+    }
+
+    // Merge the buffered blocks into a sorted run and write it into a new spill file.
+    fn spill_run(&mut self) -> Result<()> {
+        if self.buffered_blocks.is_empty() {
+            return Ok(());
+        }
+
+        let block = self.merge_buffered_blocks()?;
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        let path = self.directory.join(format!("run_{}", self.runs.len()));
+        let mut writer = SpillWriter::create(&path)?;
+        for block in DataBlock::split_block_by_size(&block, self.max_block_size)? {
+            if !block.is_empty() {
+                writer.write_block(&block)?;
+            }
+        }
+        writer.finish()?;
+
+        info!(
+            "Spilled sorted run of {} rows into {:?}",
+            block.num_rows(),
+            path
+        );
+        self.runs.push(path);
+        Ok(())
+    }
+
+    fn merge_buffered_blocks(&mut self) -> Result<DataBlock> {
+        let blocks = std::mem::take(&mut self.buffered_blocks);
+        self.buffered_bytes = 0;
+
+        let block =
+            DataBlock::merge_sort_blocks(&blocks, &self.sort_columns_descriptions, self.limit)?;
+        Ok(match self.limit {
+            Some(limit) if block.num_rows() > limit => DataBlock::slice_block(&block, 0, limit),
+            _ => block,
+        })
+    }
+
+    fn prepare_merge(&mut self) -> Result<()> {
+        if self.runs.is_empty() {
+            if !self.buffered_blocks.is_empty() {
+                let block = self.merge_buffered_blocks()?;
+                self.output_blocks(block)?;
+            }
+            self.stage = SortSpillStage::Finished;
+            return Ok(());
+        }
+
+        self.spill_run()?;
+        let schema = match &self.schema {
+            Some(schema) => schema.clone(),
+            None => return Err(ErrorCode::LogicalError("Sorted runs without schema")),
+        };
+        for path in &self.runs {
+            match SpillReader::try_open(path, schema.clone())? {
+                None => {
+                    return Err(ErrorCode::LogicalError(format!(
+                        "Spilled sorted run {:?} is missing",
+                        path
+                    )));
+                }
+                Some(mut reader) => {
+                    self.heads.push(read_next_block(&mut reader)?);
+                    self.readers.push(reader);
+                }
+            }
+        }
+
+        self.stage = SortSpillStage::Merge;
+        Ok(())
+    }
+
+    // Every run is sorted, so all the rows not greater than the minimum of the
+    // last rows of head blocks can be merged and output safely.
+    fn merge_next(&mut self) -> Result<()> {
+        let mut bound: Option<DataBlock> = None;
+        for head in self.heads.iter().flatten() {
+            let is_less = match &bound {
+                None => true,
+                Some(bound) => {
+                    DataBlock::compare_rows(
+                        head,
+                        head.num_rows() - 1,
+                        bound,
+                        bound.num_rows() - 1,
+                        &self.sort_columns_descriptions,
+                    )? == Ordering::Less
+                }
+            };
+
+            if is_less {
+                bound = Some(head.clone());
+            }
+        }
+
+        let bound = match bound {
+            None => {
+                self.stage = SortSpillStage::Finished;
+                return Ok(());
+            }
+            Some(bound) => bound,
+        };
+
+        let mut blocks = Vec::with_capacity(self.heads.len());
+        for (index, head) in self.heads.iter_mut().enumerate() {
+            if let Some(block) = head.take() {
+                let rows = DataBlock::sorted_rows_not_greater_than(
+                    &block,
+                    &bound,
+                    bound.num_rows() - 1,
+                    &self.sort_columns_descriptions,
+                )?;
+
+                if rows > 0 {
+                    blocks.push(DataBlock::slice_block(&block, 0, rows));
+                }
+
+                *head = match rows < block.num_rows() {
+                    true => Some(DataBlock::slice_block(
+                        &block,
+                        rows,
+                        block.num_rows() - rows,
+                    )),
+                    false => read_next_block(&mut self.readers[index])?,
+                };
+            }
+        }
+
+        let mut block =
+            DataBlock::merge_sort_blocks(&blocks, &self.sort_columns_descriptions, None)?;
+        if let Some(limit) = self.limit {
+            let remain = limit - self.output_rows;
+            if block.num_rows() >= remain {
+                block = DataBlock::slice_block(&block, 0, remain);
+                self.stage = SortSpillStage::Finished;
+            }
+        }
+
+        self.output_rows += block.num_rows();
+        self.output_blocks(block)
+    }
+
+    fn output_blocks(&mut self, block: DataBlock) -> Result<()> {
+        for block in DataBlock::split_block_by_size(&block, self.max_block_size)? {
+            self.output_data.push_back(block);
+        }
+        Ok(())
+    }
+}
+
+// The empty blocks are skipped, the heads of the runs are never empty, so that the
+// last rows of them can be compared.
+fn read_next_block(reader: &mut SpillReader) -> Result<Option<DataBlock>> {
+    while let Some(block) = reader.read_block()? {
+        if !block.is_empty() {
+            return Ok(Some(block));
+        }
+    }
+    Ok(None)
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformSortSpill {
+    fn name(&self) -> &'static str {
+        "SortSpillTransform"
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.stage {
+            SortSpillStage::Consume => {
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.is_finished() {
+                    self.stage = SortSpillStage::PrepareMerge;
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.has_data() {
+                    self.input_data = Some(self.input.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            SortSpillStage::PrepareMerge | SortSpillStage::Merge => Ok(Event::Sync),
+            SortSpillStage::Finished => {
+                self.input.finish();
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match self.stage {
+            SortSpillStage::Consume => match self.input_data.take() {
+                Some(block) => self.consume(block),
+                None => Ok(()),
+            },
+            SortSpillStage::PrepareMerge => self.prepare_merge(),
+            SortSpillStage::Merge => self.merge_next(),
+            SortSpillStage::Finished => Err(ErrorCode::LogicalError("It's a bug.")),
+        }
+    }
+}
+
+impl Drop for TransformSortSpill {
+    fn drop(&mut self) {
+        self.readers.clear();
+        remove_spill_directory(&self.directory);
+    }
+}
@@ -0,0 +1,483 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
+use bumpalo::Bump;
+use common_arrow::arrow::compute::partition::lexicographical_partition_ranges;
+use common_arrow::arrow::compute::sort::SortColumn;
+use common_arrow::ArrayRef;
+use common_datablocks::DataBlock;
+use common_datablocks::SortColumnDescription;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::AggregateFunctionRef;
+use common_functions::aggregates::StateAddr;
+use common_functions::window::WindowFrame;
+use common_functions::window::WindowFrameBound;
+use common_functions::window::WindowFrameUnits;
+use segment_tree::ops::Commutative;
+use segment_tree::ops::Identity;
+use segment_tree::ops::Operation;
+use segment_tree::SegmentPoint;
+
+use super::Compactor;
+use super::TransformCompact;
+use crate::sql::plans::WindowFuncType;
+
+/// Evaluate a window function over all the input blocks, the result is
+/// appended to the input as column `output_name`.
+pub struct WindowCompactor {
+    func: WindowFuncType,
+    aggregate: Option<AggregateFunctionRef>,
+    args: Vec<String>,
+    partition_by: Vec<String>,
+    order_by: Vec<SortColumnDescription>,
+    frame: WindowFrame,
+    output_field: DataField,
+    input_schema: DataSchemaRef,
+    output_schema: DataSchemaRef,
+}
+
+pub type TransformWindow = TransformCompact<WindowCompactor>;
+
+impl Compactor for WindowCompactor {
+    fn name() -> &'static str {
+        "WindowTransform"
+    }
+
+    fn compact_final(&self, blocks: &[DataBlock]) -> Result<Vec<DataBlock>> {
+        let block = if blocks.is_empty() {
+            DataBlock::empty_with_schema(self.input_schema.clone())
+        } else {
+            DataBlock::concat_blocks(blocks)?
+        };
+
+        if block.num_rows() == 0 {
+            return Ok(vec![DataBlock::empty_with_schema(
+                self.output_schema.clone(),
+            )]);
+        }
+
+        let block = self.evaluate(&block)?;
+        Ok(vec![block])
+    }
+}
+
+impl WindowCompactor {
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        func: WindowFuncType,
+        args: Vec<String>,
+        partition_by: Vec<String>,
+        order_by: Vec<SortColumnDescription>,
+        frame: WindowFrame,
+        output_field: DataField,
+        input_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Result<Self> {
+        let aggregate = match &func {
+            WindowFuncType::Aggregate { name, params } => {
+                let arg_fields = args
+                    .iter()
+                    .map(|arg| Ok(input_schema.field_with_name(arg)?.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                Some(AggregateFunctionFactory::instance().get(name, params.clone(), arg_fields)?)
+            }
+            _ => None,
+        };
+
+        Ok(WindowCompactor {
+            func,
+            aggregate,
+            args,
+            partition_by,
+            order_by,
+            frame,
+            output_field,
+            input_schema,
+            output_schema,
+        })
+    }
+
+    fn evaluate(&self, block: &DataBlock) -> Result<DataBlock> {
+        // Sort the block by partition keys and order keys, so that rows of
+        // a partition and peers in a partition are adjacent.
+        let mut sort_desc = Vec::with_capacity(self.partition_by.len() + self.order_by.len());
+        for name in self.partition_by.iter() {
+            sort_desc.push(SortColumnDescription {
+                column_name: name.clone(),
+                asc: true,
+                nulls_first: false,
+            });
+        }
+        sort_desc.extend(self.order_by.iter().cloned());
+
+        let block = if sort_desc.is_empty() {
+            block.clone()
+        } else {
+            DataBlock::sort_block(block, &sort_desc, None)?
+        };
+
+        let num_rows = block.num_rows();
+        let partitions = Self::partition_ranges(&block, &self.partition_by)?;
+        let peer_keys = self
+            .partition_by
+            .iter()
+            .cloned()
+            .chain(self.order_by.iter().map(|desc| desc.column_name.clone()))
+            .collect::<Vec<_>>();
+        let peers = Self::partition_ranges(&block, &peer_keys)?;
+
+        let args = self
+            .args
+            .iter()
+            .map(|name| Ok(block.try_column_by_name(name)?.clone()))
+            .collect::<Result<Vec<ColumnRef>>>()?;
+
+        let column = match &self.func {
+            WindowFuncType::RowNumber | WindowFuncType::Rank | WindowFuncType::DenseRank => {
+                let mut values = Vec::with_capacity(num_rows);
+                let mut peers = peers.iter();
+                for partition in partitions.iter() {
+                    let mut dense_rank = 0;
+                    for peer in peers.by_ref() {
+                        dense_rank += 1;
+                        for i in peer.clone() {
+                            let value = match self.func {
+                                WindowFuncType::RowNumber => i - partition.start + 1,
+                                WindowFuncType::Rank => peer.start - partition.start + 1,
+                                _ => dense_rank,
+                            };
+                            values.push(DataValue::UInt64(value as u64));
+                        }
+                        if peer.end >= partition.end {
+                            break;
+                        }
+                    }
+                }
+                self.output_field.data_type().create_column(&values)?
+            }
+            WindowFuncType::Lag { offset } | WindowFuncType::Lead { offset } => {
+                let offset = *offset as usize;
+                let is_lag = matches!(self.func, WindowFuncType::Lag { .. });
+                let mut values = Vec::with_capacity(num_rows);
+                for partition in partitions.iter() {
+                    for i in partition.clone() {
+                        let target = if is_lag {
+                            i.checked_sub(offset).filter(|j| *j >= partition.start)
+                        } else {
+                            Some(i + offset).filter(|j| *j < partition.end)
+                        };
+                        let value = match (target, args.get(1)) {
+                            (Some(j), _) => args[0].get(j),
+                            (None, Some(default)) => default.get(i),
+                            (None, None) => DataValue::Null,
+                        };
+                        values.push(value);
+                    }
+                }
+                self.output_field.data_type().create_column(&values)?
+            }
+            WindowFuncType::FirstValue | WindowFuncType::LastValue => {
+                let frames = self.frame_ranges(&block, &partitions, &peers)?;
+                let values = frames
+                    .iter()
+                    .map(|frame| {
+                        if frame.is_empty() {
+                            DataValue::Null
+                        } else if matches!(self.func, WindowFuncType::FirstValue) {
+                            args[0].get(frame.start)
+                        } else {
+                            args[0].get(frame.end - 1)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                self.output_field.data_type().create_column(&values)?
+            }
+            WindowFuncType::Aggregate { .. } => {
+                let frames = self.frame_ranges(&block, &partitions, &peers)?;
+                let func = self.aggregate.clone().unwrap();
+                self.evaluate_aggregate(func, &args, num_rows, &frames)?
+            }
+        };
+
+        block.add_column(column, self.output_field.clone())
+    }
+
+    /// Split the sorted block into ranges of rows with the same values of `keys`.
+    fn partition_ranges(block: &DataBlock, keys: &[String]) -> Result<Vec<Range<usize>>> {
+        if keys.is_empty() {
+            return Ok(vec![0..block.num_rows()]);
+        }
+
+        let arrays = keys
+            .iter()
+            .map(|name| {
+                let column = block.try_column_by_name(name)?;
+                Ok(column.as_arrow_array(column.data_type()))
+            })
+            .collect::<Result<Vec<ArrayRef>>>()?;
+        let sort_columns = arrays
+            .iter()
+            .map(|array| SortColumn {
+                values: array.as_ref(),
+                options: None,
+            })
+            .collect::<Vec<_>>();
+
+        Ok(lexicographical_partition_ranges(&sort_columns)?.collect())
+    }
+
+    /// Compute the frame of each row, `partitions` and `peers` are ranges of the sorted block.
+    fn frame_ranges(
+        &self,
+        block: &DataBlock,
+        partitions: &[Range<usize>],
+        peers: &[Range<usize>],
+    ) -> Result<Vec<Range<usize>>> {
+        // Order key of each row for RANGE frames with offset, descending keys are
+        // negated so that the keys are always ascending in a partition. Other RANGE
+        // frames only depend on the partition and the peers of the row.
+        let has_offset = [self.frame.start_bound, self.frame.end_bound]
+            .iter()
+            .any(|bound| {
+                matches!(
+                    bound,
+                    WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+                )
+            });
+        let keys = match (self.frame.units, self.order_by.first()) {
+            (WindowFrameUnits::Range, Some(desc)) if has_offset => {
+                let column = block.try_column_by_name(&desc.column_name)?;
+                (0..block.num_rows())
+                    .map(|i| {
+                        let value = column.get(i);
+                        if value.is_null() {
+                            Ok(None)
+                        } else {
+                            let key = value.as_f64()?;
+                            Ok(Some(if desc.asc { key } else { -key }))
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?
+            }
+            _ => vec![],
+        };
+
+        let mut frames = Vec::with_capacity(block.num_rows());
+        let mut peers = peers.iter().peekable();
+        for partition in partitions.iter() {
+            // Rows with non-null order key in current partition
+            let non_null = if keys.is_empty() {
+                partition.clone()
+            } else {
+                let start = partition.start
+                    + keys[partition.clone()]
+                        .iter()
+                        .take_while(|key| key.is_none())
+                        .count();
+                let end = start
+                    + keys[start..partition.end]
+                        .iter()
+                        .take_while(|key| key.is_some())
+                        .count();
+                start..end
+            };
+
+            for i in partition.clone() {
+                while peers.peek().map_or(false, |peer| peer.end <= i) {
+                    peers.next();
+                }
+                let peer = peers.peek().cloned().cloned().unwrap_or(i..i + 1);
+
+                let range_key = keys.get(i).cloned().flatten();
+                let start = match (self.frame.units, self.frame.start_bound) {
+                    (_, WindowFrameBound::Preceding(None)) => partition.start,
+                    (_, WindowFrameBound::Following(None)) => partition.end,
+                    (WindowFrameUnits::Rows, WindowFrameBound::CurrentRow) => i,
+                    (WindowFrameUnits::Rows, WindowFrameBound::Preceding(Some(n))) => {
+                        i.saturating_sub(n as usize).max(partition.start)
+                    }
+                    (WindowFrameUnits::Rows, WindowFrameBound::Following(Some(n))) => {
+                        (i + n as usize).min(partition.end)
+                    }
+                    (WindowFrameUnits::Range, WindowFrameBound::CurrentRow) => peer.start,
+                    (WindowFrameUnits::Range, WindowFrameBound::Preceding(Some(n))) => {
+                        Self::range_bound(&keys, &non_null, range_key, -(n as f64), false)
+                            .unwrap_or(peer.start)
+                    }
+                    (WindowFrameUnits::Range, WindowFrameBound::Following(Some(n))) => {
+                        Self::range_bound(&keys, &non_null, range_key, n as f64, false)
+                            .unwrap_or(peer.start)
+                    }
+                };
+                let end = match (self.frame.units, self.frame.end_bound) {
+                    (_, WindowFrameBound::Preceding(None)) => partition.start,
+                    (_, WindowFrameBound::Following(None)) => partition.end,
+                    (WindowFrameUnits::Rows, WindowFrameBound::CurrentRow) => i + 1,
+                    (WindowFrameUnits::Rows, WindowFrameBound::Preceding(Some(n))) => {
+                        (i + 1).saturating_sub(n as usize).max(partition.start)
+                    }
+                    (WindowFrameUnits::Rows, WindowFrameBound::Following(Some(n))) => {
+                        (i + 1 + n as usize).min(partition.end)
+                    }
+                    (WindowFrameUnits::Range, WindowFrameBound::CurrentRow) => peer.end,
+                    (WindowFrameUnits::Range, WindowFrameBound::Preceding(Some(n))) => {
+                        Self::range_bound(&keys, &non_null, range_key, -(n as f64), true)
+                            .unwrap_or(peer.end)
+                    }
+                    (WindowFrameUnits::Range, WindowFrameBound::Following(Some(n))) => {
+                        Self::range_bound(&keys, &non_null, range_key, n as f64, true)
+                            .unwrap_or(peer.end)
+                    }
+                };
+
+                frames.push(start..end.max(start));
+            }
+        }
+
+        Ok(frames)
+    }
+
+    /// Find the first row in `range` whose key is not less than (or greater than
+    /// if `inclusive`) `key + delta`. Returns None if the key of current row is NULL,
+    /// rows with NULL key are peers of each other.
+    fn range_bound(
+        keys: &[Option<f64>],
+        range: &Range<usize>,
+        key: Option<f64>,
+        delta: f64,
+        inclusive: bool,
+    ) -> Option<usize> {
+        let target = key? + delta;
+        let offset = keys[range.clone()].partition_point(|v| {
+            let v = v.unwrap_or_default();
+            if inclusive { v <= target } else { v < target }
+        });
+        Some(range.start + offset)
+    }
+
+    fn evaluate_aggregate(
+        &self,
+        func: AggregateFunctionRef,
+        args: &[ColumnRef],
+        num_rows: usize,
+        frames: &[Range<usize>],
+    ) -> Result<ColumnRef> {
+        let error = Rc::new(RefCell::new(None));
+        let agg = Agg {
+            func: func.clone(),
+            arena: Bump::with_capacity(2 * num_rows * func.state_layout().size()),
+            states: RefCell::new(Vec::with_capacity(2 * num_rows)),
+            error: error.clone(),
+        };
+
+        // Build a segment tree on the state of each row, so that the
+        // aggregation of any frame can be queried in logarithmic time.
+        let mut states = Vec::with_capacity(num_rows);
+        for i in 0..num_rows {
+            let row = args.iter().map(|c| c.slice(i, 1)).collect::<Vec<_>>();
+            let place = agg.new_state();
+            func.accumulate(place, &row, None, 1)?;
+            states.push(place);
+        }
+        let tree = SegmentPoint::build(states, agg);
+        Agg::check_error(&error)?;
+
+        let mut builder = self.output_field.data_type().create_mutable(num_rows);
+        for frame in frames {
+            let state = tree.query(frame.start, frame.end);
+            Agg::check_error(&error)?;
+            func.merge_result(state, builder.as_mut())?;
+        }
+
+        Ok(builder.to_column())
+    }
+}
+
+/// The operation of the segment tree. All the states are allocated in the arena
+/// and dropped with the tree, errors of merging states are recorded in `error`
+/// since `Operation` can not return them.
+struct Agg {
+    func: AggregateFunctionRef,
+    arena: Bump,
+    states: RefCell<Vec<StateAddr>>,
+    error: Rc<RefCell<Option<ErrorCode>>>,
+}
+
+impl Agg {
+    fn new_state(&self) -> StateAddr {
+        let place: StateAddr = self.arena.alloc_layout(self.func.state_layout()).into();
+        self.func.init_state(place);
+        self.states.borrow_mut().push(place);
+        place
+    }
+
+    fn merge(&self, place: StateAddr, rhs: StateAddr) {
+        if let Err(cause) = self.func.merge(place, rhs) {
+            self.error.borrow_mut().get_or_insert(cause);
+        }
+    }
+
+    fn check_error(error: &RefCell<Option<ErrorCode>>) -> Result<()> {
+        match error.borrow_mut().take() {
+            Some(cause) => Err(cause),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Agg {
+    fn drop(&mut self) {
+        if self.func.need_manual_drop_state() {
+            for state in self.states.get_mut().iter() {
+                unsafe { self.func.drop_state(*state) }
+            }
+        }
+    }
+}
+
+impl Operation<StateAddr> for Agg {
+    fn combine(&self, a: &StateAddr, b: &StateAddr) -> StateAddr {
+        let place = self.new_state();
+        self.merge(place, *a);
+        self.merge(place, *b);
+        place
+    }
+
+    fn combine_mut(&self, a: &mut StateAddr, b: &StateAddr) {
+        self.merge(*a, *b);
+    }
+
+    fn combine_mut2(&self, a: &StateAddr, b: &mut StateAddr) {
+        self.merge(*b, *a);
+    }
+}
+
+impl Commutative<StateAddr> for Agg {}
+
+impl Identity<StateAddr> for Agg {
+    fn identity(&self) -> StateAddr {
+        self.new_state()
+    }
+}
@@ -25,6 +25,7 @@ use crate::auth::Credential;
 use crate::servers::clickhouse::interactive_worker_base::InteractiveWorkerBase;
 use crate::servers::clickhouse::writers::to_clickhouse_err;
 use crate::servers::clickhouse::writers::QueryWriter;
+use crate::servers::utils::client_ip;
 use crate::sessions::SessionRef;
 
 pub struct InteractiveWorker {
@@ -109,11 +110,11 @@ impl ClickHouseSession for InteractiveWorker {
 
     async fn authenticate(&self, user: &str, password: &[u8], client_addr: &str) -> bool {
         // Here we don't handle the create context error.
-        let client_ip = client_addr.split(':').collect::<Vec<_>>()[0];
+        let client_ip = client_ip(client_addr);
         let credential = Credential::Password {
             name: user.to_string(),
             password: Some(password.to_owned()),
-            hostname: Some(client_ip.to_string()),
+            hostname: Some(client_ip),
         };
         let ctx = self.session.create_query_context().await;
         match ctx {
@@ -20,14 +20,11 @@ use common_base::base::TrySpawn;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
-use common_exception::ToErrorCode;
 use common_formats::FormatFactory;
 use common_io::prelude::parse_escape_string;
 use common_io::prelude::FormatSettings;
 use common_planners::InsertInputSource;
 use common_planners::PlanNode;
-use common_streams::NDJsonSourceBuilder;
-use futures::io::Cursor;
 use futures::StreamExt;
 use poem::error::InternalServerError;
 use poem::error::Result as PoemResult;
@@ -41,8 +38,6 @@ use tracing::error;
 
 use super::HttpQueryContext;
 use crate::interpreters::InterpreterFactory;
-use crate::pipelines::processors::port::OutputPort;
-use crate::pipelines::processors::StreamSourceV2;
 use crate::pipelines::SourcePipeBuilder;
 use crate::servers::http::v1::multipart_format::MultipartFormat;
 use crate::servers::http::v1::multipart_format::MultipartWorker;
@@ -183,19 +178,10 @@ pub async fn streaming_load(
                     };
                 }
 
-                if format.to_lowercase().as_str() == "ndjson"
-                    || format.to_lowercase().as_str() == "jsoneachrow"
-                {
-                    ndjson_source_pipe_builder(context.clone(), &plan, multipart).await
-                } else {
-                    Err(poem::Error::from_string(
-                        format!(
-                            "Streaming load only supports csv format, but got {}",
-                            format
-                        ),
-                        StatusCode::BAD_REQUEST,
-                    ))
-                }
+                Err(poem::Error::from_string(
+                    format!("Streaming load is not supported for format {}", format),
+                    StatusCode::BAD_REQUEST,
+                ))
             }
             _non_supported_source => Err(poem::Error::from_string(
                 "Only supports streaming upload. e.g. INSERT INTO $table FORMAT CSV",
@@ -250,27 +236,3 @@ fn format_source_pipe_builder(
         format_settings.clone(),
     )
 }
-
-async fn ndjson_source_pipe_builder(
-    ctx: Arc<QueryContext>,
-    plan: &PlanNode,
-    mut multipart: Multipart,
-) -> PoemResult<SourcePipeBuilder> {
-    let builder = NDJsonSourceBuilder::create(plan.schema(), FormatSettings::default());
-    let mut source_pipe_builder = SourcePipeBuilder::create();
-    while let Ok(Some(field)) = multipart.next_field().await {
-        let bytes = field
-            .bytes()
-            .await
-            .map_err_to_code(ErrorCode::BadBytes, || "Read part to field bytes error")
-            .map_err(InternalServerError)?;
-        let cursor = Cursor::new(bytes);
-        let ndjson_source = builder.build(cursor).map_err(InternalServerError)?;
-        let output_port = OutputPort::create();
-        let source =
-            StreamSourceV2::create(ctx.clone(), Box::new(ndjson_source), output_port.clone())
-                .map_err(InternalServerError)?;
-        source_pipe_builder.add_source(output_port, source);
-    }
-    Ok(source_pipe_builder)
-}
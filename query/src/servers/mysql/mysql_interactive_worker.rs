@@ -37,6 +37,7 @@ use tracing::error;
 use tracing::info;
 use tracing::Instrument;
 
+use crate::audit::AuditLogger;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterFactoryV2;
@@ -46,6 +47,7 @@ use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::QueryResult;
 use crate::servers::mysql::MySQLFederated;
 use crate::servers::mysql::MYSQL_VERSION;
+use crate::servers::utils::client_ip;
 use crate::servers::utils::use_planner_v2;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionRef;
@@ -248,17 +250,37 @@ impl<W: std::io::Write + Send + Sync> AsyncMysqlShim<W> for InteractiveWorker<W>
 
 impl<W: std::io::Write> InteractiveWorkerBase<W> {
     async fn authenticate(&self, salt: &[u8], info: CertifiedInfo) -> Result<bool> {
+        let res = self.do_authenticate(salt, &info).await;
+        let err = match &res {
+            Ok(true) => None,
+            Ok(false) => Some(ErrorCode::AuthenticateFailure("wrong password")),
+            Err(cause) => Some(cause.clone()),
+        };
+        AuditLogger::log_login(
+            &self.session,
+            info.user_name.clone(),
+            info.user_client_address.clone(),
+            "mysql_native_password",
+            err.as_ref(),
+        );
+        res
+    }
+
+    async fn do_authenticate(&self, salt: &[u8], info: &CertifiedInfo) -> Result<bool> {
         let user_name = &info.user_name;
-        let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        let client_ip = client_ip(&info.user_client_address);
 
         let ctx = self.session.create_query_context().await?;
         let user_manager = ctx.get_user_manager();
         let user_info = user_manager
-            .get_user_with_client_ip(&ctx.get_tenant(), user_name, client_ip)
+            .get_user_with_client_ip(&ctx.get_tenant(), user_name, &client_ip)
             .await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
         if authed {
+            user_manager
+                .check_network_policy(&ctx.get_tenant(), &user_info, Some(&client_ip))
+                .await?;
             self.session.set_current_user(user_info);
         }
         Ok(authed)
@@ -290,6 +312,14 @@ impl<W: std::io::Write> InteractiveWorkerBase<W> {
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
     fn federated_server_command_check(&self, query: &str) -> Option<DataBlock> {
+        // COMMIT and ROLLBACK are executed if there is a transaction in progress.
+        if self.session.get_txn_manager().is_active() {
+            let query = query.trim_start().to_uppercase();
+            if query.starts_with("COMMIT") || query.starts_with("ROLLBACK") {
+                return None;
+            }
+        }
+
         let federated = MySQLFederated::create();
         federated.check(query)
     }
@@ -115,6 +115,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 TypeID::UInt64 => Ok(ColumnType::MYSQL_TYPE_LONG),
                 TypeID::Float32 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
                 TypeID::Float64 => Ok(ColumnType::MYSQL_TYPE_FLOAT),
+                TypeID::Decimal | TypeID::Decimal256 => Ok(ColumnType::MYSQL_TYPE_NEWDECIMAL),
                 TypeID::String => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Boolean => Ok(ColumnType::MYSQL_TYPE_SHORT),
                 TypeID::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
@@ -123,6 +124,7 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                 TypeID::Interval => Ok(ColumnType::MYSQL_TYPE_LONG),
                 TypeID::Array => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Struct => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                TypeID::Map => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::VariantArray => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 TypeID::VariantObject => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
@@ -179,6 +181,11 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                             .write_col(
                                                 serializer.serialize_field(row_index, format)?,
                                             )?,
+                                        (TypeID::Decimal, DataValue::Int128(_))
+                                        | (TypeID::Decimal256, DataValue::Int256(_)) => row_writer
+                                            .write_col(
+                                                serializer.serialize_field(row_index, format)?,
+                                            )?,
                                         (TypeID::String, DataValue::String(v)) => {
                                             row_writer.write_col(v)?
                                         }
@@ -190,6 +197,10 @@ impl<'a, W: std::io::Write> DFQueryResultWriter<'a, W> {
                                             .write_col(
                                                 serializer.serialize_field(row_index, format)?,
                                             )?,
+                                        (TypeID::Map, DataValue::Array(_)) => row_writer
+                                            .write_col(
+                                                serializer.serialize_field(row_index, format)?,
+                                            )?,
                                         (TypeID::Variant, DataValue::Variant(_)) => row_writer
                                             .write_col(
                                                 serializer.serialize_field(row_index, format)?,
@@ -14,6 +14,8 @@
 
 // The servers module used for external communication with user, such as MySQL wired protocol, etc.
 
+use std::net::SocketAddr;
+
 use common_exception::Result;
 use common_settings::Settings;
 
@@ -37,3 +39,12 @@ pub fn use_planner_v2(
         }
     }
 }
+
+// Get the ip of the client from its socket address, such as `127.0.0.1:3307` or `[::1]:3307`,
+// the address is returned as it is if there is no port.
+pub fn client_ip(client_addr: &str) -> String {
+    match client_addr.parse::<SocketAddr>() {
+        Ok(addr) => addr.ip().to_string(),
+        Err(_) => client_addr.to_string(),
+    }
+}
@@ -26,6 +26,7 @@ mod session_ref;
 mod session_settings;
 mod session_status;
 mod session_type;
+mod user_storage_usage;
 
 pub use common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
@@ -40,3 +41,4 @@ pub use session_ref::SessionRef;
 pub use session_settings::Settings;
 pub use session_status::SessionStatus;
 pub use session_type::SessionType;
+pub use user_storage_usage::UserStorageUsageCache;
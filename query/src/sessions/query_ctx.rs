@@ -27,6 +27,7 @@ use common_base::base::Progress;
 use common_base::base::ProgressValues;
 use common_base::base::Runtime;
 use common_base::base::TrySpawn;
+use common_catalog::txn::TxnManagerRef;
 use common_contexts::DalContext;
 use common_contexts::DalMetrics;
 use common_datablocks::DataBlock;
@@ -451,6 +452,10 @@ impl TableContext for QueryContext {
             .processes_info()
             .await
     }
+
+    fn get_txn_manager(&self) -> TxnManagerRef {
+        self.shared.session.get_txn_manager()
+    }
 }
 
 impl TrySpawn for QueryContext {
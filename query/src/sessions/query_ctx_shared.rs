@@ -64,6 +64,7 @@ pub struct QueryContextShared {
     pub(in crate::sessions) result_progress: Arc<Progress>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
     pub(in crate::sessions) session: Arc<Session>,
+    pub(in crate::sessions) settings: Arc<Settings>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
@@ -89,10 +90,21 @@ impl QueryContextShared {
     ) -> Result<Arc<QueryContextShared>> {
         let conf = session.get_config();
 
+        // Cap the max threads of the query by the cpu quota of current user,
+        // the max_threads setting of the session is kept as it is.
+        let mut settings = session.get_settings();
+        if let Ok(user) = session.get_current_user() {
+            let max_cpu = user.quota.max_cpu;
+            if max_cpu != 0 {
+                settings = Arc::new(settings.with_max_threads_limit(max_cpu));
+            }
+        }
+
         let user_manager = session.session_mgr.get_user_api_provider();
 
         Ok(Arc::new(QueryContextShared {
             session,
+            settings,
             cluster_cache,
             init_query_id: Arc::new(RwLock::new(Uuid::new_v4().to_string())),
             scan_progress: Arc::new(Progress::create()),
@@ -183,7 +195,7 @@ impl QueryContextShared {
     }
 
     pub fn get_settings(&self) -> Arc<Settings> {
-        self.session.get_settings()
+        self.settings.clone()
     }
 
     pub fn get_changed_settings(&self) -> Arc<Settings> {
@@ -228,8 +240,14 @@ impl QueryContextShared {
     ) -> Result<Arc<dyn Table>> {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
-        let catalog = self.get_catalogs().get_catalog(catalog)?;
+        let catalog_name = catalog;
+        let catalog = self.get_catalogs().get_catalog(catalog_name)?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // In a transaction, the table is read at the version pinned by the transaction.
+        let cache_table = self
+            .session
+            .get_txn_manager()
+            .pin_table(catalog_name, cache_table);
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -252,6 +270,15 @@ impl QueryContextShared {
                     max_threads,
                     Some("query-ctx".to_string()),
                 )?);
+
+                // The query will be aborted if it uses more memory than the quota of current user.
+                if let Ok(user) = self.get_current_user() {
+                    let memory_limit = user.quota.max_memory_in_bytes as i64;
+                    let runtime_tracker = runtime.get_tracker();
+                    runtime_tracker
+                        .get_memory_tracker()
+                        .set_memory_limit(memory_limit);
+                }
                 *query_runtime = Some(runtime.clone());
                 Ok(runtime)
             }
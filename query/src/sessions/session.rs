@@ -12,11 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::net::SocketAddr;
 use std::sync::atomic::AtomicUsize;
 use std::sync::Arc;
 
 use chrono_tz::Tz;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_io::prelude::FormatSettings;
@@ -27,8 +29,10 @@ use common_users::RoleCacheMgr;
 use futures::channel::*;
 use opendal::Operator;
 use parking_lot::RwLock;
+use tracing::warn;
 
 use crate::catalogs::CatalogManager;
+use crate::interpreters::purge_txn_writes;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
@@ -203,6 +207,30 @@ impl Session {
         self.session_ctx.get_current_catalog()
     }
 
+    pub fn get_txn_manager(self: &Arc<Self>) -> TxnManagerRef {
+        self.session_ctx.get_txn_manager()
+    }
+
+    /// Rolls back the transaction left in progress by the session, the data written by
+    /// it are purged in background.
+    pub fn rollback_txn(self: &Arc<Self>) {
+        let writes = self.get_txn_manager().rollback();
+        if writes.is_empty() {
+            return;
+        }
+
+        let session = self.clone();
+        common_base::base::tokio::spawn(async move {
+            match session.create_query_context().await {
+                Ok(ctx) => purge_txn_writes(ctx, writes).await,
+                Err(e) => warn!(
+                    "failed to purge the data written by the transaction of session {}, the error: {}",
+                    session.id, e
+                ),
+            }
+        });
+    }
+
     pub fn get_current_tenant(self: &Arc<Self>) -> String {
         self.session_ctx.get_current_tenant()
     }
@@ -237,6 +265,37 @@ impl Session {
         Ok(all_roles)
     }
 
+    /// Returns the current role of the session, which is the role granted on the
+    /// authentication phase if any, otherwise the first role of the current user in
+    /// alphabetical order.
+    pub fn get_current_role(self: &Arc<Self>) -> Result<Option<String>> {
+        if let Some(auth_role) = self.session_ctx.get_auth_role() {
+            return Ok(Some(auth_role));
+        }
+        let current_user = self.get_current_user()?;
+        Ok(current_user.grants.roles().into_iter().min())
+    }
+
+    /// Returns true if the role is granted to the current session, either directly or
+    /// inherited from the other roles of the session.
+    pub async fn is_role_in_session(self: &Arc<Self>, role: &str) -> Result<bool> {
+        let all_roles = self.get_all_roles()?;
+        if all_roles.iter().any(|r| r == role) {
+            return Ok(true);
+        }
+
+        let tenant = self.get_current_tenant();
+        let role_cache = self
+            .get_shared_query_context()
+            .await?
+            .get_role_cache_manager();
+        Ok(role_cache
+            .find_related_roles(&tenant, &all_roles)
+            .await?
+            .iter()
+            .any(|r| r.name == role))
+    }
+
     pub async fn validate_privilege(
         self: &Arc<Self>,
         object: &GrantObject,
@@ -272,6 +331,53 @@ impl Session {
         )))
     }
 
+    /// Returns the columns of the table which can be read by current user, `None` means
+    /// all of them. The columns are only restricted when `SELECT` is granted on some
+    /// columns of the table, e.g. `GRANT SELECT(a, b) ON db.t TO role`, but not on the
+    /// table itself.
+    pub async fn get_accessible_columns(
+        self: &Arc<Self>,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<Option<HashSet<String>>> {
+        let object =
+            GrantObject::Table(catalog.to_string(), database.to_string(), table.to_string());
+        let current_user = self.get_current_user()?;
+        if current_user
+            .grants
+            .verify_privilege(&object, UserPrivilegeType::Select)
+        {
+            return Ok(None);
+        }
+
+        let all_roles = self.get_all_roles()?;
+        let tenant = self.get_current_tenant();
+        let role_cache = self
+            .get_shared_query_context()
+            .await?
+            .get_role_cache_manager();
+        let roles = role_cache.find_related_roles(&tenant, &all_roles).await?;
+        if roles.iter().any(|r| {
+            r.grants
+                .verify_privilege(&object, UserPrivilegeType::Select)
+        }) {
+            return Ok(None);
+        }
+
+        let columns = roles
+            .iter()
+            .map(|r| &r.grants)
+            .chain(std::iter::once(&current_user.grants))
+            .flat_map(|grants| grants.granted_columns(&object, UserPrivilegeType::Select))
+            .collect::<HashSet<_>>();
+        if columns.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(columns))
+        }
+    }
+
     pub fn get_settings(self: &Arc<Self>) -> Arc<Settings> {
         Arc::new(self.session_settings.clone())
     }
@@ -17,6 +17,8 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::Result;
 use common_meta_types::UserInfo;
 use futures::channel::oneshot::Sender;
@@ -36,6 +38,7 @@ pub struct SessionContext {
     client_host: RwLock<Option<SocketAddr>>,
     io_shutdown_tx: RwLock<Option<Sender<Sender<()>>>>,
     query_context_shared: RwLock<Option<Arc<QueryContextShared>>>,
+    txn_manager: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -51,6 +54,7 @@ impl SessionContext {
             current_database: RwLock::new("default".to_string()),
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
+            txn_manager: TxnManager::create(),
         })
     }
 
@@ -167,4 +171,9 @@ impl SessionContext {
         let mut lock = self.query_context_shared.write();
         lock.take()
     }
+
+    // Get the transaction of the session, which lives across the queries.
+    pub fn get_txn_manager(&self) -> TxnManagerRef {
+        self.txn_manager.clone()
+    }
 }
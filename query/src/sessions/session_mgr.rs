@@ -40,6 +40,7 @@ use tracing::info;
 use tracing_appender::non_blocking::WorkerGuard;
 
 use crate::api::DataExchangeManager;
+use crate::audit::AuditLogger;
 use crate::catalogs::CatalogManager;
 use crate::catalogs::CatalogManagerHelper;
 use crate::clusters::ClusterDiscovery;
@@ -50,6 +51,7 @@ use crate::sessions::session_ref::SessionRef;
 use crate::sessions::ProcessInfo;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
+use crate::sessions::UserStorageUsageCache;
 use crate::storages::cache::CacheManager;
 use crate::Config;
 
@@ -73,6 +75,8 @@ pub struct SessionManager {
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
     async_insert_queue: Arc<RwLock<Option<Arc<AsyncInsertQueue>>>>,
+    audit_logger: Arc<AuditLogger>,
+    user_storage_usage_cache: Arc<UserStorageUsageCache>,
 
     /// log_guard preserve the nonblocking logger's guards so that our logger
     /// can flushes spans/events on a drop
@@ -90,7 +94,7 @@ impl SessionManager {
         let _log_guards = init_logging(app_name.as_str(), &conf.log);
 
         let catalogs = Arc::new(CatalogManager::try_new(&conf).await?);
-        let storage_cache_manager = Arc::new(CacheManager::init(&conf.query));
+        let storage_cache_manager = Arc::new(CacheManager::init(&conf.query)?);
 
         // Cluster discovery.
         let discovery = ClusterDiscovery::create_global(conf.clone()).await?;
@@ -133,6 +137,10 @@ impl SessionManager {
                 Duration::from_millis(conf.query.async_insert_stale_timeout),
             )))));
 
+        let audit_logger = Arc::new(AuditLogger::try_create(&conf.query)?);
+        let user_storage_usage_cache =
+            Arc::new(UserStorageUsageCache::create(Duration::from_secs(15)));
+
         Ok(Arc::new(SessionManager {
             conf,
             catalogs,
@@ -150,6 +158,8 @@ impl SessionManager {
             mysql_conn_map,
             mysql_basic_conn_id: AtomicU32::new(9_u32.to_le() as u32),
             async_insert_queue,
+            audit_logger,
+            user_storage_usage_cache,
             _log_guards,
         }))
     }
@@ -194,6 +204,14 @@ impl SessionManager {
         self.role_cache_manager.clone()
     }
 
+    pub fn get_audit_logger(&self) -> Arc<AuditLogger> {
+        self.audit_logger.clone()
+    }
+
+    pub fn get_user_storage_usage_cache(&self) -> Arc<UserStorageUsageCache> {
+        self.user_storage_usage_cache.clone()
+    }
+
     pub async fn create_session(self: &Arc<Self>, typ: SessionType) -> Result<SessionRef> {
         // TODO: maybe deadlock
         let config = self.get_conf();
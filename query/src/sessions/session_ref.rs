@@ -57,6 +57,7 @@ impl Session {
     pub fn destroy_session_ref(self: &Arc<Self>) {
         if self.ref_count.fetch_sub(1, Ordering::Relaxed) == 1 {
             debug!("Destroy session {}", self.id);
+            self.rollback_txn();
             self.session_mgr.destroy_session(&self.id);
             self.quit();
         }
@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use common_exception::Result;
+use parking_lot::RwLock;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::sql::OPT_KEY_CREATED_BY;
+
+#[derive(Default)]
+struct Usages {
+    // Storage used by each table owner, in bytes.
+    owned: HashMap<String, u64>,
+    // Storage used by the tables without owner, in bytes.
+    unowned: u64,
+}
+
+impl Usages {
+    fn get(&self, owner: &str) -> u64 {
+        self.owned.get(owner).copied().unwrap_or(0) + self.unowned
+    }
+}
+
+struct CachedUsages {
+    usages: Usages,
+    cached_at: Instant,
+}
+
+/// Storage used by the users of a tenant, i.e. the total size of the tables they own.
+///
+/// The usages of all the users are aggregated in one pass over the catalog and cached
+/// for `ttl`, so the quota check of a write does not list every table of the tenant.
+/// A user may exceed the quota by the data written within `ttl`.
+///
+/// The tables without owner, e.g. the ones created before the owner is recorded, are
+/// counted for every user, so that writing to them doesn't bypass the quota.
+pub struct UserStorageUsageCache {
+    // Key is (tenant, catalog).
+    cache: RwLock<HashMap<(String, String), CachedUsages>>,
+    ttl: Duration,
+}
+
+impl UserStorageUsageCache {
+    pub fn create(ttl: Duration) -> UserStorageUsageCache {
+        UserStorageUsageCache {
+            cache: RwLock::new(HashMap::new()),
+            ttl,
+        }
+    }
+
+    pub async fn get_usage(
+        &self,
+        ctx: &Arc<QueryContext>,
+        catalog_name: &str,
+        owner: &str,
+    ) -> Result<u64> {
+        let key = (ctx.get_tenant(), catalog_name.to_string());
+        {
+            let cache = self.cache.read();
+            if let Some(cached) = cache.get(&key) {
+                if cached.cached_at.elapsed() < self.ttl {
+                    return Ok(cached.usages.get(owner));
+                }
+            }
+        }
+
+        let usages = Self::load_usages(ctx, catalog_name).await?;
+        let usage = usages.get(owner);
+        self.cache.write().insert(key, CachedUsages {
+            usages,
+            cached_at: Instant::now(),
+        });
+        Ok(usage)
+    }
+
+    async fn load_usages(ctx: &Arc<QueryContext>, catalog_name: &str) -> Result<Usages> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(catalog_name)?;
+        let mut usages = Usages::default();
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            for table in catalog
+                .list_tables(tenant.as_str(), database.name())
+                .await?
+            {
+                let table_info = table.get_table_info();
+                let statistics = &table_info.meta.statistics;
+                let size = statistics.compressed_data_bytes + statistics.index_data_bytes;
+                match table_info.options().get(OPT_KEY_CREATED_BY) {
+                    Some(owner) => *usages.owned.entry(owner.clone()).or_insert(0) += size,
+                    None => usages.unowned += size,
+                }
+            }
+        }
+        Ok(usages)
+    }
+}
@@ -133,6 +133,9 @@ where ExpressionBuilder<T>: FiledNameFormat
                 })
             }
             Scalar::SubqueryExpr(_) => Err(ErrorCode::UnImplement("Unsupported subquery expr")),
+            Scalar::WindowFunction(_) => Err(ErrorCode::LogicalError(
+                "Window function should be evaluated by Window operator",
+            )),
         }
     }
 
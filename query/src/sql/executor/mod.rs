@@ -19,6 +19,7 @@ mod physical_plan_display;
 mod physical_plan_visitor;
 mod physical_scalar;
 mod pipeline_builder;
+mod plan_profile;
 mod util;
 
 pub use expression_builder::ExpressionBuilder;
@@ -29,4 +30,8 @@ pub use physical_plan_builder::PhysicalPlanBuilder;
 pub use physical_plan_visitor::PhysicalPlanReplacer;
 pub use physical_scalar::*;
 pub use pipeline_builder::PipelineBuilder;
+pub use plan_profile::PipeProfiles;
+pub use plan_profile::PlanNodeProfile;
+pub use plan_profile::PlanProfiles;
+pub use plan_profile::PlanProfilesRef;
 pub use util::*;
@@ -20,10 +20,12 @@ use common_datavalues::BooleanType;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataTypeImpl;
 use common_datavalues::NullableType;
 use common_datavalues::ToDataType;
 use common_datavalues::Vu8;
 use common_exception::Result;
+use common_functions::window::WindowFrame;
 use common_planners::ReadDataSourcePlan;
 use common_planners::StageKind;
 
@@ -31,6 +33,7 @@ use super::physical_scalar::PhysicalScalar;
 use super::AggregateFunctionDesc;
 use super::SortDesc;
 use crate::sql::plans::JoinType;
+use crate::sql::plans::WindowFuncType;
 use crate::sql::IndexType;
 
 pub type ColumnID = String;
@@ -150,6 +153,37 @@ impl AggregateFinal {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AggregateExpand {
+    pub input: Box<PhysicalPlan>,
+    pub group_bys: Vec<ColumnID>,
+    /// Positions in `group_bys` of the columns of each grouping set
+    pub grouping_sets: Vec<Vec<usize>>,
+    pub grouping_id_column: ColumnID,
+}
+
+impl AggregateExpand {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = Vec::with_capacity(input_schema.fields().len() + 1);
+        for field in input_schema.fields() {
+            if self.group_bys.contains(field.name()) {
+                fields.push(DataField::new(
+                    field.name().as_str(),
+                    wrap_nullable(field.data_type()),
+                ));
+            } else {
+                fields.push(field.clone());
+            }
+        }
+        fields.push(DataField::new(
+            self.grouping_id_column.as_str(),
+            u32::to_data_type(),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct Sort {
     pub input: Box<PhysicalPlan>,
@@ -175,6 +209,30 @@ impl Limit {
     }
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Window {
+    pub input: Box<PhysicalPlan>,
+    pub func: WindowFuncType,
+    pub column_id: ColumnID,
+    pub args: Vec<ColumnID>,
+    pub partition_by: Vec<ColumnID>,
+    pub order_by: Vec<SortDesc>,
+    pub frame: WindowFrame,
+    pub return_type: DataTypeImpl,
+}
+
+impl Window {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let input_schema = self.input.output_schema()?;
+        let mut fields = input_schema.fields().clone();
+        fields.push(DataField::new(
+            self.column_id.as_str(),
+            self.return_type.clone(),
+        ));
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct HashJoin {
     pub build: Box<PhysicalPlan>,
@@ -200,6 +258,36 @@ impl HashJoin {
                 }
             }
 
+            JoinType::Right => {
+                fields = fields
+                    .iter()
+                    .map(|field| {
+                        DataField::new(field.name().as_str(), wrap_nullable(field.data_type()))
+                    })
+                    .collect();
+                for field in self.build.output_schema()?.fields() {
+                    fields.push(DataField::new(
+                        field.name().as_str(),
+                        field.data_type().clone(),
+                    ));
+                }
+            }
+
+            JoinType::Full => {
+                fields = fields
+                    .iter()
+                    .map(|field| {
+                        DataField::new(field.name().as_str(), wrap_nullable(field.data_type()))
+                    })
+                    .collect();
+                for field in self.build.output_schema()?.fields() {
+                    fields.push(DataField::new(
+                        field.name().as_str(),
+                        wrap_nullable(field.data_type()),
+                    ));
+                }
+            }
+
             JoinType::Semi | JoinType::Anti => {
                 // Do nothing
             }
@@ -289,8 +377,10 @@ pub enum PhysicalPlan {
     EvalScalar(EvalScalar),
     AggregatePartial(AggregatePartial),
     AggregateFinal(AggregateFinal),
+    AggregateExpand(AggregateExpand),
     Sort(Sort),
     Limit(Limit),
+    Window(Window),
     HashJoin(HashJoin),
     Exchange(Exchange),
 
@@ -316,8 +406,10 @@ impl PhysicalPlan {
             PhysicalPlan::EvalScalar(plan) => plan.output_schema(),
             PhysicalPlan::AggregatePartial(plan) => plan.output_schema(),
             PhysicalPlan::AggregateFinal(plan) => plan.output_schema(),
+            PhysicalPlan::AggregateExpand(plan) => plan.output_schema(),
             PhysicalPlan::Sort(plan) => plan.output_schema(),
             PhysicalPlan::Limit(plan) => plan.output_schema(),
+            PhysicalPlan::Window(plan) => plan.output_schema(),
             PhysicalPlan::HashJoin(plan) => plan.output_schema(),
             PhysicalPlan::Exchange(plan) => plan.output_schema(),
             PhysicalPlan::ExchangeSource(plan) => plan.output_schema(),
@@ -325,6 +417,14 @@ impl PhysicalPlan {
         }
     }
 
+    /// Returns the number of the nodes in the plan tree.
+    pub fn num_nodes(&self) -> usize {
+        1 + self
+            .children()
+            .map(|child| child.num_nodes())
+            .sum::<usize>()
+    }
+
     pub fn children<'a>(&'a self) -> Box<dyn Iterator<Item = &'a PhysicalPlan> + 'a> {
         match self {
             PhysicalPlan::TableScan(_) => Box::new(std::iter::empty()),
@@ -333,8 +433,10 @@ impl PhysicalPlan {
             PhysicalPlan::EvalScalar(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregatePartial(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::AggregateFinal(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::AggregateExpand(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Sort(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::Limit(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::Window(plan) => Box::new(std::iter::once(plan.input.as_ref())),
             PhysicalPlan::HashJoin(plan) => Box::new(
                 std::iter::once(plan.probe.as_ref()).chain(std::iter::once(plan.build.as_ref())),
             ),
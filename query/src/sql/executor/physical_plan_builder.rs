@@ -20,6 +20,7 @@ use common_planners::Extras;
 use common_planners::StageKind;
 use itertools::Itertools;
 
+use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
 use super::Exchange as PhysicalExchange;
@@ -29,6 +30,7 @@ use super::Limit;
 use super::Project;
 use super::Sort;
 use super::TableScan;
+use super::Window;
 use crate::sql::executor::util::check_physical;
 use crate::sql::executor::AggregateFunctionDesc;
 use crate::sql::executor::AggregateFunctionSignature;
@@ -233,6 +235,14 @@ impl PhysicalPlanBuilder {
 
                 Ok(result)
             }
+            RelOperator::AggregateExpand(expand) => {
+                Ok(PhysicalPlan::AggregateExpand(AggregateExpand {
+                    input: Box::new(self.build(s_expr.child(0)?)?),
+                    group_bys: expand.group_items.iter().map(|v| v.to_string()).collect(),
+                    grouping_sets: expand.grouping_sets.clone(),
+                    grouping_id_column: expand.grouping_id_index.to_string(),
+                }))
+            }
             RelOperator::Sort(sort) => Ok(PhysicalPlan::Sort(Sort {
                 input: Box::new(self.build(s_expr.child(0)?)?),
                 order_by: sort
@@ -250,6 +260,24 @@ impl PhysicalPlanBuilder {
                 limit: limit.limit,
                 offset: limit.offset,
             })),
+            RelOperator::Window(window) => Ok(PhysicalPlan::Window(Window {
+                input: Box::new(self.build(s_expr.child(0)?)?),
+                func: window.function.clone(),
+                column_id: window.index.to_string(),
+                args: window.arguments.iter().map(|v| v.to_string()).collect(),
+                partition_by: window.partition_by.iter().map(|v| v.to_string()).collect(),
+                order_by: window
+                    .order_by
+                    .iter()
+                    .map(|v| SortDesc {
+                        asc: v.asc,
+                        nulls_first: v.nulls_first,
+                        order_by: v.index.to_string(),
+                    })
+                    .collect(),
+                frame: window.frame,
+                return_type: window.return_type.clone(),
+            })),
             RelOperator::Exchange(exchange) => {
                 let mut keys = vec![];
                 let kind = match exchange {
@@ -315,6 +343,10 @@ impl PhysicalScalarBuilder {
                 ],
                 return_type: comp.data_type(),
             }),
+            // `GROUPING` is rewritten by `GroupingChecker` in aggregation
+            Scalar::FunctionCall(func) if func.func_name == "grouping" => Err(
+                ErrorCode::SemanticError("GROUPING can only be used with GROUP BY"),
+            ),
             Scalar::FunctionCall(func) => Ok(PhysicalScalar::Function {
                 name: func.func_name.clone(),
                 args: func
@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 use common_datavalues::format_data_type_sql;
 use itertools::Itertools;
 
+use crate::sql::executor::AggregateExpand;
 use crate::sql::executor::AggregateFinal;
 use crate::sql::executor::AggregatePartial;
 use crate::sql::executor::EvalScalar;
@@ -29,15 +30,31 @@ use crate::sql::executor::HashJoin;
 use crate::sql::executor::Limit;
 use crate::sql::executor::PhysicalPlan;
 use crate::sql::executor::PhysicalScalar;
+use crate::sql::executor::PlanNodeProfile;
+use crate::sql::executor::PlanProfiles;
 use crate::sql::executor::Project;
 use crate::sql::executor::Sort;
 use crate::sql::executor::TableScan;
+use crate::sql::executor::Window;
 use crate::sql::plans::JoinType;
 
 impl PhysicalPlan {
     pub fn format_indent(&self, indent: usize) -> impl std::fmt::Display + '_ {
         PhysicalPlanIndentFormatDisplay { indent, node: self }
     }
+
+    /// Formats the plan with the runtime profiles collected by `EXPLAIN ANALYZE`.
+    pub fn format_analyze<'a>(
+        &'a self,
+        plan_profiles: &'a PlanProfiles,
+    ) -> impl std::fmt::Display + 'a {
+        PhysicalPlanAnalyzeDisplay {
+            indent: 0,
+            plan_id: 0,
+            node: self,
+            plan_profiles,
+        }
+    }
 }
 
 pub struct PhysicalPlanIndentFormatDisplay<'a> {
@@ -48,31 +65,100 @@ pub struct PhysicalPlanIndentFormatDisplay<'a> {
 impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", "  ".repeat(self.indent))?;
+        format_node(f, self.node)?;
+
+        for node in self.node.children() {
+            writeln!(f)?;
+            write!(f, "{}", node.format_indent(self.indent + 1))?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct PhysicalPlanAnalyzeDisplay<'a> {
+    indent: usize,
+    // The id of the node, see `PlanProfiles`
+    plan_id: usize,
+    node: &'a PhysicalPlan,
+    plan_profiles: &'a PlanProfiles,
+}
+
+impl<'a> Display for PhysicalPlanAnalyzeDisplay<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", "  ".repeat(self.indent))?;
+        format_node(f, self.node)?;
 
-        match self.node {
-            PhysicalPlan::TableScan(scan) => write!(f, "{}", scan)?,
-            PhysicalPlan::Filter(filter) => write!(f, "{}", filter)?,
-            PhysicalPlan::Project(project) => write!(f, "{}", project)?,
-            PhysicalPlan::EvalScalar(eval_scalar) => write!(f, "{}", eval_scalar)?,
-            PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate)?,
-            PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate)?,
-            PhysicalPlan::Sort(sort) => write!(f, "{}", sort)?,
-            PhysicalPlan::Limit(limit) => write!(f, "{}", limit)?,
-            PhysicalPlan::HashJoin(join) => write!(f, "{}", join)?,
-            PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange)?,
-            PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source)?,
-            PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink)?,
+        if let Some(profile) = self.plan_profiles.get_plan_profile(self.plan_id) {
+            write!(f, ", {}", profile)?;
+        }
+        if let PhysicalPlan::TableScan(scan) = self.node {
+            if let Some(pruning) = &scan.source.statistics.pruning {
+                write!(
+                    f,
+                    ", pruning: [segments (range): {} -> {}, blocks (range): {} -> {}, blocks (bloom): {} -> {}]",
+                    pruning.segments_range_pruning_before,
+                    pruning.segments_range_pruning_after,
+                    pruning.blocks_range_pruning_before,
+                    pruning.blocks_range_pruning_after,
+                    pruning.blocks_bloom_pruning_before,
+                    pruning.blocks_bloom_pruning_after,
+                )?;
+            }
         }
 
+        let mut child_id = self.plan_id + 1;
         for node in self.node.children() {
             writeln!(f)?;
-            write!(f, "{}", node.format_indent(self.indent + 1))?;
+            write!(f, "{}", PhysicalPlanAnalyzeDisplay {
+                indent: self.indent + 1,
+                plan_id: child_id,
+                node,
+                plan_profiles: self.plan_profiles,
+            })?;
+            child_id += node.num_nodes();
         }
 
         Ok(())
     }
 }
 
+fn format_node(f: &mut Formatter<'_>, node: &PhysicalPlan) -> std::fmt::Result {
+    match node {
+        PhysicalPlan::TableScan(scan) => write!(f, "{}", scan),
+        PhysicalPlan::Filter(filter) => write!(f, "{}", filter),
+        PhysicalPlan::Project(project) => write!(f, "{}", project),
+        PhysicalPlan::EvalScalar(eval_scalar) => write!(f, "{}", eval_scalar),
+        PhysicalPlan::AggregatePartial(aggregate) => write!(f, "{}", aggregate),
+        PhysicalPlan::AggregateFinal(aggregate) => write!(f, "{}", aggregate),
+        PhysicalPlan::AggregateExpand(expand) => write!(f, "{}", expand),
+        PhysicalPlan::Sort(sort) => write!(f, "{}", sort),
+        PhysicalPlan::Limit(limit) => write!(f, "{}", limit),
+        PhysicalPlan::Window(window) => write!(f, "{}", window),
+        PhysicalPlan::HashJoin(join) => write!(f, "{}", join),
+        PhysicalPlan::Exchange(exchange) => write!(f, "{}", exchange),
+        PhysicalPlan::ExchangeSource(source) => write!(f, "{}", source),
+        PhysicalPlan::ExchangeSink(sink) => write!(f, "{}", sink),
+    }
+}
+
+impl Display for PlanNodeProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // The time is summed up over the processors, which may run in parallel
+        write!(
+            f,
+            "profile: [processors: {}, rows in: {}, rows out: {}, bytes in: {}, bytes out: {}, wall time: {:?}, cpu time: {:?}]",
+            self.processors,
+            self.values.rows_in,
+            self.values.rows_out,
+            self.values.bytes_in,
+            self.values.bytes_out,
+            self.values.wall_time,
+            self.values.cpu_time,
+        )
+    }
+}
+
 impl Display for TableScan {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "TableScan: [{}]", self.source.source_info.desc())
@@ -228,6 +314,59 @@ impl Display for AggregatePartial {
     }
 }
 
+impl Display for AggregateExpand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let grouping_sets = self
+            .grouping_sets
+            .iter()
+            .map(|set| {
+                format!(
+                    "({})",
+                    set.iter()
+                        .map(|position| self.group_bys[*position].clone())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(
+            f,
+            "AggregateExpand: grouping sets: [{}], grouping id: [{}]",
+            grouping_sets, self.grouping_id_column
+        )
+    }
+}
+
+impl Display for Window {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|item| {
+                format!(
+                    "{} {}",
+                    item.order_by,
+                    if item.asc { "ASC" } else { "DESC" }
+                )
+            })
+            .collect::<Vec<String>>();
+
+        write!(
+            f,
+            "Window: {}({}) partition by: [{}], order by: [{}], frame: [{} BETWEEN {} AND {}]",
+            self.func,
+            self.args.join(", "),
+            self.partition_by.join(", "),
+            order_by.join(", "),
+            self.frame.units,
+            self.frame.start_bound,
+            self.frame.end_bound
+        )
+    }
+}
+
 impl Display for Limit {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let limit = self.limit.as_ref().cloned().unwrap_or(0);
@@ -14,6 +14,7 @@
 
 use common_exception::Result;
 
+use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
 use super::EvalScalar;
@@ -27,6 +28,7 @@ use super::PhysicalPlan;
 use super::Project;
 use super::Sort;
 use super::TableScan;
+use super::Window;
 
 pub trait PhysicalPlanReplacer {
     fn replace(&mut self, plan: &PhysicalPlan) -> Result<PhysicalPlan> {
@@ -37,8 +39,10 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::EvalScalar(plan) => self.replace_eval_scalar(plan),
             PhysicalPlan::AggregatePartial(plan) => self.replace_aggregate_partial(plan),
             PhysicalPlan::AggregateFinal(plan) => self.replace_aggregate_final(plan),
+            PhysicalPlan::AggregateExpand(plan) => self.replace_aggregate_expand(plan),
             PhysicalPlan::Sort(plan) => self.replace_sort(plan),
             PhysicalPlan::Limit(plan) => self.replace_limit(plan),
+            PhysicalPlan::Window(plan) => self.replace_window(plan),
             PhysicalPlan::HashJoin(plan) => self.replace_hash_join(plan),
             PhysicalPlan::Exchange(plan) => self.replace_exchange(plan),
             PhysicalPlan::ExchangeSource(plan) => self.replace_exchange_source(plan),
@@ -98,6 +102,17 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_aggregate_expand(&mut self, plan: &AggregateExpand) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::AggregateExpand(AggregateExpand {
+            input: Box::new(input),
+            group_bys: plan.group_bys.clone(),
+            grouping_sets: plan.grouping_sets.clone(),
+            grouping_id_column: plan.grouping_id_column.clone(),
+        }))
+    }
+
     fn replace_hash_join(&mut self, plan: &HashJoin) -> Result<PhysicalPlan> {
         let build = self.replace(&plan.build)?;
         let probe = self.replace(&plan.probe)?;
@@ -133,6 +148,21 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_window(&mut self, plan: &Window) -> Result<PhysicalPlan> {
+        let input = self.replace(&plan.input)?;
+
+        Ok(PhysicalPlan::Window(Window {
+            input: Box::new(input),
+            func: plan.func.clone(),
+            column_id: plan.column_id.clone(),
+            args: plan.args.clone(),
+            partition_by: plan.partition_by.clone(),
+            order_by: plan.order_by.clone(),
+            frame: plan.frame,
+            return_type: plan.return_type.clone(),
+        }))
+    }
+
     fn replace_exchange(&mut self, plan: &Exchange) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -188,12 +218,18 @@ impl PhysicalPlan {
                 PhysicalPlan::AggregateFinal(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::AggregateExpand(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::Sort(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
                 PhysicalPlan::Limit(plan) => {
                     Self::traverse(&plan.input, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::Window(plan) => {
+                    Self::traverse(&plan.input, pre_visit, visit, post_visit);
+                }
                 PhysicalPlan::HashJoin(plan) => {
                     Self::traverse(&plan.build, pre_visit, visit, post_visit);
                     Self::traverse(&plan.probe, pre_visit, visit, post_visit);
@@ -24,6 +24,7 @@ use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::aggregates::AggregateFunctionRef;
 use common_functions::scalars::FunctionFactory;
 
+use super::AggregateExpand;
 use super::AggregateFinal;
 use super::AggregatePartial;
 use super::EvalScalar;
@@ -35,6 +36,7 @@ use super::Limit;
 use super::Project;
 use super::Sort;
 use super::TableScan;
+use super::Window;
 use crate::evaluator::EvalNode;
 use crate::evaluator::Evaluator;
 use crate::pipelines::processors::port::InputPort;
@@ -44,18 +46,24 @@ use crate::pipelines::processors::transforms::TransformFilterV2;
 use crate::pipelines::processors::transforms::TransformMarkJoin;
 use crate::pipelines::processors::transforms::TransformProject;
 use crate::pipelines::processors::transforms::TransformRename;
+use crate::pipelines::processors::transforms::TransformRightJoin;
 use crate::pipelines::processors::AggregatorParams;
 use crate::pipelines::processors::AggregatorTransformParams;
 use crate::pipelines::processors::JoinHashTable;
 use crate::pipelines::processors::MarkJoinCompactor;
+use crate::pipelines::processors::RightJoinCompactor;
 use crate::pipelines::processors::SinkBuildHashTable;
 use crate::pipelines::processors::Sinker;
 use crate::pipelines::processors::SortMergeCompactor;
+use crate::pipelines::processors::TransformAggregateExpand;
 use crate::pipelines::processors::TransformAggregator;
 use crate::pipelines::processors::TransformHashJoinProbe;
 use crate::pipelines::processors::TransformLimit;
 use crate::pipelines::processors::TransformSortMerge;
 use crate::pipelines::processors::TransformSortPartial;
+use crate::pipelines::processors::TransformSortSpill;
+use crate::pipelines::processors::TransformWindow;
+use crate::pipelines::processors::WindowCompactor;
 use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::SinkPipeBuilder;
@@ -65,6 +73,7 @@ use crate::sql::executor::physical_plan::ColumnID;
 use crate::sql::executor::physical_plan::PhysicalPlan;
 use crate::sql::executor::AggregateFunctionDesc;
 use crate::sql::executor::PhysicalScalar;
+use crate::sql::executor::PlanProfilesRef;
 use crate::sql::plans::JoinType;
 use crate::sql::ColumnBinding;
 
@@ -72,6 +81,13 @@ pub struct PipelineBuilder {
     ctx: Arc<QueryContext>,
     main_pipeline: Pipeline,
     pub pipelines: Vec<Pipeline>,
+
+    // The profiles of the processors, only collected by `EXPLAIN ANALYZE`
+    plan_profiles: Option<PlanProfilesRef>,
+    // The id of the next plan node to be built, see `PlanProfiles`
+    next_plan_id: usize,
+    // The number of the pipes of the main pipeline which are attributed to plan nodes
+    profiled_pipes: usize,
 }
 
 impl PipelineBuilder {
@@ -80,6 +96,21 @@ impl PipelineBuilder {
             ctx,
             pipelines: vec![],
             main_pipeline: Pipeline::create(),
+            plan_profiles: None,
+            next_plan_id: 0,
+            profiled_pipes: 0,
+        }
+    }
+
+    /// Creates a builder which attaches profiles to the processors, the profiles are
+    /// collected into `plan_profiles` by the plan nodes.
+    pub fn create_with_profiles(
+        ctx: Arc<QueryContext>,
+        plan_profiles: PlanProfilesRef,
+    ) -> PipelineBuilder {
+        PipelineBuilder {
+            plan_profiles: Some(plan_profiles),
+            ..Self::create(ctx)
         }
     }
 
@@ -101,6 +132,9 @@ impl PipelineBuilder {
     }
 
     fn build_pipeline(&mut self, plan: &PhysicalPlan) -> Result<()> {
+        let plan_id = self.next_plan_id;
+        self.next_plan_id += 1;
+
         match plan {
             PhysicalPlan::TableScan(scan) => self.build_table_scan(scan),
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
@@ -108,21 +142,48 @@ impl PipelineBuilder {
             PhysicalPlan::EvalScalar(eval_scalar) => self.build_eval_scalar(eval_scalar),
             PhysicalPlan::AggregatePartial(aggregate) => self.build_aggregate_partial(aggregate),
             PhysicalPlan::AggregateFinal(aggregate) => self.build_aggregate_final(aggregate),
+            PhysicalPlan::AggregateExpand(expand) => self.build_aggregate_expand(expand),
             PhysicalPlan::Sort(sort) => self.build_sort(sort),
             PhysicalPlan::Limit(limit) => self.build_limit(limit),
+            PhysicalPlan::Window(window) => self.build_window(window),
             PhysicalPlan::HashJoin(join) => self.build_join(join),
             PhysicalPlan::ExchangeSink(sink) => self.build_exchange_sink(sink),
             PhysicalPlan::ExchangeSource(source) => self.build_exchange_source(source),
             PhysicalPlan::Exchange(_) => Err(ErrorCode::LogicalError(
                 "Invalid physical plan with PhysicalPlan::Exchange",
             )),
+        }?;
+
+        // The pipes added by the children have been attributed to them already
+        self.profile_pipes(plan_id);
+        Ok(())
+    }
+
+    fn profile_pipes(&mut self, plan_id: usize) {
+        if let Some(plan_profiles) = &self.plan_profiles {
+            let pipes = self
+                .main_pipeline
+                .pipes
+                .iter()
+                .skip(self.profiled_pipes)
+                .map(|pipe| pipe.attach_profiles())
+                .collect();
+            plan_profiles.add_pipes(plan_id, pipes);
         }
+        self.profiled_pipes = self.main_pipeline.pipes.len();
     }
 
     fn build_join(&mut self, join: &HashJoin) -> Result<()> {
+        let join_plan_id = self.next_plan_id - 1;
+        // The probe side is visited before the build side, see `PhysicalPlan::children`
+        let build_plan_id = self.next_plan_id + join.probe.num_nodes();
+
         let state = self.build_join_state(join)?;
-        self.expand_build_side_pipeline(&join.build, state.clone())?;
-        self.build_join_probe(join, state)
+        self.expand_build_side_pipeline(&join.build, join_plan_id, build_plan_id, state.clone())?;
+        self.build_join_probe(join, state)?;
+
+        self.next_plan_id = build_plan_id + join.build.num_nodes();
+        Ok(())
     }
 
     fn build_join_state(&mut self, join: &HashJoin) -> Result<Arc<JoinHashTable>> {
@@ -137,10 +198,14 @@ impl PipelineBuilder {
     fn expand_build_side_pipeline(
         &mut self,
         build: &PhysicalPlan,
+        join_plan_id: usize,
+        build_plan_id: usize,
         join_state: Arc<JoinHashTable>,
     ) -> Result<()> {
         let build_side_context = QueryContext::create_from(self.ctx.clone());
-        let build_side_builder = PipelineBuilder::create(build_side_context);
+        let mut build_side_builder = PipelineBuilder::create(build_side_context);
+        build_side_builder.plan_profiles = self.plan_profiles.clone();
+        build_side_builder.next_plan_id = build_plan_id;
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -156,9 +221,12 @@ impl PipelineBuilder {
             );
         }
 
-        build_res
-            .main_pipeline
-            .add_pipe(sink_pipeline_builder.finalize());
+        // The hash table is built by the join
+        let sink_pipe = sink_pipeline_builder.finalize();
+        if let Some(plan_profiles) = &self.plan_profiles {
+            plan_profiles.add_pipes(join_plan_id, vec![sink_pipe.attach_profiles()]);
+        }
+        build_res.main_pipeline.add_pipe(sink_pipe);
 
         self.pipelines.push(build_res.main_pipeline);
         self.pipelines
@@ -292,6 +360,23 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    fn build_aggregate_expand(&mut self, expand: &AggregateExpand) -> Result<()> {
+        self.build_pipeline(&expand.input)?;
+
+        let input_schema = expand.input.output_schema()?;
+        let output_schema = expand.output_schema()?;
+        self.main_pipeline.add_transform(|input, output| {
+            TransformAggregateExpand::try_create(
+                input,
+                output,
+                input_schema.clone(),
+                output_schema.clone(),
+                &expand.group_bys,
+                &expand.grouping_sets,
+            )
+        })
+    }
+
     fn build_aggregate_partial(&mut self, aggregate: &AggregatePartial) -> Result<()> {
         self.build_pipeline(&aggregate.input)?;
         let params = Self::build_aggregator_params(
@@ -398,6 +483,20 @@ impl PipelineBuilder {
             TransformSortPartial::try_create(input, output, None, sort_desc.clone())
         })?;
 
+        // External sort, spill the sorted runs into disk when exceeding the memory threshold
+        if self.ctx.get_settings().get_sort_spill_threshold_bytes()? != 0 {
+            self.main_pipeline.resize(1)?;
+            return self.main_pipeline.add_transform(|input, output| {
+                TransformSortSpill::try_create(
+                    self.ctx.clone(),
+                    input,
+                    output,
+                    None,
+                    sort_desc.clone(),
+                )
+            });
+        }
+
         // Merge
         self.main_pipeline.add_transform(|input, output| {
             TransformSortMerge::try_create(
@@ -428,6 +527,39 @@ impl PipelineBuilder {
         })
     }
 
+    fn build_window(&mut self, window: &Window) -> Result<()> {
+        self.build_pipeline(&window.input)?;
+
+        let input_schema = window.input.output_schema()?;
+        let output_schema = window.output_schema()?;
+        let output_field = output_schema.field_with_name(&window.column_id)?.clone();
+        let order_by: Vec<SortColumnDescription> = window
+            .order_by
+            .iter()
+            .map(|desc| SortColumnDescription {
+                column_name: desc.order_by.clone(),
+                asc: desc.asc,
+                nulls_first: desc.nulls_first,
+            })
+            .collect();
+
+        // Window functions are evaluated over the whole input in single thread
+        self.main_pipeline.resize(1)?;
+        self.main_pipeline.add_transform(|input, output| {
+            let compactor = WindowCompactor::try_create(
+                window.func.clone(),
+                window.args.clone(),
+                window.partition_by.clone(),
+                order_by.clone(),
+                window.frame,
+                output_field.clone(),
+                input_schema.clone(),
+                output_schema.clone(),
+            )?;
+            TransformWindow::try_create(input, output, compactor)
+        })
+    }
+
     fn build_join_probe(&mut self, join: &HashJoin, state: Arc<JoinHashTable>) -> Result<()> {
         self.build_pipeline(&join.probe)?;
 
@@ -452,6 +584,19 @@ impl PipelineBuilder {
             })?;
         }
 
+        if join.join_type == JoinType::Right || join.join_type == JoinType::Full {
+            // The unmatched build side rows can only be emitted after all the
+            // probe processors have finished, so we merge them into one.
+            self.main_pipeline.resize(1)?;
+            self.main_pipeline.add_transform(|input, output| {
+                TransformRightJoin::try_create(
+                    input,
+                    output,
+                    RightJoinCompactor::create(state.clone()),
+                )
+            })?;
+        }
+
         Ok(())
     }
 
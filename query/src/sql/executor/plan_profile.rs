@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::ProcessorProfileRef;
+use crate::pipelines::processors::ProcessorProfileValues;
+
+pub type PlanProfilesRef = Arc<PlanProfiles>;
+
+/// The profiles of the processors of a pipe.
+pub type PipeProfiles = Vec<ProcessorProfileRef>;
+
+/// The profiles of the processors built for the nodes of a physical plan, collected
+/// by `EXPLAIN ANALYZE`.
+///
+/// A plan node is identified by its position in the pre-order traversal of the plan,
+/// in which the children of a node are visited in the order of `PhysicalPlan::children`.
+#[derive(Default)]
+pub struct PlanProfiles {
+    // The chains of the consecutive pipes built for each plan node
+    pipes: Mutex<BTreeMap<usize, Vec<Vec<PipeProfiles>>>>,
+}
+
+/// The summed up profile of the processors of a plan node.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlanNodeProfile {
+    pub processors: usize,
+    pub values: ProcessorProfileValues,
+}
+
+impl PlanProfiles {
+    pub fn create() -> PlanProfilesRef {
+        Arc::new(PlanProfiles::default())
+    }
+
+    /// Adds a chain of consecutive pipes built for the plan node.
+    pub fn add_pipes(&self, plan_id: usize, pipes: Vec<PipeProfiles>) {
+        if !pipes.is_empty() {
+            self.pipes.lock().entry(plan_id).or_default().push(pipes);
+        }
+    }
+
+    /// Returns None if no processor is built for the plan node.
+    pub fn get_plan_profile(&self, plan_id: usize) -> Option<PlanNodeProfile> {
+        let pipes = self.pipes.lock();
+        let chains = pipes.get(&plan_id)?;
+
+        let mut profile = PlanNodeProfile::default();
+        for chain in chains {
+            for (index, pipe) in chain.iter().enumerate() {
+                for processor in pipe {
+                    let values = processor.get_values();
+                    profile.processors += 1;
+                    // The rows flow through the chain, so only the input of the first
+                    // pipe and the output of the last pipe are counted.
+                    if index == 0 {
+                        profile.values.rows_in += values.rows_in;
+                        profile.values.bytes_in += values.bytes_in;
+                    }
+                    if index == chain.len() - 1 {
+                        profile.values.rows_out += values.rows_out;
+                        profile.values.bytes_out += values.bytes_out;
+                    }
+                    profile.values.wall_time += values.wall_time;
+                    profile.values.cpu_time += values.cpu_time;
+                }
+            }
+        }
+        Some(profile)
+    }
+}
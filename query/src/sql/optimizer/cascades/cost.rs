@@ -0,0 +1,76 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sql::optimizer::m_expr::MExpr;
+use crate::sql::optimizer::memo::Memo;
+use crate::sql::plans::RelOperator;
+use crate::sql::IndexType;
+
+pub type Cost = f64;
+
+/// Cost of processing a row.
+const COMPUTE_COST_PER_ROW: Cost = 1.0;
+/// Cost of inserting a row into the hash table of a hash join, which is much more
+/// expensive than probing the hash table with a row.
+const HASH_TABLE_COST_PER_ROW: Cost = 10.0;
+/// Cost of transferring a row between the nodes of the cluster.
+const EXCHANGE_COST_PER_ROW: Cost = 5.0;
+
+/// Estimates the cost of a physical operator with the cardinalities of its inputs, the
+/// costs of the children are not included.
+pub struct CostModel {
+    distributed: bool,
+}
+
+impl CostModel {
+    pub fn create(distributed: bool) -> Self {
+        CostModel { distributed }
+    }
+
+    pub fn compute_cost(&self, memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
+        match m_expr.plan() {
+            RelOperator::PhysicalScan(_) => {
+                Ok(cardinality(memo, m_expr.group_index())? * COMPUTE_COST_PER_ROW)
+            }
+            RelOperator::PhysicalHashJoin(_) => {
+                // The right child is the build side
+                let probe = cardinality(memo, m_expr.children()[0])?;
+                let build = cardinality(memo, m_expr.children()[1])?;
+                let mut cost = build * HASH_TABLE_COST_PER_ROW + probe * COMPUTE_COST_PER_ROW;
+                if self.distributed {
+                    // Both sides are shuffled by the join keys
+                    cost += (build + probe) * EXCHANGE_COST_PER_ROW;
+                }
+                Ok(cost)
+            }
+            _ => {
+                let mut cost = 0.0;
+                for child in m_expr.children() {
+                    cost += cardinality(memo, *child)? * COMPUTE_COST_PER_ROW;
+                }
+                Ok(cost)
+            }
+        }
+    }
+}
+
+fn cardinality(memo: &Memo, group_index: IndexType) -> Result<f64> {
+    memo.group(group_index)
+        .relational_prop()
+        .map(|prop| prop.cardinality)
+        .ok_or_else(|| ErrorCode::LogicalError("Relational property should have been filled"))
+}
@@ -12,21 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod cost;
 mod explore_rules;
 mod implement_rules;
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 
+use crate::sql::optimizer::cascades::cost::Cost;
+use crate::sql::optimizer::cascades::cost::CostModel;
 use crate::sql::optimizer::cascades::explore_rules::get_explore_rule_set;
 use crate::sql::optimizer::cascades::implement_rules::get_implement_rule_set;
-use crate::sql::optimizer::group::Group;
 use crate::sql::optimizer::m_expr::MExpr;
 use crate::sql::optimizer::memo::Memo;
-use crate::sql::optimizer::rule::RulePtr;
 use crate::sql::optimizer::rule::RuleSet;
 use crate::sql::optimizer::rule::TransformState;
-use crate::sql::optimizer::RequiredProperty;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Operator;
 use crate::sql::IndexType;
@@ -34,58 +37,85 @@ use crate::sql::IndexType;
 /// A cascades-style search engine to enumerate possible alternations of a relational expression and
 /// find the optimal one.
 ///
-/// NOTICE: we don't support cost-based optimization and lower bound searching for now.
-#[allow(dead_code)]
+/// The plans are compared by the costs estimated with the cardinalities of the groups.
+/// NOTICE: we don't support lower bound searching and physical properties for now.
 pub struct CascadesOptimizer {
-    // optimize_context: OptimizeContext,
     memo: Memo,
     explore_rules: RuleSet,
     implement_rules: RuleSet,
+    cost_model: CostModel,
+
+    /// The optimal plan of each group and its cost
+    best_plans: HashMap<IndexType, (Cost, SExpr)>,
 }
 
 impl CascadesOptimizer {
-    #[allow(dead_code)]
-    pub fn create() -> Self {
+    pub fn create(distributed: bool) -> Self {
         CascadesOptimizer {
             memo: Memo::create(),
             explore_rules: get_explore_rule_set(),
             implement_rules: get_implement_rule_set(),
+            cost_model: CostModel::create(distributed),
+            best_plans: HashMap::new(),
         }
     }
 
-    #[allow(dead_code)]
     fn init(&mut self, expression: SExpr) -> Result<()> {
         self.memo.init(expression)?;
 
         Ok(())
     }
 
-    #[allow(dead_code)]
     pub fn optimize(&mut self, expression: SExpr) -> Result<SExpr> {
         self.init(expression)?;
+        let root_index = self.root_index()?;
+
+        // The new expressions may enable the rules on the expressions explored before,
+        // so the memo is explored until no more expression is generated.
+        loop {
+            let num_expressions = self.memo.num_expressions();
+            self.explore_group(root_index, &mut HashSet::new())?;
+            if self.memo.num_expressions() == num_expressions {
+                break;
+            }
+        }
 
-        self.explore_group(self.memo.root().unwrap().group_index())?;
-
-        self.implement_group(self.memo.root().unwrap().group_index())?;
+        self.implement_group(root_index, &mut HashSet::new())?;
 
         self.find_optimal_plan()
     }
 
-    #[allow(dead_code)]
-    fn explore_group(&mut self, group_index: IndexType) -> Result<()> {
-        let group = self.memo.group(group_index);
-        let expressions: Vec<MExpr> = group.iter().cloned().collect();
-        for m_expr in expressions {
-            self.explore_expr(m_expr)?;
+    fn root_index(&self) -> Result<IndexType> {
+        self.memo
+            .root()
+            .map(|group| group.group_index())
+            .ok_or_else(|| ErrorCode::LogicalError("Root group cannot be None"))
+    }
+
+    fn explore_group(
+        &mut self,
+        group_index: IndexType,
+        explored: &mut HashSet<IndexType>,
+    ) -> Result<()> {
+        if !explored.insert(group_index) {
+            return Ok(());
+        }
+
+        // The expressions generated by the rules are appended to the group, and are
+        // explored as well.
+        let mut index = 0;
+        while index < self.memo.group(group_index).num_expressions() {
+            let m_expr = self.memo.group(group_index).m_expr(index).clone();
+            self.explore_expr(m_expr, explored)?;
+            index += 1;
         }
 
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn explore_expr(&mut self, m_expr: MExpr) -> Result<()> {
+    fn explore_expr(&mut self, m_expr: MExpr, explored: &mut HashSet<IndexType>) -> Result<()> {
         for child in m_expr.children() {
-            self.explore_group(*child)?;
+            self.explore_group(*child, explored)?;
         }
 
         let mut state = TransformState::new();
@@ -97,21 +127,31 @@ impl CascadesOptimizer {
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn implement_group(&mut self, group_index: IndexType) -> Result<()> {
+    fn implement_group(
+        &mut self,
+        group_index: IndexType,
+        implemented: &mut HashSet<IndexType>,
+    ) -> Result<()> {
+        if !implemented.insert(group_index) {
+            return Ok(());
+        }
+
         let group = self.memo.group(group_index);
         let expressions: Vec<MExpr> = group.iter().cloned().collect();
         for m_expr in expressions {
-            self.implement_expr(m_expr)?;
+            self.implement_expr(m_expr, implemented)?;
         }
 
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn implement_expr(&mut self, m_expr: MExpr) -> Result<()> {
+    fn implement_expr(
+        &mut self,
+        m_expr: MExpr,
+        implemented: &mut HashSet<IndexType>,
+    ) -> Result<()> {
         for child in m_expr.children() {
-            self.implement_group(*child)?;
+            self.implement_group(*child, implemented)?;
         }
 
         let mut state = TransformState::new();
@@ -123,7 +163,6 @@ impl CascadesOptimizer {
         Ok(())
     }
 
-    #[allow(dead_code)]
     fn insert_from_transform_state(
         &mut self,
         group_index: IndexType,
@@ -136,68 +175,55 @@ impl CascadesOptimizer {
         Ok(())
     }
 
-    #[allow(dead_code)]
     fn insert_expression(&mut self, group_index: IndexType, expression: &SExpr) -> Result<()> {
         self.memo.insert(Some(group_index), expression.clone())?;
 
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn apply_rule(&self, m_expr: &MExpr, rule: &RulePtr, state: &mut TransformState) -> Result<()> {
-        m_expr.apply_rule(&self.memo, rule, state)?;
-
-        Ok(())
+    fn find_optimal_plan(&mut self) -> Result<SExpr> {
+        let root_index = self.root_index()?;
+        let (_, plan) = self.optimize_group(root_index)?;
+        Ok(plan)
     }
 
-    #[allow(dead_code)]
-    fn find_optimal_plan(&self) -> Result<SExpr> {
-        let root_group = self.memo.root().unwrap();
-
-        let required_prop = RequiredProperty::default();
-
-        self.optimize_group(root_group, &required_prop)
-    }
-
-    /// We don't have cost mechanism for evaluate cost of plans, so we just extract
-    /// first physical plan in a group that satisfies given RequiredProperty.
-    #[allow(dead_code)]
-    fn optimize_group(&self, group: &Group, required_prop: &RequiredProperty) -> Result<SExpr> {
-        for m_expr in group.iter() {
-            if m_expr.plan().is_physical() {
-                let plan = m_expr.plan();
-
-                // TODO: Check properties
-                // let physical = plan.as_physical_plan().unwrap();
-                // let relational_prop = group.relational_prop().unwrap();
-                // let dummy_physical_prop = PhysicalProperty::default();
-                // let required_prop = physical.compute_required_prop(required_prop);
-                //
-                // if !required_prop.provided_by(relational_prop, &dummy_physical_prop) {
-                //     continue;
-                // }
-
-                let children = self.optimize_m_expr(m_expr, required_prop)?;
-                let result = SExpr::create(plan.clone(), children, None);
-                return Ok(result);
-            }
+    /// Returns the physical plan of the group with the lowest cost. The expressions are
+    /// compared in the order of insertion, so the original plan is kept if the costs are equal.
+    fn optimize_group(&mut self, group_index: IndexType) -> Result<(Cost, SExpr)> {
+        if let Some(best_plan) = self.best_plans.get(&group_index) {
+            return Ok(best_plan.clone());
         }
 
-        Err(ErrorCode::LogicalError("Cannot find an appropriate plan"))
-    }
+        let expressions: Vec<MExpr> = self
+            .memo
+            .group(group_index)
+            .iter()
+            .filter(|m_expr| m_expr.plan().is_physical())
+            .cloned()
+            .collect();
+        let mut best_plan: Option<(Cost, SExpr)> = None;
+        for m_expr in expressions {
+            // TODO: Check properties
+            let mut cost = self.cost_model.compute_cost(&self.memo, &m_expr)?;
+            let mut children = Vec::with_capacity(m_expr.arity());
+            for child in m_expr.children() {
+                let (child_cost, child_plan) = self.optimize_group(*child)?;
+                cost += child_cost;
+                children.push(child_plan);
+            }
 
-    #[allow(dead_code)]
-    fn optimize_m_expr(
-        &self,
-        m_expr: &MExpr,
-        required_prop: &RequiredProperty,
-    ) -> Result<Vec<SExpr>> {
-        let mut children = vec![];
-        for child in m_expr.children() {
-            let group = self.memo.group(*child);
-            children.push(self.optimize_group(group, required_prop)?);
+            if best_plan
+                .as_ref()
+                .map_or(true, |(best_cost, _)| cost < *best_cost)
+            {
+                let plan = SExpr::create(m_expr.plan().clone(), children, None);
+                best_plan = Some((cost, plan));
+            }
         }
 
-        Ok(children)
+        let best_plan =
+            best_plan.ok_or_else(|| ErrorCode::LogicalError("Cannot find an appropriate plan"))?;
+        self.best_plans.insert(group_index, best_plan.clone());
+        Ok(best_plan)
     }
 }
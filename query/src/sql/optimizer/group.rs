@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::iter::Iterator;
 
 use common_exception::Result;
@@ -25,6 +26,8 @@ use crate::sql::IndexType;
 pub struct Group {
     group_index: IndexType,
     expressions: Vec<MExpr>,
+    // Fingerprints of the expressions, to avoid inserting an expression twice
+    fingerprints: HashSet<String>,
 
     /// Relational property shared by expressions in a same `Group`
     relational_prop: Option<RelationalProperty>,
@@ -35,6 +38,7 @@ impl Group {
         Group {
             group_index: index,
             expressions: vec![],
+            fingerprints: HashSet::new(),
             relational_prop: None,
         }
     }
@@ -47,8 +51,25 @@ impl Group {
         self.expressions.iter()
     }
 
+    pub fn num_expressions(&self) -> usize {
+        self.expressions.len()
+    }
+
+    pub fn m_expr(&self, index: usize) -> &MExpr {
+        &self.expressions[index]
+    }
+
+    /// Inserts the expression into the group, an expression identical to an existing
+    /// one is ignored.
     pub fn insert(&mut self, group_expression: MExpr) -> Result<()> {
-        self.expressions.push(group_expression);
+        let fingerprint = format!(
+            "{:?} {:?}",
+            group_expression.plan(),
+            group_expression.children()
+        );
+        if self.fingerprints.insert(fingerprint) {
+            self.expressions.push(group_expression);
+        }
         Ok(())
     }
 
@@ -451,6 +451,7 @@ impl SubqueryRewriter {
                     table_index,
                     columns: self.derived_columns.values().cloned().collect(),
                     push_down_predicates: None,
+                    statistics: Default::default(),
                 }
                 .into(),
             );
@@ -628,6 +629,14 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(plan.plan().clone(), flatten_plan))
             }
 
+            RelOperator::Window(_) => Err(ErrorCode::UnImplement(
+                "Window function in correlated subquery is not supported",
+            )),
+
+            RelOperator::AggregateExpand(_) => Err(ErrorCode::UnImplement(
+                "GROUPING SETS in correlated subquery is not supported",
+            )),
+
             RelOperator::Exchange(_)
             | RelOperator::Pattern(_)
             | RelOperator::LogicalGet(_)
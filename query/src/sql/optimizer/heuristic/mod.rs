@@ -93,10 +93,21 @@ impl HeuristicOptimizer {
     }
 
     pub fn optimize(&mut self, s_expr: SExpr) -> Result<SExpr> {
+        let optimized = self.optimize_logical(s_expr)?;
+        self.optimize_physical(optimized)
+    }
+
+    /// Decorrelates the subqueries and rewrites the plan with the rules.
+    pub fn optimize_logical(&mut self, s_expr: SExpr) -> Result<SExpr> {
         let pre_optimized = self.pre_optimize(s_expr)?;
         let optimized = self.optimize_expression(&pre_optimized)?;
-        let post_optimized = self.post_optimize(optimized)?;
-        let mut result = self.implement_expression(&post_optimized)?;
+        self.post_optimize(optimized)
+    }
+
+    /// Implements the logical operators left in the plan, and enforces the distribution
+    /// of the plan if it's executed in cluster.
+    pub fn optimize_physical(&mut self, s_expr: SExpr) -> Result<SExpr> {
+        let mut result = self.implement_expression(&s_expr)?;
 
         if self.enable_distributed_optimization && validate_distributed_query(&result) {
             let required = RequiredProperty {
@@ -125,9 +125,14 @@ impl SubqueryRewriter {
                 self.rewrite(s_expr.child(1)?)?,
             )),
 
-            RelOperator::Project(_) | RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(
-                SExpr::create_unary(s_expr.plan().clone(), self.rewrite(s_expr.child(0)?)?),
-            ),
+            RelOperator::Project(_)
+            | RelOperator::Limit(_)
+            | RelOperator::Sort(_)
+            | RelOperator::AggregateExpand(_)
+            | RelOperator::Window(_) => Ok(SExpr::create_unary(
+                s_expr.plan().clone(),
+                self.rewrite(s_expr.child(0)?)?,
+            )),
 
             RelOperator::LogicalGet(_) => Ok(s_expr.clone()),
 
@@ -198,6 +203,8 @@ impl SubqueryRewriter {
 
             Scalar::AggregateFunction(_) => Ok((scalar.clone(), s_expr.clone())),
 
+            Scalar::WindowFunction(_) => Ok((scalar.clone(), s_expr.clone())),
+
             Scalar::FunctionCall(func) => {
                 let mut args = vec![];
                 let mut s_expr = s_expr.clone();
@@ -12,11 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_exception::Result;
 
 use crate::sql::optimizer::group::Group;
 use crate::sql::optimizer::m_expr::MExpr;
 use crate::sql::optimizer::s_expr::SExpr;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::Operator;
+use crate::sql::plans::RelOperator;
 use crate::sql::IndexType;
 
 /// `Memo` is a search space which memoize possible plans of a query.
@@ -26,6 +33,10 @@ use crate::sql::IndexType;
 pub struct Memo {
     groups: Vec<Group>,
     root: Option<IndexType>,
+
+    /// Groups of the inner joins, keyed by the fingerprints of the joins. The same join
+    /// generated by different transformations is put into the same group.
+    join_groups: HashMap<String, IndexType>,
 }
 
 impl Memo {
@@ -33,6 +44,7 @@ impl Memo {
         Memo {
             groups: vec![],
             root: None,
+            join_groups: HashMap::new(),
         }
     }
 
@@ -69,25 +81,41 @@ impl Memo {
             return Ok(group_index);
         }
 
-        let mut _new_group = false;
+        let plan = expression.plan();
 
         // Create new group if not specified
         let group_index = match target_group {
             Some(index) => index,
             _ => {
-                _new_group = true;
-                self.add_group()
+                let relational_prop = if plan.is_logical() {
+                    let m_expr =
+                        MExpr::create(self.groups.len(), plan.clone(), children_group.clone());
+                    Some(RelExpr::with_m_expr(&m_expr, self).derive_relational_prop()?)
+                } else {
+                    None
+                };
+                let fingerprint = relational_prop
+                    .as_ref()
+                    .and_then(|prop| join_fingerprint(plan, prop));
+                match fingerprint
+                    .as_ref()
+                    .and_then(|fingerprint| self.join_groups.get(fingerprint))
+                {
+                    Some(index) => *index,
+                    None => {
+                        let index = self.add_group();
+                        if let Some(relational_prop) = relational_prop {
+                            self.group_mut(index).set_relational_prop(relational_prop);
+                        }
+                        if let Some(fingerprint) = fingerprint {
+                            self.join_groups.insert(fingerprint, index);
+                        }
+                        index
+                    }
+                }
             }
         };
 
-        // if new_group {
-        // let group = self.group_mut(group_index);
-        // let relational_prop = expression.compute_relational_prop();
-        // group.set_relational_prop(relational_prop);
-        // }
-
-        let plan = expression.plan();
-
         let group_expression = MExpr::create(group_index, plan.clone(), children_group);
         self.insert_m_expr(group_index, group_expression)?;
 
@@ -98,6 +126,14 @@ impl Memo {
         &self.groups[index]
     }
 
+    /// Returns the number of expressions in all the groups.
+    pub fn num_expressions(&self) -> usize {
+        self.groups
+            .iter()
+            .map(|group| group.num_expressions())
+            .sum()
+    }
+
     pub fn insert_m_expr(&mut self, group_index: IndexType, expression: MExpr) -> Result<()> {
         self.group_mut(group_index).insert(expression)
     }
@@ -113,3 +149,37 @@ impl Memo {
         group_index
     }
 }
+
+/// The fingerprint of an inner join, which consists of the joined columns and the join
+/// conditions, the order of the joined relations is ignored.
+fn join_fingerprint(plan: &RelOperator, relational_prop: &RelationalProperty) -> Option<String> {
+    let join = match plan {
+        RelOperator::LogicalInnerJoin(join)
+            if matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                && !join.from_correlated_subquery =>
+        {
+            join
+        }
+        _ => return None,
+    };
+
+    let mut columns = relational_prop.output_columns.iter().collect::<Vec<_>>();
+    columns.sort();
+    let mut conditions = join
+        .left_conditions
+        .iter()
+        .zip(join.right_conditions.iter())
+        .map(|(left, right)| {
+            let mut keys = [format!("{:?}", left), format!("{:?}", right)];
+            keys.sort();
+            keys.join(" = ")
+        })
+        .chain(
+            join.other_conditions
+                .iter()
+                .map(|condition| format!("{:?}", condition)),
+        )
+        .collect::<Vec<_>>();
+    conditions.sort();
+    Some(format!("{:?} {:?}", columns, conditions))
+}
@@ -31,24 +31,36 @@ pub use heuristic::DEFAULT_REWRITE_RULES;
 pub use m_expr::MExpr;
 pub use memo::Memo;
 pub use pattern_extractor::PatternExtractor;
+pub use property::column_of;
 pub use property::ColumnSet;
+pub use property::ColumnStat;
+pub use property::ColumnStatSet;
 pub use property::Distribution;
 pub use property::PhysicalProperty;
 pub use property::RelExpr;
 pub use property::RelationalProperty;
 pub use property::RequiredProperty;
+pub use property::SelectivityEstimator;
+pub use property::Statistics;
+pub use property::DEFAULT_CARDINALITY;
+pub use property::DEFAULT_SELECTIVITY;
 pub use rule::RuleFactory;
 pub use s_expr::SExpr;
 
 use self::util::contains_local_table_scan;
+use self::util::count_reorderable_joins;
 use super::plans::Plan;
 use crate::sessions::QueryContext;
+use crate::sql::optimizer::cascades::CascadesOptimizer;
 pub use crate::sql::optimizer::heuristic::RuleList;
 pub use crate::sql::optimizer::rule::RuleID;
 use crate::sql::optimizer::rule::RuleSet;
 use crate::sql::plans::CopyPlanV2;
 use crate::sql::MetadataRef;
 
+/// The max number of the joins reordered by the cost.
+const MAX_REORDERED_JOINS: usize = 6;
+
 #[derive(Debug, Clone, Default)]
 pub struct OptimizerConfig {
     pub enable_distributed_optimization: bool,
@@ -124,10 +136,15 @@ pub fn optimize_query(
         && !contains_local_table_scan(&s_expr, &metadata);
 
     let mut heuristic = HeuristicOptimizer::new(ctx, metadata, rules, enable_distributed_query);
-    let optimized = heuristic.optimize(s_expr)?;
-    // TODO: enable cascades optimizer
-    // let mut cascades = CascadesOptimizer::create(ctx);
-    // cascades.optimize(s_expr)
+    let mut optimized = heuristic.optimize_logical(s_expr)?;
+
+    // The joins are reordered by the cost, the exploration is exhaustive, so only the
+    // queries with a few joins are optimized.
+    let num_joins = count_reorderable_joins(&optimized);
+    if num_joins > 0 && num_joins <= MAX_REORDERED_JOINS {
+        let mut cascades = CascadesOptimizer::create(enable_distributed_query);
+        optimized = cascades.optimize(optimized)?;
+    }
 
-    Ok(optimized)
+    heuristic.optimize_physical(optimized)
 }
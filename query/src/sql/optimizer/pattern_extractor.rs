@@ -32,11 +32,11 @@ impl PatternExtractor {
 
         if pattern.is_pattern() {
             // Pattern operator is `Pattern`, we can return current operator.
-            return vec![SExpr::create(
-                m_expr.plan().clone(),
-                vec![],
-                Some(m_expr.group_index()),
-            )];
+            let mut s_expr =
+                SExpr::create(m_expr.plan().clone(), vec![], Some(m_expr.group_index()));
+            // The children are not extracted, the property can't be derived from them.
+            s_expr.set_rel_prop(memo.group(m_expr.group_index()).relational_prop().cloned());
+            return vec![s_expr];
         }
 
         let pattern_children = pattern.children();
@@ -40,6 +40,12 @@ impl<'a> RelExpr<'a> {
     }
 
     pub fn derive_relational_prop(&self) -> Result<RelationalProperty> {
+        if let RelExpr::SExpr { expr } = self {
+            if let Some(rel_prop) = expr.rel_prop() {
+                return Ok(rel_prop.clone());
+            }
+        }
+
         let plan = match self {
             RelExpr::SExpr { expr } => expr.plan(),
             RelExpr::MExpr { expr, .. } => expr.plan(),
@@ -62,15 +68,19 @@ impl<'a> RelExpr<'a> {
                 let rel_expr = RelExpr::with_s_expr(child);
                 rel_expr.derive_relational_prop()
             }
-            RelExpr::MExpr { expr, memo } => memo
-                .group(expr.group_index())
-                .relational_prop()
-                .cloned()
-                .ok_or_else(|| {
-                    ErrorCode::LogicalError(
-                        "Relational property should have been filled".to_string(),
-                    )
-                }),
+            RelExpr::MExpr { expr, memo } => {
+                let child = expr.children().get(index).ok_or_else(|| {
+                    ErrorCode::LogicalError(format!("Invalid children index: {}", index))
+                })?;
+                memo.group(*child)
+                    .relational_prop()
+                    .cloned()
+                    .ok_or_else(|| {
+                        ErrorCode::LogicalError(
+                            "Relational property should have been filled".to_string(),
+                        )
+                    })
+            }
         }
     }
 
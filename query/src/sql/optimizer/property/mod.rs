@@ -14,11 +14,19 @@
 
 mod builder;
 mod enforcer;
+mod stat;
 
 use std::collections::HashSet;
 
 pub use builder::RelExpr;
 pub use enforcer::require_property;
+pub use stat::column_of;
+pub use stat::ColumnStat;
+pub use stat::ColumnStatSet;
+pub use stat::SelectivityEstimator;
+pub use stat::Statistics;
+pub use stat::DEFAULT_CARDINALITY;
+pub use stat::DEFAULT_SELECTIVITY;
 
 use crate::sql::common::IndexType;
 use crate::sql::plans::Scalar;
@@ -36,10 +44,15 @@ impl RequiredProperty {
     }
 }
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug)]
 pub struct RelationalProperty {
     pub output_columns: ColumnSet,
     pub outer_columns: ColumnSet,
+
+    /// Estimated number of the output rows
+    pub cardinality: f64,
+    /// Statistics of the output columns, if they are known
+    pub column_stats: ColumnStatSet,
 }
 
 #[derive(Default, Clone)]
@@ -0,0 +1,241 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_datavalues::DataValue;
+use common_fuse_meta::meta::Histogram;
+
+use crate::sql::common::IndexType;
+use crate::sql::plans::ComparisonOp;
+use crate::sql::plans::Scalar;
+
+/// The cardinality of a table whose row count is unknown.
+pub const DEFAULT_CARDINALITY: f64 = 1000.0;
+/// The selectivity of a predicate which can not be estimated with the statistics.
+pub const DEFAULT_SELECTIVITY: f64 = 0.2;
+
+/// Statistics of a column, collected from the storage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStat {
+    /// Min value of the column, NULLs are excluded.
+    pub min: DataValue,
+    /// Max value of the column, NULLs are excluded.
+    pub max: DataValue,
+    pub null_count: u64,
+    /// Number of the distinct values, collected by `ANALYZE TABLE`.
+    pub distinct_count: Option<u64>,
+    /// Histogram of the values, collected by `ANALYZE TABLE`.
+    pub histogram: Option<Histogram>,
+}
+
+impl ColumnStat {
+    /// Estimates the number of distinct values of the column in a relation of
+    /// `cardinality` rows.
+    ///
+    /// Without the collected number, only the integer columns can be estimated,
+    /// by the number of the values between min and max.
+    pub fn ndv(&self, cardinality: f64) -> Option<f64> {
+        if let Some(distinct_count) = self.distinct_count {
+            return Some((distinct_count as f64).max(1.0).min(cardinality.max(1.0)));
+        }
+        if !self.min.is_integer() || !self.max.is_integer() {
+            return None;
+        }
+        let (min, max) = (self.min.as_f64().ok()?, self.max.as_f64().ok()?);
+        Some((max - min + 1.0).max(1.0).min(cardinality.max(1.0)))
+    }
+
+    fn null_fraction(&self, cardinality: f64) -> f64 {
+        if cardinality <= 0.0 {
+            return 0.0;
+        }
+        (self.null_count as f64 / cardinality).min(1.0)
+    }
+}
+
+pub type ColumnStatSet = HashMap<IndexType, ColumnStat>;
+
+/// Statistics of a table, the row count is None if it's unknown.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Statistics {
+    pub num_rows: Option<u64>,
+    pub column_stats: ColumnStatSet,
+}
+
+/// Estimates the fraction of the rows satisfying the predicate.
+pub struct SelectivityEstimator<'a> {
+    cardinality: f64,
+    column_stats: &'a ColumnStatSet,
+}
+
+impl<'a> SelectivityEstimator<'a> {
+    pub fn new(cardinality: f64, column_stats: &'a ColumnStatSet) -> Self {
+        Self {
+            cardinality,
+            column_stats,
+        }
+    }
+
+    pub fn compute_selectivity(&self, predicate: &Scalar) -> f64 {
+        match predicate {
+            Scalar::ConstantExpr(constant) => match &constant.value {
+                DataValue::Boolean(false) | DataValue::Null => 0.0,
+                _ => 1.0,
+            },
+            Scalar::AndExpr(and) => {
+                self.compute_selectivity(&and.left) * self.compute_selectivity(&and.right)
+            }
+            Scalar::OrExpr(or) => {
+                let left = self.compute_selectivity(&or.left);
+                let right = self.compute_selectivity(&or.right);
+                left + right - left * right
+            }
+            Scalar::ComparisonExpr(comparison) => {
+                match (column_of(&comparison.left), constant_of(&comparison.right)) {
+                    (Some(column), Some(value)) => {
+                        self.compute_comparison(&comparison.op, column, value)
+                    }
+                    _ => match (constant_of(&comparison.left), column_of(&comparison.right)) {
+                        (Some(value), Some(column)) => {
+                            self.compute_comparison(&flip(&comparison.op), column, value)
+                        }
+                        _ => DEFAULT_SELECTIVITY,
+                    },
+                }
+            }
+            _ => DEFAULT_SELECTIVITY,
+        }
+    }
+
+    // Selectivity of `column op value`
+    fn compute_comparison(&self, op: &ComparisonOp, column: IndexType, value: &DataValue) -> f64 {
+        let stat = match self.column_stats.get(&column) {
+            Some(stat) => stat,
+            None => return DEFAULT_SELECTIVITY,
+        };
+        if value.is_null() {
+            return 0.0;
+        }
+        if !value.is_numeric() || !stat.min.is_numeric() || !stat.max.is_numeric() {
+            return DEFAULT_SELECTIVITY;
+        }
+        let (min, max, value) = match (stat.min.as_f64(), stat.max.as_f64(), value.as_f64()) {
+            (Ok(min), Ok(max), Ok(value)) => (min, max, value),
+            _ => return DEFAULT_SELECTIVITY,
+        };
+
+        let histogram_fractions = stat
+            .histogram
+            .as_ref()
+            .and_then(|histogram| histogram_fractions(histogram, value));
+        let equal = if value < min || value > max {
+            0.0
+        } else if let Some((_, equal)) = histogram_fractions {
+            equal
+        } else {
+            match stat.ndv(self.cardinality) {
+                Some(ndv) => 1.0 / ndv,
+                None => DEFAULT_SELECTIVITY,
+            }
+        };
+        // The fraction of the values in [min, value]
+        let fraction = if let Some((fraction, _)) = histogram_fractions {
+            fraction
+        } else if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else if value >= min {
+            1.0
+        } else {
+            0.0
+        };
+        let selectivity = match op {
+            ComparisonOp::Equal => equal,
+            ComparisonOp::NotEqual => 1.0 - equal,
+            ComparisonOp::LT => (fraction - equal).max(0.0),
+            ComparisonOp::LTE => fraction,
+            ComparisonOp::GT => 1.0 - fraction,
+            ComparisonOp::GTE => (1.0 - fraction + equal).min(1.0),
+        };
+        // NULLs never satisfy the comparison
+        selectivity * (1.0 - stat.null_fraction(self.cardinality))
+    }
+}
+
+/// Estimates the fraction of the values not greater than `value`, and the fraction of
+/// the values equal to `value` with a histogram. The values in a bucket are assumed to
+/// be distributed uniformly.
+///
+/// Returns None if the histogram is empty or the bounds are not numeric.
+fn histogram_fractions(histogram: &Histogram, value: f64) -> Option<(f64, f64)> {
+    let num_values = histogram.num_values();
+    if num_values == 0 {
+        return None;
+    }
+
+    let mut less_equal = 0.0;
+    let mut equal = 0.0;
+    for bucket in &histogram.buckets {
+        if !bucket.lower_bound.is_numeric() || !bucket.upper_bound.is_numeric() {
+            return None;
+        }
+        let lower = bucket.lower_bound.as_f64().ok()?;
+        let upper = bucket.upper_bound.as_f64().ok()?;
+        let bucket_values = bucket.num_values as f64;
+        if value >= upper {
+            less_equal += bucket_values;
+            if value == upper {
+                equal = bucket_values / bucket.num_distinct.max(1) as f64;
+            }
+        } else if value >= lower {
+            less_equal += bucket_values * (value - lower) / (upper - lower);
+            equal = bucket_values / bucket.num_distinct.max(1) as f64;
+            break;
+        } else {
+            break;
+        }
+    }
+
+    let num_values = num_values as f64;
+    Some((less_equal / num_values, equal / num_values))
+}
+
+/// Returns the column index if the scalar is a column, the casts are ignored.
+pub fn column_of(scalar: &Scalar) -> Option<IndexType> {
+    match scalar {
+        Scalar::BoundColumnRef(column_ref) => Some(column_ref.column.index),
+        Scalar::CastExpr(cast) => column_of(&cast.argument),
+        _ => None,
+    }
+}
+
+fn constant_of(scalar: &Scalar) -> Option<&DataValue> {
+    match scalar {
+        Scalar::ConstantExpr(constant) => Some(&constant.value),
+        Scalar::CastExpr(cast) => constant_of(&cast.argument),
+        _ => None,
+    }
+}
+
+// `value op column` is equivalent to `column flip(op) value`
+fn flip(op: &ComparisonOp) -> ComparisonOp {
+    match op {
+        ComparisonOp::Equal => ComparisonOp::Equal,
+        ComparisonOp::NotEqual => ComparisonOp::NotEqual,
+        ComparisonOp::GT => ComparisonOp::LT,
+        ComparisonOp::LT => ComparisonOp::GT,
+        ComparisonOp::GTE => ComparisonOp::LTE,
+        ComparisonOp::LTE => ComparisonOp::GTE,
+    }
+}
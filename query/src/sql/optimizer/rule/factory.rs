@@ -29,6 +29,8 @@ use crate::sql::optimizer::rule::rewrite::RulePushDownFilterScan;
 use crate::sql::optimizer::rule::rewrite::RuleSplitAggregate;
 use crate::sql::optimizer::rule::rule_implement_get::RuleImplementGet;
 use crate::sql::optimizer::rule::rule_implement_hash_join::RuleImplementHashJoin;
+use crate::sql::optimizer::rule::transform::RuleAssociateJoin;
+use crate::sql::optimizer::rule::transform::RuleCommuteJoin;
 use crate::sql::optimizer::rule::RuleID;
 use crate::sql::optimizer::rule::RulePtr;
 
@@ -58,6 +60,8 @@ impl RuleFactory {
             RuleID::NormalizeDisjunctiveFilter => {
                 Ok(Box::new(RuleNormalizeDisjunctiveFilter::new()))
             }
+            RuleID::CommuteJoin => Ok(Box::new(RuleCommuteJoin::new())),
+            RuleID::AssociateJoin => Ok(Box::new(RuleAssociateJoin::new())),
         }
     }
 }
@@ -24,6 +24,7 @@ mod rewrite;
 mod rule_implement_get;
 mod rule_implement_hash_join;
 mod rule_set;
+mod transform;
 mod transform_state;
 
 pub use factory::RuleFactory;
@@ -58,6 +59,10 @@ pub enum RuleID {
     MergeFilter,
     SplitAggregate,
 
+    // Exploration rules
+    CommuteJoin,
+    AssociateJoin,
+
     // Implementation rules
     ImplementGet,
     ImplementHashJoin,
@@ -81,6 +86,8 @@ impl Display for RuleID {
             RuleID::NormalizeScalarFilter => write!(f, "NormalizeScalarFilter"),
             RuleID::SplitAggregate => write!(f, "SplitAggregate"),
             RuleID::NormalizeDisjunctiveFilter => write!(f, "NormalizeDisjunctiveFilter"),
+            RuleID::CommuteJoin => write!(f, "CommuteJoin"),
+            RuleID::AssociateJoin => write!(f, "AssociateJoin"),
         }
     }
 }
@@ -92,18 +92,33 @@ impl Rule for RulePushDownFilterJoin {
 
         let mut need_push = false;
 
+        // For outer joins, predicates can only be pushed down to the side whose
+        // rows won't be padded with NULLs, and can't be converted into join conditions.
+        let (push_down_left, push_down_right, push_down_both) = match join.join_type {
+            JoinType::Left => (true, false, false),
+            JoinType::Right => (false, true, false),
+            JoinType::Full => (false, false, false),
+            _ => (true, true, true),
+        };
+
         for predicate in filter.predicates.into_iter() {
             let pred = JoinCondition::new(&predicate, &left_prop, &right_prop);
             match pred {
-                JoinCondition::Left(_) => {
+                JoinCondition::Left(_) if push_down_left => {
                     need_push = true;
                     left_push_down.push(predicate);
                 }
-                JoinCondition::Right(_) => {
+                JoinCondition::Right(_) if push_down_right => {
                     need_push = true;
                     right_push_down.push(predicate);
                 }
-                JoinCondition::Other(_) => original_predicates.push(predicate),
+                JoinCondition::Left(_) | JoinCondition::Right(_) | JoinCondition::Other(_) => {
+                    original_predicates.push(predicate)
+                }
+
+                JoinCondition::Both { .. } if !push_down_both => {
+                    original_predicates.push(predicate)
+                }
 
                 JoinCondition::Both { left, right } => {
                     let left_type = left.data_type();
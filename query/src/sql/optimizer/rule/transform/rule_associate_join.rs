@@ -0,0 +1,198 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use super::is_reorderable;
+use crate::sql::optimizer::rule::Rule;
+use crate::sql::optimizer::rule::RuleID;
+use crate::sql::optimizer::rule::TransformState;
+use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::LogicalInnerJoin;
+use crate::sql::plans::PatternPlan;
+use crate::sql::plans::RelOp;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+
+/// Rule to associate the inner joins, i.e. (A ⋈ B) ⋈ C => A ⋈ (B ⋈ C).
+///
+/// The conditions of both joins are redistributed, a condition is evaluated by the
+/// lower join if it only refers to B and C. Together with `RuleCommuteJoin`, all the
+/// join orders can be explored.
+pub struct RuleAssociateJoin {
+    id: RuleID,
+    pattern: SExpr,
+}
+
+impl RuleAssociateJoin {
+    pub fn new() -> Self {
+        RuleAssociateJoin {
+            id: RuleID::AssociateJoin,
+            // InnerJoin
+            // | \
+            // |  *
+            // InnerJoin
+            // | \
+            // |  *
+            // *
+            pattern: SExpr::create_binary(
+                PatternPlan {
+                    plan_type: RelOp::LogicalInnerJoin,
+                }
+                .into(),
+                SExpr::create_binary(
+                    PatternPlan {
+                        plan_type: RelOp::LogicalInnerJoin,
+                    }
+                    .into(),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::Pattern,
+                        }
+                        .into(),
+                    ),
+                    SExpr::create_leaf(
+                        PatternPlan {
+                            plan_type: RelOp::Pattern,
+                        }
+                        .into(),
+                    ),
+                ),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ),
+            ),
+        }
+    }
+}
+
+impl Rule for RuleAssociateJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformState) -> Result<()> {
+        let upper_join: LogicalInnerJoin = s_expr.plan().clone().try_into()?;
+        let lower_join_expr = s_expr.child(0)?;
+        let lower_join: LogicalInnerJoin = lower_join_expr.plan().clone().try_into()?;
+        if !is_reorderable(&upper_join) || !is_reorderable(&lower_join) {
+            return Ok(());
+        }
+
+        let a = lower_join_expr.child(0)?;
+        let b = lower_join_expr.child(1)?;
+        let c = s_expr.child(1)?;
+        let a_columns = RelExpr::with_s_expr(a)
+            .derive_relational_prop()?
+            .output_columns;
+        let b_columns = RelExpr::with_s_expr(b)
+            .derive_relational_prop()?
+            .output_columns;
+        let c_columns = RelExpr::with_s_expr(c)
+            .derive_relational_prop()?
+            .output_columns;
+        let bc_columns: ColumnSet = b_columns.union(&c_columns).cloned().collect();
+
+        let mut new_lower_join = JoinConditions::default();
+        let mut new_upper_join = JoinConditions::default();
+        let equi_conditions = lower_join
+            .left_conditions
+            .into_iter()
+            .zip(lower_join.right_conditions)
+            .chain(
+                upper_join
+                    .left_conditions
+                    .into_iter()
+                    .zip(upper_join.right_conditions),
+            );
+        for (left, right) in equi_conditions {
+            let (left_used, right_used) = (left.used_columns(), right.used_columns());
+            if left_used.is_subset(&b_columns) && right_used.is_subset(&c_columns) {
+                new_lower_join.add_equi_condition(left, right);
+            } else if left_used.is_subset(&c_columns) && right_used.is_subset(&b_columns) {
+                new_lower_join.add_equi_condition(right, left);
+            } else if left_used.is_subset(&a_columns) && right_used.is_subset(&bc_columns) {
+                new_upper_join.add_equi_condition(left, right);
+            } else if left_used.is_subset(&bc_columns) && right_used.is_subset(&a_columns) {
+                new_upper_join.add_equi_condition(right, left);
+            } else {
+                // The condition can't be evaluated as an equi condition after the
+                // association, e.g. `a.x + b.y = c.z`.
+                return Ok(());
+            }
+        }
+        for condition in lower_join
+            .other_conditions
+            .into_iter()
+            .chain(upper_join.other_conditions)
+        {
+            if condition.used_columns().is_subset(&bc_columns) {
+                new_lower_join.other_conditions.push(condition);
+            } else {
+                new_upper_join.other_conditions.push(condition);
+            }
+        }
+
+        let new_lower_join_expr =
+            SExpr::create_binary(new_lower_join.into_join().into(), b.clone(), c.clone());
+        state.add_result(SExpr::create_binary(
+            new_upper_join.into_join().into(),
+            a.clone(),
+            new_lower_join_expr,
+        ));
+
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
+
+#[derive(Default)]
+struct JoinConditions {
+    left_conditions: Vec<Scalar>,
+    right_conditions: Vec<Scalar>,
+    other_conditions: Vec<Scalar>,
+}
+
+impl JoinConditions {
+    fn add_equi_condition(&mut self, left: Scalar, right: Scalar) {
+        self.left_conditions.push(left);
+        self.right_conditions.push(right);
+    }
+
+    // A join without any condition is a cross join
+    fn into_join(self) -> LogicalInnerJoin {
+        let join_type = if self.left_conditions.is_empty() && self.other_conditions.is_empty() {
+            JoinType::Cross
+        } else {
+            JoinType::Inner
+        };
+        LogicalInnerJoin {
+            left_conditions: self.left_conditions,
+            right_conditions: self.right_conditions,
+            other_conditions: self.other_conditions,
+            join_type,
+            marker_index: None,
+            from_correlated_subquery: false,
+        }
+    }
+}
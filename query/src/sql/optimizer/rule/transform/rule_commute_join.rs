@@ -0,0 +1,93 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use super::is_reorderable;
+use crate::sql::optimizer::rule::Rule;
+use crate::sql::optimizer::rule::RuleID;
+use crate::sql::optimizer::rule::TransformState;
+use crate::sql::optimizer::SExpr;
+use crate::sql::plans::LogicalInnerJoin;
+use crate::sql::plans::PatternPlan;
+use crate::sql::plans::RelOp;
+
+/// Rule to swap the children of an inner join, i.e. A ⋈ B => B ⋈ A.
+///
+/// The right child is the build side of the hash join, so the smaller side can be
+/// picked as the build side by the cost.
+pub struct RuleCommuteJoin {
+    id: RuleID,
+    pattern: SExpr,
+}
+
+impl RuleCommuteJoin {
+    pub fn new() -> Self {
+        RuleCommuteJoin {
+            id: RuleID::CommuteJoin,
+            // InnerJoin
+            // | \
+            // |  *
+            // *
+            pattern: SExpr::create_binary(
+                PatternPlan {
+                    plan_type: RelOp::LogicalInnerJoin,
+                }
+                .into(),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ),
+                SExpr::create_leaf(
+                    PatternPlan {
+                        plan_type: RelOp::Pattern,
+                    }
+                    .into(),
+                ),
+            ),
+        }
+    }
+}
+
+impl Rule for RuleCommuteJoin {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformState) -> Result<()> {
+        let join: LogicalInnerJoin = s_expr.plan().clone().try_into()?;
+        if !is_reorderable(&join) {
+            return Ok(());
+        }
+
+        let commuted = LogicalInnerJoin {
+            left_conditions: join.right_conditions,
+            right_conditions: join.left_conditions,
+            ..join
+        };
+        state.add_result(SExpr::create_binary(
+            commuted.into(),
+            s_expr.child(1)?.clone(),
+            s_expr.child(0)?.clone(),
+        ));
+
+        Ok(())
+    }
+
+    fn pattern(&self) -> &SExpr {
+        &self.pattern
+    }
+}
@@ -17,6 +17,7 @@ use common_exception::Result;
 
 use crate::sql::optimizer::rule::AppliedRules;
 use crate::sql::optimizer::rule::RuleID;
+use crate::sql::optimizer::RelationalProperty;
 use crate::sql::plans::Operator;
 use crate::sql::plans::RelOp;
 use crate::sql::plans::RelOperator;
@@ -30,6 +31,10 @@ pub struct SExpr {
 
     original_group: Option<IndexType>,
 
+    /// Relational property of the `original_group`, which is attached to the
+    /// expressions extracted from `Memo` without children.
+    rel_prop: Option<RelationalProperty>,
+
     /// A bitmap to record applied rules on current SExpr, to prevent
     /// redundant transformations.
     pub(super) applied_rules: AppliedRules,
@@ -45,6 +50,7 @@ impl SExpr {
             plan,
             children,
             original_group,
+            rel_prop: None,
 
             applied_rules: AppliedRules::default(),
         }
@@ -88,6 +94,14 @@ impl SExpr {
         self.original_group
     }
 
+    pub fn rel_prop(&self) -> Option<&RelationalProperty> {
+        self.rel_prop.as_ref()
+    }
+
+    pub(super) fn set_rel_prop(&mut self, rel_prop: Option<RelationalProperty>) {
+        self.rel_prop = rel_prop;
+    }
+
     pub fn match_pattern(&self, pattern: &SExpr) -> bool {
         if pattern.plan.rel_op() != RelOp::Pattern {
             // Pattern is plan
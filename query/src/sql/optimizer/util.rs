@@ -37,13 +37,31 @@ pub fn validate_distributed_query(s_expr: &SExpr) -> bool {
             RelOperator::PhysicalHashJoin(join) => match join.join_type {
                 JoinType::Inner | JoinType::Semi | JoinType::Anti => true,
 
-                JoinType::Left
-                | JoinType::Right
-                | JoinType::Full
-                | JoinType::Cross
-                | JoinType::Mark
-                | JoinType::Single => false,
+                // Both sides of join are distributed by hash keys, so each node can
+                // emit its own unmatched build side rows independently.
+                JoinType::Right | JoinType::Full => !join.build_keys.is_empty(),
+
+                JoinType::Left | JoinType::Cross | JoinType::Mark | JoinType::Single => false,
             },
             _ => true,
         }
 }
+
+/// Count the inner joins and cross joins, which can be reordered.
+pub fn count_reorderable_joins(s_expr: &SExpr) -> usize {
+    let count = match s_expr.plan() {
+        RelOperator::LogicalInnerJoin(join)
+            if matches!(join.join_type, JoinType::Inner | JoinType::Cross)
+                && !join.from_correlated_subquery =>
+        {
+            1
+        }
+        _ => 0,
+    };
+    count
+        + s_expr
+            .children()
+            .iter()
+            .map(count_reorderable_joins)
+            .sum::<usize>()
+}
@@ -16,20 +16,25 @@ use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 
 use common_ast::ast::Expr;
+use common_ast::ast::GroupBy;
 use common_ast::ast::Literal;
 use common_ast::ast::SelectTarget;
 use common_ast::DisplayError;
+use common_datavalues::wrap_nullable;
 use common_datavalues::DataTypeImpl;
+use common_datavalues::ToDataType;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::sql::binder::scalar::ScalarBinder;
 use crate::sql::binder::select::SelectList;
+use crate::sql::binder::window::check_no_window_function;
 use crate::sql::binder::Binder;
 use crate::sql::binder::ColumnBinding;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::metadata::MetadataRef;
 use crate::sql::plans::Aggregate;
+use crate::sql::plans::AggregateExpand;
 use crate::sql::plans::AggregateFunction;
 use crate::sql::plans::AggregateMode;
 use crate::sql::plans::AndExpr;
@@ -42,8 +47,13 @@ use crate::sql::plans::OrExpr;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::ScalarExpr;
 use crate::sql::plans::ScalarItem;
+use crate::sql::plans::WindowFunction;
+use crate::sql::plans::WindowOrderBy;
 use crate::sql::BindContext;
 
+/// CUBE expands into `2^n` grouping sets, so the number of its expressions is limited.
+const MAX_CUBE_EXPRS: usize = 12;
+
 #[derive(Default, Clone, PartialEq, Debug)]
 pub struct AggregateInfo {
     /// Aggregation functions
@@ -71,15 +81,23 @@ pub struct AggregateInfo {
     /// TODO(leiysky): so far we are using `Debug` string of `Scalar` as identifier,
     /// maybe a more reasonable way is needed
     pub group_items_map: HashMap<String, usize>,
+
+    /// Grouping sets of `GROUPING SETS`, `ROLLUP` and `CUBE`, each of them is a list of
+    /// the positions of the group items in `group_items`.
+    pub grouping_sets: Vec<Vec<usize>>,
+
+    /// The column of the grouping id, which tells the grouping sets apart. It only
+    /// exists with grouping sets.
+    pub grouping_id_column: Option<ColumnBinding>,
 }
 
-pub(super) struct AggregateRewriter<'a> {
-    pub bind_context: &'a mut BindContext,
+pub(super) struct AggregateRewriter<'a, 'b> {
+    pub bind_context: &'a mut BindContext<'b>,
     pub metadata: MetadataRef,
 }
 
-impl<'a> AggregateRewriter<'a> {
-    pub fn new(bind_context: &'a mut BindContext, metadata: MetadataRef) -> Self {
+impl<'a, 'b> AggregateRewriter<'a, 'b> {
+    pub fn new(bind_context: &'a mut BindContext<'b>, metadata: MetadataRef) -> Self {
         Self {
             bind_context,
             metadata,
@@ -136,6 +154,37 @@ impl<'a> AggregateRewriter<'a> {
             Scalar::SubqueryExpr(_) => Ok(scalar.clone()),
 
             Scalar::AggregateFunction(agg_func) => self.replace_aggregate_function(agg_func),
+
+            // Aggregate functions can be the arguments of window functions,
+            // e.g. `SELECT rank() OVER (ORDER BY sum(a)) FROM t GROUP BY b`.
+            Scalar::WindowFunction(window) => Ok(WindowFunction {
+                display_name: window.display_name.clone(),
+                func: window.func.clone(),
+                args: window
+                    .args
+                    .iter()
+                    .map(|arg| self.visit(arg))
+                    .collect::<Result<Vec<_>>>()?,
+                partition_by: window
+                    .partition_by
+                    .iter()
+                    .map(|item| self.visit(item))
+                    .collect::<Result<Vec<_>>>()?,
+                order_by: window
+                    .order_by
+                    .iter()
+                    .map(|item| {
+                        Ok(WindowOrderBy {
+                            expr: self.visit(&item.expr)?,
+                            asc: item.asc,
+                            nulls_first: item.nulls_first,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                frame: window.frame,
+                return_type: window.return_type.clone(),
+            }
+            .into()),
         }
     }
 
@@ -217,7 +266,7 @@ impl<'a> Binder {
     /// See `AggregateRewriter` for more details.
     pub(crate) fn analyze_aggregate_select(
         &mut self,
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         select_list: &mut SelectList<'a>,
     ) -> Result<()> {
         for item in select_list.items.iter_mut() {
@@ -237,12 +286,20 @@ impl<'a> Binder {
     ///     `SELECT a as b, COUNT(a) FROM t GROUP BY b`.
     ///   - Scalar expressions that can be evaluated in current scope(doesn't contain aliases), e.g.
     ///     column `a` and expression `a+1` in `SELECT a as b, COUNT(a) FROM t GROUP BY a, a+1`.
+    ///
+    /// `GROUPING SETS`, `ROLLUP` and `CUBE` are rewritten into a list of grouping sets,
+    /// see `resolve_grouping_sets` for more details.
     pub async fn analyze_group_items(
         &mut self,
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         select_list: &SelectList<'a>,
-        group_by: &[Expr<'a>],
+        group_by: Option<&GroupBy<'a>>,
     ) -> Result<()> {
+        let group_by = match group_by {
+            Some(group_by) => group_by,
+            None => return Ok(()),
+        };
+
         let mut available_aliases = vec![];
 
         // Extract available aliases from `SELECT` clause,
@@ -264,13 +321,54 @@ impl<'a> Binder {
             }
         }
 
-        self.resolve_group_items(bind_context, select_list, group_by, &available_aliases)
-            .await
+        match group_by {
+            GroupBy::Normal(exprs) => {
+                self.resolve_group_items(bind_context, select_list, exprs, &available_aliases)
+                    .await?;
+            }
+            GroupBy::GroupingSets(sets) => {
+                self.resolve_grouping_sets(bind_context, select_list, sets, &available_aliases)
+                    .await?;
+            }
+            GroupBy::Rollup(exprs) => {
+                // ROLLUP (a, b) => GROUPING SETS ((a, b), (a), ())
+                let sets = (0..=exprs.len())
+                    .rev()
+                    .map(|len| exprs[..len].to_vec())
+                    .collect::<Vec<_>>();
+                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
+                    .await?;
+            }
+            GroupBy::Cube(exprs) => {
+                if exprs.len() > MAX_CUBE_EXPRS {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "CUBE can have at most {} expressions, but got {}",
+                        MAX_CUBE_EXPRS,
+                        exprs.len()
+                    )));
+                }
+                // CUBE (a, b) => GROUPING SETS ((a, b), (a), (b), ())
+                let sets = (0..1usize << exprs.len())
+                    .rev()
+                    .map(|mask| {
+                        exprs
+                            .iter()
+                            .enumerate()
+                            .filter(|(i, _)| mask & (1 << (exprs.len() - 1 - i)) != 0)
+                            .map(|(_, expr)| expr.clone())
+                            .collect()
+                    })
+                    .collect::<Vec<_>>();
+                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
+                    .await?;
+            }
+        }
+        Ok(())
     }
 
     pub(super) async fn bind_aggregate(
         &mut self,
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         child: SExpr,
     ) -> Result<SExpr> {
         // Enter in_grouping state
@@ -295,9 +393,50 @@ impl<'a> Binder {
             new_expr = SExpr::create_unary(eval_scalar.into(), new_expr);
         }
 
+        let mut group_items = agg_info.group_items.clone();
+        if let Some(grouping_id_column) = &agg_info.grouping_id_column {
+            // Expand the rows for each grouping set, then aggregate them by the
+            // nullable group items together with the grouping id.
+            let expand = AggregateExpand {
+                group_items: group_items.iter().map(|item| item.index).collect(),
+                grouping_sets: agg_info.grouping_sets.clone(),
+                grouping_id_index: grouping_id_column.index,
+            };
+            new_expr = SExpr::create_unary(expand.into(), new_expr);
+
+            group_items = group_items
+                .into_iter()
+                .map(|item| {
+                    let metadata = self.metadata.read();
+                    let column = metadata.column(item.index);
+                    ScalarItem {
+                        scalar: BoundColumnRef {
+                            column: ColumnBinding {
+                                database_name: None,
+                                table_name: None,
+                                column_name: column.name.clone(),
+                                index: item.index,
+                                data_type: Box::new(column.data_type.clone()),
+                                visible_in_unqualified_wildcard: true,
+                            },
+                        }
+                        .into(),
+                        index: item.index,
+                    }
+                })
+                .collect();
+            group_items.push(ScalarItem {
+                scalar: BoundColumnRef {
+                    column: grouping_id_column.clone(),
+                }
+                .into(),
+                index: grouping_id_column.index,
+            });
+        }
+
         let aggregate_plan = Aggregate {
             mode: AggregateMode::Initial,
-            group_items: bind_context.aggregate_info.group_items.clone(),
+            group_items,
             aggregate_functions: bind_context.aggregate_info.aggregate_functions.clone(),
             from_distinct: false,
         };
@@ -306,13 +445,15 @@ impl<'a> Binder {
         Ok(new_expr)
     }
 
+    /// Resolves the group items, returns the positions of them in `group_items`.
     async fn resolve_group_items(
         &mut self,
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         select_list: &SelectList<'a>,
         group_by: &[Expr<'a>],
         available_aliases: &[(ColumnBinding, Scalar)],
-    ) -> Result<()> {
+    ) -> Result<Vec<usize>> {
+        let mut positions = Vec::with_capacity(group_by.len());
         // Resolve group items with `FROM` context. Since the alias item can not be resolved
         // from the context, we can detect the failure and fallback to resolving with `available_aliases`.
         for expr in group_by.iter() {
@@ -324,19 +465,23 @@ impl<'a> Binder {
             {
                 let (scalar, alias) = Self::resolve_index_item(expr, *index, select_list)?;
                 let key = format!("{:?}", &scalar);
-                if let Entry::Vacant(entry) = bind_context.aggregate_info.group_items_map.entry(key)
-                {
-                    // Add group item if it's not duplicated
-                    let column_binding = if let Scalar::BoundColumnRef(ref column_ref) = scalar {
-                        column_ref.column.clone()
-                    } else {
-                        self.create_column_binding(None, None, alias, scalar.data_type())
-                    };
-                    bind_context.aggregate_info.group_items.push(ScalarItem {
-                        scalar,
-                        index: column_binding.index,
-                    });
-                    entry.insert(bind_context.aggregate_info.group_items.len() - 1);
+                match bind_context.aggregate_info.group_items_map.entry(key) {
+                    Entry::Vacant(entry) => {
+                        // Add group item if it's not duplicated
+                        let column_binding = if let Scalar::BoundColumnRef(ref column_ref) = scalar
+                        {
+                            column_ref.column.clone()
+                        } else {
+                            self.create_column_binding(None, None, alias, scalar.data_type())
+                        };
+                        bind_context.aggregate_info.group_items.push(ScalarItem {
+                            scalar,
+                            index: column_binding.index,
+                        });
+                        positions
+                            .push(*entry.insert(bind_context.aggregate_info.group_items.len() - 1));
+                    }
+                    Entry::Occupied(entry) => positions.push(*entry.get()),
                 }
                 continue;
             }
@@ -348,14 +493,15 @@ impl<'a> Binder {
                 .bind(expr)
                 .await
                 .or_else(|e| Self::resolve_alias_item(bind_context, expr, available_aliases, e))?;
+            check_no_window_function(&scalar_expr, "GROUP BY", expr.span())?;
 
-            if bind_context
+            if let Some(position) = bind_context
                 .aggregate_info
                 .group_items_map
                 .get(&format!("{:?}", &scalar_expr))
-                .is_some()
             {
                 // The group key is duplicated
+                positions.push(*position);
                 continue;
             }
 
@@ -379,7 +525,51 @@ impl<'a> Binder {
                 format!("{:?}", &scalar_expr),
                 bind_context.aggregate_info.group_items.len() - 1,
             );
+            positions.push(bind_context.aggregate_info.group_items.len() - 1);
+        }
+        Ok(positions)
+    }
+
+    /// Resolves the group items of all the grouping sets, the rows are expanded for each
+    /// grouping set before the aggregation, with the group items not in the grouping set
+    /// replaced with NULL, see `AggregateExpand`.
+    ///
+    /// Since the group items may be referenced by the aggregate functions, e.g.
+    /// `SELECT a, sum(a) FROM t GROUP BY ROLLUP (a)`, each of them is evaluated into a new
+    /// nullable column, which is the one replaced with NULL.
+    async fn resolve_grouping_sets(
+        &mut self,
+        bind_context: &mut BindContext<'a>,
+        select_list: &SelectList<'a>,
+        sets: &[Vec<Expr<'a>>],
+        available_aliases: &[(ColumnBinding, Scalar)],
+    ) -> Result<()> {
+        let mut grouping_sets = Vec::with_capacity(sets.len());
+        for set in sets.iter() {
+            let mut positions = self
+                .resolve_group_items(bind_context, select_list, set, available_aliases)
+                .await?;
+            positions.sort_unstable();
+            positions.dedup();
+            grouping_sets.push(positions);
         }
+
+        for item in bind_context.aggregate_info.group_items.iter_mut() {
+            let name = self.metadata.read().column(item.index).name.clone();
+            item.index = self.metadata.write().add_column(
+                name,
+                wrap_nullable(&item.scalar.data_type()),
+                None,
+            );
+        }
+
+        bind_context.aggregate_info.grouping_sets = grouping_sets;
+        bind_context.aggregate_info.grouping_id_column = Some(self.create_column_binding(
+            None,
+            None,
+            "_grouping_id".to_string(),
+            u32::to_data_type(),
+        ));
         Ok(())
     }
 
@@ -407,7 +597,7 @@ impl<'a> Binder {
     }
 
     fn resolve_alias_item(
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         expr: &Expr<'a>,
         available_aliases: &[(ColumnBinding, Scalar)],
         original_error: ErrorCode,
@@ -438,6 +628,15 @@ impl<'a> Binder {
             )))
         } else {
             let (column_binding, scalar) = available_aliases[result[0]].clone();
+            if bind_context
+                .aggregate_info
+                .group_items_map
+                .contains_key(&format!("{:?}", &scalar))
+            {
+                // The alias has been resolved, e.g. it's in several grouping sets
+                return Ok((scalar.clone(), scalar.data_type()));
+            }
+
             // We will add the alias to BindContext, so we can reference it
             // in `HAVING` and `ORDER BY` clause.
             bind_context.columns.push(column_binding.clone());
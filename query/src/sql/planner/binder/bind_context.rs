@@ -12,7 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
+
 use common_ast::ast::Identifier;
+use common_ast::ast::Query;
 use common_ast::ast::TableAlias;
 use common_ast::DisplayError;
 use common_datavalues::prelude::*;
@@ -20,6 +23,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::AggregateInfo;
+use super::WindowInfo;
 use crate::sql::common::IndexType;
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -41,15 +45,31 @@ pub struct ColumnBinding {
     pub visible_in_unqualified_wildcard: bool,
 }
 
+/// Information of a common table expression defined in `WITH` clause.
+#[derive(Clone, Debug)]
+pub struct CteInfo<'a> {
+    /// Column aliases of the CTE, e.g. `a` and `b` in `t(a, b) AS (...)`
+    pub columns_alias: Vec<String>,
+    /// The CTE query, which will be bound each time the CTE is referenced.
+    pub query: Query<'a>,
+    /// The context where the CTE is defined, it can only see the CTEs defined before it.
+    pub bind_context: BindContext<'a>,
+}
+
 /// `BindContext` stores all the free variables in a query and tracks the context of binding procedure.
+///
+/// The lifetime is the one of the AST being bound, which is referred by the CTEs. The
+/// context of a bound statement is kept by its plan without the CTEs, see [`BindContext::into_output`].
 #[derive(Clone, Default, Debug)]
-pub struct BindContext {
-    pub parent: Option<Box<BindContext>>,
+pub struct BindContext<'a> {
+    pub parent: Option<Box<BindContext<'a>>>,
 
     pub columns: Vec<ColumnBinding>,
 
     pub aggregate_info: AggregateInfo,
 
+    pub window_info: WindowInfo,
+
     /// True if there is aggregation in current context, which means
     /// non-grouping columns cannot be referenced outside aggregation
     /// functions, otherwise a grouping error will be raised.
@@ -57,20 +77,25 @@ pub struct BindContext {
 
     /// Format type of query output.
     pub format: Option<String>,
+
+    /// Common table expressions visible in current context, keyed by lowercase name.
+    pub ctes_map: HashMap<String, CteInfo<'a>>,
 }
 
-impl BindContext {
+impl<'a> BindContext<'a> {
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn with_parent(parent: Box<BindContext>) -> Self {
+    pub fn with_parent(parent: Box<BindContext<'a>>) -> Self {
         BindContext {
             parent: Some(parent),
             columns: vec![],
             aggregate_info: Default::default(),
+            window_info: Default::default(),
             in_grouping: false,
             format: None,
+            ctes_map: HashMap::new(),
         }
     }
 
@@ -78,6 +103,7 @@ impl BindContext {
     pub fn replace(&self) -> Self {
         let mut bind_context = BindContext::new();
         bind_context.parent = self.parent.clone();
+        bind_context.ctes_map = self.ctes_map.clone();
         bind_context
     }
 
@@ -86,6 +112,20 @@ impl BindContext {
         Self::with_parent(Box::new(self))
     }
 
+    /// Returns the context without the CTEs, which refer to the AST being bound, so that
+    /// it can be kept by the plan of the bound statement.
+    pub fn into_output(self) -> BindContext<'static> {
+        BindContext {
+            parent: self.parent.map(|parent| Box::new(parent.into_output())),
+            columns: self.columns,
+            aggregate_info: self.aggregate_info,
+            window_info: self.window_info,
+            in_grouping: self.in_grouping,
+            format: self.format,
+            ctes_map: HashMap::new(),
+        }
+    }
+
     /// Returns all column bindings in current scope.
     pub fn all_column_bindings(&self) -> &[ColumnBinding] {
         &self.columns
@@ -116,6 +156,20 @@ impl BindContext {
         Ok(())
     }
 
+    /// Try to find a CTE with given name in current context and its parents.
+    pub fn resolve_cte(&self, name: &str) -> Option<&CteInfo<'a>> {
+        let mut bind_context: &BindContext<'a> = self;
+        loop {
+            if let Some(cte_info) = bind_context.ctes_map.get(name) {
+                return Some(cte_info);
+            }
+            match &bind_context.parent {
+                Some(parent) => bind_context = parent,
+                None => return None,
+            }
+        }
+    }
+
     /// Try to find a column binding with given table name and column name.
     /// This method will return error if the given names are ambiguous or invalid.
     pub fn resolve_column(
@@ -126,7 +180,7 @@ impl BindContext {
     ) -> Result<ColumnBinding> {
         let mut result = vec![];
 
-        let mut bind_context: &BindContext = self;
+        let mut bind_context: &BindContext<'a> = self;
         // Lookup parent context to support correlated subquery
         loop {
             for column_binding in bind_context.columns.iter() {
@@ -42,7 +42,7 @@ use crate::sql::BindContext;
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_copy(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
     ) -> Result<Plan> {
         match (&stmt.src, &stmt.dst) {
@@ -201,7 +201,7 @@ impl<'a> Binder {
     #[allow(clippy::too_many_arguments)]
     async fn bind_copy_from_stage_into_table(
         &mut self,
-        _: &BindContext,
+        _: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
         src_stage: &str,
         src_path: &str,
@@ -254,7 +254,7 @@ impl<'a> Binder {
     #[allow(clippy::too_many_arguments)]
     async fn bind_copy_from_uri_into_table(
         &mut self,
-        _: &BindContext,
+        _: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
         src_uri_location: &UriLocation,
         dst_catalog_name: &str,
@@ -312,7 +312,7 @@ impl<'a> Binder {
     #[allow(clippy::too_many_arguments)]
     async fn bind_copy_from_table_into_stage(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
         src_catalog_name: &str,
         src_database_name: &str,
@@ -358,7 +358,7 @@ impl<'a> Binder {
     #[allow(clippy::too_many_arguments)]
     async fn bind_copy_from_table_into_uri(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
         src_catalog_name: &str,
         src_database_name: &str,
@@ -408,7 +408,7 @@ impl<'a> Binder {
     /// Bind COPY INFO <stage_location> FROM <query>
     async fn bind_copy_from_query_into_stage(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
         src_query: &Query<'_>,
         dst_stage: &str,
@@ -438,7 +438,7 @@ impl<'a> Binder {
     #[allow(clippy::too_many_arguments)]
     async fn bind_copy_from_query_into_uri(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &CopyStmt<'a>,
         src_query: &Query<'_>,
         dst_uri_location: &UriLocation,
@@ -18,6 +18,8 @@ use common_ast::ast::AlterUserStmt;
 use common_ast::ast::CreateUserStmt;
 use common_ast::ast::GrantStmt;
 use common_ast::ast::RevokeStmt;
+use common_ast::ast::RoleOption;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_types::AuthInfo;
 use common_meta_types::GrantObject;
@@ -74,6 +76,21 @@ impl<'a> Binder {
                 };
                 Ok(Plan::GrantPriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let grant_object = self.convert_to_column_grant_object(columns, level)?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                priv_types.set_privilege(*privilege);
+                let plan = GrantPrivilegePlan {
+                    principal: principal.clone(),
+                    on: grant_object,
+                    priv_types,
+                };
+                Ok(Plan::GrantPriv(Box::new(plan)))
+            }
         }
     }
 
@@ -116,6 +133,21 @@ impl<'a> Binder {
                 };
                 Ok(Plan::RevokePriv(Box::new(plan)))
             }
+            AccountMgrSource::ColumnPrivs {
+                privilege,
+                columns,
+                level,
+            } => {
+                let grant_object = self.convert_to_column_grant_object(columns, level)?;
+                let mut priv_types = UserPrivilegeSet::empty();
+                priv_types.set_privilege(*privilege);
+                let plan = RevokePrivilegePlan {
+                    principal: principal.clone(),
+                    on: grant_object,
+                    priv_types,
+                };
+                Ok(Plan::RevokePriv(Box::new(plan)))
+            }
         }
     }
 
@@ -142,6 +174,46 @@ impl<'a> Binder {
         }
     }
 
+    fn convert_to_column_grant_object(
+        &self,
+        columns: &[String],
+        level: &AccountMgrLevel,
+    ) -> Result<GrantObject> {
+        match self.convert_to_grant_object(level) {
+            GrantObject::Table(catalog_name, database_name, table_name) => {
+                let mut column_names = Vec::with_capacity(columns.len());
+                for column in columns.iter().map(|column| column.to_lowercase()) {
+                    if !column_names.contains(&column) {
+                        column_names.push(column);
+                    }
+                }
+                Ok(GrantObject::Column(
+                    catalog_name,
+                    database_name,
+                    table_name,
+                    column_names,
+                ))
+            }
+            _ => Err(ErrorCode::SemanticError(
+                "column privileges can only be granted on a table",
+            )),
+        }
+    }
+
+    // Make sure the network policies to set on the user exist.
+    async fn check_network_policies(&self, role_options: &[RoleOption]) -> Result<()> {
+        let tenant = self.ctx.get_tenant();
+        for option in role_options {
+            if let RoleOption::SetNetworkPolicy(policy) = option {
+                self.ctx
+                    .get_user_manager()
+                    .get_network_policy(&tenant, policy)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
     pub(in crate::sql::planner::binder) async fn bind_create_user(
         &mut self,
         stmt: &CreateUserStmt,
@@ -152,6 +224,7 @@ impl<'a> Binder {
             auth_option,
             role_options,
         } = stmt;
+        self.check_network_policies(role_options).await?;
         let mut user_option = UserOption::default();
         for option in role_options {
             option.apply(&mut user_option);
@@ -172,6 +245,7 @@ impl<'a> Binder {
         let AlterUserStmt {
             user,
             auth_option,
+            quota_options,
             role_options,
         } = stmt;
         // None means current user
@@ -198,6 +272,7 @@ impl<'a> Binder {
             None
         };
 
+        self.check_network_policies(role_options).await?;
         let mut user_option = user_info.option.clone();
         for option in role_options {
             option.apply(&mut user_option);
@@ -207,10 +282,21 @@ impl<'a> Binder {
         } else {
             Some(user_option)
         };
+
+        let mut user_quota = user_info.quota.clone();
+        for option in quota_options {
+            option.apply(&mut user_quota);
+        }
+        let new_user_quota = if user_quota == user_info.quota {
+            None
+        } else {
+            Some(user_quota)
+        };
         let plan = AlterUserPlan {
             user: user_info.identity(),
             auth_info: new_auth_info,
             user_option: new_user_option,
+            user_quota: new_user_quota,
         };
 
         Ok(Plan::AlterUser(Box::new(plan)))
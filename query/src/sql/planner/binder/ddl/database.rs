@@ -47,7 +47,7 @@ use crate::sql::BindContext;
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_show_databases(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &ShowDatabasesStmt<'a>,
     ) -> Result<Plan> {
         let ShowDatabasesStmt { limit } = stmt;
@@ -14,6 +14,8 @@
 
 mod account;
 mod database;
+mod masking_policy;
+mod row_access_policy;
 mod stage;
 mod table;
 mod view;
@@ -0,0 +1,60 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::CreateRowAccessPolicyStmt;
+use common_datavalues::DataType;
+use common_datavalues::TypeFactory;
+use common_exception::Result;
+use common_meta_types::RowAccessPolicy;
+use common_planners::CreateRowAccessPolicyPlan;
+
+use crate::sql::binder::Binder;
+use crate::sql::plans::Plan;
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_create_row_access_policy(
+        &mut self,
+        stmt: &CreateRowAccessPolicyStmt<'a>,
+    ) -> Result<Plan> {
+        let CreateRowAccessPolicyStmt {
+            if_not_exists,
+            policy_name,
+            parameters,
+            definition,
+            description,
+        } = stmt;
+
+        let mut parameter_names = Vec::with_capacity(parameters.len());
+        let mut parameter_types = Vec::with_capacity(parameters.len());
+        for (name, data_type) in parameters {
+            // Make sure the type is valid, it's resolved again when the policy is applied.
+            let data_type = TypeFactory::instance().get(data_type.to_string())?;
+            parameter_names.push(name.to_string());
+            parameter_types.push(data_type.name());
+        }
+
+        Ok(Plan::CreateRowAccessPolicy(Box::new(
+            CreateRowAccessPolicyPlan {
+                if_not_exists: *if_not_exists,
+                policy: RowAccessPolicy {
+                    name: policy_name.to_string(),
+                    parameters: parameter_names,
+                    parameter_types,
+                    definition: definition.to_string(),
+                    description: description.clone().unwrap_or_default(),
+                },
+            },
+        )))
+    }
+}
@@ -25,6 +25,9 @@ use common_ast::Backtrace;
 use common_datavalues::DataField;
 use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
 use common_datavalues::ToDataType;
 use common_datavalues::TypeFactory;
 use common_datavalues::Vu8;
@@ -36,6 +39,7 @@ use common_planners::*;
 use tracing::debug;
 
 use crate::catalogs::DatabaseCatalog;
+use crate::evaluator::Evaluator;
 use crate::sessions::TableContext;
 use crate::sql::binder::scalar::ScalarBinder;
 use crate::sql::binder::Binder;
@@ -43,13 +47,17 @@ use crate::sql::is_reserved_opt_key;
 use crate::sql::optimizer::optimize;
 use crate::sql::optimizer::OptimizerConfig;
 use crate::sql::optimizer::OptimizerContext;
+use crate::sql::parse_data_retention_period;
 use crate::sql::plans::create_table_v2::CreateTablePlanV2;
+use crate::sql::plans::CastExpr;
 use crate::sql::plans::Plan;
 use crate::sql::plans::RewriteKind;
+use crate::sql::plans::Scalar;
 use crate::sql::BindContext;
 use crate::sql::ColumnBinding;
 use crate::sql::ScalarExpr;
 use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::sql::OPT_KEY_DATA_RETENTION_PERIOD;
 
 struct SelectBuilder {
     from: String,
@@ -117,7 +125,7 @@ impl SelectBuilder {
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_show_tables(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &ShowTablesStmt<'a>,
     ) -> Result<Plan> {
         let ShowTablesStmt {
@@ -256,7 +264,7 @@ impl<'a> Binder {
 
     pub(in crate::sql::planner::binder) async fn bind_show_tables_status(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &ShowTablesStatusStmt<'a>,
     ) -> Result<Plan> {
         let ShowTablesStatusStmt {
@@ -583,6 +591,93 @@ impl<'a> Binder {
                     table,
                 },
             ))),
+            AlterTableAction::AddColumn { column } => {
+                let name = column.name.name.clone();
+                let data_type = TypeFactory::instance().get(column.data_type.to_string())?;
+                let default_value = match &column.default_expr {
+                    Some(default_expr) => {
+                        self.analyze_default_value(default_expr, &data_type).await?
+                    }
+                    None => data_type.default_value(),
+                };
+                let field = DataField::new(&name, data_type).with_default_expr(
+                    column
+                        .default_expr
+                        .as_ref()
+                        .map(|default_expr| default_expr.to_string()),
+                );
+
+                Ok(Plan::AddTableColumn(Box::new(AddTableColumnPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    field,
+                    comment: column.comment.clone().unwrap_or_default(),
+                    default_value,
+                })))
+            }
+            AlterTableAction::DropColumn { column } => {
+                Ok(Plan::DropTableColumn(Box::new(DropTableColumnPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: column.name.clone(),
+                })))
+            }
+            AlterTableAction::RenameColumn {
+                old_column,
+                new_column,
+            } => Ok(Plan::RenameTableColumn(Box::new(RenameTableColumnPlan {
+                tenant,
+                catalog,
+                database,
+                table,
+                old_column: old_column.name.clone(),
+                new_column: new_column.name.clone(),
+            }))),
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => Ok(
+                Plan::AddTableRowAccessPolicy(Box::new(AddTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                    columns: columns
+                        .iter()
+                        .map(|column| column.name.to_lowercase())
+                        .collect(),
+                })),
+            ),
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
+            AlterTableAction::SetMaskingPolicy { column, policy } => Ok(
+                Plan::SetTableColumnMaskingPolicy(Box::new(SetTableColumnMaskingPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: column.name.to_lowercase(),
+                    policy: policy.to_string(),
+                })),
+            ),
+            AlterTableAction::UnsetMaskingPolicy { column } => Ok(
+                Plan::UnsetTableColumnMaskingPolicy(Box::new(UnsetTableColumnMaskingPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    column: column.name.to_lowercase(),
+                })),
+            ),
         }
     }
 
@@ -706,6 +801,51 @@ impl<'a> Binder {
         })))
     }
 
+    pub(in crate::sql::planner::binder) async fn bind_analyze_table(
+        &mut self,
+        stmt: &AnalyzeTableStmt<'a>,
+    ) -> Result<Plan> {
+        let AnalyzeTableStmt {
+            catalog,
+            database,
+            table,
+            columns,
+        } = stmt;
+
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| catalog.name.to_lowercase())
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = database
+            .as_ref()
+            .map(|ident| ident.name.to_lowercase())
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table = table.name.to_lowercase();
+
+        let schema = self
+            .ctx
+            .get_table(&catalog, &database, &table)
+            .await?
+            .schema();
+        let mut column_names = Vec::with_capacity(columns.len());
+        for column in columns {
+            if !schema.has_field(&column.name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column {} doesn't exist in table {}.{}",
+                    column.name, database, table
+                )));
+            }
+            column_names.push(column.name.clone());
+        }
+
+        Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
+            catalog,
+            database,
+            table,
+            columns: column_names,
+        })))
+    }
+
     pub(in crate::sql::planner::binder) async fn bind_exists_table(
         &mut self,
         stmt: &ExistsTableStmt<'a>,
@@ -781,6 +921,37 @@ impl<'a> Binder {
         }
     }
 
+    // Evaluate the default expression of the new column, which is used to fill the rows
+    // that already exist in the table.
+    async fn analyze_default_value(
+        &self,
+        default_expr: &Expr<'a>,
+        data_type: &DataTypeImpl,
+    ) -> Result<DataValue> {
+        let bind_context = BindContext::new();
+        let mut scalar_binder =
+            ScalarBinder::new(&bind_context, self.ctx.clone(), self.metadata.clone());
+        let (scalar, from_type) = scalar_binder.bind(default_expr).await?;
+        let scalar: Scalar = CastExpr {
+            argument: Box::new(scalar),
+            from_type: Box::new(from_type),
+            target_type: Box::new(data_type.clone()),
+        }
+        .into();
+
+        let func_ctx = self.ctx.try_get_function_context()?;
+        let (value, _) = Evaluator::eval_scalar::<String>(&scalar)
+            .and_then(|evaluator| evaluator.try_eval_const(&func_ctx))
+            .map_err(|e| {
+                ErrorCode::SemanticError(format!(
+                    "default expression {} of the new column must be a constant: {}",
+                    default_expr,
+                    e.message()
+                ))
+            })?;
+        Ok(value)
+    }
+
     fn insert_table_option_with_validation(
         &self,
         options: &mut BTreeMap<String, String>,
@@ -788,11 +959,17 @@ impl<'a> Binder {
         value: String,
     ) -> Result<()> {
         if is_reserved_opt_key(&key) {
-            Err(ErrorCode::BadOption(format!(
+            return Err(ErrorCode::BadOption(format!(
                 "the following table options are reserved, please do not specify them in the CREATE TABLE statement: {}",
                 key
-            )))
-        } else if options.insert(key.clone(), value).is_some() {
+            )));
+        }
+
+        if key == OPT_KEY_DATA_RETENTION_PERIOD {
+            parse_data_retention_period(&value)?;
+        }
+
+        if options.insert(key.clone(), value).is_some() {
             Err(ErrorCode::BadOption(format!(
                 "Duplicated table option: {key}"
             )))
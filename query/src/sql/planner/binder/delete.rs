@@ -49,7 +49,7 @@ impl QueryASTIRVisitor<HashSet<String>> for DeleteCollectPushDowns {
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_delete(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         table_reference: &'a TableReference<'a>,
         selection: &'a Option<Expr<'a>>,
     ) -> Result<Plan> {
@@ -76,21 +76,30 @@ impl<'a> Binder {
             ));
         };
 
-        let (_, context) = self
-            .bind_table_reference(bind_context, table_reference)
-            .await?;
+        let target = self.bind_dml_target(bind_context, table_reference).await?;
 
-        let mut scalar_binder =
-            ScalarBinder::new(&context, self.ctx.clone(), self.metadata.clone());
+        let mut scalar_binder = ScalarBinder::new(
+            &target.bind_context,
+            self.ctx.clone(),
+            self.metadata.clone(),
+        );
+        let eb = ExpressionBuilderWithoutRenaming::create(self.metadata.clone());
 
-        let mut expression = None;
-        let mut require_columns: HashSet<String> = HashSet::new();
+        // The rows hidden by the row access policy can't be deleted.
+        let mut predicates = Vec::with_capacity(target.row_access_predicates.len() + 1);
         if let Some(expr) = selection {
             let (scalar, _) = scalar_binder.bind(expr).await?;
-            let eb = ExpressionBuilderWithoutRenaming::create(self.metadata.clone());
-            let mut pred_expr = eb.build(&scalar)?;
-            DeleteCollectPushDowns::visit_filter(&mut pred_expr, &mut require_columns)?;
-            expression = Some(pred_expr);
+            target.check_masked_columns(&self.metadata, &scalar)?;
+            predicates.push(eb.build(&scalar)?);
+        }
+        for predicate in target.row_access_predicates.iter() {
+            predicates.push(eb.build(predicate)?);
+        }
+
+        let mut require_columns: HashSet<String> = HashSet::new();
+        let mut expression = predicates.into_iter().reduce(|left, right| left.and(right));
+        if let Some(pred_expr) = expression.as_mut() {
+            DeleteCollectPushDowns::visit_filter(pred_expr, &mut require_columns)?;
         }
 
         let table = self
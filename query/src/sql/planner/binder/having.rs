@@ -18,6 +18,7 @@ use common_exception::Result;
 
 use crate::sql::binder::aggregate::AggregateRewriter;
 use crate::sql::binder::split_conjunctions;
+use crate::sql::binder::window::check_no_window_function;
 use crate::sql::binder::ScalarBinder;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::semantic::GroupingChecker;
@@ -31,19 +32,20 @@ impl<'a> Binder {
     /// See `AggregateRewriter` for more details.
     pub(super) async fn analyze_aggregate_having(
         &mut self,
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         having: &Expr<'a>,
     ) -> Result<(Scalar, &'a [Token<'a>])> {
         let mut scalar_binder =
             ScalarBinder::new(bind_context, self.ctx.clone(), self.metadata.clone());
         let (scalar, _) = scalar_binder.bind(having).await?;
+        check_no_window_function(&scalar, "HAVING", having.span())?;
         let mut rewriter = AggregateRewriter::new(bind_context, self.metadata.clone());
         Ok((rewriter.visit(&scalar)?, having.span()))
     }
 
     pub(super) async fn bind_having(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         having: Scalar,
         span: &'a [Token<'a>],
         child: SExpr,
@@ -60,7 +60,7 @@ use crate::sql::MetadataRef;
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_insert(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &InsertStmt<'a>,
     ) -> Result<Plan> {
         let catalog_name = match stmt.catalog.clone() {
@@ -152,7 +152,7 @@ impl<'a> Binder {
 
     pub(in crate::sql::planner::binder) async fn analyze_stream_format(
         &self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stream_str: &'a str,
         format: Option<String>,
         schema: DataSchemaRef,
@@ -207,7 +207,7 @@ impl<'a> Binder {
 
 pub struct ValueSourceV2<'a> {
     ctx: Arc<QueryContext>,
-    bind_context: &'a BindContext,
+    bind_context: &'a BindContext<'a>,
     schema: DataSchemaRef,
     metadata: MetadataRef,
 }
@@ -215,7 +215,7 @@ pub struct ValueSourceV2<'a> {
 impl<'a> ValueSourceV2<'a> {
     pub fn new(
         ctx: Arc<QueryContext>,
-        bind_context: &'a BindContext,
+        bind_context: &'a BindContext<'a>,
         schema: DataSchemaRef,
         metadata: MetadataRef,
     ) -> Self {
@@ -44,9 +44,9 @@ impl<'a> Binder {
     #[async_recursion]
     pub(super) async fn bind_join(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         join: &Join<'a>,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         let (left_child, left_context) =
             self.bind_table_reference(bind_context, &join.left).await?;
         let (right_child, right_context) =
@@ -77,6 +77,18 @@ impl<'a> Binder {
                     bind_context.add_column_binding(column.clone());
                 }
             }
+            JoinOperator::FullOuter => {
+                for column in left_context.all_column_bindings() {
+                    let mut nullable_column = column.clone();
+                    nullable_column.data_type = Box::new(wrap_nullable(&column.data_type));
+                    bind_context.add_column_binding(nullable_column);
+                }
+                for column in right_context.all_column_bindings().iter() {
+                    let mut nullable_column = column.clone();
+                    nullable_column.data_type = Box::new(wrap_nullable(&column.data_type));
+                    bind_context.add_column_binding(nullable_column);
+                }
+            }
             _ => {
                 for column in left_context.all_column_bindings() {
                     bind_context.add_column_binding(column.clone());
@@ -140,12 +152,12 @@ impl<'a> Binder {
                 right_child,
             ),
             JoinOperator::RightOuter => self.bind_join_with_type(
-                JoinType::Left,
-                right_join_conditions,
+                JoinType::Right,
                 left_join_conditions,
+                right_join_conditions,
                 other_conditions,
-                right_child,
                 left_child,
+                right_child,
             ),
             JoinOperator::FullOuter => self.bind_join_with_type(
                 JoinType::Full,
@@ -229,24 +241,24 @@ pub fn check_duplicate_join_tables(
     Ok(())
 }
 
-struct JoinConditionResolver<'a> {
+struct JoinConditionResolver<'a, 'b> {
     ctx: Arc<QueryContext>,
 
     metadata: MetadataRef,
 
-    left_context: &'a BindContext,
-    right_context: &'a BindContext,
-    join_context: &'a mut BindContext,
+    left_context: &'a BindContext<'b>,
+    right_context: &'a BindContext<'b>,
+    join_context: &'a mut BindContext<'b>,
     join_condition: &'a JoinCondition<'a>,
 }
 
-impl<'a> JoinConditionResolver<'a> {
+impl<'a, 'b> JoinConditionResolver<'a, 'b> {
     pub fn new(
         ctx: Arc<QueryContext>,
         metadata: MetadataRef,
-        left_context: &'a BindContext,
-        right_context: &'a BindContext,
-        join_context: &'a mut BindContext,
+        left_context: &'a BindContext<'b>,
+        right_context: &'a BindContext<'b>,
+        join_context: &'a mut BindContext<'b>,
         join_condition: &'a JoinCondition<'a>,
     ) -> Self {
         Self {
@@ -23,7 +23,7 @@ use crate::sql::plans::Plan;
 impl<'a> Binder {
     pub(super) async fn bind_kill_stmt(
         &mut self,
-        _bind_context: &BindContext,
+        _bind_context: &BindContext<'a>,
         kill_target: &KillTarget,
         object_id: &str,
     ) -> Result<Plan> {
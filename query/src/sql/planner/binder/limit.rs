@@ -26,7 +26,7 @@ use crate::sql::BindContext;
 impl<'a> Binder {
     pub(super) async fn bind_limit(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         child: SExpr,
         limit: Option<&Expr<'a>>,
         offset: &Option<Expr<'a>>,
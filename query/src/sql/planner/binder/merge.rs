@@ -0,0 +1,448 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_ast::ast::MatchOperation;
+use common_ast::ast::MergeStmt;
+use common_ast::ast::TableReference;
+use common_ast::ast::UpdateExpr;
+use common_datavalues::type_coercion::merge_types;
+use common_datavalues::wrap_nullable;
+use common_datavalues::BooleanType;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::MergeMatchedClause;
+use common_planners::MergeOperation;
+use common_planners::MergePlan;
+use common_planners::MergeUnmatchedClause;
+
+use crate::sessions::TableContext;
+use crate::sql::binder::scalar_common::split_conjunctions;
+use crate::sql::binder::scalar_common::split_equivalent_predicate;
+use crate::sql::binder::scalar_common::wrap_cast_if_needed;
+use crate::sql::binder::Binder;
+use crate::sql::binder::ScalarBinder;
+use crate::sql::executor::format_field_name;
+use crate::sql::executor::ExpressionBuilderWithRenaming;
+use crate::sql::optimizer::optimize;
+use crate::sql::optimizer::ColumnSet;
+use crate::sql::optimizer::OptimizerConfig;
+use crate::sql::optimizer::OptimizerContext;
+use crate::sql::optimizer::SExpr;
+use crate::sql::planner::binder::join::check_duplicate_join_tables;
+use crate::sql::planner::binder::table::DmlTarget;
+use crate::sql::plans::CastExpr;
+use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::JoinType;
+use crate::sql::plans::Merge;
+use crate::sql::plans::Plan;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
+use crate::sql::BindContext;
+use crate::sql::PlanParser;
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_merge(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        stmt: &'a MergeStmt<'a>,
+    ) -> Result<Plan> {
+        let MergeStmt {
+            target,
+            source,
+            join_condition,
+            matched_clauses,
+            unmatched_clauses,
+        } = stmt;
+
+        let (catalog_name, database_name, table_name) = if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            ..
+        } = target
+        {
+            (
+                catalog
+                    .as_ref()
+                    .map_or_else(|| self.ctx.get_current_catalog(), |i| i.name.clone()),
+                database
+                    .as_ref()
+                    .map_or_else(|| self.ctx.get_current_database(), |i| i.name.clone()),
+                table.name.clone(),
+            )
+        } else {
+            return Err(ErrorCode::LogicalError(
+                "should not happen, parser should have report error already",
+            ));
+        };
+
+        let dml_target = self.bind_dml_target(bind_context, target).await?;
+        let target_context = &dml_target.bind_context;
+        let (source_s_expr, source_context) =
+            self.bind_table_reference(bind_context, source).await?;
+        check_duplicate_join_tables(target_context, &source_context)?;
+
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let tbl_info = table.get_table_info();
+        let table_id = tbl_info.ident.clone();
+        let schema = tbl_info.meta.schema.clone();
+
+        // The expressions are evaluated over the joined rows, the columns are renamed
+        // with their indexes, so that the columns of both sides are distinguished.
+        let mut join_context = bind_context.replace();
+        for column in target_context.all_column_bindings() {
+            join_context.add_column_binding(column.clone());
+        }
+        for column in source_context.all_column_bindings() {
+            join_context.add_column_binding(column.clone());
+        }
+        // The masked columns of the target are read as the stored values, they can't be
+        // referenced by the statement, see `DmlTarget::check_masked_columns`.
+        let target_bindings = {
+            let metadata = self.metadata.read();
+            target_context
+                .columns
+                .iter()
+                .map(|column| {
+                    let index = dml_target
+                        .masked_columns
+                        .iter()
+                        .find(|(_, masked_index)| *masked_index == column.index)
+                        .map_or(column.index, |(index, _)| *index);
+                    let mut binding = column.clone();
+                    binding.index = index;
+                    binding.data_type = Box::new(metadata.column(index).data_type.clone());
+                    binding
+                })
+                .collect::<Vec<_>>()
+        };
+        let target_columns = {
+            let metadata = self.metadata.read();
+            target_bindings
+                .iter()
+                .map(|column| format_field_name(&metadata.column(column.index).name, column.index))
+                .collect::<Vec<_>>()
+        };
+        let source_schema = DataSchemaRefExt::create(
+            self.renamed_columns(&source_context)
+                .into_iter()
+                .zip(source_context.columns.iter())
+                .map(|(name, column)| DataField::new(&name, *column.data_type.clone()))
+                .collect(),
+        );
+        let eb = ExpressionBuilderWithRenaming::create(self.metadata.clone());
+
+        // Splits the join condition into the equi conditions and the others.
+        let target_column_set = column_set(target_context);
+        let source_column_set = column_set(&source_context);
+        let mut target_conditions = vec![];
+        let mut source_conditions = vec![];
+        let mut other_conditions = vec![];
+        let mut target_keys = vec![];
+        let mut scalar_binder =
+            ScalarBinder::new(&join_context, self.ctx.clone(), self.metadata.clone());
+        let (condition, _) = scalar_binder.bind(join_condition).await?;
+        dml_target.check_masked_columns(&self.metadata, &condition)?;
+        for predicate in split_conjunctions(&condition) {
+            let keys = split_equivalent_predicate(&predicate).and_then(|(left, right)| {
+                let (left_columns, right_columns) = (left.used_columns(), right.used_columns());
+                if left_columns.is_subset(&target_column_set)
+                    && right_columns.is_subset(&source_column_set)
+                {
+                    Some((left, right))
+                } else if left_columns.is_subset(&source_column_set)
+                    && right_columns.is_subset(&target_column_set)
+                {
+                    Some((right, left))
+                } else {
+                    None
+                }
+            });
+            match keys {
+                Some((target_key, source_key)) => {
+                    // Bump the types of the keys, so that the keys are comparable
+                    let least_super_type =
+                        merge_types(&target_key.data_type(), &source_key.data_type())?;
+                    let target_key = wrap_cast_if_needed(target_key, &least_super_type);
+                    let source_key = wrap_cast_if_needed(source_key, &least_super_type);
+                    target_keys.push(eb.build(&target_key)?);
+                    target_conditions.push(target_key);
+                    source_conditions.push(source_key);
+                }
+                None => other_conditions.push(predicate),
+            }
+        }
+
+        // The target rows hidden by the row access policy can't be updated or deleted,
+        // the predicates are added to the conditions of all the matched clauses.
+        let mut row_access_predicate = None;
+        for predicate in dml_target.row_access_predicates.iter() {
+            row_access_predicate = Some(and_predicate(row_access_predicate, eb.build(predicate)?));
+        }
+
+        let mut merge_matched_clauses = Vec::with_capacity(matched_clauses.len());
+        for clause in matched_clauses {
+            let mut selection = match &clause.selection {
+                Some(expr) => {
+                    let (scalar, _) = scalar_binder.bind(expr).await?;
+                    dml_target.check_masked_columns(&self.metadata, &scalar)?;
+                    Some(eb.build(&scalar)?)
+                }
+                None => None,
+            };
+            if let Some(predicate) = &row_access_predicate {
+                selection = Some(and_predicate(selection, predicate.clone()));
+            }
+            let operation = match &clause.operation {
+                MatchOperation::Update { update_list } => MergeOperation::Update(
+                    self.bind_merge_update_list(
+                        &mut scalar_binder,
+                        &eb,
+                        &dml_target,
+                        &schema,
+                        update_list,
+                    )
+                    .await?,
+                ),
+                MatchOperation::Delete => MergeOperation::Delete,
+            };
+            merge_matched_clauses.push(MergeMatchedClause {
+                selection,
+                operation,
+            });
+        }
+
+        // The source rows not matched can not refer to the columns of the target.
+        let mut scalar_binder =
+            ScalarBinder::new(&source_context, self.ctx.clone(), self.metadata.clone());
+        let mut merge_unmatched_clauses = Vec::with_capacity(unmatched_clauses.len());
+        for clause in unmatched_clauses {
+            let selection = match &clause.selection {
+                Some(expr) => {
+                    let (scalar, _) = scalar_binder.bind(expr).await?;
+                    Some(eb.build(&scalar)?)
+                }
+                None => None,
+            };
+
+            let column_indexes = if clause.columns.is_empty() {
+                (0..schema.num_fields()).collect::<Vec<_>>()
+            } else {
+                let mut indexes = Vec::with_capacity(clause.columns.len());
+                for column in &clause.columns {
+                    let index = schema.index_of(&column.name).map_err(|_| {
+                        ErrorCode::UnknownColumn(format!("Column [{}] not found", column.name))
+                    })?;
+                    if indexes.contains(&index) {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Multiple assignments to the same column {}",
+                            column.name
+                        )));
+                    }
+                    indexes.push(index);
+                }
+                indexes
+            };
+            if column_indexes.len() != clause.values.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The number of values {} does not match the number of columns {}",
+                    clause.values.len(),
+                    column_indexes.len()
+                )));
+            }
+
+            // The columns not listed are filled with the default values.
+            let mut values = Vec::with_capacity(schema.num_fields());
+            for (index, field) in schema.fields().iter().enumerate() {
+                let value = match column_indexes.iter().position(|i| *i == index) {
+                    Some(position) => {
+                        let (scalar, data_type) =
+                            scalar_binder.bind(&clause.values[position]).await?;
+                        let scalar = CastExpr {
+                            argument: Box::new(scalar),
+                            from_type: Box::new(data_type),
+                            target_type: Box::new(field.data_type().clone()),
+                        }
+                        .into();
+                        eb.build(&scalar)?
+                    }
+                    None => default_value_of(field)?,
+                };
+                values.push(value);
+            }
+            merge_unmatched_clauses.push(MergeUnmatchedClause { selection, values });
+        }
+
+        // The target is joined with the source by a query, the target rows are read as
+        // they are stored, with a constant column telling whether a source row is matched.
+        let matched_column = self.create_column_binding(
+            None,
+            None,
+            "_merge_matched".to_string(),
+            BooleanType::new_impl(),
+        );
+        let target_s_expr = SExpr::create_unary(
+            EvalScalar {
+                items: vec![ScalarItem {
+                    scalar: ConstantExpr {
+                        value: DataValue::Boolean(true),
+                        data_type: Box::new(BooleanType::new_impl()),
+                    }
+                    .into(),
+                    index: matched_column.index,
+                }],
+            }
+            .into(),
+            dml_target.scan.clone(),
+        );
+        let join_s_expr = self.bind_join_with_type(
+            JoinType::Right,
+            target_conditions,
+            source_conditions,
+            other_conditions,
+            target_s_expr,
+            source_s_expr,
+        )?;
+
+        // The joined rows are the target columns, the matched column and the source
+        // columns, the target side is nullable since it's a right outer join.
+        let mut joined_context = bind_context.replace();
+        for column in target_bindings
+            .into_iter()
+            .chain(std::iter::once(matched_column))
+        {
+            let mut nullable_column = column;
+            nullable_column.data_type = Box::new(wrap_nullable(&nullable_column.data_type));
+            joined_context.add_column_binding(nullable_column);
+        }
+        for column in source_context.columns.iter() {
+            joined_context.add_column_binding(column.clone());
+        }
+        let join_plan = Plan::Query {
+            s_expr: join_s_expr,
+            metadata: self.metadata.clone(),
+            bind_context: Box::new(joined_context.into_output()),
+            rewrite_kind: None,
+        };
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig::default()));
+        let join_plan = optimize(self.ctx.clone(), opt_ctx, join_plan)?;
+
+        let plan = MergePlan {
+            catalog_name,
+            database_name,
+            table_name,
+            table_id,
+            target_columns,
+            source_schema,
+            target_keys,
+            matched_clauses: merge_matched_clauses,
+            unmatched_clauses: merge_unmatched_clauses,
+        };
+        Ok(Plan::Merge(Box::new(Merge {
+            plan,
+            join: Box::new(join_plan),
+        })))
+    }
+
+    async fn bind_merge_update_list(
+        &self,
+        scalar_binder: &mut ScalarBinder<'_>,
+        eb: &ExpressionBuilderWithRenaming,
+        dml_target: &DmlTarget<'a>,
+        schema: &DataSchemaRef,
+        update_list: &[UpdateExpr<'a>],
+    ) -> Result<Vec<(usize, Expression)>> {
+        let mut columns = HashSet::with_capacity(update_list.len());
+        let mut update_columns = Vec::with_capacity(update_list.len());
+        for update_expr in update_list {
+            let col_name = &update_expr.name.name;
+            let index = schema.index_of(col_name).map_err(|_| {
+                ErrorCode::UnknownColumn(format!("Column [{}] not found", col_name))
+            })?;
+            if !columns.insert(index) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Multiple assignments to the same column {}",
+                    col_name
+                )));
+            }
+
+            let target_type = schema.field(index).data_type().clone();
+            let (scalar, data_type) = scalar_binder.bind(&update_expr.expr).await?;
+            dml_target.check_masked_columns(&self.metadata, &scalar)?;
+            let scalar = CastExpr {
+                argument: Box::new(scalar),
+                from_type: Box::new(data_type),
+                target_type: Box::new(target_type),
+            }
+            .into();
+            update_columns.push((index, eb.build(&scalar)?));
+        }
+        Ok(update_columns)
+    }
+
+    fn renamed_columns(&self, bind_context: &BindContext<'a>) -> Vec<String> {
+        let metadata = self.metadata.read();
+        bind_context
+            .columns
+            .iter()
+            .map(|column| format_field_name(&metadata.column(column.index).name, column.index))
+            .collect()
+    }
+}
+
+fn column_set(bind_context: &BindContext) -> ColumnSet {
+    bind_context
+        .all_column_bindings()
+        .iter()
+        .map(|column| column.index)
+        .collect()
+}
+
+fn and_predicate(left: Option<Expression>, right: Expression) -> Expression {
+    match left {
+        Some(left) => left.and(right),
+        None => right,
+    }
+}
+
+/// The value of a column not listed in `INSERT`, which is the default expression of
+/// the column, or the default value of the type.
+fn default_value_of(field: &DataField) -> Result<Expression> {
+    let expr = match field.default_expr() {
+        Some(default_expr) => PlanParser::parse_expr(default_expr)?,
+        None => Expression::Literal {
+            value: field.data_type().default_value(),
+            column_name: None,
+            data_type: field.data_type().clone(),
+        },
+    };
+    Ok(Expression::Cast {
+        expr: Box::new(expr),
+        data_type: field.data_type().clone(),
+        pg_style: false,
+    })
+}
@@ -17,19 +17,25 @@ use std::sync::Arc;
 pub use aggregate::AggregateInfo;
 pub use bind_context::BindContext;
 pub use bind_context::ColumnBinding;
+pub use bind_context::CteInfo;
 use common_ast::ast::Statement;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_datavalues::DataTypeImpl;
 use common_exception::Result;
+use common_meta_types::NetworkPolicy;
 use common_meta_types::UserDefinedFunction;
 use common_planners::AlterUserUDFPlan;
 use common_planners::CallPlan;
+use common_planners::CreateNetworkPolicyPlan;
 use common_planners::CreateRolePlan;
 use common_planners::CreateUserUDFPlan;
 use common_planners::DescribeUserStagePlan;
+use common_planners::DropMaskingPolicyPlan;
+use common_planners::DropNetworkPolicyPlan;
 use common_planners::DropRolePlan;
+use common_planners::DropRowAccessPolicyPlan;
 use common_planners::DropUserPlan;
 use common_planners::DropUserStagePlan;
 use common_planners::DropUserUDFPlan;
@@ -37,6 +43,7 @@ use common_planners::ShowGrantsPlan;
 use common_planners::UseDatabasePlan;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
+pub use window::WindowInfo;
 
 use super::plans::Plan;
 use super::plans::RewriteKind;
@@ -55,6 +62,7 @@ mod insert;
 mod join;
 mod kill;
 mod limit;
+mod merge;
 mod presign;
 mod project;
 mod scalar;
@@ -65,6 +73,8 @@ mod setting;
 mod show;
 mod sort;
 mod table;
+mod update;
+mod window;
 
 /// Binder is responsible to transform AST of a query into a canonical logical SExpr.
 ///
@@ -100,7 +110,7 @@ impl<'a> Binder {
     #[async_recursion::async_recursion]
     async fn bind_statement(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &Statement<'a>,
     ) -> Result<Plan> {
         let plan = match stmt {
@@ -109,7 +119,7 @@ impl<'a> Binder {
                 Plan::Query {
                     s_expr,
                     metadata: self.metadata.clone(),
-                    bind_context: Box::new(bind_context),
+                    bind_context: Box::new(bind_context.into_output()),
                     rewrite_kind: None,
                 }
             }
@@ -169,6 +179,7 @@ impl<'a> Binder {
             Statement::RenameTable(stmt) => self.bind_rename_table(stmt).await?,
             Statement::TruncateTable(stmt) => self.bind_truncate_table(stmt).await?,
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(stmt).await?,
+            Statement::AnalyzeTable(stmt) => self.bind_analyze_table(stmt).await?,
             Statement::ExistsTable(stmt) => self.bind_exists_table(stmt).await?,
 
             // Views
@@ -231,6 +242,13 @@ impl<'a> Binder {
                 self.bind_delete(bind_context, table_reference, selection)
                     .await?
             }
+            Statement::Update(stmt) => self.bind_update(bind_context, stmt).await?,
+            Statement::Merge(stmt) => self.bind_merge(bind_context, stmt).await?,
+
+            // Transactions
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Rollback => Plan::Rollback,
 
             // Permissions
             Statement::Grant(stmt) => self.bind_grant(stmt).await?,
@@ -275,6 +293,49 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 name: udf_name.to_string(),
             })),
+
+            // Row access policies
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy {
+                if_exists,
+                policy_name,
+            } => Plan::DropRowAccessPolicy(Box::new(DropRowAccessPolicyPlan {
+                if_exists: *if_exists,
+                name: policy_name.to_string(),
+            })),
+
+            // Masking policies
+            Statement::CreateMaskingPolicy(stmt) => self.bind_create_masking_policy(stmt).await?,
+            Statement::DropMaskingPolicy {
+                if_exists,
+                policy_name,
+            } => Plan::DropMaskingPolicy(Box::new(DropMaskingPolicyPlan {
+                if_exists: *if_exists,
+                name: policy_name.to_string(),
+            })),
+
+            // Network policies
+            Statement::CreateNetworkPolicy(stmt) => {
+                Plan::CreateNetworkPolicy(Box::new(CreateNetworkPolicyPlan {
+                    if_not_exists: stmt.if_not_exists,
+                    policy: NetworkPolicy::new(
+                        &stmt.policy_name.to_string(),
+                        stmt.allowed_ip_list.clone(),
+                        stmt.blocked_ip_list.clone(),
+                        &stmt.comment.clone().unwrap_or_default(),
+                    ),
+                }))
+            }
+            Statement::DropNetworkPolicy {
+                if_exists,
+                policy_name,
+            } => Plan::DropNetworkPolicy(Box::new(DropNetworkPolicyPlan {
+                if_exists: *if_exists,
+                name: policy_name.to_string(),
+            })),
+
             Statement::Call(stmt) => Plan::Call(Box::new(CallPlan {
                 name: stmt.name.clone(),
                 args: stmt.args.clone(),
@@ -300,7 +361,7 @@ impl<'a> Binder {
 
     async fn bind_rewrite_to_query(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         query: &str,
         rewrite_kind_r: RewriteKind,
     ) -> Result<Plan> {
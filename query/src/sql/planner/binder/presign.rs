@@ -28,7 +28,7 @@ use crate::sql::BindContext;
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_presign(
         &mut self,
-        _: &BindContext,
+        _: &BindContext<'a>,
         stmt: &PresignStmt,
     ) -> Result<Plan> {
         match &stmt.location {
@@ -28,6 +28,7 @@ use crate::sql::planner::binder::BindContext;
 use crate::sql::planner::binder::Binder;
 use crate::sql::planner::binder::ColumnBinding;
 use crate::sql::planner::semantic::GroupingChecker;
+use crate::sql::planner::semantic::WindowChecker;
 use crate::sql::plans::BoundColumnRef;
 use crate::sql::plans::EvalScalar;
 use crate::sql::plans::Project;
@@ -94,7 +95,7 @@ impl<'a> Binder {
 
     pub(super) fn bind_projection(
         &mut self,
-        bind_context: &mut BindContext,
+        bind_context: &mut BindContext<'a>,
         columns: &[ColumnBinding],
         scalars: &HashMap<IndexType, ScalarItem>,
         child: SExpr,
@@ -110,7 +111,12 @@ impl<'a> Binder {
                         index: item.index,
                     })
                 } else {
-                    Ok(item.clone())
+                    let mut window_checker = WindowChecker::new(bind_context);
+                    let scalar = window_checker.resolve(&item.scalar)?;
+                    Ok(ScalarItem {
+                        scalar,
+                        index: item.index,
+                    })
                 }
             })
             .collect::<Result<Vec<_>>>()?;
@@ -150,7 +156,7 @@ impl<'a> Binder {
     /// in this function.
     pub(super) async fn normalize_select_list(
         &mut self,
-        input_context: &BindContext,
+        input_context: &BindContext<'a>,
         select_list: &'a [SelectTarget<'a>],
     ) -> Result<SelectList<'a>> {
         let mut output = SelectList::<'a>::default();
@@ -164,6 +170,9 @@ impl<'a> Binder {
                             Indirection::Identifier(ident) => {
                                 let column_binding =
                                     input_context.resolve_column(None, None, ident)?;
+                                self.metadata
+                                    .read()
+                                    .check_column_access(column_binding.index)?;
                                 output.items.push(SelectItem {
                                     select_target,
                                     scalar: BoundColumnRef {
@@ -180,6 +189,9 @@ impl<'a> Binder {
                                     if !column_binding.visible_in_unqualified_wildcard {
                                         continue;
                                     }
+                                    self.metadata
+                                        .read()
+                                        .check_column_access(column_binding.index)?;
                                     output.items.push(SelectItem {
                                         select_target,
                                         scalar: BoundColumnRef {
@@ -26,14 +26,14 @@ use crate::sql::plans::Scalar;
 
 /// Helper for binding scalar expression with `BindContext`.
 pub struct ScalarBinder<'a> {
-    bind_context: &'a BindContext,
+    bind_context: &'a BindContext<'a>,
     ctx: Arc<QueryContext>,
     metadata: MetadataRef,
 }
 
 impl<'a> ScalarBinder<'a> {
     pub fn new(
-        bind_context: &'a BindContext,
+        bind_context: &'a BindContext<'a>,
         ctx: Arc<QueryContext>,
         metadata: MetadataRef,
     ) -> Self {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 use async_recursion::async_recursion;
 use common_ast::ast::Expr;
@@ -26,15 +27,19 @@ use common_ast::ast::SelectTarget;
 use common_ast::ast::SetExpr;
 use common_ast::ast::SetOperator;
 use common_ast::ast::TableReference;
+use common_ast::ast::With;
+use common_ast::DisplayError;
 use common_datavalues::type_coercion::merge_types;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
 use crate::sql::binder::scalar_common::split_conjunctions;
+use crate::sql::binder::window::check_no_window_function;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::binder::scalar::ScalarBinder;
 use crate::sql::planner::binder::BindContext;
 use crate::sql::planner::binder::Binder;
+use crate::sql::planner::binder::CteInfo;
 use crate::sql::plans::BoundColumnRef;
 use crate::sql::plans::Filter;
 use crate::sql::plans::JoinType;
@@ -56,10 +61,10 @@ pub struct SelectItem<'a> {
 impl<'a> Binder {
     pub(super) async fn bind_select_stmt(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &SelectStmt<'a>,
         order_by: &[OrderByExpr<'a>],
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         let (mut s_expr, mut from_context) = if stmt.from.is_empty() {
             self.bind_one_table(bind_context, stmt).await?
         } else {
@@ -93,11 +98,13 @@ impl<'a> Binder {
         let (mut scalar_items, projections) = self.analyze_projection(&select_list)?;
 
         // This will potentially add some alias group items to `from_context` if find some.
-        self.analyze_group_items(&mut from_context, &select_list, &stmt.group_by)
+        self.analyze_group_items(&mut from_context, &select_list, stmt.group_by.as_ref())
             .await?;
 
         self.analyze_aggregate_select(&mut from_context, &mut select_list)?;
 
+        self.analyze_window_select(&mut from_context, &select_list)?;
+
         let having = if let Some(having) = &stmt.having {
             Some(
                 self.analyze_aggregate_having(&mut from_context, having)
@@ -117,8 +124,7 @@ impl<'a> Binder {
             )
             .await?;
 
-        if !from_context.aggregate_info.aggregate_functions.is_empty() || !stmt.group_by.is_empty()
-        {
+        if !from_context.aggregate_info.aggregate_functions.is_empty() || stmt.group_by.is_some() {
             s_expr = self.bind_aggregate(&mut from_context, s_expr).await?;
         }
 
@@ -128,6 +134,10 @@ impl<'a> Binder {
                 .await?;
         }
 
+        if !from_context.window_info.window_functions.is_empty() {
+            s_expr = self.bind_window(&from_context, s_expr)?;
+        }
+
         if stmt.distinct {
             s_expr = self.bind_distinct(&from_context, &projections, &mut scalar_items, s_expr)?;
         }
@@ -156,10 +166,10 @@ impl<'a> Binder {
     #[async_recursion]
     pub(crate) async fn bind_set_expr(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         set_expr: &SetExpr,
         order_by: &[OrderByExpr],
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         match set_expr {
             SetExpr::Select(stmt) => self.bind_select_stmt(bind_context, stmt, order_by).await,
             SetExpr::Query(stmt) => self.bind_query(bind_context, stmt).await,
@@ -178,9 +188,19 @@ impl<'a> Binder {
 
     pub(crate) async fn bind_query(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         query: &Query<'_>,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
+        let mut with_context;
+        let bind_context = match &query.with {
+            Some(with) => {
+                with_context = bind_context.clone();
+                self.bind_with(&mut with_context, with)?;
+                &with_context
+            }
+            None => bind_context,
+        };
+
         let (mut s_expr, mut bind_context) = match query.body {
             SetExpr::Select(_) | SetExpr::Query(_) => {
                 self.bind_set_expr(bind_context, &query.body, &query.order_by)
@@ -226,15 +246,53 @@ impl<'a> Binder {
         Ok((s_expr, bind_context))
     }
 
+    /// Register CTEs of `WITH` clause into `bind_context`, they will be bound
+    /// when referenced as table in `FROM` clause.
+    fn bind_with(&mut self, bind_context: &mut BindContext<'a>, with: &With<'a>) -> Result<()> {
+        if with.recursive {
+            return Err(ErrorCode::SemanticError(
+                with.span
+                    .display_error("recursive CTE is not supported".to_string()),
+            ));
+        }
+
+        let mut cte_names = HashSet::new();
+        for cte in with.ctes.iter() {
+            let table_name = cte.alias.name.name.to_lowercase();
+            if !cte_names.insert(table_name.clone()) {
+                return Err(ErrorCode::SemanticError(
+                    cte.alias
+                        .name
+                        .span
+                        .display_error(format!("duplicate CTE name `{}`", table_name)),
+                ));
+            }
+            let cte_info = CteInfo {
+                columns_alias: cte
+                    .alias
+                    .columns
+                    .iter()
+                    .map(|column| column.name.to_lowercase())
+                    .collect(),
+                query: cte.query.clone(),
+                bind_context: bind_context.clone(),
+            };
+            bind_context.ctes_map.insert(table_name, cte_info);
+        }
+
+        Ok(())
+    }
+
     pub(super) async fn bind_where(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         expr: &Expr<'a>,
         child: SExpr,
     ) -> Result<SExpr> {
         let mut scalar_binder =
             ScalarBinder::new(bind_context, self.ctx.clone(), self.metadata.clone());
         let (scalar, _) = scalar_binder.bind(expr).await?;
+        check_no_window_function(&scalar, "WHERE", expr.span())?;
         let filter_plan = Filter {
             predicates: split_conjunctions(&scalar),
             is_having: false,
@@ -245,12 +303,12 @@ impl<'a> Binder {
 
     pub(super) async fn bind_set_operator(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         left: &SetExpr<'_>,
         right: &SetExpr<'_>,
         op: &SetOperator,
         all: &bool,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         let (left_expr, mut left_bind_context) =
             self.bind_set_expr(bind_context, left, &[]).await?;
         let (right_expr, mut right_bind_context) =
@@ -294,11 +352,11 @@ impl<'a> Binder {
 
     fn bind_intersect(
         &mut self,
-        left_context: BindContext,
-        right_context: BindContext,
+        left_context: BindContext<'a>,
+        right_context: BindContext<'a>,
         left_expr: SExpr,
         right_expr: SExpr,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         self.bind_intersect_or_except(
             left_context,
             right_context,
@@ -310,11 +368,11 @@ impl<'a> Binder {
 
     fn bind_except(
         &mut self,
-        left_context: BindContext,
-        right_context: BindContext,
+        left_context: BindContext<'a>,
+        right_context: BindContext<'a>,
         left_expr: SExpr,
         right_expr: SExpr,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         self.bind_intersect_or_except(
             left_context,
             right_context,
@@ -326,12 +384,12 @@ impl<'a> Binder {
 
     fn bind_intersect_or_except(
         &mut self,
-        left_context: BindContext,
-        right_context: BindContext,
+        left_context: BindContext<'a>,
+        right_context: BindContext<'a>,
         left_expr: SExpr,
         right_expr: SExpr,
         join_type: JoinType,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         let left_expr = self.bind_distinct(
             &left_context,
             left_context.all_column_bindings(),
@@ -26,7 +26,7 @@ use crate::sql::plans::Plan;
 impl<'a> Binder {
     pub(in crate::sql::planner::binder) async fn bind_set_variable(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         is_global: bool,
         variable: &Identifier<'a>,
         value: &Literal,
@@ -29,6 +29,7 @@ use crate::sql::binder::Binder;
 use crate::sql::binder::ColumnBinding;
 use crate::sql::optimizer::SExpr;
 use crate::sql::planner::semantic::GroupingChecker;
+use crate::sql::planner::semantic::WindowChecker;
 use crate::sql::plans::AggregateFunction;
 use crate::sql::plans::AndExpr;
 use crate::sql::plans::BoundColumnRef;
@@ -60,7 +61,7 @@ pub struct OrderItem<'a> {
 impl<'a> Binder {
     pub(super) async fn analyze_order_items(
         &mut self,
-        from_context: &BindContext,
+        from_context: &BindContext<'a>,
         scalar_items: &mut HashMap<IndexType, ScalarItem>,
         projections: &[ColumnBinding],
         order_by: &'a [OrderByExpr<'a>],
@@ -180,7 +181,7 @@ impl<'a> Binder {
 
     pub(super) async fn bind_order_by(
         &mut self,
-        from_context: &BindContext,
+        from_context: &BindContext<'a>,
         order_by: OrderItems<'a>,
         select_list: &'a SelectList<'a>,
         scalar_items: &mut HashMap<IndexType, ScalarItem>,
@@ -226,6 +227,9 @@ impl<'a> Binder {
                     if from_context.in_grouping {
                         let mut group_checker = GroupingChecker::new(from_context);
                         scalar = group_checker.resolve(&scalar, None)?;
+                    } else {
+                        let mut window_checker = WindowChecker::new(from_context);
+                        scalar = window_checker.resolve(&scalar)?;
                     }
                     scalars.push(ScalarItem { scalar, index });
                 }
@@ -254,7 +258,7 @@ impl<'a> Binder {
 
     pub(crate) async fn bind_order_by_for_set_operation(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         child: SExpr,
         order_by: &[OrderByExpr<'_>],
     ) -> Result<SExpr> {
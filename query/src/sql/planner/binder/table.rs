@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_ast::ast::Indirection;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
+use common_ast::ast::TableAlias;
 use common_ast::ast::TableReference;
 use common_ast::ast::TimeTravelPoint;
+use common_ast::parser::parse_expr;
 use common_ast::parser::parse_sql;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
@@ -27,20 +30,34 @@ use common_ast::DisplayError;
 use common_datavalues::prelude::*;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_meta_types::MaskingPolicy;
+use common_meta_types::RowAccessPolicy;
 use common_planners::Expression;
 
 use crate::catalogs::CATALOG_DEFAULT;
 use crate::sessions::TableContext;
 use crate::sql::binder::scalar::ScalarBinder;
+use crate::sql::binder::split_conjunctions;
 use crate::sql::binder::Binder;
 use crate::sql::binder::ColumnBinding;
+use crate::sql::binder::CteInfo;
+use crate::sql::get_masking_policies;
+use crate::sql::get_row_access_policy;
+use crate::sql::optimizer::ColumnStat;
 use crate::sql::optimizer::SExpr;
+use crate::sql::optimizer::Statistics;
 use crate::sql::planner::semantic::TypeChecker;
 use crate::sql::plans::ConstantExpr;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::Filter;
 use crate::sql::plans::LogicalGet;
+use crate::sql::plans::RelOperator;
 use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
 use crate::sql::BindContext;
 use crate::sql::IndexType;
+use crate::sql::MetadataRef;
 use crate::storages::view::view_table::QUERY;
 use crate::storages::NavigationPoint;
 use crate::storages::Table;
@@ -50,9 +67,9 @@ use crate::table_functions::TableFunction;
 impl<'a> Binder {
     pub(super) async fn bind_one_table(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         stmt: &SelectStmt<'a>,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         for select_target in &stmt.select_list {
             if let SelectTarget::QualifiedName(names) = select_target {
                 for indirect in names {
@@ -78,14 +95,14 @@ impl<'a> Binder {
             source,
         );
 
-        self.bind_base_table(bind_context, database, table_index)
+        self.bind_base_table(bind_context, database, table_index, Statistics::default())
     }
 
     pub(super) async fn bind_table_reference(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         table_ref: &TableReference<'a>,
-    ) -> Result<(SExpr, BindContext)> {
+    ) -> Result<(SExpr, BindContext<'a>)> {
         match table_ref {
             TableReference::Table {
                 span: _,
@@ -95,6 +112,17 @@ impl<'a> Binder {
                 alias,
                 travel_point,
             } => {
+                // Check and bind common table expression
+                if catalog.is_none() && database.is_none() {
+                    let table_name = table.name.to_lowercase();
+                    if let Some(cte_info) = bind_context.resolve_cte(&table_name) {
+                        let cte_info = cte_info.clone();
+                        return self
+                            .bind_cte(bind_context, &table_name, alias, &cte_info)
+                            .await;
+                    }
+                }
+
                 // Get catalog name
                 let catalog = catalog
                     .as_ref()
@@ -157,8 +185,23 @@ impl<'a> Binder {
                             source,
                         );
 
-                        let (s_expr, mut bind_context) =
-                            self.bind_base_table(bind_context, database.as_str(), table_index)?;
+                        let statistics = self.collect_statistics(table_index).await?;
+                        let (s_expr, mut bind_context) = self.bind_base_table(
+                            bind_context,
+                            database.as_str(),
+                            table_index,
+                            statistics,
+                        )?;
+                        // The row access policy can refer to the columns which are not
+                        // accessible to current user, so bind it before marking them.
+                        let s_expr = self
+                            .bind_row_access_policy(&bind_context, table_index, s_expr)
+                            .await?;
+                        let (s_expr, masked_columns) = self
+                            .bind_masking_policies(&mut bind_context, table_index, s_expr)
+                            .await?;
+                        self.mark_inaccessible_columns(table_index, &masked_columns)
+                            .await?;
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias)?;
                         }
@@ -213,8 +256,12 @@ impl<'a> Binder {
                     source,
                 );
 
-                let (s_expr, mut bind_context) =
-                    self.bind_base_table(bind_context, "system", table_index)?;
+                let (s_expr, mut bind_context) = self.bind_base_table(
+                    bind_context,
+                    "system",
+                    table_index,
+                    Statistics::default(),
+                )?;
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias)?;
                 }
@@ -235,12 +282,49 @@ impl<'a> Binder {
         }
     }
 
+    async fn bind_cte(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        table_name: &str,
+        alias: &Option<TableAlias<'a>>,
+        cte_info: &CteInfo<'a>,
+    ) -> Result<(SExpr, BindContext<'a>)> {
+        // Bind the CTE query within the context where it's defined, and make
+        // the output columns visible in current context.
+        let (s_expr, cte_context) = self
+            .bind_query(&cte_info.bind_context, &cte_info.query)
+            .await?;
+        let mut new_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        new_context.columns = cte_context.columns;
+
+        if cte_info.columns_alias.len() > new_context.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                new_context.columns.len(),
+                cte_info.columns_alias.len()
+            )));
+        }
+        for column in new_context.columns.iter_mut() {
+            column.database_name = None;
+            column.table_name = Some(table_name.to_string());
+        }
+        for (index, column_name) in cte_info.columns_alias.iter().enumerate() {
+            new_context.columns[index].column_name = column_name.clone();
+        }
+
+        if let Some(alias) = alias {
+            new_context.apply_table_alias(alias)?;
+        }
+        Ok((s_expr, new_context))
+    }
+
     fn bind_base_table(
         &mut self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         database_name: &str,
         table_index: IndexType,
-    ) -> Result<(SExpr, BindContext)> {
+        statistics: Statistics,
+    ) -> Result<(SExpr, BindContext<'a>)> {
         let mut bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
         let metadata = self.metadata.read();
         let columns = metadata.columns_by_table_index(table_index);
@@ -262,6 +346,7 @@ impl<'a> Binder {
                     table_index,
                     columns: columns.into_iter().map(|col| col.column_index).collect(),
                     push_down_predicates: None,
+                    statistics,
                 }
                 .into(),
             ),
@@ -269,6 +354,324 @@ impl<'a> Binder {
         ))
     }
 
+    /// Puts the row access policy of the table as a filter right above the scan, with the
+    /// parameters of the policy bound to the columns it's attached to. As the filter sits
+    /// next to the scan, it can be pushed down into the scan like the other predicates.
+    async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        table_index: IndexType,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let (table_name, policy) = {
+            let metadata = self.metadata.read();
+            let table = metadata.table(table_index);
+            (
+                table.name.clone(),
+                get_row_access_policy(table.table.options()),
+            )
+        };
+        let (policy_name, columns) = match policy {
+            Some(policy) => policy,
+            None => return Ok(s_expr),
+        };
+
+        let policy = self
+            .ctx
+            .get_user_manager()
+            .get_policy::<RowAccessPolicy>(self.ctx.get_tenant().as_str(), &policy_name)
+            .await?;
+        if policy.parameters.len() != columns.len() {
+            return Err(ErrorCode::LogicalError(format!(
+                "Row access policy {} requires {} columns, but table {} provides {}",
+                policy_name,
+                policy.parameters.len(),
+                table_name,
+                columns.len()
+            )));
+        }
+
+        let mut policy_context = BindContext::new();
+        for (parameter, column) in policy.parameters.iter().zip(columns.iter()) {
+            let column_binding = bind_context
+                .columns
+                .iter()
+                .find(|column_binding| &column_binding.column_name == column)
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "Unknown column {} of table {} in row access policy {}",
+                        column, table_name, policy_name
+                    ))
+                })?;
+            policy_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: parameter.to_lowercase(),
+                ..column_binding.clone()
+            });
+        }
+
+        let backtrace = Backtrace::new();
+        let tokens = tokenize_sql(policy.definition.as_str())?;
+        let expr = parse_expr(&tokens, &backtrace)?;
+        let mut scalar_binder =
+            ScalarBinder::new(&policy_context, self.ctx.clone(), self.metadata.clone());
+        let (scalar, data_type) = scalar_binder.bind(&expr).await?;
+        if remove_nullable(&data_type).data_type_id() != TypeID::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy {} must return a boolean, but got: {}",
+                policy_name,
+                data_type.name()
+            )));
+        }
+
+        let filter = Filter {
+            predicates: split_conjunctions(&scalar),
+            is_having: false,
+        };
+        Ok(SExpr::create_unary(filter.into(), s_expr))
+    }
+
+    /// Binds the target table of `DELETE`, `UPDATE` and `MERGE`. The statements modify the
+    /// table directly instead of reading it through the bound plan, so the policies put
+    /// above the scan by `bind_row_access_policy` and `bind_masking_policies` are taken
+    /// out of the plan, for the statements to apply them by themselves.
+    pub(super) async fn bind_dml_target(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        table_reference: &TableReference<'a>,
+    ) -> Result<DmlTarget<'a>> {
+        let (s_expr, bind_context) = self
+            .bind_table_reference(bind_context, table_reference)
+            .await?;
+
+        let mut row_access_predicates = vec![];
+        let mut masked_indexes = vec![];
+        let mut masked_columns = vec![];
+        let mut scan = s_expr.clone();
+        let mut s_expr = &s_expr;
+        loop {
+            match s_expr.plan() {
+                RelOperator::Filter(filter) => {
+                    row_access_predicates.extend(filter.predicates.iter().cloned())
+                }
+                RelOperator::EvalScalar(eval_scalar) => {
+                    masked_indexes.extend(eval_scalar.items.iter().map(|item| item.index))
+                }
+                RelOperator::LogicalGet(get) => {
+                    // The masked columns are named after the original columns.
+                    let metadata = self.metadata.read();
+                    let columns = metadata.columns_by_table_index(get.table_index);
+                    for masked_index in masked_indexes.iter() {
+                        let name = &metadata.column(*masked_index).name;
+                        if let Some(column) = columns.iter().find(|column| &column.name == name) {
+                            masked_columns.push((column.column_index, *masked_index));
+                        }
+                    }
+                    scan = s_expr.clone();
+                    break;
+                }
+                _ => {}
+            }
+            if s_expr.children().is_empty() {
+                break;
+            }
+            s_expr = s_expr.child(0)?;
+        }
+        Ok(DmlTarget {
+            bind_context,
+            row_access_predicates,
+            masked_columns,
+            scan,
+        })
+    }
+
+    /// Replaces the columns which have masking policies with the masked values, by evaluating
+    /// the policies right above the scan and rebinding the column names to the results. So
+    /// the masked values are read wherever the columns are referenced by the query.
+    ///
+    /// Returns the pairs of the original column index and the masked column index.
+    async fn bind_masking_policies(
+        &mut self,
+        bind_context: &mut BindContext<'a>,
+        table_index: IndexType,
+        s_expr: SExpr,
+    ) -> Result<(SExpr, Vec<(IndexType, IndexType)>)> {
+        let (table_name, policies) = {
+            let metadata = self.metadata.read();
+            let table = metadata.table(table_index);
+            (
+                table.name.clone(),
+                get_masking_policies(table.table.options()),
+            )
+        };
+        if policies.is_empty() {
+            return Ok((s_expr, vec![]));
+        }
+
+        let mut items = Vec::with_capacity(policies.len());
+        let mut masked_columns = Vec::with_capacity(policies.len());
+        for (column, policy_name) in policies.iter() {
+            // The column may have been dropped after the policy is set.
+            let position = match bind_context
+                .columns
+                .iter()
+                .position(|column_binding| &column_binding.column_name == column)
+            {
+                Some(position) => position,
+                None => continue,
+            };
+            let column_binding = bind_context.columns[position].clone();
+
+            let policy = self
+                .ctx
+                .get_user_manager()
+                .get_policy::<MaskingPolicy>(self.ctx.get_tenant().as_str(), policy_name)
+                .await?;
+            if policy.parameters.len() != 1 {
+                return Err(ErrorCode::LogicalError(format!(
+                    "Masking policy {} must have exactly one parameter, but got {}",
+                    policy_name,
+                    policy.parameters.len()
+                )));
+            }
+
+            let mut policy_context = BindContext::new();
+            policy_context.add_column_binding(ColumnBinding {
+                database_name: None,
+                table_name: None,
+                column_name: policy.parameters[0].to_lowercase(),
+                ..column_binding.clone()
+            });
+
+            let backtrace = Backtrace::new();
+            let tokens = tokenize_sql(policy.definition.as_str())?;
+            let expr = parse_expr(&tokens, &backtrace)?;
+            let mut scalar_binder =
+                ScalarBinder::new(&policy_context, self.ctx.clone(), self.metadata.clone());
+            let (scalar, data_type) = scalar_binder.bind(&expr).await?;
+            if remove_nullable(&data_type).data_type_id()
+                != remove_nullable(&column_binding.data_type).data_type_id()
+            {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Masking policy {} on column {} of table {} must return {}, but got: {}",
+                    policy_name,
+                    column,
+                    table_name,
+                    column_binding.data_type.name(),
+                    data_type.name()
+                )));
+            }
+
+            let masked_binding = ColumnBinding {
+                visible_in_unqualified_wildcard: column_binding.visible_in_unqualified_wildcard,
+                ..self.create_column_binding(
+                    column_binding.database_name.clone(),
+                    column_binding.table_name.clone(),
+                    column_binding.column_name.clone(),
+                    data_type,
+                )
+            };
+            items.push(ScalarItem {
+                scalar,
+                index: masked_binding.index,
+            });
+            masked_columns.push((column_binding.index, masked_binding.index));
+            bind_context.columns[position] = masked_binding;
+        }
+
+        if items.is_empty() {
+            return Ok((s_expr, masked_columns));
+        }
+        let eval_scalar = EvalScalar { items };
+        Ok((
+            SExpr::create_unary(eval_scalar.into(), s_expr),
+            masked_columns,
+        ))
+    }
+
+    /// Marks the columns of the table which can't be read by current user, it will fail
+    /// when they are referenced by the query, see `Metadata::check_column_access`. The
+    /// masked columns are inaccessible as well if the original columns are.
+    async fn mark_inaccessible_columns(
+        &self,
+        table_index: IndexType,
+        masked_columns: &[(IndexType, IndexType)],
+    ) -> Result<()> {
+        let (catalog, database, table) = {
+            let metadata = self.metadata.read();
+            let table = metadata.table(table_index);
+            (
+                table.catalog.clone(),
+                table.database.clone(),
+                table.name.clone(),
+            )
+        };
+        let accessible_columns = self
+            .ctx
+            .get_current_session()
+            .get_accessible_columns(&catalog, &database, &table)
+            .await?;
+
+        if let Some(accessible_columns) = accessible_columns {
+            let mut metadata = self.metadata.write();
+            for column in metadata.columns_by_table_index(table_index) {
+                if !accessible_columns.contains(&column.name.to_lowercase()) {
+                    metadata.add_inaccessible_column(column.column_index);
+                    for (_, masked_index) in masked_columns
+                        .iter()
+                        .filter(|(index, _)| *index == column.column_index)
+                    {
+                        metadata.add_inaccessible_column(*masked_index);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Collects the statistics of the table, which are used to estimate the
+    /// cardinalities of the plan.
+    async fn collect_statistics(&self, table_index: IndexType) -> Result<Statistics> {
+        let (table, column_indexes) = {
+            let metadata = self.metadata.read();
+            let column_indexes = metadata
+                .columns_by_table_index(table_index)
+                .into_iter()
+                .map(|column| column.column_index)
+                .collect::<Vec<_>>();
+            (metadata.table(table_index).table.clone(), column_indexes)
+        };
+
+        let num_rows = table
+            .statistics(self.ctx.clone())
+            .await?
+            .and_then(|statistics| statistics.num_rows);
+        let mut column_stats = HashMap::new();
+        if let Some(col_stats) = table.column_statistics(self.ctx.clone()).await? {
+            let mut distributions = table
+                .column_distributions(self.ctx.clone())
+                .await?
+                .unwrap_or_default();
+            for (position, stat) in col_stats {
+                if let Some(column_index) = column_indexes.get(position as usize) {
+                    let distribution = distributions.remove(&position);
+                    column_stats.insert(*column_index, ColumnStat {
+                        min: stat.min,
+                        max: stat.max,
+                        null_count: stat.null_count,
+                        distinct_count: distribution.as_ref().map(|d| d.ndv),
+                        histogram: distribution.map(|d| d.histogram),
+                    });
+                }
+            }
+        }
+        Ok(Statistics {
+            num_rows,
+            column_stats,
+        })
+    }
+
     async fn resolve_data_source(
         &self,
         tenant: &str,
@@ -289,7 +692,7 @@ impl<'a> Binder {
 
     async fn resolve_data_travel_point(
         &self,
-        bind_context: &BindContext,
+        bind_context: &BindContext<'a>,
         travel_point: &TimeTravelPoint<'a>,
     ) -> Result<NavigationPoint> {
         match travel_point {
@@ -315,3 +718,32 @@ impl<'a> Binder {
         }
     }
 }
+
+/// The target table of `DELETE`, `UPDATE` and `MERGE`, see `Binder::bind_dml_target`.
+pub(super) struct DmlTarget<'a> {
+    pub bind_context: BindContext<'a>,
+    /// The predicates of the row access policy of the table, only the rows satisfying
+    /// all of them can be modified by the statement.
+    pub row_access_predicates: Vec<Scalar>,
+    /// The pairs of the original column index and the masked column index.
+    pub masked_columns: Vec<(IndexType, IndexType)>,
+    /// The scan of the table, which reads the stored values of all the rows.
+    pub scan: SExpr,
+}
+
+impl DmlTarget<'_> {
+    /// The statements evaluate the expressions over the stored values instead of the
+    /// masked values, so the masked columns can't be referenced by them.
+    pub fn check_masked_columns(&self, metadata: &MetadataRef, scalar: &Scalar) -> Result<()> {
+        let used_columns = scalar.used_columns();
+        for (_, masked_index) in self.masked_columns.iter() {
+            if used_columns.contains(masked_index) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, masked column {} can't be referenced by DML statements",
+                    metadata.read().column(*masked_index).name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use common_ast::ast::TableReference;
+use common_ast::ast::UpdateStmt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::UpdatePlan;
+
+use crate::sessions::TableContext;
+use crate::sql::binder::Binder;
+use crate::sql::binder::ScalarBinder;
+use crate::sql::executor::ExpressionBuilderWithoutRenaming;
+use crate::sql::plans::CastExpr;
+use crate::sql::plans::Plan;
+use crate::sql::BindContext;
+
+impl<'a> Binder {
+    pub(in crate::sql::planner::binder) async fn bind_update(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        stmt: &'a UpdateStmt<'a>,
+    ) -> Result<Plan> {
+        let UpdateStmt {
+            table_reference,
+            update_list,
+            selection,
+        } = stmt;
+
+        let (catalog_name, database_name, table_name) = if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            ..
+        } = table_reference
+        {
+            (
+                catalog
+                    .as_ref()
+                    .map_or_else(|| self.ctx.get_current_catalog(), |i| i.name.clone()),
+                database
+                    .as_ref()
+                    .map_or_else(|| self.ctx.get_current_database(), |i| i.name.clone()),
+                table.name.clone(),
+            )
+        } else {
+            return Err(ErrorCode::LogicalError(
+                "should not happen, parser should have report error already",
+            ));
+        };
+
+        let target = self.bind_dml_target(bind_context, table_reference).await?;
+
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let tbl_info = table.get_table_info();
+        let table_id = tbl_info.ident.clone();
+        let schema = tbl_info.meta.schema.clone();
+
+        let mut scalar_binder = ScalarBinder::new(
+            &target.bind_context,
+            self.ctx.clone(),
+            self.metadata.clone(),
+        );
+        let eb = ExpressionBuilderWithoutRenaming::create(self.metadata.clone());
+
+        let mut columns = HashSet::with_capacity(update_list.len());
+        let mut update_columns = Vec::with_capacity(update_list.len());
+        for update_expr in update_list {
+            let col_name = &update_expr.name.name;
+            let index = schema.index_of(col_name).map_err(|_| {
+                ErrorCode::UnknownColumn(format!("Column [{}] not found", col_name))
+            })?;
+            if !columns.insert(index) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Multiple assignments to the same column {}",
+                    col_name
+                )));
+            }
+
+            // The new value is casted to the type of the column, so that the
+            // updated blocks keep the schema of the table.
+            let target_type = schema.field(index).data_type().clone();
+            let (scalar, data_type) = scalar_binder.bind(&update_expr.expr).await?;
+            target.check_masked_columns(&self.metadata, &scalar)?;
+            let scalar = CastExpr {
+                argument: Box::new(scalar),
+                from_type: Box::new(data_type),
+                target_type: Box::new(target_type),
+            }
+            .into();
+            update_columns.push((index, eb.build(&scalar)?));
+        }
+
+        // The rows hidden by the row access policy can't be updated.
+        let mut predicates = Vec::with_capacity(target.row_access_predicates.len() + 1);
+        if let Some(expr) = selection {
+            let (scalar, _) = scalar_binder.bind(expr).await?;
+            target.check_masked_columns(&self.metadata, &scalar)?;
+            predicates.push(eb.build(&scalar)?);
+        }
+        for predicate in target.row_access_predicates.iter() {
+            predicates.push(eb.build(predicate)?);
+        }
+        let selection = predicates.into_iter().reduce(|left, right| left.and(right));
+
+        let plan = UpdatePlan {
+            catalog_name,
+            database_name,
+            table_name,
+            table_id,
+            selection,
+            update_list: update_columns,
+        };
+        Ok(Plan::Update(Box::new(plan)))
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_ast::parser::token::Token;
+use common_ast::DisplayError;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sql::binder::find_window_functions;
+use crate::sql::binder::select::SelectList;
+use crate::sql::binder::Binder;
+use crate::sql::binder::ColumnBinding;
+use crate::sql::optimizer::SExpr;
+use crate::sql::planner::semantic::GroupingChecker;
+use crate::sql::plans::BoundColumnRef;
+use crate::sql::plans::EvalScalar;
+use crate::sql::plans::Scalar;
+use crate::sql::plans::ScalarExpr;
+use crate::sql::plans::ScalarItem;
+use crate::sql::plans::SortItem;
+use crate::sql::plans::Window;
+use crate::sql::plans::WindowFunction;
+use crate::sql::BindContext;
+use crate::sql::IndexType;
+
+#[derive(Default, Clone, PartialEq, Debug)]
+pub struct WindowInfo {
+    /// Window functions in select list, the output column of each
+    /// function is allocated in `analyze_window_select`.
+    pub window_functions: Vec<ScalarItem>,
+
+    /// Mapping: (window function display name) -> (index of window func in `window_functions`)
+    pub window_functions_map: HashMap<String, usize>,
+}
+
+impl<'a> Binder {
+    /// Collect window functions in select clause and allocate a column for each of them.
+    pub(super) fn analyze_window_select(
+        &mut self,
+        bind_context: &mut BindContext<'a>,
+        select_list: &SelectList<'a>,
+    ) -> Result<()> {
+        for item in select_list.items.iter() {
+            for scalar in find_window_functions(&item.scalar)? {
+                let window = WindowFunction::try_from(scalar)?;
+                let window_info = &mut bind_context.window_info;
+                if window_info
+                    .window_functions_map
+                    .contains_key(&window.display_name)
+                {
+                    continue;
+                }
+
+                let index = self.metadata.write().add_column(
+                    window.display_name.clone(),
+                    *window.return_type.clone(),
+                    None,
+                );
+                window_info.window_functions.push(ScalarItem {
+                    scalar: window.clone().into(),
+                    index,
+                });
+                window_info.window_functions_map.insert(
+                    window.display_name.clone(),
+                    window_info.window_functions.len() - 1,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build a `Window` operator for each window function. Arguments, partition keys
+    /// and order keys of the window functions will be evaluated by an `EvalScalar` first.
+    pub(super) fn bind_window(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        child: SExpr,
+    ) -> Result<SExpr> {
+        let mut scalar_items = vec![];
+        let mut windows = Vec::with_capacity(bind_context.window_info.window_functions.len());
+
+        for item in bind_context.window_info.window_functions.iter() {
+            let window = WindowFunction::try_from(item.scalar.clone())?;
+
+            let mut arguments = Vec::with_capacity(window.args.len());
+            for (i, arg) in window.args.iter().enumerate() {
+                let name = format!("{}_arg_{}", &window.func, i);
+                arguments.push(self.bind_window_input(
+                    bind_context,
+                    arg,
+                    name,
+                    &mut scalar_items,
+                )?);
+            }
+
+            let mut partition_by = Vec::with_capacity(window.partition_by.len());
+            for (i, partition) in window.partition_by.iter().enumerate() {
+                let name = format!("{}_partition_{}", &window.func, i);
+                partition_by.push(self.bind_window_input(
+                    bind_context,
+                    partition,
+                    name,
+                    &mut scalar_items,
+                )?);
+            }
+
+            let mut order_by = Vec::with_capacity(window.order_by.len());
+            for (i, order) in window.order_by.iter().enumerate() {
+                let name = format!("{}_order_{}", &window.func, i);
+                order_by.push(SortItem {
+                    index: self.bind_window_input(
+                        bind_context,
+                        &order.expr,
+                        name,
+                        &mut scalar_items,
+                    )?,
+                    asc: order.asc,
+                    nulls_first: order.nulls_first,
+                });
+            }
+
+            windows.push(Window {
+                index: item.index,
+                function: window.func.clone(),
+                arguments,
+                partition_by,
+                order_by,
+                frame: window.frame,
+                return_type: *window.return_type.clone(),
+            });
+        }
+
+        let mut new_expr = child;
+        if !scalar_items.is_empty() {
+            let eval_scalar = EvalScalar {
+                items: scalar_items,
+            };
+            new_expr = SExpr::create_unary(eval_scalar.into(), new_expr);
+        }
+
+        for window in windows {
+            new_expr = SExpr::create_unary(window.into(), new_expr);
+        }
+
+        Ok(new_expr)
+    }
+
+    /// Return the index of the column that holds value of `scalar`, a new column will
+    /// be evaluated if `scalar` is not a column reference.
+    fn bind_window_input(
+        &mut self,
+        bind_context: &BindContext<'a>,
+        scalar: &Scalar,
+        name: String,
+        scalar_items: &mut Vec<ScalarItem>,
+    ) -> Result<IndexType> {
+        let scalar = if bind_context.in_grouping {
+            let mut grouping_checker = GroupingChecker::new(bind_context);
+            grouping_checker.resolve(scalar, None)?
+        } else {
+            scalar.clone()
+        };
+
+        if let Scalar::BoundColumnRef(BoundColumnRef {
+            column: ColumnBinding { index, .. },
+        }) = &scalar
+        {
+            return Ok(*index);
+        }
+
+        let index = self
+            .metadata
+            .write()
+            .add_column(name, scalar.data_type(), None);
+        scalar_items.push(ScalarItem { scalar, index });
+        Ok(index)
+    }
+}
+
+/// Window functions are evaluated after `WHERE`, `GROUP BY` and `HAVING`,
+/// so they can not be referenced by these clauses.
+pub(super) fn check_no_window_function(
+    scalar: &Scalar,
+    clause: &str,
+    span: &[Token<'_>],
+) -> Result<()> {
+    if !find_window_functions(scalar)?.is_empty() {
+        return Err(ErrorCode::SemanticError(span.display_error(format!(
+            "window functions are not allowed in {}",
+            clause
+        ))));
+    }
+    Ok(())
+}
@@ -53,8 +53,18 @@ impl Plan {
             Plan::DropTableClusterKey(drop_table_cluster_key) => {
                 Ok(format!("{:?}", drop_table_cluster_key))
             }
+            Plan::AddTableColumn(add_table_column) => Ok(format!("{:?}", add_table_column)),
+            Plan::DropTableColumn(drop_table_column) => Ok(format!("{:?}", drop_table_column)),
+            Plan::RenameTableColumn(rename_table_column) => {
+                Ok(format!("{:?}", rename_table_column))
+            }
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
+            Plan::AnalyzeTable(analyze_table) => Ok(format!("{:?}", analyze_table)),
+            Plan::AddTableRowAccessPolicy(add_policy) => Ok(format!("{:?}", add_policy)),
+            Plan::DropTableRowAccessPolicy(drop_policy) => Ok(format!("{:?}", drop_policy)),
+            Plan::SetTableColumnMaskingPolicy(set_policy) => Ok(format!("{:?}", set_policy)),
+            Plan::UnsetTableColumnMaskingPolicy(unset_policy) => Ok(format!("{:?}", unset_policy)),
             Plan::ExistsTable(exists_table) => Ok(format!("{:?}", exists_table)),
 
             // Views
@@ -65,6 +75,13 @@ impl Plan {
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
             Plan::Delete(delete) => Ok(format!("{:?}", delete)),
+            Plan::Update(update) => Ok(format!("{:?}", update)),
+            Plan::Merge(merge) => Ok(format!("{:?}", merge)),
+
+            // Transactions
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Rollback => Ok("Rollback".to_string()),
 
             // Stages
             Plan::ListStage(s) => Ok(format!("{:?}", s)),
@@ -84,6 +101,12 @@ impl Plan {
             Plan::CreateUDF(create_user_udf) => Ok(format!("{:?}", create_user_udf)),
             Plan::AlterUDF(alter_user_udf) => Ok(format!("{alter_user_udf:?}")),
             Plan::DropUDF(drop_udf) => Ok(format!("{drop_udf:?}")),
+            Plan::CreateRowAccessPolicy(create_policy) => Ok(format!("{create_policy:?}")),
+            Plan::DropRowAccessPolicy(drop_policy) => Ok(format!("{drop_policy:?}")),
+            Plan::CreateMaskingPolicy(create_policy) => Ok(format!("{create_policy:?}")),
+            Plan::DropMaskingPolicy(drop_policy) => Ok(format!("{drop_policy:?}")),
+            Plan::CreateNetworkPolicy(create_policy) => Ok(format!("{create_policy:?}")),
+            Plan::DropNetworkPolicy(drop_policy) => Ok(format!("{drop_policy:?}")),
             Plan::AlterUser(alter_user) => Ok(format!("{:?}", alter_user)),
             Plan::CreateRole(create_role) => Ok(format!("{:?}", create_role)),
             Plan::DropRole(drop_role) => Ok(format!("{:?}", drop_role)),
@@ -21,6 +21,7 @@ use itertools::Itertools;
 use super::FormatTreeNode;
 use crate::sql::optimizer::SExpr;
 use crate::sql::plans::Aggregate;
+use crate::sql::plans::AggregateExpand;
 use crate::sql::plans::AggregateMode;
 use crate::sql::plans::AndExpr;
 use crate::sql::plans::ComparisonExpr;
@@ -38,6 +39,8 @@ use crate::sql::plans::Project;
 use crate::sql::plans::RelOperator;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::Sort;
+use crate::sql::plans::Window;
+use crate::sql::IndexType;
 use crate::sql::MetadataRef;
 use crate::sql::ScalarExpr;
 
@@ -75,8 +78,10 @@ impl Display for FormatContext {
             RelOperator::EvalScalar(op) => format_eval_scalar(f, &self.metadata, op),
             RelOperator::Filter(op) => format_filter(f, &self.metadata, op),
             RelOperator::Aggregate(op) => format_aggregate(f, &self.metadata, op),
+            RelOperator::AggregateExpand(op) => format_aggregate_expand(f, &self.metadata, op),
             RelOperator::Sort(op) => format_sort(f, &self.metadata, op),
             RelOperator::Limit(op) => format_limit(f, &self.metadata, op),
+            RelOperator::Window(op) => format_window(f, &self.metadata, op),
             RelOperator::Exchange(op) => format_exchange(f, &self.metadata, op),
             RelOperator::Pattern(_) => write!(f, "Pattern"),
         }
@@ -116,6 +121,7 @@ pub fn format_scalar(metadata: &MetadataRef, scalar: &Scalar) -> String {
             format_scalar(metadata, &comp.right)
         ),
         Scalar::AggregateFunction(agg) => agg.display_name.clone(),
+        Scalar::WindowFunction(window) => window.display_name.clone(),
         Scalar::FunctionCall(func) => {
             format!(
                 "{}({})",
@@ -347,6 +353,66 @@ pub fn format_limit(
     write!(f, "Limit: [{}], Offset: [{}]", limit, op.offset)
 }
 
+pub fn format_aggregate_expand(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &AggregateExpand,
+) -> std::fmt::Result {
+    let column_name =
+        |index: &IndexType| format!("{} (#{})", metadata.read().column(*index).name, index);
+    let grouping_sets = op
+        .grouping_sets
+        .iter()
+        .map(|set| {
+            format!(
+                "({})",
+                set.iter()
+                    .map(|position| column_name(&op.group_items[*position]))
+                    .join(", ")
+            )
+        })
+        .join(", ");
+    write!(
+        f,
+        "AggregateExpand: grouping sets: [{}], grouping id: [{}]",
+        grouping_sets,
+        column_name(&op.grouping_id_index)
+    )
+}
+
+pub fn format_window(
+    f: &mut std::fmt::Formatter<'_>,
+    metadata: &MetadataRef,
+    op: &Window,
+) -> std::fmt::Result {
+    let column_name =
+        |index: &IndexType| format!("{} (#{})", metadata.read().column(*index).name, index);
+    let arguments = op.arguments.iter().map(column_name).join(", ");
+    let partition_by = op.partition_by.iter().map(column_name).join(", ");
+    let order_by = op
+        .order_by
+        .iter()
+        .map(|item| {
+            format!(
+                "{} {}",
+                column_name(&item.index),
+                if item.asc { "ASC" } else { "DESC" }
+            )
+        })
+        .join(", ");
+    write!(
+        f,
+        "Window: {}({}) partition by: [{}], order by: [{}], frame: [{} BETWEEN {} AND {}]",
+        op.function,
+        arguments,
+        partition_by,
+        order_by,
+        op.frame.units,
+        op.frame.start_bound,
+        op.frame.end_bound
+    )
+}
+
 pub fn format_exchange(
     f: &mut std::fmt::Formatter<'_>,
     metadata: &MetadataRef,
@@ -18,6 +18,8 @@ use std::sync::Arc;
 use common_ast::ast::Expr;
 use common_ast::ast::Literal;
 use common_datavalues::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use common_planners::ReadDataSourcePlan;
 use parking_lot::RwLock;
 
@@ -102,6 +104,9 @@ impl ColumnEntry {
 pub struct Metadata {
     tables: Vec<TableEntry>,
     columns: Vec<ColumnEntry>,
+    /// Columns of tables which can't be read by current user, which is
+    /// restricted by column level privileges.
+    inaccessible_columns: ColumnSet,
 }
 
 impl Metadata {
@@ -109,6 +114,7 @@ impl Metadata {
         Self {
             tables: vec![],
             columns: vec![],
+            inaccessible_columns: ColumnSet::new(),
         }
     }
 
@@ -163,6 +169,32 @@ impl Metadata {
         column_index
     }
 
+    pub fn add_inaccessible_column(&mut self, index: IndexType) {
+        self.inaccessible_columns.insert(index);
+    }
+
+    /// Check if the column can be read by current user.
+    pub fn check_column_access(&self, index: IndexType) -> Result<()> {
+        if !self.inaccessible_columns.contains(&index) {
+            return Ok(());
+        }
+        let column = self.column(index);
+        let table = match column.table_index {
+            Some(table_index) => {
+                let table = self.table(table_index);
+                format!(
+                    " of table '{}'.'{}'.'{}'",
+                    table.catalog, table.database, table.name
+                )
+            }
+            None => "".to_string(),
+        };
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied, SELECT privilege on column {}{} is required",
+            column.name, table
+        )))
+    }
+
     pub fn add_table(
         &mut self,
         catalog: String,
@@ -14,6 +14,7 @@
 
 use common_exception::Result;
 
+use crate::sql::optimizer::column_of;
 use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::Distribution;
 use crate::sql::optimizer::PhysicalProperty;
@@ -144,9 +145,34 @@ impl LogicalOperator for Aggregate {
             .cloned()
             .collect();
 
+        // Derive cardinality, which is the number of the groups. It's estimated with the
+        // distinct values of the group by columns, if all of them are known.
+        let cardinality = if self.group_items.is_empty() {
+            1.0
+        } else {
+            self.group_items
+                .iter()
+                .map(|item| {
+                    column_of(&item.scalar)
+                        .and_then(|column| input_prop.column_stats.get(&column))
+                        .and_then(|stat| stat.ndv(input_prop.cardinality))
+                })
+                .product::<Option<f64>>()
+                .map_or(input_prop.cardinality, |groups| {
+                    groups.min(input_prop.cardinality)
+                })
+        };
+        let column_stats = input_prop
+            .column_stats
+            .into_iter()
+            .filter(|(column, _)| output_columns.contains(column))
+            .collect();
+
         Ok(RelationalProperty {
             output_columns,
             outer_columns,
+            cardinality,
+            column_stats,
         })
     }
 }
@@ -0,0 +1,100 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+
+use crate::sql::optimizer::PhysicalProperty;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::RequiredProperty;
+use crate::sql::plans::LogicalOperator;
+use crate::sql::plans::Operator;
+use crate::sql::plans::PhysicalOperator;
+use crate::sql::plans::RelOp;
+use crate::sql::IndexType;
+
+/// Expand each row of the input into one row for each grouping set of
+/// `GROUP BY GROUPING SETS/ROLLUP/CUBE`, the group items which are not in the
+/// grouping set are replaced with NULL. The position of the grouping set is
+/// appended as the grouping id, so the aggregation above can group by the
+/// group items and the grouping id at once.
+#[derive(Clone, Debug)]
+pub struct AggregateExpand {
+    /// Columns of the group items, which are nullable in the output
+    pub group_items: Vec<IndexType>,
+    /// Positions in `group_items` of the items of each grouping set
+    pub grouping_sets: Vec<Vec<usize>>,
+    /// Index of the grouping id column
+    pub grouping_id_index: IndexType,
+}
+
+impl Operator for AggregateExpand {
+    fn rel_op(&self) -> RelOp {
+        RelOp::AggregateExpand
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalOperator> {
+        Some(self)
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalOperator> {
+        Some(self)
+    }
+}
+
+impl PhysicalOperator for AggregateExpand {
+    fn derive_physical_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child<'a>(
+        &self,
+        _rel_expr: &RelExpr<'a>,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        Ok(required.clone())
+    }
+}
+
+impl LogicalOperator for AggregateExpand {
+    fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        let mut output_columns = input_prop.output_columns;
+        output_columns.insert(self.grouping_id_index);
+
+        // The group items are filled with NULLs, so their statistics are dropped
+        let column_stats = input_prop
+            .column_stats
+            .into_iter()
+            .filter(|(column, _)| !self.group_items.contains(column))
+            .collect();
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns,
+            cardinality: input_prop.cardinality * self.grouping_sets.len() as f64,
+            column_stats,
+        })
+    }
+}
@@ -101,6 +101,8 @@ impl LogicalOperator for EvalScalar {
         Ok(RelationalProperty {
             output_columns,
             outer_columns,
+            cardinality: input_prop.cardinality,
+            column_stats: input_prop.column_stats,
         })
     }
 }
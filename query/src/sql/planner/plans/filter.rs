@@ -19,6 +19,7 @@ use crate::sql::optimizer::PhysicalProperty;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
 use crate::sql::optimizer::RequiredProperty;
+use crate::sql::optimizer::SelectivityEstimator;
 use crate::sql::plans::LogicalOperator;
 use crate::sql::plans::Operator;
 use crate::sql::plans::PhysicalOperator;
@@ -87,9 +88,19 @@ impl LogicalOperator for Filter {
         }
         outer_columns = outer_columns.difference(&output_columns).cloned().collect();
 
+        // Derive cardinality, the predicates are assumed to be independent
+        let estimator = SelectivityEstimator::new(input_prop.cardinality, &input_prop.column_stats);
+        let selectivity = self
+            .predicates
+            .iter()
+            .map(|predicate| estimator.compute_selectivity(predicate))
+            .product::<f64>();
+
         Ok(RelationalProperty {
             output_columns,
             outer_columns,
+            cardinality: input_prop.cardinality * selectivity,
+            column_stats: input_prop.column_stats,
         })
     }
 }
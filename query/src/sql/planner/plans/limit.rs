@@ -71,6 +71,10 @@ impl PhysicalOperator for Limit {
 
 impl LogicalOperator for Limit {
     fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
-        rel_expr.derive_relational_prop_child(0)
+        let mut prop = rel_expr.derive_relational_prop_child(0)?;
+        if let Some(limit) = self.limit {
+            prop.cardinality = prop.cardinality.min(limit as f64);
+        }
+        Ok(prop)
     }
 }
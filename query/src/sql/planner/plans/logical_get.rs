@@ -17,6 +17,8 @@ use common_exception::Result;
 use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::Statistics;
+use crate::sql::optimizer::DEFAULT_CARDINALITY;
 use crate::sql::plans::LogicalOperator;
 use crate::sql::plans::Operator;
 use crate::sql::plans::PhysicalOperator;
@@ -30,6 +32,7 @@ pub struct LogicalGet {
     pub columns: ColumnSet,
 
     pub push_down_predicates: Option<Vec<Scalar>>,
+    pub statistics: Statistics,
 }
 
 impl Operator for LogicalGet {
@@ -56,9 +59,15 @@ impl Operator for LogicalGet {
 
 impl LogicalOperator for LogicalGet {
     fn derive_relational_prop<'a>(&self, _rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let cardinality = self
+            .statistics
+            .num_rows
+            .map_or(DEFAULT_CARDINALITY, |num_rows| num_rows as f64);
         Ok(RelationalProperty {
             output_columns: self.columns.clone(),
             outer_columns: Default::default(),
+            cardinality,
+            column_stats: self.statistics.column_stats.clone(),
         })
     }
 }
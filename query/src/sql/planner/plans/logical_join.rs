@@ -18,8 +18,10 @@ use std::fmt::Formatter;
 use common_exception::Result;
 
 use super::ScalarExpr;
+use crate::sql::optimizer::column_of;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::DEFAULT_SELECTIVITY;
 use crate::sql::plans::LogicalOperator;
 use crate::sql::plans::Operator;
 use crate::sql::plans::PhysicalOperator;
@@ -114,6 +116,10 @@ impl LogicalOperator for LogicalInnerJoin {
         let left_prop = rel_expr.derive_relational_prop_child(0)?;
         let right_prop = rel_expr.derive_relational_prop_child(1)?;
 
+        let cardinality = self.derive_cardinality(&left_prop, &right_prop);
+        let mut column_stats = left_prop.column_stats;
+        column_stats.extend(right_prop.column_stats);
+
         // Derive output columns
         let mut output_columns = left_prop.output_columns;
         if let Some(mark_index) = self.marker_index {
@@ -144,6 +150,43 @@ impl LogicalOperator for LogicalInnerJoin {
         Ok(RelationalProperty {
             output_columns,
             outer_columns,
+            cardinality,
+            column_stats,
         })
     }
 }
+
+impl LogicalInnerJoin {
+    fn derive_cardinality(&self, left: &RelationalProperty, right: &RelationalProperty) -> f64 {
+        // Each equi condition keeps 1 / max(ndv(left key), ndv(right key)) of the cross
+        // product. If the distinct values of both keys are unknown, the join is assumed
+        // to be a primary key - foreign key join, which yields as many rows as the larger side.
+        let mut selectivity = 1.0;
+        for (left_key, right_key) in self.left_conditions.iter().zip(&self.right_conditions) {
+            let left_ndv = column_of(left_key)
+                .and_then(|column| left.column_stats.get(&column))
+                .and_then(|stat| stat.ndv(left.cardinality));
+            let right_ndv = column_of(right_key)
+                .and_then(|column| right.column_stats.get(&column))
+                .and_then(|stat| stat.ndv(right.cardinality));
+            let ndv = match (left_ndv, right_ndv) {
+                (Some(left_ndv), Some(right_ndv)) => left_ndv.max(right_ndv),
+                (Some(ndv), None) | (None, Some(ndv)) => ndv,
+                (None, None) => left.cardinality.min(right.cardinality),
+            };
+            selectivity /= ndv.max(1.0);
+        }
+        selectivity *= DEFAULT_SELECTIVITY.powi(self.other_conditions.len() as i32);
+        let inner = left.cardinality * right.cardinality * selectivity;
+
+        match self.join_type {
+            JoinType::Inner | JoinType::Cross => inner,
+            JoinType::Left | JoinType::Single => inner.max(left.cardinality),
+            JoinType::Right => inner.max(right.cardinality),
+            JoinType::Full => inner.max(left.cardinality + right.cardinality),
+            JoinType::Semi | JoinType::Anti => left.cardinality,
+            // The subquery is the left side of a mark join
+            JoinType::Mark => right.cardinality,
+        }
+    }
+}
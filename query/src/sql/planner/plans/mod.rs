@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod aggregate;
+mod aggregate_expand;
 mod copy_v2;
 pub mod create_table_v2;
 mod eval_scalar;
@@ -23,6 +24,7 @@ pub mod insert;
 mod limit;
 mod logical_get;
 mod logical_join;
+mod merge;
 mod operator;
 mod pattern;
 mod physical_scan;
@@ -30,11 +32,13 @@ mod presign;
 mod project;
 mod scalar;
 mod sort;
+mod window;
 
 use std::fmt::Display;
 use std::sync::Arc;
 
 pub use aggregate::*;
+pub use aggregate_expand::AggregateExpand;
 use common_ast::ast::ExplainKind;
 use common_datavalues::DataField;
 use common_datavalues::DataSchema;
@@ -42,13 +46,19 @@ use common_datavalues::DataSchemaRef;
 use common_datavalues::DataSchemaRefExt;
 use common_datavalues::ToDataType;
 use common_datavalues::Vu8;
+use common_planners::AddTableColumnPlan;
+use common_planners::AddTableRowAccessPolicyPlan;
 use common_planners::AlterTableClusterKeyPlan;
 use common_planners::AlterUserPlan;
 use common_planners::AlterUserUDFPlan;
 use common_planners::AlterViewPlan;
+use common_planners::AnalyzeTablePlan;
 use common_planners::CallPlan;
 use common_planners::CreateDatabasePlan;
+use common_planners::CreateMaskingPolicyPlan;
+use common_planners::CreateNetworkPolicyPlan;
 use common_planners::CreateRolePlan;
+use common_planners::CreateRowAccessPolicyPlan;
 use common_planners::CreateUserPlan;
 use common_planners::CreateUserStagePlan;
 use common_planners::CreateUserUDFPlan;
@@ -57,9 +67,14 @@ use common_planners::DeletePlan;
 use common_planners::DescribeTablePlan;
 use common_planners::DescribeUserStagePlan;
 use common_planners::DropDatabasePlan;
+use common_planners::DropMaskingPolicyPlan;
+use common_planners::DropNetworkPolicyPlan;
 use common_planners::DropRolePlan;
+use common_planners::DropRowAccessPolicyPlan;
 use common_planners::DropTableClusterKeyPlan;
+use common_planners::DropTableColumnPlan;
 use common_planners::DropTablePlan;
+use common_planners::DropTableRowAccessPolicyPlan;
 use common_planners::DropUserPlan;
 use common_planners::DropUserStagePlan;
 use common_planners::DropUserUDFPlan;
@@ -72,9 +87,11 @@ use common_planners::ListPlan;
 use common_planners::OptimizeTablePlan;
 use common_planners::RemoveUserStagePlan;
 use common_planners::RenameDatabasePlan;
+use common_planners::RenameTableColumnPlan;
 use common_planners::RenameTablePlan;
 use common_planners::RevokePrivilegePlan;
 use common_planners::RevokeRolePlan;
+use common_planners::SetTableColumnMaskingPolicyPlan;
 use common_planners::SettingPlan;
 use common_planners::ShowCreateDatabasePlan;
 use common_planners::ShowCreateTablePlan;
@@ -82,6 +99,8 @@ use common_planners::ShowGrantsPlan;
 use common_planners::TruncateTablePlan;
 use common_planners::UndropDatabasePlan;
 use common_planners::UndropTablePlan;
+use common_planners::UnsetTableColumnMaskingPolicyPlan;
+use common_planners::UpdatePlan;
 use common_planners::UseDatabasePlan;
 pub use copy_v2::CopyPlanV2;
 pub use copy_v2::ValidationMode;
@@ -98,6 +117,7 @@ pub use limit::Limit;
 pub use logical_get::LogicalGet;
 pub use logical_join::JoinType;
 pub use logical_join::LogicalInnerJoin;
+pub use merge::Merge;
 pub use operator::*;
 pub use pattern::PatternPlan;
 pub use physical_scan::PhysicalScan;
@@ -107,6 +127,8 @@ pub use project::Project;
 pub use scalar::*;
 pub use sort::Sort;
 pub use sort::SortItem;
+pub use window::Window;
+pub use window::WindowFuncType;
 
 use super::BindContext;
 use super::MetadataRef;
@@ -118,7 +140,7 @@ pub enum Plan {
     Query {
         s_expr: SExpr,
         metadata: MetadataRef,
-        bind_context: Box<BindContext>,
+        bind_context: Box<BindContext<'static>>,
         rewrite_kind: Option<RewriteKind>,
     },
 
@@ -150,13 +172,28 @@ pub enum Plan {
     RenameTable(Box<RenameTablePlan>),
     AlterTableClusterKey(Box<AlterTableClusterKeyPlan>),
     DropTableClusterKey(Box<DropTableClusterKeyPlan>),
+    AddTableColumn(Box<AddTableColumnPlan>),
+    DropTableColumn(Box<DropTableColumnPlan>),
+    RenameTableColumn(Box<RenameTableColumnPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
+    SetTableColumnMaskingPolicy(Box<SetTableColumnMaskingPolicyPlan>),
+    UnsetTableColumnMaskingPolicy(Box<UnsetTableColumnMaskingPolicyPlan>),
     TruncateTable(Box<TruncateTablePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
+    AnalyzeTable(Box<AnalyzeTablePlan>),
     ExistsTable(Box<ExistsTablePlan>),
 
     // Insert
     Insert(Box<Insert>),
     Delete(Box<DeletePlan>),
+    Update(Box<UpdatePlan>),
+    Merge(Box<Merge>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Rollback,
 
     // Views
     CreateView(Box<CreateViewPlan>),
@@ -173,6 +210,18 @@ pub enum Plan {
     AlterUDF(Box<AlterUserUDFPlan>),
     DropUDF(Box<DropUserUDFPlan>),
 
+    // Row access policies
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+
+    // Masking policies
+    CreateMaskingPolicy(Box<CreateMaskingPolicyPlan>),
+    DropMaskingPolicy(Box<DropMaskingPolicyPlan>),
+
+    // Network policies
+    CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
+    DropNetworkPolicy(Box<DropNetworkPolicyPlan>),
+
     // Role
     CreateRole(Box<CreateRolePlan>),
     DropRole(Box<DropRolePlan>),
@@ -235,8 +284,16 @@ impl Display for Plan {
             Plan::RenameTable(_) => write!(f, "RenameTable"),
             Plan::AlterTableClusterKey(_) => write!(f, "AlterTableClusterKey"),
             Plan::DropTableClusterKey(_) => write!(f, "DropTableClusterKey"),
+            Plan::AddTableColumn(_) => write!(f, "AddTableColumn"),
+            Plan::DropTableColumn(_) => write!(f, "DropTableColumn"),
+            Plan::RenameTableColumn(_) => write!(f, "RenameTableColumn"),
+            Plan::AddTableRowAccessPolicy(_) => write!(f, "AddTableRowAccessPolicy"),
+            Plan::DropTableRowAccessPolicy(_) => write!(f, "DropTableRowAccessPolicy"),
+            Plan::SetTableColumnMaskingPolicy(_) => write!(f, "SetTableColumnMaskingPolicy"),
+            Plan::UnsetTableColumnMaskingPolicy(_) => write!(f, "UnsetTableColumnMaskingPolicy"),
             Plan::TruncateTable(_) => write!(f, "TruncateTable"),
             Plan::OptimizeTable(_) => write!(f, "OptimizeTable"),
+            Plan::AnalyzeTable(_) => write!(f, "AnalyzeTable"),
             Plan::ExistsTable(_) => write!(f, "ExistsTable"),
             Plan::CreateView(_) => write!(f, "CreateView"),
             Plan::AlterView(_) => write!(f, "AlterView"),
@@ -259,8 +316,19 @@ impl Display for Plan {
             Plan::CreateUDF(_) => write!(f, "CreateUDF"),
             Plan::AlterUDF(_) => write!(f, "AlterUDF"),
             Plan::DropUDF(_) => write!(f, "DropUDF"),
+            Plan::CreateRowAccessPolicy(_) => write!(f, "CreateRowAccessPolicy"),
+            Plan::DropRowAccessPolicy(_) => write!(f, "DropRowAccessPolicy"),
+            Plan::CreateMaskingPolicy(_) => write!(f, "CreateMaskingPolicy"),
+            Plan::DropMaskingPolicy(_) => write!(f, "DropMaskingPolicy"),
+            Plan::CreateNetworkPolicy(_) => write!(f, "CreateNetworkPolicy"),
+            Plan::DropNetworkPolicy(_) => write!(f, "DropNetworkPolicy"),
             Plan::Insert(_) => write!(f, "Insert"),
             Plan::Delete(_) => write!(f, "Delete"),
+            Plan::Update(_) => write!(f, "Update"),
+            Plan::Merge(_) => write!(f, "Merge"),
+            Plan::Begin => write!(f, "Begin"),
+            Plan::Commit => write!(f, "Commit"),
+            Plan::Rollback => write!(f, "Rollback"),
             Plan::Call(_) => write!(f, "Call"),
             Plan::Presign(_) => write!(f, "Presign"),
             Plan::SetVariable(_) => write!(f, "SetVariable"),
@@ -297,8 +365,16 @@ impl Plan {
             Plan::RenameTable(plan) => plan.schema(),
             Plan::AlterTableClusterKey(plan) => plan.schema(),
             Plan::DropTableClusterKey(plan) => plan.schema(),
+            Plan::AddTableColumn(plan) => plan.schema(),
+            Plan::DropTableColumn(plan) => plan.schema(),
+            Plan::RenameTableColumn(plan) => plan.schema(),
+            Plan::AddTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropTableRowAccessPolicy(plan) => plan.schema(),
+            Plan::SetTableColumnMaskingPolicy(plan) => plan.schema(),
+            Plan::UnsetTableColumnMaskingPolicy(plan) => plan.schema(),
             Plan::TruncateTable(plan) => plan.schema(),
             Plan::OptimizeTable(plan) => plan.schema(),
+            Plan::AnalyzeTable(plan) => plan.schema(),
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::CreateView(plan) => plan.schema(),
             Plan::AlterView(plan) => plan.schema(),
@@ -321,8 +397,17 @@ impl Plan {
             Plan::CreateUDF(_) => Arc::new(DataSchema::empty()),
             Plan::AlterUDF(_) => Arc::new(DataSchema::empty()),
             Plan::DropUDF(_) => Arc::new(DataSchema::empty()),
+            Plan::CreateRowAccessPolicy(plan) => plan.schema(),
+            Plan::DropRowAccessPolicy(plan) => plan.schema(),
+            Plan::CreateMaskingPolicy(plan) => plan.schema(),
+            Plan::DropMaskingPolicy(plan) => plan.schema(),
+            Plan::CreateNetworkPolicy(plan) => plan.schema(),
+            Plan::DropNetworkPolicy(plan) => plan.schema(),
             Plan::Insert(plan) => plan.schema(),
             Plan::Delete(_) => Arc::new(DataSchema::empty()),
+            Plan::Update(_) => Arc::new(DataSchema::empty()),
+            Plan::Merge(plan) => plan.schema(),
+            Plan::Begin | Plan::Commit | Plan::Rollback => Arc::new(DataSchema::empty()),
             Plan::Call(_) => Arc::new(DataSchema::empty()),
             Plan::Presign(plan) => plan.schema(),
             Plan::SetVariable(plan) => plan.schema(),
@@ -16,6 +16,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 
 use super::aggregate::Aggregate;
+use super::aggregate_expand::AggregateExpand;
 use super::eval_scalar::EvalScalar;
 use super::filter::Filter;
 use super::hash_join::PhysicalHashJoin;
@@ -26,6 +27,7 @@ use super::pattern::PatternPlan;
 use super::physical_scan::PhysicalScan;
 use super::project::Project;
 use super::sort::Sort;
+use super::window::Window;
 use crate::sql::optimizer::PhysicalProperty;
 use crate::sql::optimizer::RelExpr;
 use crate::sql::optimizer::RelationalProperty;
@@ -79,8 +81,10 @@ pub enum RelOp {
     EvalScalar,
     Filter,
     Aggregate,
+    AggregateExpand,
     Sort,
     Limit,
+    Window,
     Exchange,
 
     // Pattern
@@ -100,8 +104,10 @@ pub enum RelOperator {
     EvalScalar(EvalScalar),
     Filter(Filter),
     Aggregate(Aggregate),
+    AggregateExpand(AggregateExpand),
     Sort(Sort),
     Limit(Limit),
+    Window(Window),
     Exchange(Exchange),
 
     Pattern(PatternPlan),
@@ -118,8 +124,10 @@ impl Operator for RelOperator {
             RelOperator::EvalScalar(rel_op) => rel_op.rel_op(),
             RelOperator::Filter(rel_op) => rel_op.rel_op(),
             RelOperator::Aggregate(rel_op) => rel_op.rel_op(),
+            RelOperator::AggregateExpand(rel_op) => rel_op.rel_op(),
             RelOperator::Sort(rel_op) => rel_op.rel_op(),
             RelOperator::Limit(rel_op) => rel_op.rel_op(),
+            RelOperator::Window(rel_op) => rel_op.rel_op(),
             RelOperator::Pattern(rel_op) => rel_op.rel_op(),
             RelOperator::Exchange(rel_op) => rel_op.rel_op(),
         }
@@ -135,8 +143,10 @@ impl Operator for RelOperator {
             RelOperator::EvalScalar(rel_op) => rel_op.is_physical(),
             RelOperator::Filter(rel_op) => rel_op.is_physical(),
             RelOperator::Aggregate(rel_op) => rel_op.is_physical(),
+            RelOperator::AggregateExpand(rel_op) => rel_op.is_physical(),
             RelOperator::Sort(rel_op) => rel_op.is_physical(),
             RelOperator::Limit(rel_op) => rel_op.is_physical(),
+            RelOperator::Window(rel_op) => rel_op.is_physical(),
             RelOperator::Pattern(rel_op) => rel_op.is_physical(),
             RelOperator::Exchange(rel_op) => rel_op.is_physical(),
         }
@@ -152,8 +162,10 @@ impl Operator for RelOperator {
             RelOperator::EvalScalar(rel_op) => rel_op.is_logical(),
             RelOperator::Filter(rel_op) => rel_op.is_logical(),
             RelOperator::Aggregate(rel_op) => rel_op.is_logical(),
+            RelOperator::AggregateExpand(rel_op) => rel_op.is_logical(),
             RelOperator::Sort(rel_op) => rel_op.is_logical(),
             RelOperator::Limit(rel_op) => rel_op.is_logical(),
+            RelOperator::Window(rel_op) => rel_op.is_logical(),
             RelOperator::Pattern(rel_op) => rel_op.is_logical(),
             RelOperator::Exchange(rel_op) => rel_op.is_logical(),
         }
@@ -169,8 +181,10 @@ impl Operator for RelOperator {
             RelOperator::EvalScalar(rel_op) => rel_op.as_logical(),
             RelOperator::Filter(rel_op) => rel_op.as_logical(),
             RelOperator::Aggregate(rel_op) => rel_op.as_logical(),
+            RelOperator::AggregateExpand(rel_op) => rel_op.as_logical(),
             RelOperator::Sort(rel_op) => rel_op.as_logical(),
             RelOperator::Limit(rel_op) => rel_op.as_logical(),
+            RelOperator::Window(rel_op) => rel_op.as_logical(),
             RelOperator::Pattern(rel_op) => rel_op.as_logical(),
             RelOperator::Exchange(rel_op) => rel_op.as_logical(),
         }
@@ -186,8 +200,10 @@ impl Operator for RelOperator {
             RelOperator::EvalScalar(rel_op) => rel_op.as_physical(),
             RelOperator::Filter(rel_op) => rel_op.as_physical(),
             RelOperator::Aggregate(rel_op) => rel_op.as_physical(),
+            RelOperator::AggregateExpand(rel_op) => rel_op.as_physical(),
             RelOperator::Sort(rel_op) => rel_op.as_physical(),
             RelOperator::Limit(rel_op) => rel_op.as_physical(),
+            RelOperator::Window(rel_op) => rel_op.as_physical(),
             RelOperator::Pattern(rel_op) => rel_op.as_physical(),
             RelOperator::Exchange(rel_op) => rel_op.as_physical(),
         }
@@ -385,6 +401,44 @@ impl TryFrom<RelOperator> for Limit {
     }
 }
 
+impl From<AggregateExpand> for RelOperator {
+    fn from(v: AggregateExpand) -> Self {
+        Self::AggregateExpand(v)
+    }
+}
+
+impl TryFrom<RelOperator> for AggregateExpand {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::AggregateExpand(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::LogicalError(
+                "Cannot downcast RelOperator to AggregateExpand",
+            ))
+        }
+    }
+}
+
+impl From<Window> for RelOperator {
+    fn from(v: Window) -> Self {
+        Self::Window(v)
+    }
+}
+
+impl TryFrom<RelOperator> for Window {
+    type Error = ErrorCode;
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::Window(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::LogicalError(
+                "Cannot downcast RelOperator to Window",
+            ))
+        }
+    }
+}
+
 impl From<PatternPlan> for RelOperator {
     fn from(v: PatternPlan) -> Self {
         Self::Pattern(v)
@@ -72,6 +72,8 @@ impl LogicalOperator for Project {
         Ok(RelationalProperty {
             output_columns: self.columns.clone(),
             outer_columns: input_prop.outer_columns,
+            cardinality: input_prop.cardinality,
+            column_stats: input_prop.column_stats,
         })
     }
 }
@@ -19,10 +19,12 @@ use common_datavalues::DataValue;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::scalars::FunctionFactory;
+use common_functions::window::WindowFrame;
 
 use crate::sql::binder::ColumnBinding;
 use crate::sql::optimizer::ColumnSet;
 use crate::sql::optimizer::SExpr;
+use crate::sql::plans::WindowFuncType;
 use crate::sql::IndexType;
 
 pub trait ScalarExpr {
@@ -49,6 +51,7 @@ pub enum Scalar {
     OrExpr(OrExpr),
     ComparisonExpr(ComparisonExpr),
     AggregateFunction(AggregateFunction),
+    WindowFunction(WindowFunction),
     FunctionCall(FunctionCall),
     // TODO(leiysky): maybe we don't need this variant any more
     // after making functions static typed?
@@ -65,6 +68,7 @@ impl ScalarExpr for Scalar {
             Scalar::OrExpr(scalar) => scalar.data_type(),
             Scalar::ComparisonExpr(scalar) => scalar.data_type(),
             Scalar::AggregateFunction(scalar) => scalar.data_type(),
+            Scalar::WindowFunction(scalar) => scalar.data_type(),
             Scalar::FunctionCall(scalar) => scalar.data_type(),
             Scalar::CastExpr(scalar) => scalar.data_type(),
             Scalar::SubqueryExpr(scalar) => scalar.data_type(),
@@ -79,6 +83,7 @@ impl ScalarExpr for Scalar {
             Scalar::OrExpr(scalar) => scalar.used_columns(),
             Scalar::ComparisonExpr(scalar) => scalar.used_columns(),
             Scalar::AggregateFunction(scalar) => scalar.used_columns(),
+            Scalar::WindowFunction(scalar) => scalar.used_columns(),
             Scalar::FunctionCall(scalar) => scalar.used_columns(),
             Scalar::CastExpr(scalar) => scalar.used_columns(),
             Scalar::SubqueryExpr(scalar) => scalar.used_columns(),
@@ -93,6 +98,7 @@ impl ScalarExpr for Scalar {
             Scalar::OrExpr(scalar) => scalar.is_deterministic(),
             Scalar::ComparisonExpr(scalar) => scalar.is_deterministic(),
             Scalar::AggregateFunction(scalar) => scalar.is_deterministic(),
+            Scalar::WindowFunction(scalar) => scalar.is_deterministic(),
             Scalar::FunctionCall(scalar) => scalar.is_deterministic(),
             Scalar::CastExpr(scalar) => scalar.is_deterministic(),
             Scalar::SubqueryExpr(scalar) => scalar.is_deterministic(),
@@ -210,6 +216,25 @@ impl TryFrom<Scalar> for AggregateFunction {
     }
 }
 
+impl From<WindowFunction> for Scalar {
+    fn from(v: WindowFunction) -> Self {
+        Self::WindowFunction(v)
+    }
+}
+
+impl TryFrom<Scalar> for WindowFunction {
+    type Error = ErrorCode;
+    fn try_from(value: Scalar) -> Result<Self> {
+        if let Scalar::WindowFunction(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::LogicalError(
+                "Cannot downcast Scalar to WindowFunction",
+            ))
+        }
+    }
+}
+
 impl From<FunctionCall> for Scalar {
     fn from(v: FunctionCall) -> Self {
         Self::FunctionCall(v)
@@ -462,6 +487,49 @@ impl ScalarExpr for AggregateFunction {
     }
 }
 
+#[derive(Clone, PartialEq, Debug)]
+pub struct WindowOrderBy {
+    pub expr: Scalar,
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct WindowFunction {
+    pub display_name: String,
+
+    pub func: WindowFuncType,
+    pub args: Vec<Scalar>,
+    pub partition_by: Vec<Scalar>,
+    pub order_by: Vec<WindowOrderBy>,
+    pub frame: WindowFrame,
+    pub return_type: Box<DataTypeImpl>,
+}
+
+impl ScalarExpr for WindowFunction {
+    fn data_type(&self) -> DataTypeImpl {
+        *self.return_type.clone()
+    }
+
+    fn used_columns(&self) -> ColumnSet {
+        let mut result = ColumnSet::new();
+        let order_by = self.order_by.iter().map(|item| &item.expr);
+        for scalar in self
+            .args
+            .iter()
+            .chain(self.partition_by.iter())
+            .chain(order_by)
+        {
+            result = result.union(&scalar.used_columns()).cloned().collect();
+        }
+        result
+    }
+
+    fn is_deterministic(&self) -> bool {
+        false
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct FunctionCall {
     pub arguments: Vec<Scalar>,
@@ -0,0 +1,151 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_datavalues::DataTypeImpl;
+use common_datavalues::DataValue;
+use common_exception::Result;
+use common_functions::window::WindowFrame;
+
+use crate::sql::optimizer::Distribution;
+use crate::sql::optimizer::PhysicalProperty;
+use crate::sql::optimizer::RelExpr;
+use crate::sql::optimizer::RelationalProperty;
+use crate::sql::optimizer::RequiredProperty;
+use crate::sql::plans::LogicalOperator;
+use crate::sql::plans::Operator;
+use crate::sql::plans::PhysicalOperator;
+use crate::sql::plans::RelOp;
+use crate::sql::plans::SortItem;
+use crate::sql::IndexType;
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum WindowFuncType {
+    RowNumber,
+    Rank,
+    DenseRank,
+    /// The value of the row `offset` rows before the current row in the partition
+    Lag {
+        offset: u64,
+    },
+    /// The value of the row `offset` rows after the current row in the partition
+    Lead {
+        offset: u64,
+    },
+    FirstValue,
+    LastValue,
+    /// Aggregate function evaluated over the window frame
+    Aggregate {
+        name: String,
+        params: Vec<DataValue>,
+    },
+}
+
+impl WindowFuncType {
+    /// Returns true if the result of the function depends on the window frame.
+    pub fn use_frame(&self) -> bool {
+        matches!(
+            self,
+            WindowFuncType::FirstValue
+                | WindowFuncType::LastValue
+                | WindowFuncType::Aggregate { .. }
+        )
+    }
+}
+
+impl Display for WindowFuncType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowFuncType::RowNumber => write!(f, "row_number"),
+            WindowFuncType::Rank => write!(f, "rank"),
+            WindowFuncType::DenseRank => write!(f, "dense_rank"),
+            WindowFuncType::Lag { .. } => write!(f, "lag"),
+            WindowFuncType::Lead { .. } => write!(f, "lead"),
+            WindowFuncType::FirstValue => write!(f, "first_value"),
+            WindowFuncType::LastValue => write!(f, "last_value"),
+            WindowFuncType::Aggregate { name, .. } => write!(f, "{}", name),
+        }
+    }
+}
+
+/// Evaluate a window function over the partitions of input, the result is
+/// appended to the input as a new column.
+#[derive(Clone, Debug)]
+pub struct Window {
+    /// Index of the output column
+    pub index: IndexType,
+    pub function: WindowFuncType,
+    pub arguments: Vec<IndexType>,
+    pub partition_by: Vec<IndexType>,
+    pub order_by: Vec<SortItem>,
+    pub frame: WindowFrame,
+    pub return_type: DataTypeImpl,
+}
+
+impl Operator for Window {
+    fn rel_op(&self) -> RelOp {
+        RelOp::Window
+    }
+
+    fn is_physical(&self) -> bool {
+        true
+    }
+
+    fn is_logical(&self) -> bool {
+        true
+    }
+
+    fn as_physical(&self) -> Option<&dyn PhysicalOperator> {
+        Some(self)
+    }
+
+    fn as_logical(&self) -> Option<&dyn LogicalOperator> {
+        Some(self)
+    }
+}
+
+impl PhysicalOperator for Window {
+    fn derive_physical_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<PhysicalProperty> {
+        rel_expr.derive_physical_prop_child(0)
+    }
+
+    fn compute_required_prop_child<'a>(
+        &self,
+        _rel_expr: &RelExpr<'a>,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
+
+impl LogicalOperator for Window {
+    fn derive_relational_prop<'a>(&self, rel_expr: &RelExpr<'a>) -> Result<RelationalProperty> {
+        let input_prop = rel_expr.derive_relational_prop_child(0)?;
+
+        let mut output_columns = input_prop.output_columns;
+        output_columns.insert(self.index);
+
+        Ok(RelationalProperty {
+            output_columns,
+            outer_columns: input_prop.outer_columns,
+            cardinality: input_prop.cardinality,
+            column_stats: input_prop.column_stats,
+        })
+    }
+}
@@ -14,6 +14,10 @@
 
 use common_ast::parser::token::Token;
 use common_ast::DisplayError;
+use common_datavalues::wrap_nullable;
+use common_datavalues::BooleanType;
+use common_datavalues::DataValue;
+use common_datavalues::UInt32Type;
 use common_exception::ErrorCode;
 use common_exception::Result;
 
@@ -22,6 +26,8 @@ use crate::sql::plans::AndExpr;
 use crate::sql::plans::BoundColumnRef;
 use crate::sql::plans::CastExpr;
 use crate::sql::plans::ComparisonExpr;
+use crate::sql::plans::ComparisonOp;
+use crate::sql::plans::ConstantExpr;
 use crate::sql::plans::FunctionCall;
 use crate::sql::plans::OrExpr;
 use crate::sql::plans::Scalar;
@@ -32,11 +38,11 @@ use crate::sql::BindContext;
 /// The matched grouping item will be replaced with a BoundColumnRef
 /// to corresponding grouping item column.
 pub struct GroupingChecker<'a> {
-    bind_context: &'a BindContext,
+    bind_context: &'a BindContext<'a>,
 }
 
 impl<'a> GroupingChecker<'a> {
-    pub fn new(bind_context: &'a BindContext) -> Self {
+    pub fn new(bind_context: &'a BindContext<'a>) -> Self {
         Self { bind_context }
     }
 
@@ -48,12 +54,23 @@ impl<'a> GroupingChecker<'a> {
             .get(&format!("{:?}", scalar))
         {
             let column = &self.bind_context.aggregate_info.group_items[*index];
+            // The group items are filled with NULLs for the grouping sets they are not in
+            let data_type = if self
+                .bind_context
+                .aggregate_info
+                .grouping_id_column
+                .is_some()
+            {
+                wrap_nullable(&column.scalar.data_type())
+            } else {
+                column.scalar.data_type()
+            };
             let column_binding = ColumnBinding {
                 database_name: None,
                 table_name: None,
                 column_name: "group_item".to_string(),
                 index: column.index,
-                data_type: Box::new(column.scalar.data_type()),
+                data_type: Box::new(data_type),
                 visible_in_unqualified_wildcard: true,
             };
             return Ok(BoundColumnRef {
@@ -63,6 +80,7 @@ impl<'a> GroupingChecker<'a> {
         }
 
         match scalar {
+            Scalar::BoundColumnRef(column) if self.is_grouping_id(column) => Ok(scalar.clone()),
             Scalar::BoundColumnRef(column) => {
                 // If this is a group item, then it should have been replaced with `group_items_map`
                 let mut err_msg = format!(
@@ -92,6 +110,9 @@ impl<'a> GroupingChecker<'a> {
                 return_type: scalar.return_type.clone(),
             }
             .into()),
+            Scalar::FunctionCall(func) if func.func_name == "grouping" => {
+                self.resolve_grouping(func, span)
+            }
             Scalar::FunctionCall(func) => {
                 let args = func
                     .arguments
@@ -140,6 +161,134 @@ impl<'a> GroupingChecker<'a> {
                 }
                 Err(ErrorCode::LogicalError("Invalid aggregate function"))
             }
+
+            Scalar::WindowFunction(window) => {
+                if let Some(column) = self
+                    .bind_context
+                    .window_info
+                    .window_functions_map
+                    .get(&window.display_name)
+                {
+                    let window_func = &self.bind_context.window_info.window_functions[*column];
+                    let column_binding = ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_name: window.display_name.clone(),
+                        index: window_func.index,
+                        data_type: window.return_type.clone(),
+                        visible_in_unqualified_wildcard: true,
+                    };
+                    return Ok(BoundColumnRef {
+                        column: column_binding,
+                    }
+                    .into());
+                }
+                Err(ErrorCode::LogicalError("Invalid window function"))
+            }
+        }
+    }
+
+    /// Rewrite `GROUPING(a, b)` into the bits of the arguments for the grouping set of
+    /// current row, the bit of an argument is set if it's not in the grouping set, and
+    /// the bit of the last argument is the least significant bit.
+    fn resolve_grouping(&self, func: &FunctionCall, span: Option<&[Token<'_>]>) -> Result<Scalar> {
+        let agg_info = &self.bind_context.aggregate_info;
+        let positions = func
+            .arguments
+            .iter()
+            .map(|arg| {
+                agg_info
+                    .group_items_map
+                    .get(&format!("{:?}", arg))
+                    .cloned()
+                    .ok_or_else(|| {
+                        let err_msg =
+                            "arguments of GROUPING must be grouping expressions".to_string();
+                        ErrorCode::SemanticError(
+                            span.map_or(err_msg.clone(), |span| span.display_error(err_msg)),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<usize>>>()?;
+        // The result is a UInt32 with a bit for each argument
+        if positions.len() > 32 {
+            let err_msg = format!(
+                "GROUPING can have at most 32 arguments, but got {}",
+                positions.len()
+            );
+            return Err(ErrorCode::SemanticError(
+                span.map_or(err_msg.clone(), |span| span.display_error(err_msg)),
+            ));
+        }
+
+        let grouping_id_column = match &agg_info.grouping_id_column {
+            Some(column) => column,
+            // All the group items are in the only grouping set
+            None => return Ok(Self::grouping_constant(0)),
+        };
+
+        let values = agg_info
+            .grouping_sets
+            .iter()
+            .map(|set| {
+                positions.iter().fold(0u32, |acc, position| {
+                    (acc << 1) | !set.contains(position) as u32
+                })
+            })
+            .collect::<Vec<u32>>();
+        if values.iter().all(|value| *value == values[0]) {
+            return Ok(Self::grouping_constant(values[0]));
+        }
+
+        // multi_if(_grouping_id = 0, v0, _grouping_id = 1, v1, ..., vn)
+        let mut arguments = Vec::with_capacity(values.len() * 2 - 1);
+        let mut arg_types = Vec::with_capacity(values.len() * 2 - 1);
+        for (grouping_id, value) in values.iter().enumerate().take(values.len() - 1) {
+            arguments.push(
+                ComparisonExpr {
+                    op: ComparisonOp::Equal,
+                    left: Box::new(
+                        BoundColumnRef {
+                            column: grouping_id_column.clone(),
+                        }
+                        .into(),
+                    ),
+                    right: Box::new(Self::grouping_constant(grouping_id as u32)),
+                    return_type: Box::new(BooleanType::new_impl()),
+                }
+                .into(),
+            );
+            arg_types.push(BooleanType::new_impl());
+            arguments.push(Self::grouping_constant(*value));
+            arg_types.push(UInt32Type::new_impl());
+        }
+        arguments.push(Self::grouping_constant(values[values.len() - 1]));
+        arg_types.push(UInt32Type::new_impl());
+
+        Ok(FunctionCall {
+            arguments,
+            func_name: "multi_if".to_string(),
+            arg_types,
+            return_type: Box::new(UInt32Type::new_impl()),
+        }
+        .into())
+    }
+
+    fn is_grouping_id(&self, column: &BoundColumnRef) -> bool {
+        self.bind_context
+            .aggregate_info
+            .grouping_id_column
+            .as_ref()
+            .map_or(false, |grouping_id| {
+                grouping_id.index == column.column.index
+            })
+    }
+
+    fn grouping_constant(value: u32) -> Scalar {
+        ConstantExpr {
+            value: DataValue::UInt64(value as u64),
+            data_type: Box::new(UInt32Type::new_impl()),
         }
+        .into()
     }
 }
@@ -14,6 +14,8 @@
 
 mod grouping_check;
 mod type_check;
+mod window_check;
 
 pub use grouping_check::GroupingChecker;
 pub use type_check::TypeChecker;
+pub use window_check::WindowChecker;
@@ -25,12 +25,15 @@ use common_ast::ast::Query;
 use common_ast::ast::SubqueryModifier;
 use common_ast::ast::TrimWhere;
 use common_ast::ast::UnaryOperator;
+use common_ast::ast::WindowSpec;
 use common_ast::parser::parse_expr;
 use common_ast::parser::token::Token;
 use common_ast::parser::tokenize_sql;
 use common_ast::Backtrace;
 use common_ast::DisplayError;
+use common_datavalues::remove_nullable;
 use common_datavalues::type_coercion::merge_types;
+use common_datavalues::wrap_nullable;
 use common_datavalues::ArrayType;
 use common_datavalues::BooleanType;
 use common_datavalues::DataField;
@@ -41,13 +44,19 @@ use common_datavalues::IntervalType;
 use common_datavalues::NullType;
 use common_datavalues::StringType;
 use common_datavalues::TimestampType;
+use common_datavalues::UInt32Type;
+use common_datavalues::UInt64Type;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::is_builtin_function;
+use common_functions::scalars::assert_numeric;
 use common_functions::scalars::CastFunction;
 use common_functions::scalars::FunctionFactory;
 use common_functions::scalars::TupleFunction;
+use common_functions::window::WindowFrame;
+use common_functions::window::WindowFrameBound;
+use common_functions::window::WindowFrameUnits;
 use common_planners::validate_function_arg;
 
 use crate::evaluator::Evaluator;
@@ -70,6 +79,9 @@ use crate::sql::plans::OrExpr;
 use crate::sql::plans::Scalar;
 use crate::sql::plans::SubqueryExpr;
 use crate::sql::plans::SubqueryType;
+use crate::sql::plans::WindowFuncType;
+use crate::sql::plans::WindowFunction;
+use crate::sql::plans::WindowOrderBy;
 use crate::sql::BindContext;
 use crate::sql::ScalarExpr;
 
@@ -83,18 +95,22 @@ use crate::sql::ScalarExpr;
 /// If failed, a `SemanticError` will be raised. This may caused by incompatible
 /// argument types of expressions, or unresolvable columns.
 pub struct TypeChecker<'a> {
-    bind_context: &'a BindContext,
+    bind_context: &'a BindContext<'a>,
     ctx: Arc<QueryContext>,
     metadata: MetadataRef,
 
     // true if current expr is inside an aggregate function.
     // This is used to check if there is nested aggregate function.
     in_aggregate_function: bool,
+
+    // true if current expr is inside a window function.
+    // This is used to check if there is nested window function.
+    in_window_function: bool,
 }
 
 impl<'a> TypeChecker<'a> {
     pub fn new(
-        bind_context: &'a BindContext,
+        bind_context: &'a BindContext<'a>,
         ctx: Arc<QueryContext>,
         metadata: MetadataRef,
     ) -> Self {
@@ -103,6 +119,7 @@ impl<'a> TypeChecker<'a> {
             ctx,
             metadata,
             in_aggregate_function: false,
+            in_window_function: false,
         }
     }
 
@@ -153,6 +170,7 @@ impl<'a> TypeChecker<'a> {
                     table.as_ref().map(|ident| ident.name.as_str()),
                     column,
                 )?;
+                self.metadata.read().check_column_access(column.index)?;
                 let data_type = column.data_type.clone();
 
                 Box::new((BoundColumnRef { column }.into(), *data_type))
@@ -414,6 +432,7 @@ impl<'a> TypeChecker<'a> {
                                 },
                                 args: vec![*operand.clone(), c.clone()],
                                 params: vec![],
+                                window: None,
                             };
                             arguments.push(equal_expr)
                         }
@@ -471,6 +490,26 @@ impl<'a> TypeChecker<'a> {
                 ))
             }
 
+            Expr::FunctionCall {
+                span,
+                distinct,
+                name,
+                args,
+                params,
+                window: Some(window),
+            } => {
+                self.resolve_window_function(
+                    expr,
+                    span,
+                    *distinct,
+                    name.name.as_str(),
+                    args,
+                    params,
+                    window,
+                )
+                .await?
+            }
+
             Expr::FunctionCall {
                 span,
                 distinct,
@@ -480,6 +519,12 @@ impl<'a> TypeChecker<'a> {
                 ..
             } => {
                 let func_name = name.name.as_str();
+                if func_name.eq_ignore_ascii_case("grouping") {
+                    return self.resolve_grouping(span, args).await;
+                }
+                if func_name.eq_ignore_ascii_case("is_role_in_session") {
+                    return self.resolve_is_role_in_session(span, args).await;
+                }
                 if !is_builtin_function(func_name) {
                     return self.resolve_udf(span, func_name, args).await;
                 }
@@ -561,6 +606,14 @@ impl<'a> TypeChecker<'a> {
                 }
             }
 
+            Expr::CountAll {
+                span,
+                window: Some(window),
+            } => {
+                self.resolve_window_function(expr, span, false, "count", &[], &[], window)
+                    .await?
+            }
+
             Expr::CountAll { .. } => {
                 let agg_func = AggregateFunctionFactory::instance().get("count", vec![], vec![])?;
 
@@ -812,6 +865,7 @@ impl<'a> TypeChecker<'a> {
                         },
                         args: vec![expr.clone()],
                         params: vec![],
+                        window: None,
                     };
 
                     args.push(is_not_null_expr);
@@ -896,6 +950,312 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Resolve `GROUPING(expr, ...)`. It's a placeholder which will be rewritten
+    /// into the grouping bits by `GroupingChecker` once the grouping sets are known.
+    #[async_recursion::async_recursion]
+    async fn resolve_grouping(
+        &mut self,
+        span: &[Token<'_>],
+        args: &[Expr<'_>],
+    ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        if self.in_aggregate_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(span.display_error(
+                "aggregate function calls cannot contain GROUPING".to_string(),
+            )));
+        }
+        if args.is_empty() {
+            return Err(ErrorCode::SemanticError(span.display_error(
+                "GROUPING requires at least one argument".to_string(),
+            )));
+        }
+
+        let mut arguments = Vec::with_capacity(args.len());
+        let mut arg_types = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            let box (scalar, data_type) = self.resolve(arg, None).await?;
+            arguments.push(scalar);
+            arg_types.push(data_type);
+        }
+
+        Ok(Box::new((
+            FunctionCall {
+                arguments,
+                func_name: "grouping".to_string(),
+                arg_types,
+                return_type: Box::new(UInt32Type::new_impl()),
+            }
+            .into(),
+            UInt32Type::new_impl(),
+        )))
+    }
+
+    /// Resolve `IS_ROLE_IN_SESSION('role')`, which is evaluated into a constant since the
+    /// roles of current session are known while binding.
+    async fn resolve_is_role_in_session(
+        &mut self,
+        span: &[Token<'_>],
+        args: &[Expr<'_>],
+    ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        let role = match args {
+            [
+                Expr::Literal {
+                    lit: Literal::String(role),
+                    ..
+                },
+            ] => role,
+            _ => {
+                return Err(ErrorCode::SemanticError(span.display_error(
+                    "IS_ROLE_IN_SESSION requires a string literal of the role name".to_string(),
+                )));
+            }
+        };
+
+        let value = self
+            .ctx
+            .get_current_session()
+            .is_role_in_session(role)
+            .await?;
+        Ok(Box::new((
+            ConstantExpr {
+                value: DataValue::Boolean(value),
+                data_type: Box::new(BooleanType::new_impl()),
+            }
+            .into(),
+            BooleanType::new_impl(),
+        )))
+    }
+
+    /// Resolve window function call, e.g. `rank() OVER (PARTITION BY a ORDER BY b)`.
+    #[allow(clippy::too_many_arguments)]
+    #[async_recursion::async_recursion]
+    async fn resolve_window_function(
+        &mut self,
+        expr: &Expr<'_>,
+        span: &[Token<'_>],
+        distinct: bool,
+        func_name: &str,
+        args: &[Expr<'_>],
+        params: &[Literal],
+        window: &WindowSpec<'_>,
+    ) -> Result<Box<(Scalar, DataTypeImpl)>> {
+        if self.in_aggregate_function {
+            // Reset the state
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(span.display_error(
+                "aggregate function calls cannot contain window function calls".to_string(),
+            )));
+        }
+        if self.in_window_function {
+            // Reset the state
+            self.in_window_function = false;
+            return Err(ErrorCode::SemanticError(span.display_error(
+                "window function calls cannot be nested".to_string(),
+            )));
+        }
+
+        self.in_window_function = true;
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            arguments.push(self.resolve(arg, None).await?);
+        }
+        let mut partition_by = Vec::with_capacity(window.partition_by.len());
+        for partition in window.partition_by.iter() {
+            let box (scalar, _) = self.resolve(partition, None).await?;
+            partition_by.push(scalar);
+        }
+        let mut order_by = Vec::with_capacity(window.order_by.len());
+        let mut order_by_types = Vec::with_capacity(window.order_by.len());
+        for order in window.order_by.iter() {
+            let box (scalar, data_type) = self.resolve(&order.expr, None).await?;
+            order_by.push(WindowOrderBy {
+                expr: scalar,
+                asc: order.asc.unwrap_or(true),
+                nulls_first: order.nulls_first.unwrap_or(false),
+            });
+            order_by_types.push(data_type);
+        }
+        self.in_window_function = false;
+
+        let frame = match &window.window_frame {
+            Some(frame) => {
+                let frame = WindowFrame::try_new(
+                    match frame.units {
+                        common_ast::ast::WindowFrameUnits::Rows => WindowFrameUnits::Rows,
+                        common_ast::ast::WindowFrameUnits::Range => WindowFrameUnits::Range,
+                    },
+                    Self::resolve_window_frame_bound(&frame.start_bound),
+                    Self::resolve_window_frame_bound(&frame.end_bound),
+                )
+                .map_err(|e| ErrorCode::SemanticError(span.display_error(e.message())))?;
+
+                let has_offset = [frame.start_bound, frame.end_bound].iter().any(|bound| {
+                    matches!(
+                        bound,
+                        WindowFrameBound::Preceding(Some(_)) | WindowFrameBound::Following(Some(_))
+                    )
+                });
+                if frame.units == WindowFrameUnits::Range
+                    && has_offset
+                    && (order_by_types.len() != 1
+                        || assert_numeric(&remove_nullable(&order_by_types[0])).is_err())
+                {
+                    return Err(ErrorCode::SemanticError(span.display_error(
+                        "RANGE frame with offset requires exactly one numeric ORDER BY expression"
+                            .to_string(),
+                    )));
+                }
+                frame
+            }
+            // The frame is the whole partition if there is no ORDER BY
+            None if order_by.is_empty() => WindowFrame {
+                units: WindowFrameUnits::Range,
+                start_bound: WindowFrameBound::Preceding(None),
+                end_bound: WindowFrameBound::Following(None),
+            },
+            None => WindowFrame::default(),
+        };
+
+        let (func, args, return_type) = match func_name.to_lowercase().as_str() {
+            name @ ("row_number" | "rank" | "dense_rank") => {
+                if !arguments.is_empty() {
+                    return Err(ErrorCode::SemanticError(span.display_error(format!(
+                        "window function {} takes no arguments",
+                        name
+                    ))));
+                }
+                let func = match name {
+                    "row_number" => WindowFuncType::RowNumber,
+                    "rank" => WindowFuncType::Rank,
+                    _ => WindowFuncType::DenseRank,
+                };
+                (func, vec![], UInt64Type::new_impl())
+            }
+            name @ ("lag" | "lead") => {
+                if arguments.is_empty() || arguments.len() > 3 {
+                    return Err(ErrorCode::SemanticError(span.display_error(format!(
+                        "window function {} takes 1 to 3 arguments",
+                        name
+                    ))));
+                }
+                let offset = match arguments.get(1).map(|arg| &arg.0) {
+                    None => 1,
+                    Some(Scalar::ConstantExpr(ConstantExpr {
+                        value: DataValue::UInt64(offset),
+                        ..
+                    })) => *offset,
+                    Some(Scalar::ConstantExpr(ConstantExpr {
+                        value: DataValue::Int64(offset),
+                        ..
+                    })) if *offset >= 0 => *offset as u64,
+                    _ => {
+                        return Err(ErrorCode::SemanticError(span.display_error(format!(
+                            "offset of window function {} must be a non-negative integer constant",
+                            name
+                        ))));
+                    }
+                };
+
+                let box (value, value_type) = arguments[0].clone();
+                // Rows out of the partition take the default value, which is NULL if not specified
+                let return_type = match arguments.get(2) {
+                    Some(box (_, default_type)) => merge_types(&value_type, default_type)?,
+                    None => wrap_nullable(&value_type),
+                };
+                let mut args = vec![wrap_cast_if_needed(value, &return_type)];
+                if let Some(box (default, _)) = arguments.get(2) {
+                    args.push(wrap_cast_if_needed(default.clone(), &return_type));
+                }
+
+                let func = if name == "lag" {
+                    WindowFuncType::Lag { offset }
+                } else {
+                    WindowFuncType::Lead { offset }
+                };
+                (func, args, return_type)
+            }
+            name @ ("first_value" | "last_value") => {
+                if arguments.len() != 1 {
+                    return Err(ErrorCode::SemanticError(span.display_error(format!(
+                        "window function {} takes exactly one argument",
+                        name
+                    ))));
+                }
+                // The frame of a row can be empty, then the result is NULL
+                let box (value, value_type) = arguments[0].clone();
+                let return_type = wrap_nullable(&value_type);
+                let func = if name == "first_value" {
+                    WindowFuncType::FirstValue
+                } else {
+                    WindowFuncType::LastValue
+                };
+                (
+                    func,
+                    vec![wrap_cast_if_needed(value, &return_type)],
+                    return_type,
+                )
+            }
+            _ if AggregateFunctionFactory::instance().check(func_name) => {
+                if distinct {
+                    return Err(ErrorCode::SemanticError(span.display_error(
+                        "DISTINCT is not supported in window function".to_string(),
+                    )));
+                }
+                let params = params
+                    .iter()
+                    .map(|literal| {
+                        self.resolve_literal(literal, None)
+                            .map(|box (value, _)| value)
+                    })
+                    .collect::<Result<Vec<DataValue>>>()?;
+                let data_fields = arguments
+                    .iter()
+                    .map(|box (_, data_type)| DataField::new("", data_type.clone()))
+                    .collect();
+                let agg_func = AggregateFunctionFactory::instance()
+                    .get(func_name, params.clone(), data_fields)
+                    .map_err(|e| ErrorCode::SemanticError(span.display_error(e.message())))?;
+
+                (
+                    WindowFuncType::Aggregate {
+                        name: func_name.to_lowercase(),
+                        params,
+                    },
+                    arguments.into_iter().map(|box (arg, _)| arg).collect(),
+                    agg_func.return_type()?,
+                )
+            }
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    span.display_error(format!("unknown window function {}", func_name)),
+                ));
+            }
+        };
+
+        Ok(Box::new((
+            WindowFunction {
+                display_name: format!("{:#}", expr),
+                func,
+                args,
+                partition_by,
+                order_by,
+                frame,
+                return_type: Box::new(return_type.clone()),
+            }
+            .into(),
+            return_type,
+        )))
+    }
+
+    fn resolve_window_frame_bound(bound: &common_ast::ast::WindowFrameBound) -> WindowFrameBound {
+        match bound {
+            common_ast::ast::WindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
+            common_ast::ast::WindowFrameBound::Preceding(n) => WindowFrameBound::Preceding(*n),
+            common_ast::ast::WindowFrameBound::Following(n) => WindowFrameBound::Following(*n),
+        }
+    }
+
     #[async_recursion::async_recursion]
     pub async fn resolve_scalar_function_call(
         &mut self,
@@ -1377,6 +1737,19 @@ impl<'a> TypeChecker<'a> {
                 }
                 Err(e) => Some(Err(e)),
             },
+            "current_role" => match self.ctx.get_current_session().get_current_role() {
+                Ok(role) => {
+                    let arg = Expr::Literal {
+                        span: &[],
+                        lit: Literal::String(role.unwrap_or_default()),
+                    };
+                    Some(
+                        self.resolve_function(span, "current_role", &[&arg], None)
+                            .await,
+                    )
+                }
+                Err(e) => Some(Err(e)),
+            },
             "connection_id" => {
                 let arg = Expr::Literal {
                     span: &[],
@@ -1764,6 +2137,7 @@ impl<'a> TypeChecker<'a> {
                     name,
                     args,
                     params,
+                    window,
                 } => Ok(Expr::FunctionCall {
                     span,
                     distinct: *distinct,
@@ -1773,6 +2147,7 @@ impl<'a> TypeChecker<'a> {
                         .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                         .collect::<Result<Vec<Expr>>>()?,
                     params: params.clone(),
+                    window: window.clone(),
                 }),
                 Expr::Case {
                     span,
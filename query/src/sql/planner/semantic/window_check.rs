@@ -0,0 +1,113 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use crate::sql::binder::ColumnBinding;
+use crate::sql::plans::AndExpr;
+use crate::sql::plans::BoundColumnRef;
+use crate::sql::plans::CastExpr;
+use crate::sql::plans::ComparisonExpr;
+use crate::sql::plans::FunctionCall;
+use crate::sql::plans::OrExpr;
+use crate::sql::plans::Scalar;
+use crate::sql::BindContext;
+
+/// Replace the window functions in a scalar expression with a BoundColumnRef
+/// to the column produced by corresponding `Window` operator.
+pub struct WindowChecker<'a> {
+    bind_context: &'a BindContext<'a>,
+}
+
+impl<'a> WindowChecker<'a> {
+    pub fn new(bind_context: &'a BindContext<'a>) -> Self {
+        Self { bind_context }
+    }
+
+    pub fn resolve(&mut self, scalar: &Scalar) -> Result<Scalar> {
+        match scalar {
+            Scalar::BoundColumnRef(_)
+            | Scalar::ConstantExpr(_)
+            | Scalar::AggregateFunction(_)
+            | Scalar::SubqueryExpr(_) => Ok(scalar.clone()),
+            Scalar::AndExpr(scalar) => Ok(AndExpr {
+                left: Box::new(self.resolve(&scalar.left)?),
+                right: Box::new(self.resolve(&scalar.right)?),
+                return_type: scalar.return_type.clone(),
+            }
+            .into()),
+            Scalar::OrExpr(scalar) => Ok(OrExpr {
+                left: Box::new(self.resolve(&scalar.left)?),
+                right: Box::new(self.resolve(&scalar.right)?),
+                return_type: scalar.return_type.clone(),
+            }
+            .into()),
+            Scalar::ComparisonExpr(scalar) => Ok(ComparisonExpr {
+                op: scalar.op.clone(),
+                left: Box::new(self.resolve(&scalar.left)?),
+                right: Box::new(self.resolve(&scalar.right)?),
+                return_type: scalar.return_type.clone(),
+            }
+            .into()),
+            Scalar::FunctionCall(func) => {
+                let args = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.resolve(arg))
+                    .collect::<Result<Vec<Scalar>>>()?;
+                Ok(FunctionCall {
+                    arguments: args,
+                    func_name: func.func_name.clone(),
+                    arg_types: func.arg_types.clone(),
+                    return_type: func.return_type.clone(),
+                }
+                .into())
+            }
+            Scalar::CastExpr(cast) => Ok(CastExpr {
+                argument: Box::new(self.resolve(&cast.argument)?),
+                from_type: cast.from_type.clone(),
+                target_type: cast.target_type.clone(),
+            }
+            .into()),
+
+            Scalar::WindowFunction(window) => {
+                if let Some(index) = self
+                    .bind_context
+                    .window_info
+                    .window_functions_map
+                    .get(&window.display_name)
+                {
+                    let window_func = &self.bind_context.window_info.window_functions[*index];
+                    let column_binding = ColumnBinding {
+                        database_name: None,
+                        table_name: None,
+                        column_name: window.display_name.clone(),
+                        index: window_func.index,
+                        data_type: window.return_type.clone(),
+                        visible_in_unqualified_wildcard: true,
+                    };
+                    return Ok(BoundColumnRef {
+                        column: column_binding,
+                    }
+                    .into());
+                }
+                Err(ErrorCode::SemanticError(format!(
+                    "window function {} is only allowed in SELECT list and ORDER BY",
+                    window.display_name
+                )))
+            }
+        }
+    }
+}
@@ -39,7 +39,13 @@ impl SQLCommon {
             | SQLDataType::Text => Ok(Vu8::to_data_type()),
 
             SQLDataType::Float(_) => Ok(f32::to_data_type()),
-            SQLDataType::Decimal(_, _) => Ok(f64::to_data_type()),
+            SQLDataType::Decimal(precision, scale) => {
+                let precision = precision.map_or(DECIMAL_DEFAULT_PRECISION, |v| v as usize);
+                let scale = scale.map_or(DECIMAL_DEFAULT_SCALE, |v| v as usize);
+                Ok(DataTypeImpl::Decimal(DecimalType::try_create(
+                    precision, scale,
+                )?))
+            }
             SQLDataType::Real | SQLDataType::Double => Ok(f64::to_data_type()),
             SQLDataType::Boolean => Ok(bool::to_data_type()),
             SQLDataType::Date => Ok(DateType::new_impl()),
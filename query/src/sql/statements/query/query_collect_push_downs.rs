@@ -15,6 +15,7 @@
 use std::collections::HashSet;
 
 use common_datavalues::DataType;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::Expression;
 use common_planners::Extras;
@@ -68,7 +69,7 @@ impl QueryCollectPushDowns {
     fn collect_push_downs(mut self, ir: &QueryASTIR, schema: &mut JoinedSchema) -> Result<()> {
         for index in 0..schema.get_tables_desc().len() {
             let table_desc = &schema.get_tables_desc()[index];
-            let projection = self.collect_table_require_columns(table_desc);
+            let projection = self.collect_table_require_columns(table_desc)?;
 
             let mut limit = None;
             let mut order_by = vec![];
@@ -91,7 +92,10 @@ impl QueryCollectPushDowns {
         Ok(())
     }
 
-    fn collect_table_require_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
+    fn collect_table_require_columns(
+        &mut self,
+        table_desc: &JoinedTableDesc,
+    ) -> Result<Vec<usize>> {
         let has_exact_total_row_count = if let JoinedTableDesc::Table { table, .. } = table_desc {
             table.has_exact_total_row_count()
         } else {
@@ -106,9 +110,9 @@ impl QueryCollectPushDowns {
                 // - requires no columns
                 // - DO have the exact number of row count
                 // thus, no need to collect the smallest column
-                vec![]
+                Ok(vec![])
             }
-            true => Self::collect_table_smallest_column(table_desc),
+            true => Ok(Self::collect_table_smallest_column(table_desc)),
             false => self.collect_table_projection_columns(table_desc),
         }
     }
@@ -130,7 +134,10 @@ impl QueryCollectPushDowns {
         vec![smallest_index]
     }
 
-    fn collect_table_projection_columns(&mut self, table_desc: &JoinedTableDesc) -> Vec<usize> {
+    fn collect_table_projection_columns(
+        &mut self,
+        table_desc: &JoinedTableDesc,
+    ) -> Result<Vec<usize>> {
         let mut table_require_columns = Vec::new();
         let columns_desc = table_desc.get_columns_desc();
         for (column_index, column_desc) in columns_desc.iter().enumerate() {
@@ -144,11 +151,31 @@ impl QueryCollectPushDowns {
             };
 
             if self.require_columns.remove(&column_name) {
+                Self::check_column_access(table_desc, column_index)?;
                 // Require this column.
                 table_require_columns.push(column_index);
             }
         }
 
-        table_require_columns
+        Ok(table_require_columns)
+    }
+
+    fn check_column_access(table_desc: &JoinedTableDesc, column_index: usize) -> Result<()> {
+        if let JoinedTableDesc::Table {
+            table,
+            columns_desc,
+            inaccessible_columns,
+            ..
+        } = table_desc
+        {
+            if inaccessible_columns.contains(&column_index) {
+                return Err(ErrorCode::PermissionDenied(format!(
+                    "Permission denied, SELECT privilege on column {} of table {} is required",
+                    columns_desc[column_index].short_name,
+                    table.get_table_info().desc
+                )));
+            }
+        }
+        Ok(())
     }
 }
@@ -14,6 +14,7 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
@@ -93,6 +94,19 @@ impl JoinedSchema {
         }
     }
 
+    /// Marks the columns of the table which can't be read by current user, the query fails
+    /// when they are required by it, see `QueryCollectPushDowns`.
+    pub fn set_table_inaccessible_columns(&mut self, table_pos: usize, columns: HashSet<usize>) {
+        let table_desc = &mut self.tables_long_name_columns[table_pos];
+        if let JoinedTableDesc::Table {
+            inaccessible_columns,
+            ..
+        } = table_desc
+        {
+            *inaccessible_columns = columns;
+        }
+    }
+
     pub fn take_tables_desc(self) -> Vec<JoinedTableDesc> {
         self.tables_long_name_columns
     }
@@ -165,6 +179,8 @@ pub enum JoinedTableDesc {
         name_parts: Vec<String>,
         columns_desc: Vec<JoinedColumnDesc>,
         push_downs: Option<Extras>,
+        /// The indexes of the columns which can't be read by current user.
+        inaccessible_columns: HashSet<usize>,
     },
     Subquery {
         state: Box<QueryAnalyzeState>,
@@ -187,6 +203,7 @@ impl JoinedTableDesc {
             columns_desc,
             name_parts: prefix,
             push_downs: None,
+            inaccessible_columns: HashSet::new(),
         }
     }
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_exception::ErrorCode;
@@ -29,6 +30,8 @@ use sqlparser::ast::TableWithJoins;
 use crate::catalogs::CATALOG_DEFAULT;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::sql::get_masking_policies;
+use crate::sql::get_row_access_policy;
 use crate::sql::statements::analyzer_expr::ExpressionAnalyzer;
 use crate::sql::statements::query::query_schema_joined::JoinedSchema;
 use crate::sql::statements::resolve_table;
@@ -113,6 +116,22 @@ impl JoinedSchemaAnalyzer {
         }
         let tbl_info = read_table.get_table_info();
 
+        // The row access policies and masking policies are only applied by the new
+        // planner, never read the table without them.
+        if let Some((policy, _)) = get_row_access_policy(tbl_info.options()) {
+            return Err(ErrorCode::UnImplement(format!(
+                "Table {} has row access policy {}, which is only supported by the new planner, please set enable_planner_v2 = 1",
+                tbl_info.name, policy
+            )));
+        }
+        if let Some((column, policy)) = get_masking_policies(tbl_info.options()).into_iter().next()
+        {
+            return Err(ErrorCode::UnImplement(format!(
+                "Column {} of table {} has masking policy {}, which is only supported by the new planner, please set enable_planner_v2 = 1",
+                column, tbl_info.name, policy
+            )));
+        }
+
         if tbl_info.engine() == VIEW_ENGINE {
             if let Some(query) = tbl_info.options().get(QUERY) {
                 let (statements, _) =
@@ -132,16 +151,37 @@ impl JoinedSchemaAnalyzer {
                 "Logical error, subquery analyzed data must be SelectQuery, it's a bug.",
             ))
         } else {
-            match &item.alias {
+            // The columns which can't be read are only checked when they are required by
+            // the query, like the new planner does.
+            let accessible_columns = self
+                .ctx
+                .get_current_session()
+                .get_accessible_columns(&catalog, &database, &table)
+                .await?;
+            let inaccessible_columns = match accessible_columns {
+                Some(accessible_columns) => read_table
+                    .schema()
+                    .fields()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !accessible_columns.contains(&f.name().to_lowercase()))
+                    .map(|(index, _)| index)
+                    .collect(),
+                None => HashSet::new(),
+            };
+
+            let mut joined_schema = match &item.alias {
                 None => {
                     let name_prefix = vec![catalog, database, table];
-                    JoinedSchema::from_table(read_table, name_prefix)
+                    JoinedSchema::from_table(read_table, name_prefix)?
                 }
                 Some(table_alias) => {
                     let name_prefix = vec![table_alias.name.value.clone()];
-                    JoinedSchema::from_table(read_table, name_prefix)
+                    JoinedSchema::from_table(read_table, name_prefix)?
                 }
-            }
+            };
+            joined_schema.set_table_inaccessible_columns(0, inaccessible_columns);
+            Ok(joined_schema)
         }
     }
 
@@ -12,12 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
+use chrono::Duration;
+use common_exception::ErrorCode;
+use common_exception::Result;
 use once_cell::sync::Lazy;
 
 pub const OPT_KEY_DATABASE_ID: &str = "database_id";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
+/// The user who created the table, used to account the storage quota of users
+pub const OPT_KEY_CREATED_BY: &str = "created_by";
+/// The period to keep the historical data of the table, e.g. `24h`.
+/// Snapshots older than the period will be purged by GC, and can no longer be time traveled.
+pub const OPT_KEY_DATA_RETENTION_PERIOD: &str = "data_retention_period";
+/// The row access policy of the table, which is attached by `ALTER TABLE ... ADD ROW ACCESS POLICY`.
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
+/// The comma separated columns bound to the parameters of the row access policy, in order.
+pub const OPT_KEY_ROW_ACCESS_POLICY_COLUMNS: &str = "row_access_policy_columns";
+/// The prefix of the keys of masking policies, which is followed by the column name, e.g.
+/// `masking_policy.email`. They are set by `ALTER TABLE ... MODIFY COLUMN ... SET MASKING POLICY`.
+pub const OPT_KEY_MASKING_POLICY_PREFIX: &str = "masking_policy.";
 
 /// Legacy table snapshot location key
 ///
@@ -36,6 +52,9 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_CREATED_BY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
     r
 });
 
@@ -44,13 +63,76 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_CREATED_BY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS);
     r
 });
 
 pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
-    RESERVED_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
+    let opt_key = opt_key.as_ref().to_lowercase();
+    RESERVED_TABLE_OPTION_KEYS.contains(opt_key.as_str())
+        || opt_key.starts_with(OPT_KEY_MASKING_POLICY_PREFIX)
 }
 
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
-    INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
+    let opt_key = opt_key.as_ref().to_lowercase();
+    INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_str())
+        || opt_key.starts_with(OPT_KEY_MASKING_POLICY_PREFIX)
+}
+
+/// Returns the row access policy attached to the table, and the columns bound to its parameters.
+pub fn get_row_access_policy(options: &BTreeMap<String, String>) -> Option<(String, Vec<String>)> {
+    let policy = options.get(OPT_KEY_ROW_ACCESS_POLICY)?;
+    let columns = options
+        .get(OPT_KEY_ROW_ACCESS_POLICY_COLUMNS)
+        .map(|columns| columns.split(',').map(|c| c.to_string()).collect())
+        .unwrap_or_default();
+    Some((policy.clone(), columns))
+}
+
+/// Returns the option key of the masking policy set on the column.
+pub fn masking_policy_opt_key(column: &str) -> String {
+    format!("{}{}", OPT_KEY_MASKING_POLICY_PREFIX, column)
+}
+
+/// Returns the masking policies set on the columns of the table, keyed by the column names.
+pub fn get_masking_policies(options: &BTreeMap<String, String>) -> BTreeMap<String, String> {
+    options
+        .iter()
+        .filter_map(|(key, policy)| {
+            key.strip_prefix(OPT_KEY_MASKING_POLICY_PREFIX)
+                .map(|column| (column.to_string(), policy.clone()))
+        })
+        .collect()
+}
+
+/// Parse the value of `data_retention_period`, which is a positive integer with an optional
+/// unit of `s`(seconds, default), `m`(minutes), `h`(hours) or `d`(days), e.g. `90m`, `24h`.
+pub fn parse_data_retention_period(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => value.split_at(index),
+        None => (value, "s"),
+    };
+
+    let invalid = || {
+        ErrorCode::BadOption(format!(
+            "Invalid {}: '{}', expect a positive integer with an optional unit of s/m/h/d, e.g. 24h",
+            OPT_KEY_DATA_RETENTION_PERIOD, value
+        ))
+    };
+
+    let number = number.parse::<i64>().map_err(|_| invalid())?;
+    if number <= 0 {
+        return Err(invalid());
+    }
+
+    match unit.to_lowercase().as_str() {
+        "s" => Ok(Duration::seconds(number)),
+        "m" => Ok(Duration::minutes(number)),
+        "h" => Ok(Duration::hours(number)),
+        "d" => Ok(Duration::days(number)),
+        _ => Err(invalid()),
+    }
 }
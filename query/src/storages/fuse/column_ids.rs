@@ -0,0 +1,183 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_datavalues::DataType;
+use common_datavalues::DataValue;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::ColumnId;
+use common_fuse_meta::meta::ColumnMeta;
+
+use crate::storages::fuse::fuse_part::build_column_leaves;
+
+/// Key of the schema metadata, in which the column ids of an altered table are kept.
+pub const FUSE_COLUMN_IDS_KEY: &str = "fuse.column_ids";
+
+/// The ids of the columns of a fuse table.
+///
+/// The column statistics of a block are keyed by the ids of the columns, and the column
+/// metas are keyed by the ids of the leaf columns. For a table that has never been altered,
+/// the ids are just the positions of the (leaf) columns; once a column is added, dropped or
+/// renamed, the ids are kept in the metadata of the table schema.
+///
+/// Ids are never reused, so the blocks written before an ALTER TABLE are still readable
+/// without being rewritten: the columns they do not contain are filled with the default
+/// values, and the columns that have been dropped are ignored.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct ColumnIds {
+    column_ids: Vec<ColumnId>,
+    leaf_ids: Vec<Vec<ColumnId>>,
+    next_column_id: ColumnId,
+    next_leaf_id: ColumnId,
+    // Values of the added columns, for the blocks written before the columns were added.
+    default_values: BTreeMap<ColumnId, DataValue>,
+    // Names of the dropped or renamed columns, the bloom filter indexes of the existing
+    // blocks are no longer reliable for the columns taking these names.
+    unindexed_names: BTreeSet<String>,
+}
+
+impl ColumnIds {
+    pub fn from_schema(schema: &DataSchema) -> Result<ColumnIds> {
+        if let Some(column_ids) = schema.meta().get(FUSE_COLUMN_IDS_KEY) {
+            return serde_json::from_str(column_ids).map_err(|e| {
+                ErrorCode::LogicalError(format!("Invalid column ids of fuse table: {}", e))
+            });
+        }
+
+        let mut next_leaf_id = 0;
+        let mut leaf_ids = Vec::with_capacity(schema.num_fields());
+        for leaf_count in Self::leaf_counts(schema)? {
+            let end = next_leaf_id + leaf_count as ColumnId;
+            leaf_ids.push((next_leaf_id..end).collect());
+            next_leaf_id = end;
+        }
+
+        Ok(ColumnIds {
+            column_ids: (0..schema.num_fields() as ColumnId).collect(),
+            leaf_ids,
+            next_column_id: schema.num_fields() as ColumnId,
+            next_leaf_id,
+            default_values: BTreeMap::new(),
+            unindexed_names: BTreeSet::new(),
+        })
+    }
+
+    /// Returns true if the table has been altered, i.e. the ids are kept in the schema.
+    pub fn is_altered(schema: &DataSchema) -> bool {
+        schema.meta().contains_key(FUSE_COLUMN_IDS_KEY)
+    }
+
+    /// Returns a schema of the given fields, which keeps the ids in its metadata.
+    pub fn to_schema(&self, schema: &DataSchema, fields: Vec<DataField>) -> Result<DataSchema> {
+        let mut metadata = schema.meta().clone();
+        metadata.insert(FUSE_COLUMN_IDS_KEY.to_owned(), serde_json::to_string(self)?);
+        Ok(DataSchema::new_from(fields, metadata))
+    }
+
+    pub fn add_column(&mut self, field: &DataField, default_value: DataValue) -> Result<()> {
+        let leaf_count = Self::leaf_counts(&DataSchema::new(vec![field.clone()]))?[0];
+        let column_id = self.next_column_id;
+        let end = self.next_leaf_id + leaf_count as ColumnId;
+
+        self.column_ids.push(column_id);
+        self.leaf_ids.push((self.next_leaf_id..end).collect());
+        self.default_values.insert(column_id, default_value);
+        self.next_column_id += 1;
+        self.next_leaf_id = end;
+        Ok(())
+    }
+
+    pub fn drop_column(&mut self, index: usize, name: &str) {
+        let column_id = self.column_ids.remove(index);
+        self.leaf_ids.remove(index);
+        self.default_values.remove(&column_id);
+        self.unindexed_names.insert(name.to_owned());
+    }
+
+    pub fn rename_column(&mut self, old_name: &str, new_name: &str) {
+        self.unindexed_names.insert(old_name.to_owned());
+        self.unindexed_names.insert(new_name.to_owned());
+    }
+
+    /// Returns the ids of the leaf columns, indexed by the positions of the leaves.
+    pub fn leaf_ids(&self) -> Vec<ColumnId> {
+        self.leaf_ids.iter().flatten().copied().collect()
+    }
+
+    /// Returns the value of the column at `index` for the blocks that do not contain it.
+    pub fn default_value(&self, index: usize, field: &DataField) -> DataValue {
+        match self.default_values.get(&self.column_ids[index]) {
+            Some(value) => value.clone(),
+            None => field.data_type().default_value(),
+        }
+    }
+
+    /// Returns false if the bloom filter indexes of the existing blocks can't be used to
+    /// prune the column with the name.
+    pub fn is_indexed(&self, name: &str) -> bool {
+        !self.unindexed_names.contains(name)
+    }
+
+    /// Re-keys the column statistics by the ids of the columns, instead of the positions.
+    pub fn statistics_by_id<T>(&self, statistics: HashMap<ColumnId, T>) -> HashMap<ColumnId, T> {
+        statistics
+            .into_iter()
+            .map(|(index, stats)| (self.column_ids[index as usize], stats))
+            .collect()
+    }
+
+    /// Re-keys the column statistics by the positions of the columns, instead of the ids.
+    /// The columns not covered by the statistics are left out.
+    pub fn statistics_by_index<T: Clone>(
+        &self,
+        statistics: &HashMap<ColumnId, T>,
+    ) -> HashMap<ColumnId, T> {
+        self.column_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(index, id)| {
+                statistics
+                    .get(id)
+                    .map(|stats| (index as ColumnId, stats.clone()))
+            })
+            .collect()
+    }
+
+    /// Re-keys the column metas by the ids of the leaf columns, instead of the positions.
+    pub fn metas_by_id(
+        &self,
+        metas: HashMap<ColumnId, ColumnMeta>,
+    ) -> HashMap<ColumnId, ColumnMeta> {
+        let leaf_ids = self.leaf_ids();
+        metas
+            .into_iter()
+            .map(|(index, meta)| (leaf_ids[index as usize], meta))
+            .collect()
+    }
+
+    fn leaf_counts(schema: &DataSchema) -> Result<Vec<usize>> {
+        let parquet_schema_descriptor = to_parquet_schema(&schema.to_arrow())?;
+        Ok(build_column_leaves(&parquet_schema_descriptor)
+            .iter()
+            .map(|column_leaf| column_leaf.leaf_ids.len())
+            .collect())
+    }
+}
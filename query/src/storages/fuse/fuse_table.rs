@@ -16,38 +16,52 @@ use std::any::Any;
 use std::convert::TryFrom;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use common_cache::Cache;
 use common_datablocks::DataBlock;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::ClusterKey;
+use common_fuse_meta::meta::DistributionsOfColumns;
 use common_fuse_meta::meta::Statistics as FuseStatistics;
+use common_fuse_meta::meta::StatisticsOfColumns;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
+use common_planners::AddTableColumnPlan;
+use common_planners::AnalyzeTablePlan;
 use common_planners::DeletePlan;
+use common_planners::DropTableColumnPlan;
 use common_planners::Expression;
 use common_planners::Extras;
+use common_planners::MergePlan;
 use common_planners::OptimizeTablePlan;
 use common_planners::Partitions;
 use common_planners::ReadDataSourcePlan;
+use common_planners::RenameTableColumnPlan;
 use common_planners::Statistics;
 use common_planners::TruncateTablePlan;
+use common_planners::UpdatePlan;
+use common_streams::SendableDataBlockStream;
 use uuid::Uuid;
 
 use crate::pipelines::Pipeline;
 use crate::sessions::TableContext;
+use crate::sql::parse_data_retention_period;
 use crate::sql::PlanParser;
 use crate::sql::OPT_KEY_DATABASE_ID;
+use crate::sql::OPT_KEY_DATA_RETENTION_PERIOD;
 use crate::sql::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use crate::sql::OPT_KEY_SNAPSHOT_LOCATION;
 use crate::storages::fuse::io::write_meta;
 use crate::storages::fuse::io::MetaReaders;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::NavigationPoint;
 use crate::storages::StorageContext;
 use crate::storages::StorageDescription;
@@ -188,16 +202,20 @@ impl FuseTable {
         // remove legacy options
         meta.options.remove(OPT_KEY_LEGACY_SNAPSHOT_LOC);
 
-        let table_id = self.table_info.ident.table_id;
-        let table_version = self.table_info.ident.seq;
-        let req = UpdateTableMetaReq {
-            table_id,
-            seq: MatchSeq::Exact(table_version),
-            new_table_meta: meta.clone(),
+        let result = if Self::buffer_in_txn(ctx, catalog_name, &self.table_info, meta)? {
+            Ok(())
+        } else {
+            let table_id = self.table_info.ident.table_id;
+            let table_version = self.table_info.ident.seq;
+            let req = UpdateTableMetaReq {
+                table_id,
+                seq: MatchSeq::Exact(table_version),
+                new_table_meta: meta.clone(),
+            };
+
+            let catalog = ctx.get_catalog(catalog_name)?;
+            catalog.update_table_meta(req).await.map(|_| ())
         };
-
-        let catalog = ctx.get_catalog(catalog_name)?;
-        let result = catalog.update_table_meta(req).await;
         match result {
             Ok(_) => {
                 if let Some(snapshot_cache) =
@@ -220,6 +238,20 @@ impl FuseTable {
     pub fn transient(&self) -> bool {
         self.table_info.meta.options.contains_key("TRANSIENT")
     }
+
+    /// Returns the earliest time point of the historical data that should be kept,
+    /// None if the table option `data_retention_period` is not specified.
+    pub fn data_retention_point(&self) -> Result<Option<DateTime<Utc>>> {
+        match self
+            .table_info
+            .meta
+            .options
+            .get(OPT_KEY_DATA_RETENTION_PERIOD)
+        {
+            None => Ok(None),
+            Some(period) => Ok(Some(Utc::now() - parse_data_retention_period(period)?)),
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -263,13 +295,17 @@ impl Table for FuseTable {
         let prev_version = self.snapshot_format_version();
         let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
+        let (summary, segments, table_statistics_location) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.table_statistics_location.clone(),
+            )
         } else {
-            (FuseStatistics::default(), vec![])
+            (FuseStatistics::default(), vec![], None)
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -278,6 +314,7 @@ impl Table for FuseTable {
             segments,
             cluster_key_meta,
         );
+        new_snapshot.table_statistics_location = table_statistics_location;
 
         self.update_table_meta(
             ctx.as_ref(),
@@ -307,13 +344,17 @@ impl Table for FuseTable {
         let prev_version = self.snapshot_format_version();
         let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
         let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
+        let (summary, segments, table_statistics_location) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.table_statistics_location.clone(),
+            )
         } else {
-            (FuseStatistics::default(), vec![])
+            (FuseStatistics::default(), vec![], None)
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_timestamp,
             prev_snapshot_id,
@@ -322,6 +363,7 @@ impl Table for FuseTable {
             segments,
             None,
         );
+        new_snapshot.table_statistics_location = table_statistics_location;
 
         self.update_table_meta(
             ctx.as_ref(),
@@ -391,6 +433,21 @@ impl Table for FuseTable {
         self.do_gc(&ctx, keep_last_snapshot).await
     }
 
+    async fn txn_committed(&self, ctx: Arc<dyn TableContext>) -> Result<()> {
+        if self.transient() {
+            self.purge_transient_history(&ctx).await;
+        }
+        Ok(())
+    }
+
+    async fn purge_txn_writes(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        written_locations: &[String],
+    ) -> Result<()> {
+        self.do_purge_txn_writes(&ctx, written_locations).await
+    }
+
     async fn statistics(&self, _ctx: Arc<dyn TableContext>) -> Result<Option<TableStatistics>> {
         let s = &self.table_info.meta.statistics;
         Ok(Some(TableStatistics {
@@ -401,6 +458,39 @@ impl Table for FuseTable {
         }))
     }
 
+    async fn column_statistics(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<StatisticsOfColumns>> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => return Ok(None),
+        };
+        let column_ids = ColumnIds::from_schema(&self.table_info.schema())?;
+        Ok(Some(
+            column_ids.statistics_by_index(&snapshot.summary.col_stats),
+        ))
+    }
+
+    async fn column_distributions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<Option<DistributionsOfColumns>> {
+        let location = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => match &snapshot.table_statistics_location {
+                Some(location) => location.clone(),
+                None => return Ok(None),
+            },
+            None => return Ok(None),
+        };
+        let statistics =
+            MetaReaders::read_table_snapshot_statistics(ctx.as_ref(), &location).await?;
+        let column_ids = ColumnIds::from_schema(&self.table_info.schema())?;
+        Ok(Some(
+            column_ids.statistics_by_index(&statistics.column_distributions),
+        ))
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_navigate_to", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn navigate_to(
         &self,
@@ -422,8 +512,48 @@ impl Table for FuseTable {
         self.do_delete(ctx, &delete_plan).await
     }
 
+    #[tracing::instrument(level = "debug", name = "fuse_table_update", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn update(&self, ctx: Arc<dyn TableContext>, update_plan: UpdatePlan) -> Result<()> {
+        self.do_update(ctx, &update_plan).await
+    }
+
+    #[tracing::instrument(level = "debug", name = "fuse_table_merge", skip(self, ctx, joined), fields(ctx.id = ctx.get_id().as_str()))]
+    async fn merge(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        merge_plan: MergePlan,
+        joined: SendableDataBlockStream,
+    ) -> Result<()> {
+        self.do_merge(ctx, &merge_plan, joined).await
+    }
+
     #[tracing::instrument(level = "debug", name = "fuse_table_compact", skip(self, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     async fn compact(&self, ctx: Arc<dyn TableContext>, plan: OptimizeTablePlan) -> Result<()> {
         self.do_compact(ctx, &plan).await
     }
+
+    async fn analyze(&self, ctx: Arc<dyn TableContext>, plan: AnalyzeTablePlan) -> Result<()> {
+        self.check_mutable()?;
+        self.do_analyze(&ctx, &plan).await
+    }
+
+    async fn add_column(&self, ctx: Arc<dyn TableContext>, plan: AddTableColumnPlan) -> Result<()> {
+        self.do_add_column(ctx, &plan).await
+    }
+
+    async fn drop_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: DropTableColumnPlan,
+    ) -> Result<()> {
+        self.do_drop_column(ctx, &plan).await
+    }
+
+    async fn rename_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: RenameTableColumnPlan,
+    ) -> Result<()> {
+        self.do_rename_column(ctx, &plan).await
+    }
 }
@@ -20,12 +20,14 @@ use common_fuse_meta::meta::BlockBloomFilterIndex;
 use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::SegmentInfo;
 use common_fuse_meta::meta::SnapshotVersion;
+use common_fuse_meta::meta::TableSnapshotStatistics;
 use common_fuse_meta::meta::Versioned;
 use uuid::Uuid;
 
 use crate::storages::fuse::constants::FUSE_TBL_BLOCK_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SEGMENT_PREFIX;
 use crate::storages::fuse::constants::FUSE_TBL_SNAPSHOT_PREFIX;
+use crate::storages::fuse::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::storages::fuse::FUSE_TBL_BLOCK_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -86,6 +88,20 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_snapshot_statistics_location(&self) -> Location {
+        let statistics_uuid = Uuid::new_v4().simple().to_string();
+        (
+            format!(
+                "{}/{}/{}_v{}.json",
+                &self.prefix,
+                FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX,
+                statistics_uuid,
+                TableSnapshotStatistics::VERSION,
+            ),
+            TableSnapshotStatistics::VERSION,
+        )
+    }
+
     pub fn snapshot_location_from_uuid(&self, id: &Uuid, version: u64) -> Result<String> {
         let snapshot_version = SnapshotVersion::try_from(version)?;
         Ok(snapshot_version.create(id, &self.prefix))
@@ -31,7 +31,10 @@ use common_datablocks::DataBlock;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_fuse_meta::caches::ColumnDataCache;
+use common_fuse_meta::caches::ColumnDataDiskCache;
 use common_fuse_meta::meta::BlockMeta;
+use common_fuse_meta::meta::ColumnId;
 use common_fuse_meta::meta::Compression;
 use common_planners::PartInfoPtr;
 use futures::AsyncReadExt;
@@ -49,6 +52,7 @@ use crate::storages::fuse::fuse_part::ColumnMeta;
 use crate::storages::fuse::fuse_part::FusePartInfo;
 use crate::storages::fuse::io::retry;
 use crate::storages::fuse::io::retry::Retryable;
+use crate::storages::fuse::ColumnIds;
 
 #[derive(Clone)]
 pub struct BlockReader {
@@ -58,6 +62,11 @@ pub struct BlockReader {
     projected_schema: DataSchemaRef,
     column_leaves: Vec<ColumnLeaf>,
     parquet_schema_descriptor: SchemaDescriptor,
+    column_ids: ColumnIds,
+    // The ids of the leaf columns, indexed by the positions of the leaves.
+    leaf_ids: Vec<ColumnId>,
+    // Column chunks are cached on local disk if enabled.
+    disk_cache: Option<ColumnDataDiskCache>,
 }
 
 impl BlockReader {
@@ -65,12 +74,23 @@ impl BlockReader {
         operator: Operator,
         schema: DataSchemaRef,
         projection: Vec<usize>,
+    ) -> Result<Arc<BlockReader>> {
+        Self::create_with_disk_cache(operator, schema, projection, None)
+    }
+
+    pub fn create_with_disk_cache(
+        operator: Operator,
+        schema: DataSchemaRef,
+        projection: Vec<usize>,
+        disk_cache: Option<ColumnDataDiskCache>,
     ) -> Result<Arc<BlockReader>> {
         let projected_schema = DataSchemaRef::new(schema.project(&projection));
 
         let arrow_schema = schema.to_arrow();
         let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
         let column_leaves = build_column_leaves(&parquet_schema_descriptor);
+        let column_ids = ColumnIds::from_schema(&schema)?;
+        let leaf_ids = column_ids.leaf_ids();
 
         Ok(Arc::new(BlockReader {
             operator,
@@ -78,6 +98,9 @@ impl BlockReader {
             projected_schema,
             parquet_schema_descriptor,
             column_leaves,
+            column_ids,
+            leaf_ids,
+            disk_cache,
             arrow_schema: Arc::new(arrow_schema),
         }))
     }
@@ -123,8 +146,9 @@ impl BlockReader {
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn read_with_block_meta(&self, meta: &BlockMeta) -> Result<DataBlock> {
         let (num_rows, columns_array_iter) = self.read_columns_with_block_meta(meta).await?;
-        let mut deserializer = RowGroupDeserializer::new(columns_array_iter, num_rows, None);
-        self.try_next_block(&mut deserializer)
+        self.to_block(num_rows, columns_array_iter, |leaf| {
+            meta.col_metas.contains_key(&self.leaf_ids[leaf])
+        })
     }
     // TODO refine these
 
@@ -142,14 +166,23 @@ impl BlockReader {
             let column_leaf = &self.column_leaves[*proj];
             let indices = &column_leaf.leaf_ids;
             for index in indices {
-                let column_meta = &meta.col_metas[&(*index as u32)];
+                // The column is added after the block was written.
+                let column_meta = match meta.col_metas.get(&self.leaf_ids[*index]) {
+                    None => continue,
+                    Some(column_meta) => column_meta,
+                };
 
-                let column_reader = self.operator.object(&meta.location.0);
+                let location = &meta.location.0;
                 let fut = async move {
-                    let column_chunk = column_reader
-                        .range_read(column_meta.offset..column_meta.offset + column_meta.len)
+                    let (idx, column_chunk) = self
+                        .read_column_with_cache(
+                            location,
+                            *index,
+                            column_meta.offset,
+                            column_meta.len,
+                        )
                         .await?;
-                    Ok::<_, ErrorCode>((*index, column_chunk))
+                    Ok::<_, ErrorCode>((idx, column_chunk))
                 }
                 .instrument(debug_span!("read_col_chunk"));
                 column_chunk_futs.push(fut);
@@ -163,16 +196,20 @@ impl BlockReader {
 
         let num_cols = columns_meta.len();
         let chunks = futures::stream::iter(column_chunk_futs)
-            .buffered(std::cmp::min(10, num_cols))
+            .buffered(num_cols.clamp(1, 10))
             .try_collect::<Vec<_>>()
             .await?;
         let mut chunk_map: HashMap<usize, Vec<u8>> = chunks.into_iter().collect();
 
         let mut columns_array_iter = Vec::with_capacity(num_cols);
         for proj in &self.projection {
-            let field = self.arrow_schema.fields[*proj].clone();
             let column_leaf = &self.column_leaves[*proj];
             let indices = &column_leaf.leaf_ids;
+            if !columns_meta.contains_key(&indices[0]) {
+                continue;
+            }
+
+            let field = self.arrow_schema.fields[*proj].clone();
 
             let mut column_metas = Vec::with_capacity(indices.len());
             let mut column_chunks = Vec::with_capacity(indices.len());
@@ -209,16 +246,21 @@ impl BlockReader {
             let column_leaf = &self.column_leaves[*proj];
             let indices = &column_leaf.leaf_ids;
             for index in indices {
-                let column_meta = &part.columns_meta[index];
-                let column_reader = self.operator.object(&part.location);
+                // The column is added after the block was written.
+                let column_meta = match part.columns_meta.get(index) {
+                    None => continue,
+                    Some(column_meta) => column_meta,
+                };
+                let location = &part.location;
                 let fut = async move {
-                    let (idx, column_chunk) = Self::read_column(
-                        column_reader,
-                        *index,
-                        column_meta.offset,
-                        column_meta.length,
-                    )
-                    .await?;
+                    let (idx, column_chunk) = self
+                        .read_column_with_cache(
+                            location,
+                            *index,
+                            column_meta.offset,
+                            column_meta.length,
+                        )
+                        .await?;
                     Ok::<_, ErrorCode>((idx, column_chunk))
                 }
                 .instrument(debug_span!("read_col_chunk"));
@@ -228,16 +270,20 @@ impl BlockReader {
 
         let num_cols = column_chunk_futs.len();
         let chunks = futures::stream::iter(column_chunk_futs)
-            .buffered(std::cmp::min(10, num_cols))
+            .buffered(num_cols.clamp(1, 10))
             .try_collect::<Vec<_>>()
             .await?;
         let mut chunk_map: HashMap<usize, Vec<u8>> = chunks.into_iter().collect();
 
         let mut columns_array_iter = Vec::with_capacity(num_cols);
         for proj in &self.projection {
-            let field = self.arrow_schema.fields[*proj].clone();
             let column_leaf = &self.column_leaves[*proj];
             let indices = &column_leaf.leaf_ids;
+            if !part.columns_meta.contains_key(&indices[0]) {
+                continue;
+            }
+
+            let field = self.arrow_schema.fields[*proj].clone();
 
             let mut column_metas = Vec::with_capacity(indices.len());
             let mut column_chunks = Vec::with_capacity(indices.len());
@@ -274,9 +320,13 @@ impl BlockReader {
 
         let num_rows = part.nums_rows;
         for proj in &self.projection {
-            let field = self.arrow_schema.fields[*proj].clone();
             let column_leaf = &self.column_leaves[*proj];
             let indices = &column_leaf.leaf_ids;
+            if !part.columns_meta.contains_key(&indices[0]) {
+                continue;
+            }
+
+            let field = self.arrow_schema.fields[*proj].clone();
 
             let mut column_metas = Vec::with_capacity(indices.len());
             let mut column_chunks = Vec::with_capacity(indices.len());
@@ -299,9 +349,9 @@ impl BlockReader {
             )?);
         }
 
-        let mut deserializer = RowGroupDeserializer::new(columns_array_iter, num_rows, None);
-
-        self.try_next_block(&mut deserializer)
+        self.to_block(num_rows, columns_array_iter, |leaf| {
+            part.columns_meta.contains_key(&leaf)
+        })
     }
 
     pub async fn read_columns_data(&self, part: PartInfoPtr) -> Result<Vec<(usize, Vec<u8>)>> {
@@ -313,10 +363,14 @@ impl BlockReader {
             let indices = &column_leaf.leaf_ids;
 
             for index in indices {
-                let column_meta = &part.columns_meta[index];
+                // The column is added after the block was written.
+                let column_meta = match part.columns_meta.get(index) {
+                    None => continue,
+                    Some(column_meta) => column_meta,
+                };
 
-                join_handlers.push(Self::read_column(
-                    self.operator.object(&part.location),
+                join_handlers.push(self.read_column_with_cache(
+                    &part.location,
                     *index,
                     column_meta.offset,
                     column_meta.length,
@@ -327,6 +381,32 @@ impl BlockReader {
         futures::future::try_join_all(join_handlers).await
     }
 
+    async fn read_column_with_cache(
+        &self,
+        location: &str,
+        index: usize,
+        offset: u64,
+        length: u64,
+    ) -> Result<(usize, Vec<u8>)> {
+        let object = self.operator.object(location);
+        let disk_cache = match &self.disk_cache {
+            None => return Self::read_column(object, index, offset, length).await,
+            Some(disk_cache) => disk_cache,
+        };
+
+        let key = ColumnDataCache::cache_key(location, self.leaf_ids[index]);
+        if let Some(chunk) = disk_cache.get(&key).await {
+            return Ok((index, chunk));
+        }
+
+        let (index, chunk) = Self::read_column(object, index, offset, length).await?;
+        // Failing to cache the chunk should not fail the read.
+        if let Err(cause) = disk_cache.put(&key, chunk.clone()).await {
+            warn!("failed to cache column chunk {}: {}", key, cause);
+        }
+        Ok((index, chunk))
+    }
+
     pub async fn read_column(
         o: Object,
         index: usize,
@@ -356,16 +436,66 @@ impl BlockReader {
 
     #[tracing::instrument(level = "debug", skip_all)]
     pub async fn read(&self, part: PartInfoPtr) -> Result<DataBlock> {
-        let (num_rows, columns_array_iter) = self.read_columns(part).await?;
-        let mut deserializer = RowGroupDeserializer::new(columns_array_iter, num_rows, None);
-        self.try_next_block(&mut deserializer)
+        let (num_rows, columns_array_iter) = self.read_columns(part.clone()).await?;
+        let part = FusePartInfo::from_part(&part)?;
+        self.to_block(num_rows, columns_array_iter, |leaf| {
+            part.columns_meta.contains_key(&leaf)
+        })
     }
 
-    fn try_next_block(&self, deserializer: &mut RowGroupDeserializer) -> Result<DataBlock> {
+    // The columns of the projection which are added after the block was written
+    // are filled with their default values.
+    fn to_block(
+        &self,
+        num_rows: usize,
+        columns_array_iter: Vec<ArrayIter<'static>>,
+        contains_leaf: impl Fn(usize) -> bool,
+    ) -> Result<DataBlock> {
+        let mut contained = Vec::with_capacity(self.projection.len());
+        let mut missing = vec![];
+        for (i, proj) in self.projection.iter().enumerate() {
+            match contains_leaf(self.column_leaves[*proj].leaf_ids[0]) {
+                true => contained.push(i),
+                false => missing.push(i),
+            }
+        }
+
+        if missing.is_empty() {
+            let mut deserializer = RowGroupDeserializer::new(columns_array_iter, num_rows, None);
+            return self.try_next_block(&self.projected_schema, &mut deserializer);
+        }
+
+        let mut block = match contained.is_empty() {
+            true => DataBlock::empty(),
+            false => {
+                let schema = DataSchemaRef::new(self.projected_schema.project(&contained));
+                let mut deserializer =
+                    RowGroupDeserializer::new(columns_array_iter, num_rows, None);
+                self.try_next_block(&schema, &mut deserializer)?
+            }
+        };
+
+        for i in missing {
+            let field = self.projected_schema.field(i);
+            let value = self.column_ids.default_value(self.projection[i], field);
+            let column = field
+                .data_type()
+                .create_constant_column(&value, num_rows)?
+                .convert_full_column();
+            block = block.add_column(column, field.clone())?;
+        }
+        block.resort(self.projected_schema.clone())
+    }
+
+    fn try_next_block(
+        &self,
+        schema: &DataSchemaRef,
+        deserializer: &mut RowGroupDeserializer,
+    ) -> Result<DataBlock> {
         match deserializer.next() {
             None => Err(ErrorCode::ParquetError("fail to get a chunk")),
             Some(Err(cause)) => Err(ErrorCode::from(cause)),
-            Some(Ok(chunk)) => DataBlock::from_chunk(&self.projected_schema, &chunk),
+            Some(Ok(chunk)) => DataBlock::from_chunk(schema, &chunk),
         }
     }
 
@@ -16,10 +16,13 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_fuse_meta::caches::TenantLabel;
+use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::SegmentInfo;
 use common_fuse_meta::meta::SegmentInfoVersion;
 use common_fuse_meta::meta::SnapshotVersion;
 use common_fuse_meta::meta::TableSnapshot;
+use common_fuse_meta::meta::TableSnapshotStatistics;
+use common_fuse_meta::meta::TableSnapshotStatisticsVersion;
 use futures::io::BufReader;
 use opendal::BytesReader;
 
@@ -59,6 +62,16 @@ impl MetaReaders {
             "SNAPSHOT_CACHE".to_owned(),
         )
     }
+
+    /// Reads the column distributions collected by `ANALYZE TABLE`.
+    pub async fn read_table_snapshot_statistics(
+        ctx: &dyn TableContext,
+        location: &Location,
+    ) -> Result<TableSnapshotStatistics> {
+        let version = TableSnapshotStatisticsVersion::try_from(location.1)?;
+        let reader = ctx.buf_reader(&location.0, None).await?;
+        version.read(reader).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -33,6 +33,7 @@ use crate::storages::fuse::io::BlockWriter;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::statistics::BlockStatistics;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::index::ClusterKeyInfo;
 
 pub type SegmentInfoStream =
@@ -45,6 +46,7 @@ pub struct BlockStreamWriter {
     statistics_accumulator: Option<StatisticsAccumulator>,
     meta_locations: TableMetaLocationGenerator,
     cluster_key_info: Option<ClusterKeyInfo>,
+    column_ids: ColumnIds,
     ctx: Arc<dyn TableContext>,
 }
 
@@ -56,6 +58,7 @@ impl BlockStreamWriter {
         block_per_segment: usize,
         meta_locations: TableMetaLocationGenerator,
         cluster_key_info: Option<ClusterKeyInfo>,
+        column_ids: ColumnIds,
     ) -> Result<SegmentInfoStream> {
         // filter out empty blocks
         let block_stream =
@@ -76,6 +79,7 @@ impl BlockStreamWriter {
             meta_locations,
             ctx,
             cluster_key_info,
+            column_ids,
         )?;
         let segments = Self::transform(Box::pin(block_stream), block_writer);
 
@@ -87,6 +91,7 @@ impl BlockStreamWriter {
         meta_locations: TableMetaLocationGenerator,
         ctx: Arc<dyn TableContext>,
         cluster_key_info: Option<ClusterKeyInfo>,
+        column_ids: ColumnIds,
     ) -> Result<Self> {
         let data_accessor = ctx.get_storage_operator()?;
         Ok(Self {
@@ -96,6 +101,7 @@ impl BlockStreamWriter {
             statistics_accumulator: None,
             meta_locations,
             cluster_key_info,
+            column_ids,
             ctx,
         })
     }
@@ -185,9 +191,18 @@ impl BlockStreamWriter {
 
         let mut acc = self.statistics_accumulator.take().unwrap_or_default();
         let (location, block_id) = self.meta_locations.gen_block_location();
-        let block_statistics = BlockStatistics::from(&block, location.0.clone(), cluster_stats)?;
-
-        let block_writer = BlockWriter::new(&self.ctx, &self.data_accessor, &self.meta_locations);
+        let mut block_statistics =
+            BlockStatistics::from(&block, location.0.clone(), cluster_stats)?;
+        block_statistics.block_column_statistics = self
+            .column_ids
+            .statistics_by_id(block_statistics.block_column_statistics);
+
+        let block_writer = BlockWriter::new(
+            &self.ctx,
+            &self.data_accessor,
+            &self.meta_locations,
+            &self.column_ids,
+        );
         let block_meta = block_writer
             .write_with_location(block, block_id, location)
             .await?;
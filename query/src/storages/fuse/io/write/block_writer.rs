@@ -32,6 +32,7 @@ use crate::storages::fuse::io::retry::Retryable;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::operations::util;
 use crate::storages::fuse::statistics::gen_columns_statistics;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::index::BloomFilterIndexer;
 
 const DEFAULT_BLOOM_INDEX_WRITE_BUFFER_SIZE: usize = 300 * 1024;
@@ -41,6 +42,7 @@ pub struct BlockWriter<'a> {
     ctx: &'a Arc<dyn TableContext>,
     location_generator: &'a TableMetaLocationGenerator,
     data_accessor: &'a Operator,
+    column_ids: &'a ColumnIds,
 }
 
 impl<'a> BlockWriter<'a> {
@@ -48,11 +50,13 @@ impl<'a> BlockWriter<'a> {
         ctx: &'a Arc<dyn TableContext>,
         data_accessor: &'a Operator,
         location_generator: &'a TableMetaLocationGenerator,
+        column_ids: &'a ColumnIds,
     ) -> Self {
         Self {
             ctx,
             location_generator,
             data_accessor,
+            column_ids,
         }
     }
     pub async fn write_with_location(
@@ -64,12 +68,16 @@ impl<'a> BlockWriter<'a> {
         let data_accessor = &self.data_accessor;
         let row_count = block.num_rows() as u64;
         let block_size = block.memory_size() as u64;
-        let col_stats = gen_columns_statistics(&block)?;
+        let col_stats = self
+            .column_ids
+            .statistics_by_id(gen_columns_statistics(&block)?);
         let (bloom_filter_index_size, bloom_filter_index_location) = self
             .build_block_index(data_accessor, &block, block_id)
             .await?;
         let (file_size, file_meta_data) = write_block(block, data_accessor, &location.0).await?;
-        let col_metas = util::column_metas(&file_meta_data)?;
+        let col_metas = self
+            .column_ids
+            .metas_by_id(util::column_metas(&file_meta_data)?);
         let cluster_stats = None; // TODO confirm this with zhyass
         let block_meta = BlockMeta::new(
             row_count,
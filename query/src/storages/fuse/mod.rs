@@ -12,6 +12,7 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod column_ids;
 mod constants;
 mod fuse_part;
 mod fuse_table;
@@ -21,6 +22,8 @@ pub mod pruning;
 pub mod statistics;
 pub mod table_functions;
 
+pub use column_ids::ColumnIds;
+pub use column_ids::FUSE_COLUMN_IDS_KEY;
 pub use constants::*;
 pub use fuse_part::ColumnLeaf;
 pub use fuse_table::FuseTable;
@@ -0,0 +1,192 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::DataField;
+use common_datavalues::DataSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_fuse_meta::meta::Statistics as FuseStatistics;
+use common_fuse_meta::meta::TableSnapshot;
+use common_meta_app::schema::TableMeta;
+use common_planners::find_column_exprs;
+use common_planners::AddTableColumnPlan;
+use common_planners::DropTableColumnPlan;
+use common_planners::RenameTableColumnPlan;
+use uuid::Uuid;
+
+use crate::sessions::TableContext;
+use crate::storages::fuse::ColumnIds;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+// Columns are added, dropped and renamed by changing the schema of the table only, the
+// existing blocks are not rewritten. See `ColumnIds` for how the blocks are read afterwards.
+impl FuseTable {
+    pub async fn do_add_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &AddTableColumnPlan,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        if schema.has_field(plan.field.name()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} already exists in table {}",
+                plan.field.name(),
+                plan.table
+            )));
+        }
+
+        let mut column_ids = ColumnIds::from_schema(&schema)?;
+        column_ids.add_column(&plan.field, plan.default_value.clone())?;
+
+        let mut new_table_meta = self.get_table_info().meta.clone();
+        let mut fields = schema.fields().clone();
+        let num_fields = fields.len();
+        if new_table_meta.field_comments.len() == num_fields || !plan.comment.is_empty() {
+            new_table_meta
+                .field_comments
+                .resize(num_fields, String::new());
+            new_table_meta.field_comments.push(plan.comment.clone());
+        }
+        fields.push(plan.field.clone());
+
+        let new_schema = column_ids.to_schema(&schema, fields)?;
+        self.commit_new_schema(ctx, &plan.catalog, new_schema, new_table_meta)
+            .await
+    }
+
+    pub async fn do_drop_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DropTableColumnPlan,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let index = self.index_of_column(&schema, &plan.column, &plan.table)?;
+        if schema.num_fields() == 1 {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can't drop column {}, table {} must have at least one column",
+                plan.column, plan.table
+            )));
+        }
+        self.check_not_cluster_key(&plan.column, "drop")?;
+
+        let mut column_ids = ColumnIds::from_schema(&schema)?;
+        column_ids.drop_column(index, &plan.column);
+
+        let mut new_table_meta = self.get_table_info().meta.clone();
+        if new_table_meta.field_comments.len() == schema.num_fields() {
+            new_table_meta.field_comments.remove(index);
+        }
+        let mut fields = schema.fields().clone();
+        fields.remove(index);
+
+        let new_schema = column_ids.to_schema(&schema, fields)?;
+        self.commit_new_schema(ctx, &plan.catalog, new_schema, new_table_meta)
+            .await
+    }
+
+    pub async fn do_rename_column(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &RenameTableColumnPlan,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let index = self.index_of_column(&schema, &plan.old_column, &plan.table)?;
+        if schema.has_field(&plan.new_column) {
+            return Err(ErrorCode::BadArguments(format!(
+                "Column {} already exists in table {}",
+                plan.new_column, plan.table
+            )));
+        }
+        self.check_not_cluster_key(&plan.old_column, "rename")?;
+
+        let mut column_ids = ColumnIds::from_schema(&schema)?;
+        column_ids.rename_column(&plan.old_column, &plan.new_column);
+
+        let mut fields = schema.fields().clone();
+        let field = &fields[index];
+        fields[index] = DataField::new(&plan.new_column, field.data_type().clone())
+            .with_default_expr(field.default_expr().cloned());
+
+        let new_table_meta = self.get_table_info().meta.clone();
+        let new_schema = column_ids.to_schema(&schema, fields)?;
+        self.commit_new_schema(ctx, &plan.catalog, new_schema, new_table_meta)
+            .await
+    }
+
+    fn index_of_column(&self, schema: &DataSchema, column: &str, table: &str) -> Result<usize> {
+        schema.index_of(column).map_err(|_| {
+            ErrorCode::UnknownColumn(format!("Unknown column {} in table {}", column, table))
+        })
+    }
+
+    fn check_not_cluster_key(&self, column: &str, action: &str) -> Result<()> {
+        let cluster_key_columns = find_column_exprs(&self.cluster_keys);
+        if cluster_key_columns
+            .iter()
+            .any(|expr| expr.column_name() == column)
+        {
+            return Err(ErrorCode::BadArguments(format!(
+                "Can't {} column {}, it is referenced by the cluster key",
+                action, column
+            )));
+        }
+        Ok(())
+    }
+
+    async fn commit_new_schema(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        catalog_name: &str,
+        new_schema: DataSchema,
+        mut new_table_meta: TableMeta,
+    ) -> Result<()> {
+        new_table_meta.schema = Arc::new(new_schema.clone());
+
+        let prev = self.read_table_snapshot(ctx.as_ref()).await?;
+        let prev_version = self.snapshot_format_version();
+        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
+        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
+        let (summary, segments, table_statistics_location) = if let Some(v) = prev {
+            (
+                v.summary.clone(),
+                v.segments.clone(),
+                v.table_statistics_location.clone(),
+            )
+        } else {
+            (FuseStatistics::default(), vec![], None)
+        };
+
+        let mut new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev_timestamp,
+            prev_snapshot_id,
+            new_schema,
+            summary,
+            segments,
+            self.cluster_key_meta.clone(),
+        );
+        new_snapshot.table_statistics_location = table_statistics_location;
+
+        self.update_table_meta(
+            ctx.as_ref(),
+            catalog_name,
+            &new_snapshot,
+            &mut new_table_meta,
+        )
+        .await
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datavalues::remove_nullable;
+use common_datavalues::DataTypeImpl;
+use common_exception::Result;
+use common_fuse_meta::meta::TableSnapshot;
+use common_fuse_meta::meta::TableSnapshotStatistics;
+use common_planners::AnalyzeTablePlan;
+
+use crate::sessions::TableContext;
+use crate::storages::fuse::io::write_meta;
+use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::statistics::ColumnDistributionCollector;
+use crate::storages::fuse::statistics::DEFAULT_HISTOGRAM_BUCKETS;
+use crate::storages::fuse::ColumnIds;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+impl FuseTable {
+    pub async fn do_analyze(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        plan: &AnalyzeTablePlan,
+    ) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            // empty table, nothing to analyze
+            None => return Ok(()),
+        };
+
+        let schema = self.table_info.schema();
+        let projection = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| plan.columns.is_empty() || plan.columns.contains(field.name()))
+            .filter(|(_, field)| is_analyzable(field.data_type()))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        let mut collectors = projection
+            .iter()
+            .map(|_| ColumnDistributionCollector::new())
+            .collect::<Vec<_>>();
+        if !projection.is_empty() {
+            let block_metas = BlockPruner::new(snapshot.clone())
+                .prune(ctx, schema.clone(), &None)
+                .await?;
+            let reader = self.create_block_reader(ctx, projection.clone())?;
+            for (_, block_meta) in block_metas {
+                let block = reader.read_with_block_meta(&block_meta).await?;
+                for (collector, column) in collectors.iter_mut().zip(block.columns()) {
+                    collector.add_column(column);
+                }
+            }
+        }
+
+        // the distributions of the columns not analyzed this time are kept
+        let column_ids = ColumnIds::from_schema(&schema)?;
+        let mut column_distributions = match &snapshot.table_statistics_location {
+            Some(location) if !plan.columns.is_empty() => {
+                MetaReaders::read_table_snapshot_statistics(ctx.as_ref(), location)
+                    .await?
+                    .column_distributions
+            }
+            _ => Default::default(),
+        };
+        column_distributions.extend(
+            column_ids.statistics_by_id(
+                projection
+                    .into_iter()
+                    .zip(collectors)
+                    .map(|(index, collector)| {
+                        (index as u32, collector.finish(DEFAULT_HISTOGRAM_BUCKETS))
+                    })
+                    .collect(),
+            ),
+        );
+
+        let statistics = TableSnapshotStatistics::new(snapshot.snapshot_id, column_distributions);
+        let statistics_location = self
+            .meta_location_generator
+            .gen_snapshot_statistics_location();
+        let operator = ctx.get_storage_operator()?;
+        write_meta(&operator, &statistics_location.0, &statistics).await?;
+
+        let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
+        new_snapshot.table_statistics_location = Some(statistics_location);
+        let mut new_table_meta = self.get_table_info().meta.clone();
+        self.update_table_meta(
+            ctx.as_ref(),
+            &plan.catalog,
+            &new_snapshot,
+            &mut new_table_meta,
+        )
+        .await
+    }
+}
+
+// The values of the nested columns are not comparable, no distribution is collected
+fn is_analyzable(data_type: &DataTypeImpl) -> bool {
+    !matches!(
+        remove_nullable(data_type),
+        DataTypeImpl::Struct(_)
+            | DataTypeImpl::Array(_)
+            | DataTypeImpl::Map(_)
+            | DataTypeImpl::Variant(_)
+            | DataTypeImpl::VariantArray(_)
+            | DataTypeImpl::VariantObject(_)
+    )
+}
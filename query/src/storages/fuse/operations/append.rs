@@ -37,6 +37,7 @@ use crate::storages::fuse::io::write_meta;
 use crate::storages::fuse::io::BlockStreamWriter;
 use crate::storages::fuse::operations::AppendOperationLogEntry;
 use crate::storages::fuse::operations::FuseTableSink;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::fuse::FuseTable;
 use crate::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
 use crate::storages::fuse::DEFAULT_BLOCK_SIZE_IN_MEM_SIZE_THRESHOLD;
@@ -78,6 +79,7 @@ impl FuseTable {
             block_per_seg,
             self.meta_location_generator().clone(),
             cluster_key_info,
+            ColumnIds::from_schema(&self.table_info.schema())?,
         )
         .await?;
 
@@ -209,6 +211,7 @@ impl FuseTable {
             });
         }
 
+        let column_ids = ColumnIds::from_schema(&self.table_info.schema())?;
         let mut sink_pipeline_builder = SinkPipeBuilder::create();
         for _ in 0..pipeline.output_len() {
             let input_port = InputPort::create();
@@ -221,6 +224,7 @@ impl FuseTable {
                     da.clone(),
                     self.meta_location_generator().clone(),
                     cluster_key_info.clone(),
+                    column_ids.clone(),
                 )?,
             );
         }
@@ -29,6 +29,7 @@ use common_fuse_meta::meta::Statistics;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
@@ -93,26 +94,12 @@ impl FuseTable {
             {
                 Ok(_) => {
                     break {
-                        if transient {
-                            // Removes historical data, if table is transient
-                            warn!(
-                                "transient table detected, purging historical data. ({})",
-                                tbl.table_info.ident
-                            );
-
+                        // the new snapshot is not committed yet, if it is buffered in a
+                        // transaction, the history is purged by `COMMIT` then.
+                        if transient && !ctx.get_txn_manager().is_active() {
                             let latest = tbl.latest(ctx.as_ref(), catalog_name).await?;
                             tbl = FuseTable::try_from_table(latest.as_ref())?;
-
-                            let keep_last_snapshot = true;
-                            if let Err(e) = tbl.do_gc(&ctx, keep_last_snapshot).await {
-                                // Errors of GC, if any, are ignored, since GC task can be picked up
-                                warn!(
-                                    "GC of transient table not success (this is not a permanent error). the error : {}",
-                                    e
-                                );
-                            } else {
-                                info!("GC of transient table done");
-                            }
+                            tbl.purge_transient_history(&ctx).await;
                         }
                         Ok(())
                     };
@@ -153,6 +140,25 @@ impl FuseTable {
         }
     }
 
+    /// Removes the historical data of a transient table, errors are ignored since they
+    /// can be picked up by the next GC.
+    pub async fn purge_transient_history(&self, ctx: &Arc<dyn TableContext>) {
+        warn!(
+            "transient table detected, purging historical data. ({})",
+            self.table_info.ident
+        );
+
+        let keep_last_snapshot = true;
+        if let Err(e) = self.do_gc(ctx, keep_last_snapshot).await {
+            warn!(
+                "GC of transient table not success (this is not a permanent error). the error : {}",
+                e
+            );
+        } else {
+            info!("GC of transient table done");
+        }
+    }
+
     #[inline]
     pub async fn try_commit(
         &self,
@@ -235,7 +241,7 @@ impl FuseTable {
             new_segments.append(&mut segments)
         };
 
-        let new_snapshot = TableSnapshot::new(
+        let mut new_snapshot = TableSnapshot::new(
             Uuid::new_v4(),
             &prev_snapshot_timestamp,
             prev_snapshot_id,
@@ -244,6 +250,10 @@ impl FuseTable {
             new_segments,
             cluster_key_meta,
         );
+        // 3. keep the column distributions of the previous snapshot, they are still good
+        // estimations until the table is analyzed again
+        new_snapshot.table_statistics_location =
+            previous.and_then(|v| v.table_statistics_location.clone());
         Ok(new_snapshot)
     }
 
@@ -277,6 +287,10 @@ impl FuseTable {
             index_data_bytes: stats.index_size,
         };
 
+        if Self::buffer_in_txn(ctx, catalog_name, table_info, &new_table_meta)? {
+            return Ok(UpdateTableMetaReply {});
+        }
+
         let req = UpdateTableMetaReq {
             table_id,
             seq: MatchSeq::Exact(table_version),
@@ -286,6 +300,42 @@ impl FuseTable {
         catalog.update_table_meta(req).await
     }
 
+    /// Buffers the new table meta in the transaction of the session, instead of
+    /// committing it to the meta server.
+    ///
+    /// Returns false if there is no transaction in progress.
+    pub fn buffer_in_txn(
+        ctx: &dyn TableContext,
+        catalog_name: &str,
+        table_info: &TableInfo,
+        new_table_meta: &TableMeta,
+    ) -> Result<bool> {
+        let txn_manager = ctx.get_txn_manager();
+        if !txn_manager.is_active() {
+            return Ok(false);
+        }
+
+        // the seq is kept, the version of the table is not changed until the commit
+        let new_table_info = TableInfo {
+            meta: new_table_meta.clone(),
+            ..table_info.clone()
+        };
+        let catalog = ctx.get_catalog(catalog_name)?;
+        let base_table = catalog.get_table_by_info(table_info)?;
+        let new_table = catalog.get_table_by_info(&new_table_info)?;
+
+        // The new snapshot is purged if the transaction is rolled back, along with the
+        // segments and blocks only referenced by it, see `do_purge_txn_writes`.
+        let written_locations = new_table_meta
+            .options
+            .get(OPT_KEY_SNAPSHOT_LOCATION)
+            .filter(|loc| table_info.meta.options.get(OPT_KEY_SNAPSHOT_LOCATION) != Some(*loc))
+            .cloned()
+            .into_iter()
+            .collect();
+        txn_manager.buffer_table(catalog_name, base_table, new_table, written_locations)
+    }
+
     pub fn merge_append_operations(
         append_log_entries: &[AppendOperationLogEntry],
     ) -> Result<(Vec<String>, Statistics)> {
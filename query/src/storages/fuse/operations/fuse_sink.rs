@@ -28,6 +28,7 @@ use common_fuse_meta::meta::SegmentInfo;
 use common_fuse_meta::meta::Statistics;
 use opendal::Operator;
 
+use super::column_metas;
 use super::AppendOperationLogEntry;
 use crate::pipelines::processors::port::InputPort;
 use crate::pipelines::processors::processor::Event;
@@ -38,6 +39,7 @@ use crate::storages::fuse::io;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::statistics::BlockStatistics;
 use crate::storages::fuse::statistics::StatisticsAccumulator;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::index::BloomFilterIndexer;
 use crate::storages::index::ClusterKeyInfo;
 
@@ -75,6 +77,7 @@ pub struct FuseTableSink {
     meta_locations: TableMetaLocationGenerator,
     accumulator: StatisticsAccumulator,
     cluster_key_info: Option<ClusterKeyInfo>,
+    column_ids: ColumnIds,
 }
 
 impl FuseTableSink {
@@ -85,6 +88,7 @@ impl FuseTableSink {
         data_accessor: Operator,
         meta_locations: TableMetaLocationGenerator,
         cluster_key_info: Option<ClusterKeyInfo>,
+        column_ids: ColumnIds,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(FuseTableSink {
             ctx,
@@ -95,6 +99,7 @@ impl FuseTableSink {
             accumulator: Default::default(),
             num_block_threshold: num_block_threshold as u64,
             cluster_key_info,
+            column_ids,
         })))
     }
 }
@@ -183,8 +188,11 @@ impl Processor for FuseTableSink {
                     }
                 };
 
-                let block_statistics =
+                let mut block_statistics =
                     BlockStatistics::from(&block, block_location.0, cluster_stats)?;
+                block_statistics.block_column_statistics = self
+                    .column_ids
+                    .statistics_by_id(block_statistics.block_column_statistics);
                 // we need a configuration of block size threshold here
                 let mut data = Vec::with_capacity(100 * 1024 * 1024);
                 let schema = block.schema().clone();
@@ -251,9 +259,10 @@ impl Processor for FuseTableSink {
                 .await?;
 
                 let bloom_filter_index_size = bloom_index_state.size;
-                self.accumulator.add_block(
+                let col_metas = self.column_ids.metas_by_id(column_metas(&meta_data)?);
+                self.accumulator.add_with_column_metas(
                     size,
-                    *meta_data,
+                    col_metas,
                     block_statistics,
                     Some(bloom_index_state.location),
                     bloom_filter_index_size,
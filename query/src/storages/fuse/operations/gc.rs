@@ -26,6 +26,7 @@ use tracing::warn;
 
 use crate::sessions::TableContext;
 use crate::storages::fuse::io::MetaReaders;
+use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -69,9 +70,12 @@ impl FuseTable {
                 // just drop the whole snapshot,
                 let snapshots = vec![(last_snapshot.snapshot_id, self.snapshot_format_version())];
                 let segments = HashSet::from_iter(last_snapshot.segments.clone());
+                let statistics =
+                    HashSet::from_iter(last_snapshot.table_statistics_location.clone());
                 self.purge_blocks(ctx.as_ref(), segments.iter(), &HashSet::new())
                     .await?;
-                self.collect(ctx.as_ref(), segments, snapshots).await
+                self.collect(ctx.as_ref(), segments, statistics, snapshots)
+                    .await
             };
         };
 
@@ -82,13 +86,21 @@ impl FuseTable {
         let mut snapshot_history =
             reader.snapshot_history(prev_loc, prev_ver, self.meta_location_generator.clone());
 
+        // the snapshots within the data retention period are kept along with the last snapshot,
+        // if the last snapshot is not kept (e.g. truncate purge), all the history is dropped.
+        let retention_point = if keep_last_snapshot {
+            self.data_retention_point()?
+        } else {
+            None
+        };
+
         let mut snapshots_to_be_deleted: Vec<_> = Vec::new();
         if !keep_last_snapshot {
             snapshots_to_be_deleted
                 .push((last_snapshot.snapshot_id, self.snapshot_format_version()));
         }
 
-        let segments_referenced_by_gc_root: HashSet<Location> = if !keep_last_snapshot {
+        let mut segments_referenced_by_gc_root: HashSet<Location> = if !keep_last_snapshot {
             //  segment gc root references nothing;
             HashSet::new()
         } else {
@@ -96,23 +108,60 @@ impl FuseTable {
             HashSet::from_iter(last_snapshot.segments.clone())
         };
 
+        // the column distributions are collected in the same way as the segments
+        let mut statistics_referenced_by_gc_root: HashSet<Location> = if !keep_last_snapshot {
+            HashSet::new()
+        } else {
+            HashSet::from_iter(last_snapshot.table_statistics_location.clone())
+        };
+
         // segments which no longer need to be kept
         let mut segments_to_be_deleted: HashSet<_> = HashSet::new();
+        let mut statistics_to_be_deleted: HashSet<_> = HashSet::new();
         {
             if !keep_last_snapshot {
-                segments_to_be_deleted.extend(last_snapshot.segments.clone())
+                segments_to_be_deleted.extend(last_snapshot.segments.clone());
+                statistics_to_be_deleted.extend(last_snapshot.table_statistics_location.clone());
             }
 
             // collects
-            // - all the previous snapshots
-            // - segments referenced by previous snapshots, but not by gc_root
+            // - all the previous snapshots that are beyond the retention period
+            // - segments referenced by those snapshots, but not by gc_root
+            //
+            // snapshots are ordered by timestamp DESC, so the retained snapshots are visited
+            // first, and the segments they reference are put into gc root before collecting.
+            // the latest snapshot not newer than the retention point is retained as well,
+            // since it is the version of the table at the retention point.
+            let mut retaining = match (last_snapshot.timestamp, retention_point) {
+                (Some(ts), Some(point)) => ts > point,
+                _ => false,
+            };
             while let Some(s) = snapshot_history.try_next().await? {
+                if retaining {
+                    let (retained, newer) = match (s.timestamp, retention_point) {
+                        (Some(ts), Some(point)) => (true, ts > point),
+                        _ => (false, false),
+                    };
+                    retaining = newer;
+                    if retained {
+                        segments_referenced_by_gc_root.extend(s.segments.clone());
+                        statistics_referenced_by_gc_root
+                            .extend(s.table_statistics_location.clone());
+                        continue;
+                    }
+                }
+
                 snapshots_to_be_deleted.push((s.snapshot_id, s.format_version()));
                 for seg in &s.segments {
                     if !segments_referenced_by_gc_root.contains(seg) {
                         segments_to_be_deleted.insert(seg.clone());
                     }
                 }
+                if let Some(statistics) = &s.table_statistics_location {
+                    if !statistics_referenced_by_gc_root.contains(statistics) {
+                        statistics_to_be_deleted.insert(statistics.clone());
+                    }
+                }
             }
         }
 
@@ -131,6 +180,67 @@ impl FuseTable {
         self.collect(
             ctx.as_ref(),
             segments_to_be_deleted,
+            statistics_to_be_deleted,
+            snapshots_to_be_deleted,
+        )
+        .await
+    }
+
+    /// Purges the snapshots written by a rolled back transaction, together with the
+    /// segments and blocks referenced by them but not by the snapshot of this table,
+    /// which is the version pinned by the transaction.
+    pub async fn do_purge_txn_writes(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_locations: &[String],
+    ) -> Result<()> {
+        let (segments_referenced_by_gc_root, statistics_referenced_by_gc_root) =
+            match self.read_table_snapshot(ctx.as_ref()).await? {
+                Some(s) => (
+                    HashSet::from_iter(s.segments.clone()),
+                    HashSet::from_iter(s.table_statistics_location.clone()),
+                ),
+                None => (HashSet::new(), HashSet::new()),
+            };
+
+        let reader = MetaReaders::table_snapshot_reader(ctx.as_ref());
+        let mut snapshots_to_be_deleted = Vec::with_capacity(snapshot_locations.len());
+        let mut segments_to_be_deleted = HashSet::new();
+        let mut statistics_to_be_deleted = HashSet::new();
+        for location in snapshot_locations {
+            let ver = TableMetaLocationGenerator::snapshot_version(location);
+            let s = match reader.read(location.as_str(), None, ver).await {
+                Err(e) if e.code() == ErrorCode::storage_not_found_code() => continue,
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            snapshots_to_be_deleted.push((s.snapshot_id, ver));
+            for seg in &s.segments {
+                if !segments_referenced_by_gc_root.contains(seg) {
+                    segments_to_be_deleted.insert(seg.clone());
+                }
+            }
+            if let Some(statistics) = &s.table_statistics_location {
+                if !statistics_referenced_by_gc_root.contains(statistics) {
+                    statistics_to_be_deleted.insert(statistics.clone());
+                }
+            }
+        }
+
+        let blocks_referenced_by_gc_root: HashSet<String> = self
+            .blocks_of(ctx.as_ref(), segments_referenced_by_gc_root.iter())
+            .await?;
+        self.purge_blocks(
+            ctx.as_ref(),
+            segments_to_be_deleted.iter(),
+            &blocks_referenced_by_gc_root,
+        )
+        .await?;
+
+        self.collect(
+            ctx.as_ref(),
+            segments_to_be_deleted,
+            statistics_to_be_deleted,
             snapshots_to_be_deleted,
         )
         .await
@@ -234,6 +344,7 @@ impl FuseTable {
         &self,
         ctx: &dyn TableContext,
         segments_to_be_deleted: HashSet<Location>,
+        statistics_to_be_deleted: HashSet<Location>,
         snapshots_to_be_deleted: Vec<(SnapshotId, u64)>,
     ) -> Result<()> {
         let accessor = ctx.get_storage_operator()?;
@@ -250,8 +361,13 @@ impl FuseTable {
             self.remove_location(&accessor, x.as_str()).await?;
         }
 
+        // 2. remove the column distributions
+        for (x, _v) in statistics_to_be_deleted {
+            self.remove_location(&accessor, x.as_str()).await?;
+        }
+
         let locs = self.meta_location_generator();
-        // 2. remove the snapshots
+        // 3. remove the snapshots
         for (id, ver) in snapshots_to_be_deleted.iter().rev() {
             let loc = locs.snapshot_location_from_uuid(id, *ver)?;
             if let Some(c) = ctx.get_storage_cache_manager().get_table_snapshot_cache() {
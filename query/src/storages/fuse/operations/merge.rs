@@ -0,0 +1,136 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_cache::Cache;
+use common_exception::Result;
+use common_fuse_meta::meta::SegmentInfo;
+use common_planners::Extras;
+use common_planners::MergePlan;
+use common_streams::SendableDataBlockStream;
+use futures::TryStreamExt;
+
+use crate::sessions::TableContext;
+use crate::storages::fuse::io::write_meta;
+use crate::storages::fuse::io::BlockWriter;
+use crate::storages::fuse::operations::mutation::block_filter::all_the_columns_ids;
+use crate::storages::fuse::operations::mutation::BlockMerger;
+use crate::storages::fuse::operations::mutation::DeletionMutator;
+use crate::storages::fuse::operations::AppendOperationLogEntry;
+use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::statistics::reducers::reduce_block_metas;
+use crate::storages::fuse::ColumnIds;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+impl FuseTable {
+    pub async fn do_merge(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &MergePlan,
+        mut joined: SendableDataBlockStream,
+    ) -> Result<()> {
+        let schema = self.table_info.schema();
+        let mut merger = BlockMerger::try_create(&ctx, plan, schema.clone())?;
+
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) => snapshot,
+            None => {
+                return self
+                    .merge_into_empty_table(&ctx, plan, merger, joined)
+                    .await;
+            }
+        };
+
+        // the rows to be inserted are written while the joined rows are consumed, the
+        // blocks which may hold the matched target rows are visited afterwards
+        let mut mutator =
+            DeletionMutator::try_create(&ctx, &self.meta_location_generator, &snapshot)?;
+        while let Some(block) = joined.try_next().await? {
+            mutator.append(merger.merge_joined(block)?).await?;
+        }
+
+        let block_metas = if merger.matches_nothing() {
+            vec![]
+        } else {
+            let push_downs = merger.pruning_filter().map(|filter| Extras {
+                filters: vec![filter],
+                ..Extras::default()
+            });
+            BlockPruner::new(snapshot.clone())
+                .prune(&ctx, schema, &push_downs)
+                .await?
+        };
+
+        let reader = self.create_block_reader(&ctx, all_the_columns_ids(self))?;
+        for (seg_idx, block_meta) in block_metas {
+            let block = reader.read_with_block_meta(&block_meta).await?;
+            if let Some(block) = merger.merge_block(block)? {
+                mutator
+                    .replace_with(seg_idx, block_meta.location.clone(), block)
+                    .await?;
+            }
+        }
+        merger.check_matches()?;
+
+        let (new_snapshot, loc) = mutator.into_new_snapshot().await?;
+        Self::commit_to_meta_server(
+            ctx.as_ref(),
+            &plan.catalog_name,
+            self.get_table_info(),
+            loc,
+            &new_snapshot.summary,
+        )
+        .await?;
+        Ok(())
+    }
+
+    async fn merge_into_empty_table(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        plan: &MergePlan,
+        mut merger: BlockMerger<'_>,
+        mut joined: SendableDataBlockStream,
+    ) -> Result<()> {
+        // no target rows, all the source rows are unmatched
+        let operator = ctx.get_storage_operator()?;
+        let column_ids = ColumnIds::from_schema(&self.table_info.schema())?;
+        let block_writer =
+            BlockWriter::new(ctx, &operator, &self.meta_location_generator, &column_ids);
+        let mut block_metas = vec![];
+        while let Some(block) = joined.try_next().await? {
+            let block = merger.merge_joined(block)?;
+            if block.num_rows() > 0 {
+                block_metas.push(block_writer.write(block).await?);
+            }
+        }
+        if block_metas.is_empty() {
+            return Ok(());
+        }
+
+        let summary = reduce_block_metas(&block_metas)?;
+        let segment = Arc::new(SegmentInfo::new(block_metas, summary));
+        let segment_location = self.meta_location_generator.gen_segment_info_location();
+        write_meta(&operator, &segment_location, segment.as_ref()).await?;
+        if let Some(cache) = ctx.get_storage_cache_manager().get_table_segment_cache() {
+            let cache = &mut cache.write().await;
+            cache.put(segment_location.clone(), segment.clone());
+        }
+
+        let log_entry = AppendOperationLogEntry::new(segment_location, segment);
+        self.do_commit(ctx.clone(), &plan.catalog_name, vec![log_entry], false)
+            .await
+    }
+}
@@ -12,18 +12,22 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+mod alter_column;
+mod analyze;
 mod append;
 mod commit;
 mod compact;
 mod delete;
 mod fuse_sink;
 mod gc;
+mod merge;
 mod mutation;
 mod navigate;
 mod operation_log;
 mod read;
 mod read_partitions;
 mod truncate;
+mod update;
 
 pub mod util;
 
@@ -0,0 +1,554 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp;
+use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::remove_nullable;
+use common_datavalues::BooleanColumn;
+use common_datavalues::ColumnRef;
+use common_datavalues::DataField;
+use common_datavalues::DataSchemaRef;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::DataValue;
+use common_datavalues::Series;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::MergeOperation;
+use common_planners::MergePlan;
+
+use crate::pipelines::processors::transforms::ExpressionExecutor;
+use crate::sessions::TableContext;
+
+/// Applies the clauses of a MERGE to the rows of the join of the target table and the
+/// source, and merges the results into the blocks of the target table.
+///
+/// The join is executed by a query, see `MergePlan`. A target row is identified by its
+/// values, the target rows with the same values are joined with the same source rows,
+/// so they are changed in the same way. The results of the matched target rows are kept
+/// in memory until the blocks of the table are merged, which are bounded by the setting
+/// `max_merge_source_bytes`. The source rows matching no target rows are returned to be
+/// inserted as soon as they are joined.
+pub struct BlockMerger<'a> {
+    ctx: &'a Arc<dyn TableContext>,
+    plan: &'a MergePlan,
+    table_schema: DataSchemaRef,
+    // the schema of the target blocks, with the columns renamed as in the plan
+    target_schema: DataSchemaRef,
+    joined_schema: DataSchemaRef,
+    // the actions of the matched clauses, `None` for DELETE
+    update_execs: Vec<Option<ExpressionExecutor>>,
+    // the serialized values of the matched target rows, and the results of them
+    matched: HashMap<Vec<u8>, MatchedRow>,
+    updated_blocks: Vec<DataBlock>,
+    // the min and max values of each target key in the matched rows
+    target_key_ranges: Vec<Option<(DataValue, DataValue)>>,
+    memory_size: usize,
+    max_memory_size: usize,
+}
+
+struct MatchedRow {
+    // the number of the joined rows of the target rows with the values
+    joined: usize,
+    // the number of the target rows with the values found in the table
+    targets: usize,
+    action: MatchedAction,
+}
+
+#[derive(Clone, Copy)]
+enum MatchedAction {
+    Keep,
+    Delete,
+    // the row of `updated_blocks` holding the new values
+    Update { block: u32, row: u32 },
+}
+
+impl<'a> BlockMerger<'a> {
+    pub fn try_create(
+        ctx: &'a Arc<dyn TableContext>,
+        plan: &'a MergePlan,
+        table_schema: DataSchemaRef,
+    ) -> Result<Self> {
+        let target_schema = DataSchemaRefExt::create(
+            table_schema
+                .fields()
+                .iter()
+                .zip(plan.target_columns.iter())
+                .map(|(field, name)| DataField::new(name, field.data_type().clone()))
+                .collect(),
+        );
+        let mut joined_fields = target_schema.fields().clone();
+        joined_fields.extend(plan.source_schema.fields().iter().cloned());
+        let joined_schema = DataSchemaRefExt::create(joined_fields);
+
+        let update_execs = plan
+            .matched_clauses
+            .iter()
+            .map(|clause| match &clause.operation {
+                MergeOperation::Update(update_list) => {
+                    // projects the updated columns to the new values, and keeps the others
+                    let exprs = table_schema
+                        .fields()
+                        .iter()
+                        .enumerate()
+                        .map(|(index, field)| {
+                            let value = match update_list.iter().find(|(idx, _)| *idx == index) {
+                                Some((_, value)) => value.clone(),
+                                None => Expression::Column(plan.target_columns[index].clone()),
+                            };
+                            Expression::Alias(field.name().to_string(), Box::new(value))
+                        })
+                        .collect::<Vec<_>>();
+                    ExpressionExecutor::try_create(
+                        ctx.clone(),
+                        "update expression executor (merge)",
+                        joined_schema.clone(),
+                        table_schema.clone(),
+                        exprs,
+                        true,
+                    )
+                    .map(Some)
+                }
+                MergeOperation::Delete => Ok(None),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let max_memory_size = ctx.get_settings().get_max_merge_source_bytes()? as usize;
+        Ok(Self {
+            ctx,
+            plan,
+            table_schema,
+            target_schema,
+            joined_schema,
+            update_execs,
+            matched: HashMap::new(),
+            updated_blocks: vec![],
+            target_key_ranges: vec![None; plan.target_keys.len()],
+            memory_size: 0,
+            max_memory_size,
+        })
+    }
+
+    /// Applies the clauses to a block of the joined rows, the results of the matched
+    /// target rows are recorded, and the rows to be inserted are returned.
+    pub fn merge_joined(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let num_target_columns = self.plan.target_columns.len();
+        let matched_column = block.column(num_target_columns);
+        let flags = (0..block.num_rows())
+            .map(|row| !matched_column.null_at(row))
+            .collect::<Vec<_>>();
+        let (matched_rows, unmatched_rows) = split_rows(&flags);
+
+        let source_columns = block.columns()[num_target_columns + 1..].to_vec();
+        let source = DataBlock::create(self.plan.source_schema.clone(), source_columns);
+        let inserted = if unmatched_rows.is_empty() {
+            DataBlock::empty_with_schema(self.table_schema.clone())
+        } else {
+            self.unmatched_block(&DataBlock::block_take_by_indices(&source, &unmatched_rows)?)?
+        };
+
+        if !matched_rows.is_empty() {
+            // the target side of the matched rows are not NULL, they are read in the
+            // types of the table
+            let mut columns = Vec::with_capacity(self.joined_schema.num_fields());
+            for (column, field) in block.columns()[..num_target_columns]
+                .iter()
+                .zip(self.table_schema.fields())
+            {
+                let column = column.convert_full_column();
+                if field.data_type().is_nullable() {
+                    columns.push(column);
+                } else {
+                    columns.push(Series::remove_nullable(&column));
+                }
+            }
+            columns.extend(source.columns().iter().cloned());
+            let joined = DataBlock::create(self.joined_schema.clone(), columns);
+            let joined = DataBlock::block_take_by_indices(&joined, &matched_rows)?;
+            self.merge_matched(&joined)?;
+        }
+
+        self.add_memory_size(inserted.memory_size())?;
+        Ok(inserted)
+    }
+
+    /// Whether none of the target rows match the source rows.
+    pub fn matches_nothing(&self) -> bool {
+        self.matched.is_empty()
+    }
+
+    /// The filter over the columns of the table which holds for all the matched target
+    /// rows, it bounds the target keys by the range of them in the matched rows and is
+    /// used to prune the blocks of the table.
+    pub fn pruning_filter(&self) -> Option<Expression> {
+        self.plan
+            .target_keys
+            .iter()
+            .zip(self.target_key_ranges.iter())
+            .filter_map(|(key, range)| {
+                let (min, max) = range.as_ref()?;
+                let name = match key {
+                    Expression::Column(name) => name,
+                    _ => return None,
+                };
+                let index = self.plan.target_columns.iter().position(|c| c == name)?;
+                let field = self.table_schema.field(index);
+                let data_type = remove_nullable(field.data_type());
+                let column = Expression::Column(field.name().clone());
+                let min = Expression::create_literal_with_type(min.clone(), data_type.clone());
+                let max = Expression::create_literal_with_type(max.clone(), data_type);
+                Some(column.gt_eq(min).and(column.lt_eq(max)))
+            })
+            .reduce(|a, b| a.and(b))
+    }
+
+    /// Merges the results of the matched target rows into a block of the target table.
+    ///
+    /// Returns `None` if none of the rows are changed, the block should be kept as it is.
+    /// Otherwise the new block is returned, in which the updated rows are placed after
+    /// the unchanged rows, and the deleted rows are removed.
+    pub fn merge_block(&mut self, block: DataBlock) -> Result<Option<DataBlock>> {
+        let keys = serialize_rows(block.columns(), block.num_rows());
+        let mut changed = vec![false; block.num_rows()];
+        let mut updated_rows: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+        for (row, key) in keys.iter().enumerate() {
+            if let Some(matched) = self.matched.get_mut(key) {
+                matched.targets += 1;
+                match matched.action {
+                    MatchedAction::Keep => {}
+                    MatchedAction::Delete => changed[row] = true,
+                    MatchedAction::Update { block, row: r } => {
+                        changed[row] = true;
+                        updated_rows.entry(block).or_default().push(r);
+                    }
+                }
+            }
+        }
+        if changed.iter().all(|c| !c) {
+            return Ok(None);
+        }
+
+        let (_, unchanged) = split_rows(&changed);
+        let mut blocks = vec![DataBlock::block_take_by_indices(&block, &unchanged)?];
+        for (updated_block, rows) in updated_rows {
+            blocks.push(DataBlock::block_take_by_indices(
+                &self.updated_blocks[updated_block as usize],
+                &rows,
+            )?);
+        }
+        Ok(Some(DataBlock::concat_blocks(&blocks)?))
+    }
+
+    /// Checks that every target row matches at most one source row, after all the
+    /// blocks which may hold the matched target rows are merged.
+    pub fn check_matches(&self) -> Result<()> {
+        if self
+            .matched
+            .values()
+            .any(|matched| matched.joined != matched.targets)
+        {
+            return Err(ErrorCode::BadArguments(
+                "MERGE INTO failed, a target row matches more than one source row",
+            ));
+        }
+        Ok(())
+    }
+
+    fn merge_matched(&mut self, joined: &DataBlock) -> Result<()> {
+        // the first clause whose condition holds decides the action of a joined row
+        let mut actions = vec![MatchedAction::Keep; joined.num_rows()];
+        if !self.plan.matched_clauses.is_empty() {
+            let clauses = assign_clauses(
+                self.ctx,
+                &self.joined_schema,
+                self.plan
+                    .matched_clauses
+                    .iter()
+                    .map(|c| c.selection.as_ref()),
+                joined,
+            )?;
+            let mut updated_blocks = vec![];
+            for (clause_idx, exec) in self.update_execs.iter().enumerate() {
+                let rows = rows_of_clause(&clauses, clause_idx);
+                if rows.is_empty() {
+                    continue;
+                }
+                match exec {
+                    Some(exec) => {
+                        let updated = DataBlock::block_take_by_indices(joined, &rows)?;
+                        let block = (self.updated_blocks.len() + updated_blocks.len()) as u32;
+                        for (i, row) in rows.iter().enumerate() {
+                            actions[*row as usize] = MatchedAction::Update {
+                                block,
+                                row: i as u32,
+                            };
+                        }
+                        updated_blocks.push(exec.execute(&updated)?);
+                    }
+                    None => {
+                        for row in rows {
+                            actions[row as usize] = MatchedAction::Delete;
+                        }
+                    }
+                }
+            }
+            for updated in updated_blocks {
+                self.add_memory_size(updated.memory_size())?;
+                self.updated_blocks.push(updated);
+            }
+        }
+
+        let num_target_columns = self.target_schema.num_fields();
+        let target_columns = &joined.columns()[..num_target_columns];
+        let target = DataBlock::create(self.target_schema.clone(), target_columns.to_vec());
+        if !self.plan.target_keys.is_empty() {
+            let key_columns = eval_key_columns(self.ctx, &self.plan.target_keys, &target)?;
+            for (range, column) in self.target_key_ranges.iter_mut().zip(key_columns.iter()) {
+                *range = merge_ranges(range.take(), value_range(column));
+            }
+        }
+
+        let keys = serialize_rows(target.columns(), target.num_rows());
+        for (key, action) in keys.into_iter().zip(actions) {
+            let key_size = key.len();
+            let matched = match self.matched.entry(key) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    self.memory_size += key_size;
+                    entry.insert(MatchedRow {
+                        joined: 0,
+                        targets: 0,
+                        action,
+                    })
+                }
+            };
+            matched.joined += 1;
+        }
+        self.add_memory_size(0)
+    }
+
+    /// Returns the rows to be inserted, which are built from the source rows that
+    /// do not match any of the target rows.
+    fn unmatched_block(&self, unmatched: &DataBlock) -> Result<DataBlock> {
+        if self.plan.unmatched_clauses.is_empty() {
+            return Ok(DataBlock::empty_with_schema(self.table_schema.clone()));
+        }
+
+        let actions = assign_clauses(
+            self.ctx,
+            &self.plan.source_schema,
+            self.plan
+                .unmatched_clauses
+                .iter()
+                .map(|c| c.selection.as_ref()),
+            unmatched,
+        )?;
+
+        let mut blocks = vec![DataBlock::empty_with_schema(self.table_schema.clone())];
+        for (clause_idx, clause) in self.plan.unmatched_clauses.iter().enumerate() {
+            let rows = rows_of_clause(&actions, clause_idx);
+            if rows.is_empty() {
+                continue;
+            }
+            let exprs = self
+                .table_schema
+                .fields()
+                .iter()
+                .zip(clause.values.iter())
+                .map(|(field, value)| {
+                    Expression::Alias(field.name().to_string(), Box::new(value.clone()))
+                })
+                .collect::<Vec<_>>();
+            let insert_exec = ExpressionExecutor::try_create(
+                self.ctx.clone(),
+                "insert expression executor (merge)",
+                self.plan.source_schema.clone(),
+                self.table_schema.clone(),
+                exprs,
+                true,
+            )?;
+            let inserted = DataBlock::block_take_by_indices(unmatched, &rows)?;
+            blocks.push(insert_exec.execute(&inserted)?);
+        }
+        DataBlock::concat_blocks(&blocks)
+    }
+
+    fn add_memory_size(&mut self, size: usize) -> Result<()> {
+        self.memory_size += size;
+        if self.memory_size > self.max_memory_size {
+            return Err(ErrorCode::BadArguments(format!(
+                "MERGE INTO failed, the rows being merged take more than {} bytes, which is limited by the setting max_merge_source_bytes",
+                self.max_memory_size
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn eval_key_columns(
+    ctx: &Arc<dyn TableContext>,
+    keys: &[Expression],
+    block: &DataBlock,
+) -> Result<Vec<ColumnRef>> {
+    let schema = block.schema();
+    let key_fields = keys
+        .iter()
+        .map(|key| key.to_data_field(schema))
+        .collect::<Result<Vec<_>>>()?;
+    let key_exec = ExpressionExecutor::try_create(
+        ctx.clone(),
+        "join key expression executor (merge)",
+        schema.clone(),
+        DataSchemaRefExt::create(key_fields),
+        keys.to_vec(),
+        false,
+    )?;
+    let key_block = key_exec.execute(block)?;
+    Ok(key_block
+        .columns()
+        .iter()
+        .map(|column| column.convert_full_column())
+        .collect())
+}
+
+/// Serializes the values of the rows, so that the rows with the same values are the same.
+fn serialize_rows(columns: &[ColumnRef], rows: usize) -> Vec<Vec<u8>> {
+    let columns = columns
+        .iter()
+        .map(|column| {
+            let column = column.convert_full_column();
+            (Series::remove_nullable(&column), column)
+        })
+        .collect::<Vec<_>>();
+    let mut keys = Vec::with_capacity(rows);
+    for row in 0..rows {
+        let mut key = vec![];
+        for (values, column) in columns.iter() {
+            if column.null_at(row) {
+                key.push(0);
+            } else {
+                key.push(1);
+                values.serialize(&mut key, row);
+            }
+        }
+        keys.push(key);
+    }
+    keys
+}
+
+/// The min and max of the non-NULL values of the column.
+fn value_range(column: &ColumnRef) -> Option<(DataValue, DataValue)> {
+    let mut range: Option<(DataValue, DataValue)> = None;
+    for row in (0..column.len()).filter(|row| !column.null_at(*row)) {
+        let value = column.get(row);
+        range = match range {
+            None => Some((value.clone(), value)),
+            Some((min, max)) => Some((cmp::min(min, value.clone()), cmp::max(max, value))),
+        };
+    }
+    range
+}
+
+fn merge_ranges(
+    left: Option<(DataValue, DataValue)>,
+    right: Option<(DataValue, DataValue)>,
+) -> Option<(DataValue, DataValue)> {
+    match (left, right) {
+        (Some((min1, max1)), Some((min2, max2))) => {
+            Some((cmp::min(min1, min2), cmp::max(max1, max2)))
+        }
+        (left, right) => left.or(right),
+    }
+}
+
+/// Evaluates the predicate over the rows of the block, NULL is regarded as false.
+fn eval_predicate(
+    ctx: &Arc<dyn TableContext>,
+    schema: &DataSchemaRef,
+    expr: &Expression,
+    block: &DataBlock,
+) -> Result<Vec<bool>> {
+    let expr_field = expr.to_data_field(schema)?;
+    let expr_schema = DataSchemaRefExt::create(vec![expr_field]);
+    let expr_exec = ExpressionExecutor::try_create(
+        ctx.clone(),
+        "filter expression executor (merge)",
+        schema.clone(),
+        expr_schema,
+        vec![expr.clone()],
+        false,
+    )?;
+    let filter_result = expr_exec.execute(block)?;
+
+    let predicates = DataBlock::cast_to_nonull_boolean(filter_result.column(0))?;
+    if let Some(const_bool) = DataBlock::try_as_const_bool(&predicates)? {
+        return Ok(vec![const_bool; block.num_rows()]);
+    }
+    let boolean_col: &BooleanColumn = Series::check_get(&predicates)?;
+    Ok(boolean_col.values().iter().collect())
+}
+
+/// Returns the index of the first clause that applies to each row, the clauses
+/// without condition apply to all the rows.
+fn assign_clauses<'b>(
+    ctx: &Arc<dyn TableContext>,
+    schema: &DataSchemaRef,
+    selections: impl Iterator<Item = Option<&'b Expression>>,
+    block: &DataBlock,
+) -> Result<Vec<Option<usize>>> {
+    let mut actions = vec![None; block.num_rows()];
+    for (clause_idx, selection) in selections.enumerate() {
+        if actions.iter().all(|action| action.is_some()) {
+            break;
+        }
+        let values = match selection {
+            Some(expr) => eval_predicate(ctx, schema, expr, block)?,
+            None => vec![true; block.num_rows()],
+        };
+        for (action, value) in actions.iter_mut().zip(values) {
+            if action.is_none() && value {
+                *action = Some(clause_idx);
+            }
+        }
+    }
+    Ok(actions)
+}
+
+fn rows_of_clause(actions: &[Option<usize>], clause_idx: usize) -> Vec<u32> {
+    actions
+        .iter()
+        .enumerate()
+        .filter(|(_, action)| **action == Some(clause_idx))
+        .map(|(row, _)| row as u32)
+        .collect()
+}
+
+/// Splits the rows into the ones being set and the others.
+fn split_rows(flags: &[bool]) -> (Vec<u32>, Vec<u32>) {
+    let mut set = vec![];
+    let mut unset = vec![];
+    for (row, flag) in flags.iter().enumerate() {
+        if *flag {
+            set.push(row as u32);
+        } else {
+            unset.push(row as u32);
+        }
+    }
+    (set, unset)
+}
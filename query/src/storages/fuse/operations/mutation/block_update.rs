@@ -0,0 +1,91 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::ops::Not;
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::BooleanColumn;
+use common_datavalues::DataSchemaRefExt;
+use common_datavalues::Series;
+use common_exception::Result;
+use common_fuse_meta::meta::BlockMeta;
+use common_planners::Expression;
+
+use crate::pipelines::processors::transforms::ExpressionExecutor;
+use crate::sessions::TableContext;
+use crate::storages::fuse::operations::mutation::block_filter::all_the_columns_ids;
+use crate::storages::fuse::FuseTable;
+
+/// Applies `update_exec` to the rows of the block that match `filter_expr`.
+///
+/// Returns `None` if none of the rows are matched, the block should be kept as it is.
+/// Otherwise the new block is returned, in which the updated rows are placed after
+/// the unchanged rows.
+pub async fn update_from_block(
+    table: &FuseTable,
+    block_meta: &BlockMeta,
+    ctx: &Arc<dyn TableContext>,
+    filter_expr: Option<&Expression>,
+    update_exec: &ExpressionExecutor,
+) -> Result<Option<DataBlock>> {
+    let reader = table.create_block_reader(ctx, all_the_columns_ids(table))?;
+    let data_block = reader.read_with_block_meta(block_meta).await?;
+
+    let filter_expr = match filter_expr {
+        None => return Ok(Some(update_exec.execute(&data_block)?)),
+        Some(expr) => expr,
+    };
+
+    let schema = table.table_info.schema();
+    let expr_field = filter_expr.to_data_field(&schema)?;
+    let expr_schema = DataSchemaRefExt::create(vec![expr_field]);
+    let expr_exec = ExpressionExecutor::try_create(
+        ctx.clone(),
+        "filter expression executor (update) ",
+        schema,
+        expr_schema,
+        vec![filter_expr.clone()],
+        false,
+    )?;
+    let filter_result = expr_exec.execute(&data_block)?;
+
+    let predicates = DataBlock::cast_to_nonull_boolean(filter_result.column(0))?;
+    // shortcut, if predicates is const boolean (or can be cast to boolean)
+    if let Some(const_bool) = DataBlock::try_as_const_bool(&predicates)? {
+        return if const_bool {
+            // all the rows should be updated
+            Ok(Some(update_exec.execute(&data_block)?))
+        } else {
+            Ok(None)
+        };
+    }
+
+    let boolean_col: &BooleanColumn = Series::check_get(&predicates)?;
+    let values = boolean_col.values();
+    if values.unset_bits() == values.len() {
+        // false positive of pruning, nothing to update
+        return Ok(None);
+    }
+
+    let unchanged = BooleanColumn::from_arrow_data(values.not());
+    let unchanged_block = DataBlock::filter_block_with_bool_column(data_block.clone(), &unchanged)?;
+    let matched_block = DataBlock::filter_block_with_bool_column(data_block, boolean_col)?;
+    let updated_block = update_exec.execute(&matched_block)?;
+
+    Ok(Some(DataBlock::concat_blocks(&[
+        unchanged_block,
+        updated_block,
+    ])?))
+}
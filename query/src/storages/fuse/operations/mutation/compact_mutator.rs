@@ -30,6 +30,7 @@ use crate::storages::fuse::io::SegmentWriter;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::statistics::reducers::reduce_block_metas;
 use crate::storages::fuse::statistics::reducers::reduce_statistics;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::fuse::FuseTable;
 
 pub struct CompactMutator<'a> {
@@ -37,6 +38,7 @@ pub struct CompactMutator<'a> {
     location_generator: &'a TableMetaLocationGenerator,
     base_snapshot: &'a TableSnapshot,
     data_accessor: Operator,
+    column_ids: ColumnIds,
     row_per_block: usize,
     block_per_seg: usize,
 }
@@ -50,11 +52,13 @@ impl<'a> CompactMutator<'a> {
         block_per_seg: usize,
     ) -> Result<Self> {
         let data_accessor = ctx.get_storage_operator()?;
+        let column_ids = ColumnIds::from_schema(&base_snapshot.schema)?;
         Ok(Self {
             ctx,
             location_generator,
             base_snapshot,
             data_accessor,
+            column_ids,
             row_per_block,
             block_per_seg,
         })
@@ -98,7 +102,12 @@ impl<'a> CompactMutator<'a> {
         // Compact the blocks.
         let col_ids = all_the_columns_ids(table);
         let mut compactor = BlockCompactor::new(self.row_per_block);
-        let block_writer = BlockWriter::new(self.ctx, &self.data_accessor, self.location_generator);
+        let block_writer = BlockWriter::new(
+            self.ctx,
+            &self.data_accessor,
+            self.location_generator,
+            &self.column_ids,
+        );
         for block_meta in &merged_blocks {
             let block_reader = table.create_block_reader(self.ctx, col_ids.clone())?;
             let data_block = block_reader.read_with_block_meta(block_meta).await?;
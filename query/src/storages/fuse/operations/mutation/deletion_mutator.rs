@@ -33,6 +33,7 @@ use crate::storages::fuse::io::SegmentWriter;
 use crate::storages::fuse::io::TableMetaLocationGenerator;
 use crate::storages::fuse::statistics::reducers::reduce_block_metas;
 use crate::storages::fuse::statistics::reducers::reduce_statistics;
+use crate::storages::fuse::ColumnIds;
 
 pub enum Deletion {
     NothingDeleted,
@@ -52,6 +53,8 @@ pub struct DeletionMutator<'a> {
     location_generator: &'a TableMetaLocationGenerator,
     base_snapshot: &'a TableSnapshot,
     data_accessor: Operator,
+    column_ids: ColumnIds,
+    appended_blocks: Vec<BlockMeta>,
 }
 
 impl<'a> DeletionMutator<'a> {
@@ -61,12 +64,15 @@ impl<'a> DeletionMutator<'a> {
         base_snapshot: &'a TableSnapshot,
     ) -> Result<Self> {
         let data_accessor = ctx.get_storage_operator()?;
+        let column_ids = ColumnIds::from_schema(&base_snapshot.schema)?;
         Ok(Self {
             mutations: HashMap::new(),
             ctx,
             location_generator,
             base_snapshot,
             data_accessor,
+            column_ids,
+            appended_blocks: vec![],
         })
     }
 
@@ -152,6 +158,14 @@ impl<'a> DeletionMutator<'a> {
         // assign back the mutated segments to snapshot
         new_snapshot.segments = segments_editor.into_values().collect();
 
+        // the appended blocks go to a new segment, which is placed ahead of the others
+        if !self.appended_blocks.is_empty() {
+            let new_summary = reduce_block_metas(&self.appended_blocks)?;
+            let new_segment = SegmentInfo::new(self.appended_blocks, new_summary);
+            let new_segment_location = seg_writer.write_segment(new_segment).await?;
+            new_snapshot.segments.insert(0, new_segment_location);
+        }
+
         let mut new_segment_summaries = Vec::with_capacity(new_snapshot.segments.len());
         for (loc, ver) in &new_snapshot.segments {
             let seg = segment_reader.read(loc, None, *ver).await?;
@@ -183,8 +197,12 @@ impl<'a> DeletionMutator<'a> {
         let new_block_meta = if replace_with.num_rows() == 0 {
             None
         } else {
-            let block_writer =
-                BlockWriter::new(self.ctx, &self.data_accessor, self.location_generator);
+            let block_writer = BlockWriter::new(
+                self.ctx,
+                &self.data_accessor,
+                self.location_generator,
+                &self.column_ids,
+            );
             Some(block_writer.write(replace_with).await?)
         };
         let original_block_loc = location_of_block_to_be_replaced;
@@ -197,4 +215,20 @@ impl<'a> DeletionMutator<'a> {
             });
         Ok(())
     }
+
+    /// Records the block being appended, it is written to a new segment
+    /// by `into_new_snapshot`.
+    pub async fn append(&mut self, block: DataBlock) -> Result<()> {
+        if block.num_rows() == 0 {
+            return Ok(());
+        }
+        let block_writer = BlockWriter::new(
+            self.ctx,
+            &self.data_accessor,
+            self.location_generator,
+            &self.column_ids,
+        );
+        self.appended_blocks.push(block_writer.write(block).await?);
+        Ok(())
+    }
 }
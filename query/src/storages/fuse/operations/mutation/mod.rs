@@ -13,9 +13,13 @@
 //  limitations under the License.
 
 pub mod block_filter;
+pub mod block_merge;
+pub mod block_update;
 pub mod compact_mutator;
 pub mod deletion_mutator;
 
 pub use block_filter::delete_from_block;
+pub use block_merge::BlockMerger;
+pub use block_update::update_from_block;
 pub use compact_mutator::CompactMutator;
 pub use deletion_mutator::DeletionMutator;
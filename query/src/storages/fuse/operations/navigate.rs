@@ -33,6 +33,15 @@ impl FuseTable {
         ctx: &dyn TableContext,
         time_point: DateTime<Utc>,
     ) -> Result<Arc<FuseTable>> {
+        if let Some(retention_point) = self.data_retention_point()? {
+            if time_point < retention_point {
+                return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                    "The time point {} is beyond the data retention period of table {}, the earliest time point can be traveled to is {}",
+                    time_point, self.table_info.name, retention_point
+                )));
+            }
+        }
+
         self.find(ctx, |snapshot| {
             if let Some(ts) = snapshot.timestamp {
                 ts <= time_point
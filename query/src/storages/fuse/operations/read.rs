@@ -43,7 +43,8 @@ impl FuseTable {
     ) -> Result<Arc<BlockReader>> {
         let operator = ctx.get_storage_operator()?;
         let table_schema = self.table_info.schema();
-        BlockReader::create(operator, table_schema, projection)
+        let disk_cache = ctx.get_storage_cache_manager().get_column_data_disk_cache();
+        BlockReader::create_with_disk_cache(operator, table_schema, projection, disk_cache)
     }
 
     pub fn projection_of_push_downs(&self, push_downs: &Option<Extras>) -> Vec<usize> {
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
+use common_fuse_meta::meta::ColumnId;
 use common_fuse_meta::meta::TableSnapshot;
 use common_planners::Extras;
 use common_planners::PartInfoPtr;
@@ -30,6 +31,7 @@ use crate::storages::fuse::fuse_part::ColumnLeaf;
 use crate::storages::fuse::fuse_part::ColumnMeta;
 use crate::storages::fuse::fuse_part::FusePartInfo;
 use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::fuse::FuseTable;
 
 impl FuseTable {
@@ -50,23 +52,23 @@ impl FuseTable {
                 let arrow_schema = schema.to_arrow();
                 let parquet_schema_descriptor = to_parquet_schema(&arrow_schema)?;
                 let column_leaves = build_column_leaves(&parquet_schema_descriptor);
+                let leaf_ids = ColumnIds::from_schema(&schema)?.leaf_ids();
 
-                let block_metas = BlockPruner::new(snapshot.clone())
-                    .prune(&ctx, schema, &push_downs)
-                    .await?
-                    .into_iter()
-                    .map(|(_, v)| v)
-                    .collect::<Vec<_>>();
+                let (block_metas, pruning_stats) = BlockPruner::new(snapshot.clone())
+                    .prune_with_statistics(&ctx, schema, &push_downs)
+                    .await?;
+                let block_metas = block_metas.into_iter().map(|(_, v)| v).collect::<Vec<_>>();
 
                 let partitions_scanned = block_metas.len();
                 let partitions_total = snapshot.summary.block_count as usize;
 
                 let (mut statistics, parts) =
-                    Self::to_partitions(&block_metas, &column_leaves, push_downs);
+                    Self::to_partitions(&block_metas, &column_leaves, &leaf_ids, push_downs);
 
                 // Update planner statistics.
                 statistics.partitions_total = partitions_total;
                 statistics.partitions_scanned = partitions_scanned;
+                statistics.pruning = Some(pruning_stats);
 
                 // Update context statistics.
                 ctx.get_dal_context()
@@ -82,9 +84,12 @@ impl FuseTable {
         }
     }
 
+    /// `leaf_ids` are the ids of the leaf columns, by which the column metas of the blocks
+    /// are keyed, indexed by the positions of the leaves.
     pub fn to_partitions(
         blocks_metas: &[BlockMeta],
         column_leaves: &[ColumnLeaf],
+        leaf_ids: &[ColumnId],
         push_down: Option<Extras>,
     ) -> (Statistics, Partitions) {
         let limit = push_down
@@ -93,14 +98,32 @@ impl FuseTable {
             .and_then(|p| p.limit)
             .unwrap_or(usize::MAX);
 
-        let (mut statistics, partitions) = match &push_down {
-            None => Self::all_columns_partitions(blocks_metas, limit),
-            Some(extras) => match &extras.projection {
-                None => Self::all_columns_partitions(blocks_metas, limit),
-                Some(projection) => {
-                    Self::projection_partitions(blocks_metas, column_leaves, projection, limit)
-                }
-            },
+        let projection = push_down
+            .as_ref()
+            .and_then(|extras| extras.projection.clone());
+        let is_positional = leaf_ids
+            .iter()
+            .enumerate()
+            .all(|(index, id)| *id as usize == index);
+        let (mut statistics, partitions) = match projection {
+            None if is_positional => Self::all_columns_partitions(blocks_metas, limit),
+            None => {
+                let projection = (0..column_leaves.len()).collect::<Vec<_>>();
+                Self::projection_partitions(
+                    blocks_metas,
+                    column_leaves,
+                    leaf_ids,
+                    &projection,
+                    limit,
+                )
+            }
+            Some(projection) => Self::projection_partitions(
+                blocks_metas,
+                column_leaves,
+                leaf_ids,
+                &projection,
+                limit,
+            ),
         };
 
         statistics.is_exact = statistics.is_exact && Self::is_exact(&push_down);
@@ -150,6 +173,7 @@ impl FuseTable {
     fn projection_partitions(
         metas: &[BlockMeta],
         column_leaves: &[ColumnLeaf],
+        leaf_ids: &[ColumnId],
         projections: &[usize],
         limit: usize,
     ) -> (Statistics, Partitions) {
@@ -166,6 +190,7 @@ impl FuseTable {
             partitions.push(Self::projection_part(
                 block_meta,
                 column_leaves,
+                leaf_ids,
                 projections,
             ));
 
@@ -176,8 +201,9 @@ impl FuseTable {
                 let column_leaf = &column_leaves[*projection];
                 let indices = &column_leaf.leaf_ids;
                 for index in indices {
-                    let col_metas = &block_meta.col_metas[&(*index as u32)];
-                    statistics.read_bytes += col_metas.len as usize;
+                    if let Some(col_metas) = block_meta.col_metas.get(&leaf_ids[*index]) {
+                        statistics.read_bytes += col_metas.len as usize;
+                    }
                 }
             }
 
@@ -219,6 +245,7 @@ impl FuseTable {
     fn projection_part(
         meta: &BlockMeta,
         column_leaves: &[ColumnLeaf],
+        leaf_ids: &[ColumnId],
         projections: &[usize],
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projections.len());
@@ -227,7 +254,11 @@ impl FuseTable {
             let column_leaf = &column_leaves[*projection];
             let indices = &column_leaf.leaf_ids;
             for index in indices {
-                let column_meta = &meta.col_metas[&(*index as u32)];
+                // The column is added after the block was written.
+                let column_meta = match meta.col_metas.get(&leaf_ids[*index]) {
+                    None => continue,
+                    Some(column_meta) => column_meta,
+                };
 
                 columns_meta.insert(
                     *index,
@@ -269,6 +300,7 @@ impl FuseTable {
                     partitions_scanned: 0,
                     partitions_total: summary.block_count as usize,
                     is_exact: true,
+                    pruning: None,
                 };
                 Some((stats, vec![]))
             }
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::TableSnapshot;
 use common_fuse_meta::meta::Versioned;
@@ -34,6 +35,13 @@ impl FuseTable {
         purge: bool,
         catalog_name: &str,
     ) -> Result<()> {
+        // purge removes the snapshots a rollback of the transaction relies on
+        if purge && ctx.get_txn_manager().is_active() {
+            return Err(ErrorCode::BadArguments(
+                "TRUNCATE TABLE ... PURGE is not allowed in a transaction",
+            ));
+        }
+
         if let Some(prev_snapshot) = self.read_table_snapshot(ctx.as_ref()).await? {
             let prev_id = prev_snapshot.snapshot_id;
 
@@ -67,6 +75,15 @@ impl FuseTable {
             // update table statistics, all zeros
             new_table_meta.statistics = TableStatistics::default();
 
+            if Self::buffer_in_txn(
+                ctx.as_ref(),
+                catalog_name,
+                &self.table_info,
+                &new_table_meta,
+            )? {
+                return Ok(());
+            }
+
             let table_id = self.table_info.ident.table_id;
             let table_version = self.table_info.ident.seq;
             ctx.get_catalog(catalog_name)?
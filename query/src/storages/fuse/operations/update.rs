@@ -0,0 +1,96 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_planners::Expression;
+use common_planners::Extras;
+use common_planners::UpdatePlan;
+
+use crate::pipelines::processors::transforms::ExpressionExecutor;
+use crate::sessions::TableContext;
+use crate::storages::fuse::operations::mutation::update_from_block;
+use crate::storages::fuse::operations::mutation::DeletionMutator;
+use crate::storages::fuse::pruning::BlockPruner;
+use crate::storages::fuse::FuseTable;
+use crate::storages::Table;
+
+impl FuseTable {
+    pub async fn do_update(&self, ctx: Arc<dyn TableContext>, plan: &UpdatePlan) -> Result<()> {
+        let snapshot = match self.read_table_snapshot(ctx.as_ref()).await? {
+            Some(snapshot) if snapshot.summary.row_count != 0 => snapshot,
+            // empty table, nothing to update
+            _ => return Ok(()),
+        };
+
+        // the executor projects the updated columns to the new values, and keeps the others
+        let schema = self.table_info.schema();
+        let exprs = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(
+                |(index, field)| match plan.update_list.iter().find(|(idx, _)| *idx == index) {
+                    Some((_, value)) => {
+                        Expression::Alias(field.name().to_string(), Box::new(value.clone()))
+                    }
+                    None => Expression::Column(field.name().to_string()),
+                },
+            )
+            .collect::<Vec<_>>();
+        let update_exec = ExpressionExecutor::try_create(
+            ctx.clone(),
+            "update expression executor",
+            schema.clone(),
+            schema.clone(),
+            exprs,
+            true,
+        )?;
+
+        let push_downs = Some(Extras {
+            projection: None,
+            filters: plan.selection.iter().cloned().collect(),
+            limit: None,
+            order_by: vec![],
+        });
+        let block_metas = BlockPruner::new(snapshot.clone())
+            .prune(&ctx, schema, &push_downs)
+            .await?;
+
+        let mut mutator =
+            DeletionMutator::try_create(&ctx, &self.meta_location_generator, &snapshot)?;
+        for (seg_idx, block_meta) in block_metas {
+            let filter = plan.selection.as_ref();
+            if let Some(block) =
+                update_from_block(self, &block_meta, &ctx, filter, &update_exec).await?
+            {
+                mutator
+                    .replace_with(seg_idx, block_meta.location.clone(), block)
+                    .await?;
+            }
+        }
+
+        let (new_snapshot, loc) = mutator.into_new_snapshot().await?;
+        Self::commit_to_meta_server(
+            ctx.as_ref(),
+            &plan.catalog_name,
+            self.get_table_info(),
+            loc,
+            &new_snapshot.summary,
+        )
+        .await?;
+        Ok(())
+    }
+}
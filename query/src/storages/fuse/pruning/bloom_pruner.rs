@@ -25,6 +25,7 @@ use common_planners::Recursion;
 use opendal::Operator;
 
 use crate::storages::fuse::io::BlockBloomFilterIndexReader;
+use crate::storages::fuse::ColumnIds;
 use crate::storages::index::BloomFilterIndexer;
 
 #[async_trait::async_trait]
@@ -115,7 +116,11 @@ pub fn new_bloom_filter_pruner(
     if let Some(expr) = filter_expr {
         // check if there were applicable filter conditions
         let point_query_cols = columns_names_of_eq_expressions(expr)?;
-        if !point_query_cols.is_empty() {
+        // the bloom filter indexes of the existing blocks may not match the columns, which
+        // have been dropped or renamed
+        let column_ids = ColumnIds::from_schema(schema)?;
+        let is_indexed = point_query_cols.iter().all(|c| column_ids.is_indexed(c));
+        if !point_query_cols.is_empty() && is_indexed {
             // convert to bloom filter block's column names
             let filter_block_cols = point_query_cols
                 .into_iter()
@@ -161,6 +166,19 @@ mod util {
         columns: HashSet<String>,
     }
 
+    impl PointQueryVisitor {
+        // the arguments of map functions like 'map_contains(map_column, key)'
+        fn visit_map_key_args(mut self, args: &[Expression]) -> Result<Recursion<Self>> {
+            match args {
+                [Expression::Column(column), Expression::Literal { .. }] => {
+                    self.columns.insert(column.clone());
+                    Ok(Recursion::Stop(self))
+                }
+                _ => Ok(Recursion::Continue(self)),
+            }
+        }
+    }
+
     impl ExpressionVisitor for PointQueryVisitor {
         fn pre_visit(mut self, expr: &Expression) -> Result<Recursion<Self>> {
             // TODO
@@ -174,9 +192,21 @@ mod util {
                             self.columns.insert(column.clone());
                             Ok(Recursion::Stop(self))
                         }
+                        // map_column[key] = literal
+                        (Expression::ScalarFunction { op, args }, Expression::Literal { .. })
+                        | (Expression::Literal { .. }, Expression::ScalarFunction { op, args })
+                            if op.eq_ignore_ascii_case("get") =>
+                        {
+                            self.visit_map_key_args(args)
+                        }
                         _ => Ok(Recursion::Continue(self)),
                     }
                 }
+                Expression::ScalarFunction { op, args }
+                    if op.eq_ignore_ascii_case("map_contains") =>
+                {
+                    self.visit_map_key_args(args)
+                }
                 _ => Ok(Recursion::Continue(self)),
             }
         }
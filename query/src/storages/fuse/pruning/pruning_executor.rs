@@ -21,8 +21,10 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_fuse_meta::meta::BlockMeta;
 use common_fuse_meta::meta::Location;
+use common_fuse_meta::meta::StatisticsOfColumns;
 use common_fuse_meta::meta::TableSnapshot;
 use common_planners::Extras;
+use common_planners::PruningStatistics;
 use futures::future;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -33,6 +35,7 @@ use crate::sessions::TableContext;
 use crate::storages::fuse::io::MetaReaders;
 use crate::storages::fuse::pruning::limiter;
 use crate::storages::fuse::pruning::range_pruner;
+use crate::storages::fuse::ColumnIds;
 
 pub struct BlockPruner {
     table_snapshot: Arc<TableSnapshot>,
@@ -46,17 +49,29 @@ impl BlockPruner {
     }
 
     // prune blocks by utilizing min_max index and bloom filter, according to the pushdowns
-    #[tracing::instrument(level = "debug", skip(self, schema, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     pub async fn prune(
         &self,
         ctx: &Arc<dyn TableContext>,
         schema: DataSchemaRef,
         push_down: &Option<Extras>,
     ) -> Result<Vec<(usize, BlockMeta)>> {
+        let (block_metas, _) = self.prune_with_statistics(ctx, schema, push_down).await?;
+        Ok(block_metas)
+    }
+
+    // same as `prune`, also returns the number of the segments and blocks before and
+    // after each step of the pruning
+    #[tracing::instrument(level = "debug", skip(self, schema, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    pub async fn prune_with_statistics(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        schema: DataSchemaRef,
+        push_down: &Option<Extras>,
+    ) -> Result<(Vec<(usize, BlockMeta)>, PruningStatistics)> {
         let segment_locs = self.table_snapshot.segments.clone();
 
         if segment_locs.is_empty() {
-            return Ok(vec![]);
+            return Ok((vec![], PruningStatistics::default()));
         };
 
         // if there are ordering clause, ignore limit, even it has been pushed down
@@ -69,7 +84,18 @@ impl BlockPruner {
 
         // shortcut, just returns all the blocks
         if limit.is_none() && filter_expression.is_none() {
-            return Self::all_the_blocks(segment_locs, ctx.as_ref()).await;
+            let segments = segment_locs.len();
+            let block_metas = Self::all_the_blocks(segment_locs, ctx.as_ref()).await?;
+            let blocks = block_metas.len();
+            let stats = PruningStatistics {
+                segments_range_pruning_before: segments,
+                segments_range_pruning_after: segments,
+                blocks_range_pruning_before: blocks,
+                blocks_range_pruning_after: blocks,
+                blocks_bloom_pruning_before: blocks,
+                blocks_bloom_pruning_after: blocks,
+            };
+            return Ok((block_metas, stats));
         }
 
         // 1. prepare pruners
@@ -87,6 +113,12 @@ impl BlockPruner {
         let bloom_filter_pruner =
             bloom_pruner::new_bloom_filter_pruner(ctx, filter_expression, &schema, dal)?;
 
+        // the column statistics of an altered table are keyed by the column ids
+        let column_ids = match ColumnIds::is_altered(&schema) {
+            true => Some(Arc::new(ColumnIds::from_schema(&schema)?)),
+            false => None,
+        };
+
         // 2. kick off
         //
         // As suggested by Winter, to make the pruning process more parallel (not just concurrent),
@@ -109,27 +141,40 @@ impl BlockPruner {
             let range_filter_pruner = range_filter_pruner.clone();
             let bloom_filter_pruner = bloom_filter_pruner.clone();
             let limiter = limiter.clone();
+            let column_ids = column_ids.clone();
             let segment_pruning_fut = async move {
+                let should_keep = |stats: &StatisticsOfColumns| match &column_ids {
+                    None => range_filter_pruner.should_keep(stats),
+                    Some(ids) => range_filter_pruner.should_keep(&ids.statistics_by_index(stats)),
+                };
+
                 let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+                let mut stats = PruningStatistics::default();
                 if limiter.exceeded() {
                     // before read segment info, check if limit already exceeded
-                    return Ok(vec![]);
+                    return Ok((vec![], stats));
                 }
                 let segment_info = segment_reader.read(seg_loc, None, ver).await?;
                 let mut result = Vec::with_capacity(segment_info.blocks.len());
-                if range_filter_pruner.should_keep(&segment_info.summary.col_stats) {
+                stats.segments_range_pruning_before = 1;
+                if should_keep(&segment_info.summary.col_stats) {
+                    stats.segments_range_pruning_after = 1;
                     for block_meta in &segment_info.blocks {
                         // prune block using range filter
                         if limiter.exceeded() {
                             // before using bloom index to prune, check if limit already exceeded
-                            return Ok(result);
+                            return Ok((result, stats));
                         }
-                        if range_filter_pruner.should_keep(&block_meta.col_stats) {
+                        stats.blocks_range_pruning_before += 1;
+                        if should_keep(&block_meta.col_stats) {
+                            stats.blocks_range_pruning_after += 1;
+                            stats.blocks_bloom_pruning_before += 1;
                             // prune block using bloom filter
                             if bloom_filter_pruner
                                 .should_keep(&block_meta.bloom_filter_index_location)
                                 .await
                             {
+                                stats.blocks_bloom_pruning_after += 1;
                                 if limiter.within_limit(block_meta.row_count) {
                                     result.push((idx, block_meta.clone()));
                                 } else {
@@ -139,7 +184,7 @@ impl BlockPruner {
                         }
                     }
                 }
-                Ok::<_, ErrorCode>(result)
+                Ok::<_, ErrorCode>((result, stats))
             }
             .instrument(tracing::debug_span!("filter_segment_with_storage_rt"));
             join_handlers.push(pruning_runtime.try_spawn(segment_pruning_fut)?);
@@ -151,13 +196,14 @@ impl BlockPruner {
 
         // 3. collect the result
         tracing::debug_span!("collect_result").in_scope(|| {
-            // flatten the collected block metas
-            let metas = joint
-                .into_iter()
-                .collect::<Result<Vec<_>>>()?
-                .into_iter()
-                .flatten();
-            Ok(metas.collect())
+            // flatten the collected block metas, and sum up the statistics
+            let mut metas = vec![];
+            let mut stats = PruningStatistics::default();
+            for (segment_metas, segment_stats) in joint.into_iter().collect::<Result<Vec<_>>>()? {
+                metas.extend(segment_metas);
+                stats.merge(&segment_stats);
+            }
+            Ok((metas, stats))
         })
     }
 
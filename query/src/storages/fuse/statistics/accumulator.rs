@@ -51,7 +51,7 @@ impl StatisticsAccumulator {
         bloom_filter_index_size: u64,
     ) -> Result<()> {
         let col_metas = column_metas(&file_meta)?;
-        self.add(
+        self.add_with_column_metas(
             file_size,
             col_metas,
             block_statistics,
@@ -70,7 +70,7 @@ impl StatisticsAccumulator {
         let file_size = block_meta.file_size;
         let col_metas = block_meta.col_metas;
 
-        self.add(
+        self.add_with_column_metas(
             file_size,
             col_metas,
             block_statistics,
@@ -83,7 +83,7 @@ impl StatisticsAccumulator {
         super::reduce_block_statistics(&self.blocks_statistics)
     }
 
-    fn add(
+    pub fn add_with_column_metas(
         &mut self,
         file_size: u64,
         column_meta: HashMap<u32, ColumnMeta>,
@@ -0,0 +1,228 @@
+//  Copyright 2022 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::hash::Hasher;
+
+use common_datavalues::ColumnRef;
+use common_datavalues::DataValue;
+use common_fuse_meta::meta::ColumnDistribution;
+use common_fuse_meta::meta::Histogram;
+use common_fuse_meta::meta::HistogramBucket;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use twox_hash::XxHash64;
+
+/// There are 2^HLL_PRECISION registers in a HyperLogLog sketch, the standard error of
+/// the estimation is 1.04 / sqrt(2^HLL_PRECISION), i.e. about 0.8%.
+const HLL_PRECISION: u32 = 14;
+
+/// Max number of the buckets of a histogram.
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
+
+/// Max number of the values sampled to build a histogram.
+const HISTOGRAM_SAMPLE_SIZE: usize = 100_000;
+
+/// HyperLogLog sketch, to estimate the number of the distinct values.
+#[derive(Clone)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub fn new() -> Self {
+        HyperLogLog {
+            registers: vec![0; 1 << HLL_PRECISION],
+        }
+    }
+
+    pub fn add(&mut self, value: &DataValue) {
+        self.add_hash(hash_value(value));
+    }
+
+    pub fn add_hash(&mut self, hash: u64) {
+        // the first bits choose the register, which keeps the max rank of the rest bits
+        let index = (hash >> (64 - HLL_PRECISION)) as usize;
+        let rank = ((hash << HLL_PRECISION) | (1 << (HLL_PRECISION - 1))).leading_zeros() + 1;
+        self.registers[index] = self.registers[index].max(rank as u8);
+    }
+
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        // small cardinalities are estimated by linear counting
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|register| **register == 0)
+            .count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn hash_value(value: &DataValue) -> u64 {
+    let mut hasher = XxHash64::default();
+    match value {
+        DataValue::Boolean(v) => hasher.write_u8(*v as u8),
+        DataValue::Int64(v) => hasher.write_i64(*v),
+        DataValue::UInt64(v) => hasher.write_u64(*v),
+        DataValue::Float64(v) => hasher.write_u64(v.to_bits()),
+        DataValue::String(v) => hasher.write(v),
+        DataValue::Int128(v) => hasher.write_i128(*v),
+        DataValue::Int256(v) => hasher.write(&v.to_le_bytes()),
+        other => hasher.write(format!("{:?}", other).as_bytes()),
+    }
+    hasher.finish()
+}
+
+/// Collects the distribution of a column block by block, the NULLs are ignored.
+///
+/// The number of the distinct values is estimated by a HyperLogLog sketch of all the
+/// values, while the histogram is built on a uniform sample of the values.
+pub struct ColumnDistributionCollector {
+    hll: HyperLogLog,
+    num_values: u64,
+    samples: Vec<DataValue>,
+    rng: StdRng,
+}
+
+impl ColumnDistributionCollector {
+    pub fn new() -> Self {
+        ColumnDistributionCollector {
+            hll: HyperLogLog::new(),
+            num_values: 0,
+            samples: vec![],
+            // a fixed seed makes the histograms reproducible
+            rng: StdRng::seed_from_u64(0),
+        }
+    }
+
+    pub fn add_column(&mut self, column: &ColumnRef) {
+        for row in 0..column.len() {
+            let value = column.get(row);
+            if value.is_null() {
+                continue;
+            }
+            self.hll.add(&value);
+
+            // reservoir sampling
+            if self.samples.len() < HISTOGRAM_SAMPLE_SIZE {
+                self.samples.push(value);
+            } else {
+                let index = self.rng.gen_range(0..=self.num_values) as usize;
+                if index < HISTOGRAM_SAMPLE_SIZE {
+                    self.samples[index] = value;
+                }
+            }
+            self.num_values += 1;
+        }
+    }
+
+    pub fn finish(mut self, max_buckets: usize) -> ColumnDistribution {
+        self.samples.sort();
+        let ndv = if self.samples.len() as u64 == self.num_values {
+            // all the values are sampled, the number of the distinct values is exact
+            count_distinct(&self.samples) as u64
+        } else {
+            self.hll.count().min(self.num_values)
+        };
+        let histogram = build_histogram(&self.samples, self.num_values, ndv, max_buckets);
+        ColumnDistribution { ndv, histogram }
+    }
+}
+
+impl Default for ColumnDistributionCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds an equi-height histogram from the sorted samples of `num_values` values, which
+/// have `ndv` distinct values. The same values are always put into the same bucket.
+fn build_histogram(
+    samples: &[DataValue],
+    num_values: u64,
+    ndv: u64,
+    max_buckets: usize,
+) -> Histogram {
+    if samples.is_empty() || max_buckets == 0 {
+        return Histogram::default();
+    }
+
+    let bucket_height = (samples.len() + max_buckets - 1) / max_buckets;
+    let mut sample_buckets = vec![];
+    let mut start = 0;
+    while start < samples.len() {
+        let mut end = (start + bucket_height).min(samples.len());
+        while end < samples.len() && samples[end] == samples[end - 1] {
+            end += 1;
+        }
+        sample_buckets.push((start, end, count_distinct(&samples[start..end])));
+        start = end;
+    }
+
+    // scale the counts of the sample to the whole column
+    let sample_ndv: usize = sample_buckets.iter().map(|bucket| bucket.2).sum();
+    let value_scale = num_values as f64 / samples.len() as f64;
+    let distinct_scale = ndv.max(sample_ndv as u64) as f64 / sample_ndv as f64;
+    let buckets = sample_buckets
+        .into_iter()
+        .map(|(start, end, num_distinct)| {
+            let num_values = (((end - start) as f64 * value_scale).round() as u64).max(1);
+            let num_distinct =
+                ((num_distinct as f64 * distinct_scale).round() as u64).clamp(1, num_values);
+            HistogramBucket {
+                lower_bound: samples[start].clone(),
+                upper_bound: samples[end - 1].clone(),
+                num_values,
+                num_distinct,
+            }
+        })
+        .collect();
+    Histogram { buckets }
+}
+
+// Counts the distinct values of the sorted values
+fn count_distinct(sorted_values: &[DataValue]) -> usize {
+    if sorted_values.is_empty() {
+        return 0;
+    }
+    1 + sorted_values
+        .windows(2)
+        .filter(|pair| pair[0] != pair[1])
+        .count()
+}
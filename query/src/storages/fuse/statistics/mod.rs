@@ -14,11 +14,15 @@
 
 pub mod accumulator;
 mod block_statistics;
+mod column_distribution;
 mod column_statistic;
 pub mod reducers;
 
 pub use accumulator::StatisticsAccumulator;
 pub use block_statistics::BlockStatistics;
+pub use column_distribution::ColumnDistributionCollector;
+pub use column_distribution::HyperLogLog;
+pub use column_distribution::DEFAULT_HISTOGRAM_BUCKETS;
 pub use column_statistic::gen_columns_statistics;
 pub use column_statistic::traverse;
 pub use reducers::merge_statistics;
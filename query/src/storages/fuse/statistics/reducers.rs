@@ -39,12 +39,20 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         )
     });
 
+    // The columns added by ALTER TABLE are not covered by the blocks written before, the
+    // statistics of them are left out, unless all the (non-empty) inputs cover them.
+    let num_covered = stats_of_columns
+        .iter()
+        .filter(|item| !item.borrow().is_empty())
+        .count();
+
     // Reduce the `Vec<&ColumnStatistics` into ColumnStatistics`, i.e.:
     // from : `HashMap<ColumnId, Vec<&ColumnStatistics>)>`
     // to   : `type BlockStatistics = HashMap<ColumnId, ColumnStatistics>`
     let len = stats_of_columns.len();
     col_to_stats_lit
         .iter()
+        .filter(|(_, stats)| stats.len() == num_covered)
         .try_fold(HashMap::with_capacity(len), |mut acc, (id, stats)| {
             let mut min_stats = Vec::with_capacity(stats.len());
             let mut max_stats = Vec::with_capacity(stats.len());
@@ -26,6 +26,8 @@ use common_planners::Expression;
 use tracing::info;
 
 use crate::pipelines::processors::transforms::ExpressionExecutor;
+use crate::storages::index::is_default_supported_type;
+use crate::storages::index::remove_nullable;
 use crate::storages::index::IndexSchemaVersion;
 use crate::storages::index::SupportedType;
 
@@ -61,6 +63,9 @@ pub enum BloomFilterExprEvalResult {
 ///         |  123456789abcd |  ac2345bcd   |
 ///         +----------------+--------------+
 /// ```
+///
+/// For map columns, the keys of the map are added into the bloom filter, so the filters like
+/// `m['k'] = v` and `map_contains(m, 'k')` can skip the blocks which never have the key `k`.
 pub struct BloomFilterIndexer {
     // The schema of the source table/block, which the bloom filter work for.
     pub source_schema: DataSchemaRef,
@@ -236,10 +241,35 @@ impl BloomFilterIndexer {
                 "or" => self.eval_logical_or(left, right),
                 _ => Ok(BloomFilterExprEvalResult::NotApplicable),
             },
+            Expression::ScalarFunction { op, args } if op.eq_ignore_ascii_case("map_contains") => {
+                match args.as_slice() {
+                    [
+                        Expression::Column(column),
+                        Expression::Literal { value, .. },
+                    ] => self.find_map_key(column, value.clone()),
+                    _ => Ok(BloomFilterExprEvalResult::NotApplicable),
+                }
+            }
             _ => Ok(BloomFilterExprEvalResult::NotApplicable),
         }
     }
 
+    // Find the key in the bloom filter of map column, the keys of map are added into the bloom filter.
+    fn find_map_key(&self, column: &str, key: DataValue) -> Result<BloomFilterExprEvalResult> {
+        match self.source_schema.column_with_name(column) {
+            Some((_index, data_field)) => match remove_nullable(data_field.data_type()) {
+                DataTypeImpl::Map(map_type) => {
+                    self.find(column, key, map_type.key_type().clone(), self.ctx.clone())
+                }
+                _ => Ok(BloomFilterExprEvalResult::NotApplicable),
+            },
+            None => Err(ErrorCode::BadArguments(format!(
+                "Column '{}' not found in schema",
+                column
+            ))),
+        }
+    }
+
     // Evaluate the equivalent expression like "name='Alice'"
     fn eval_equivalent_expression(
         &self,
@@ -265,6 +295,20 @@ impl BloomFilterIndexer {
                     ))),
                 }
             }
+            // match the expression of 'map_column[key] = literal constant', the value is NULL
+            // if the key does not exist, so the key must exist if the expression is true.
+            (Expression::ScalarFunction { op, args }, Expression::Literal { value, .. })
+            | (Expression::Literal { value, .. }, Expression::ScalarFunction { op, args })
+                if op.eq_ignore_ascii_case("get") && !value.is_null() =>
+            {
+                match args.as_slice() {
+                    [
+                        Expression::Column(column),
+                        Expression::Literal { value: key, .. },
+                    ] => self.find_map_key(column, key.clone()),
+                    _ => Ok(BloomFilterExprEvalResult::NotApplicable),
+                }
+            }
             _ => Ok(BloomFilterExprEvalResult::NotApplicable),
         }
     }
@@ -541,6 +585,14 @@ impl BloomFilter {
             )));
         }
 
+        // Add the keys of map, the entries of Null maps are empty.
+        if remove_nullable(&column.data_type()).data_type_id() == TypeID::Map {
+            let column = Series::remove_nullable(column).convert_full_column();
+            let map_column: &ArrayColumn = Series::check_get(&column)?;
+            let entries: &StructColumn = Series::check_get(map_column.values())?;
+            return self.add(&entries.values()[0], ctx);
+        }
+
         let (is_all_null, validity) = column.validity();
         if is_all_null {
             return Ok(());
@@ -670,4 +722,12 @@ impl BloomFilter {
     }
 }
 
-impl SupportedType for BloomFilter {}
+impl SupportedType for BloomFilter {
+    /// Besides the default types, map is also supported if the type of its keys is supported.
+    fn is_supported_type(data_type: &DataTypeImpl) -> bool {
+        match remove_nullable(data_type) {
+            DataTypeImpl::Map(map_type) => Self::is_supported_type(map_type.key_type()),
+            _ => is_default_supported_type(data_type),
+        }
+    }
+}
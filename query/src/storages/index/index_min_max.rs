@@ -13,10 +13,38 @@
 // limitations under the License.
 
 /// MinMaxIndex is absorbed into the table meta statics
+use common_datavalues::DataTypeImpl;
+use common_datavalues::TypeID;
+
+use crate::storages::index::remove_nullable;
 use crate::storages::index::SupportedType;
 
 /// Min and Max index.
 pub struct MinMaxIndex {}
 
-/// using the default implementation
-impl SupportedType for MinMaxIndex {}
+impl SupportedType for MinMaxIndex {
+    /// Besides the types supported by bloom filter, decimal is also supported,
+    /// the unscaled values are comparable since all values of a column share the same scale.
+    fn is_supported_type(data_type: &DataTypeImpl) -> bool {
+        let inner_type = remove_nullable(data_type);
+        matches!(
+            inner_type.data_type_id(),
+            TypeID::UInt8
+                | TypeID::UInt16
+                | TypeID::UInt32
+                | TypeID::UInt64
+                | TypeID::Int8
+                | TypeID::Int16
+                | TypeID::Int32
+                | TypeID::Int64
+                | TypeID::Float32
+                | TypeID::Float64
+                | TypeID::Decimal
+                | TypeID::Decimal256
+                | TypeID::Date
+                | TypeID::Timestamp
+                | TypeID::Interval
+                | TypeID::String
+        )
+    }
+}
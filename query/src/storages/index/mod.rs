@@ -43,29 +43,34 @@ pub trait SupportedType {
     /// Nulls are not added to the Bloom
     /// filter, so any null related filter requires reading the data file. "
     fn is_supported_type(data_type: &DataTypeImpl) -> bool {
-        // we support nullable column but Nulls are not added into the bloom filter.
-        let inner_type = remove_nullable(data_type);
-        let data_type_id = inner_type.data_type_id();
-        matches!(
-            data_type_id,
-            TypeID::UInt8
-                | TypeID::UInt16
-                | TypeID::UInt32
-                | TypeID::UInt64
-                | TypeID::Int8
-                | TypeID::Int16
-                | TypeID::Int32
-                | TypeID::Int64
-                | TypeID::Float32
-                | TypeID::Float64
-                | TypeID::Date
-                | TypeID::Timestamp
-                | TypeID::Interval
-                | TypeID::String
-        )
+        is_default_supported_type(data_type)
     }
 }
 
+/// The types supported by [`SupportedType::is_supported_type`] by default.
+pub fn is_default_supported_type(data_type: &DataTypeImpl) -> bool {
+    // we support nullable column but Nulls are not added into the bloom filter.
+    let inner_type = remove_nullable(data_type);
+    let data_type_id = inner_type.data_type_id();
+    matches!(
+        data_type_id,
+        TypeID::UInt8
+            | TypeID::UInt16
+            | TypeID::UInt32
+            | TypeID::UInt64
+            | TypeID::Int8
+            | TypeID::Int16
+            | TypeID::Int32
+            | TypeID::Int64
+            | TypeID::Float32
+            | TypeID::Float64
+            | TypeID::Date
+            | TypeID::Timestamp
+            | TypeID::Interval
+            | TypeID::String
+    )
+}
+
 pub fn remove_nullable(data_type: &DataTypeImpl) -> DataTypeImpl {
     if matches!(data_type.data_type_id(), TypeID::Nullable) {
         let nullable: NullableType = data_type.to_owned().try_into().unwrap();
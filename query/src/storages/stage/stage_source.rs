@@ -132,7 +132,10 @@ impl StageSourceHelper {
         let name = match format {
             StageFileFormatType::Csv => "csv",
             StageFileFormatType::Tsv => "tsv",
+            StageFileFormatType::Json => "json",
             StageFileFormatType::NdJson => "ndjson",
+            StageFileFormatType::Avro => "avro",
+            StageFileFormatType::Orc => "orc",
             StageFileFormatType::Parquet => "parquet",
             format => {
                 return Err(ErrorCode::LogicalError(format!(
@@ -0,0 +1,157 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_cache::Cache;
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_fuse_meta::caches::ItemCache;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::sessions::TableContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+pub struct CachesTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct CachesBuilder {
+    names: Vec<&'static str>,
+    num_items: Vec<u64>,
+    sizes: Vec<u64>,
+    capacities: Vec<u64>,
+    hits: Vec<Option<u64>>,
+    misses: Vec<Option<u64>>,
+}
+
+impl CachesBuilder {
+    async fn append_item_cache<V>(&mut self, name: &'static str, cache: Option<ItemCache<V>>) {
+        if let Some(cache) = cache {
+            let cache = cache.read().await;
+            self.append(
+                name,
+                cache.len() as u64,
+                cache.size(),
+                cache.capacity(),
+                None,
+            );
+        }
+    }
+
+    fn append(
+        &mut self,
+        name: &'static str,
+        num_items: u64,
+        size: u64,
+        capacity: u64,
+        hits_and_misses: Option<(u64, u64)>,
+    ) {
+        self.names.push(name);
+        self.num_items.push(num_items);
+        self.sizes.push(size);
+        self.capacities.push(capacity);
+        self.hits.push(hits_and_misses.map(|v| v.0));
+        self.misses.push(hits_and_misses.map(|v| v.1));
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for CachesTable {
+    const NAME: &'static str = "system.caches";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let cache_manager = ctx.get_storage_cache_manager();
+        let mut builder = CachesBuilder::default();
+
+        // Only the caches enabled are listed, hits and misses are tracked by the disk cache only.
+        builder
+            .append_item_cache("table_snapshot", cache_manager.get_table_snapshot_cache())
+            .await;
+        builder
+            .append_item_cache("table_segment", cache_manager.get_table_segment_cache())
+            .await;
+        if let Some(cache) = cache_manager.get_bloom_index_cache() {
+            let cache = cache.read().await;
+            builder.append(
+                "bloom_index",
+                cache.len() as u64,
+                cache.size(),
+                cache.capacity(),
+                None,
+            );
+        }
+        builder
+            .append_item_cache(
+                "bloom_index_meta",
+                cache_manager.get_bloom_index_meta_cache(),
+            )
+            .await;
+        if let Some(cache) = cache_manager.get_column_data_disk_cache() {
+            let stats = cache.statistics();
+            builder.append(
+                "column_data_disk",
+                stats.num_items,
+                stats.size,
+                stats.capacity,
+                Some((stats.hits, stats.misses)),
+            );
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(builder.names),
+            Series::from_data(builder.num_items),
+            Series::from_data(builder.sizes),
+            Series::from_data(builder.capacities),
+            Series::from_data(builder.hits),
+            Series::from_data(builder.misses),
+        ]))
+    }
+}
+
+impl CachesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("num_items", u64::to_data_type()),
+            DataField::new("size", u64::to_data_type()),
+            DataField::new("capacity", u64::to_data_type()),
+            DataField::new_nullable("hits", u64::to_data_type()),
+            DataField::new_nullable("misses", u64::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'caches'".to_string(),
+            name: "caches".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemCaches".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(CachesTable { table_info })
+    }
+}
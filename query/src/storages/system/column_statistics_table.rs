@@ -0,0 +1,145 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_datablocks::DataBlock;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_fuse_meta::meta::Histogram;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::catalogs::CATALOG_DEFAULT;
+use crate::sessions::TableContext;
+use crate::storages::system::table::AsyncOneBlockSystemTable;
+use crate::storages::system::table::AsyncSystemTable;
+use crate::storages::Table;
+
+/// Statistics of the columns of the tables which keep them, e.g. the fuse tables. The
+/// distinct count and the histogram are NULL until the table is analyzed.
+pub struct ColumnStatisticsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ColumnStatisticsTable {
+    const NAME: &'static str = "system.column_statistics";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT)?;
+
+        let mut databases: Vec<Vec<u8>> = vec![];
+        let mut tables: Vec<Vec<u8>> = vec![];
+        let mut names: Vec<Vec<u8>> = vec![];
+        let mut mins: Vec<Vec<u8>> = vec![];
+        let mut maxs: Vec<Vec<u8>> = vec![];
+        let mut null_counts: Vec<u64> = vec![];
+        let mut distinct_counts: Vec<Option<u64>> = vec![];
+        let mut histograms: Vec<Option<Vec<u8>>> = vec![];
+        for database in catalog.list_databases(tenant.as_str()).await? {
+            for table in catalog
+                .list_tables(tenant.as_str(), database.name())
+                .await?
+            {
+                let column_stats = match table.column_statistics(ctx.clone()).await? {
+                    Some(column_stats) => column_stats,
+                    None => continue,
+                };
+                let mut distributions = table
+                    .column_distributions(ctx.clone())
+                    .await?
+                    .unwrap_or_default();
+
+                let schema = table.schema();
+                for (position, field) in schema.fields().iter().enumerate() {
+                    let stat = match column_stats.get(&(position as u32)) {
+                        Some(stat) => stat,
+                        None => continue,
+                    };
+                    let distribution = distributions.remove(&(position as u32));
+                    databases.push(database.name().as_bytes().to_vec());
+                    tables.push(table.name().as_bytes().to_vec());
+                    names.push(field.name().as_bytes().to_vec());
+                    mins.push(stat.min.to_string().into_bytes());
+                    maxs.push(stat.max.to_string().into_bytes());
+                    null_counts.push(stat.null_count);
+                    distinct_counts.push(distribution.as_ref().map(|d| d.ndv));
+                    histograms.push(distribution.map(|d| format_histogram(&d.histogram)));
+                }
+            }
+        }
+
+        Ok(DataBlock::create(self.table_info.schema(), vec![
+            Series::from_data(databases),
+            Series::from_data(tables),
+            Series::from_data(names),
+            Series::from_data(mins),
+            Series::from_data(maxs),
+            Series::from_data(null_counts),
+            Series::from_data(distinct_counts),
+            Series::from_data(histograms),
+        ]))
+    }
+}
+
+impl ColumnStatisticsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = DataSchemaRefExt::create(vec![
+            DataField::new("database", Vu8::to_data_type()),
+            DataField::new("table", Vu8::to_data_type()),
+            DataField::new("name", Vu8::to_data_type()),
+            DataField::new("min", Vu8::to_data_type()),
+            DataField::new("max", Vu8::to_data_type()),
+            DataField::new("null_count", u64::to_data_type()),
+            DataField::new_nullable("distinct_count", u64::to_data_type()),
+            DataField::new_nullable("histogram", Vu8::to_data_type()),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'column_statistics'".to_string(),
+            name: "column_statistics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemColumnStats".to_string(),
+                ..Default::default()
+            },
+        };
+
+        AsyncOneBlockSystemTable::create(ColumnStatisticsTable { table_info })
+    }
+}
+
+// Formats the buckets as `[<lower bound>, <upper bound>]: <values>/<distinct values>`
+fn format_histogram(histogram: &Histogram) -> Vec<u8> {
+    histogram
+        .buckets
+        .iter()
+        .map(|bucket| {
+            format!(
+                "[{}, {}]: {}/{}",
+                bucket.lower_bound, bucket.upper_bound, bucket.num_values, bucket.num_distinct
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+        .into_bytes()
+}
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod caches_table;
 mod clusters_table;
+mod column_statistics_table;
 mod columns_table;
 mod configs_table;
 mod contributors_table;
@@ -33,7 +35,9 @@ mod tracing_table;
 mod tracing_table_stream;
 mod users_table;
 
+pub use caches_table::CachesTable;
 pub use clusters_table::ClustersTable;
+pub use column_statistics_table::ColumnStatisticsTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
 pub use contributors_table::ContributorsTable;
@@ -0,0 +1,125 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use databend_query::audit::AuditEvent;
+use databend_query::audit::AuditEventType;
+use databend_query::audit::RotatingFileWriter;
+use databend_query::audit::AUDIT_LOG_FILE_NAME;
+
+#[test]
+fn test_rotating_file_writer() -> Result<()> {
+    let dir = tempfile::tempdir()?;
+    let mut writer = RotatingFileWriter::create(dir.path(), 16, 2);
+
+    // Each line takes 10 bytes, so every file holds one line.
+    for i in 0..5 {
+        writer.write_line(&format!("line-{:04}", i))?;
+    }
+
+    let read = |name: String| fs::read_to_string(dir.path().join(name)).unwrap();
+    assert_eq!(read(AUDIT_LOG_FILE_NAME.to_string()), "line-0004\n");
+    assert_eq!(read(format!("{}.1", AUDIT_LOG_FILE_NAME)), "line-0003\n");
+    assert_eq!(read(format!("{}.2", AUDIT_LOG_FILE_NAME)), "line-0002\n");
+    assert!(
+        !dir.path()
+            .join(format!("{}.3", AUDIT_LOG_FILE_NAME))
+            .exists()
+    );
+
+    // Reopening appends to the current file.
+    let mut writer = RotatingFileWriter::create(dir.path(), 32, 2);
+    writer.write_line("line-0005")?;
+    assert_eq!(
+        read(AUDIT_LOG_FILE_NAME.to_string()),
+        "line-0004\nline-0005\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_audit_event_json() -> Result<()> {
+    let event = AuditEvent::login(
+        "admin".to_string(),
+        "".to_string(),
+        "root".to_string(),
+        "MySQL".to_string(),
+        "127.0.0.1:3307".to_string(),
+        "mysql_native_password",
+        None,
+    );
+    let json: serde_json::Value = serde_json::to_value(&event)?;
+    assert_eq!(json["event_type"], "login");
+    assert_eq!(json["user"], "root");
+    assert_eq!(json["auth_type"], "mysql_native_password");
+    assert_eq!(json["success"], true);
+    assert_eq!(json["exception_code"], 0);
+
+    assert_eq!(
+        serde_json::to_value(AuditEventType::UserManagement)?,
+        "user_management"
+    );
+    assert_eq!(AuditEventType::DataTransfer.to_string(), "data_transfer");
+
+    Ok(())
+}
+
+#[test]
+fn test_audit_event_table_block() -> Result<()> {
+    let login = |user: &str| {
+        AuditEvent::login(
+            "admin".to_string(),
+            "".to_string(),
+            user.to_string(),
+            "MySQL".to_string(),
+            "127.0.0.1:3307".to_string(),
+            "mysql_native_password",
+            None,
+        )
+    };
+    let events = vec![login("u1"), login("u2")];
+
+    let block = AuditEvent::to_data_block(&events);
+    assert_eq!(block.num_rows(), 2);
+    assert_eq!(block.schema(), &AuditEvent::schema());
+
+    // The columns follow the order of the table, the extra columns are left out.
+    let mut fields = AuditEvent::schema().fields().clone();
+    fields.reverse();
+    fields[0] = DataField::new_nullable("exception", Vu8::to_data_type());
+    fields.push(DataField::new("note", Vu8::to_data_type()));
+    let table_schema = DataSchemaRefExt::create(fields);
+
+    let block = AuditEvent::to_table_block(&events, &table_schema)?;
+    assert_eq!(block.num_rows(), 2);
+    assert_eq!(block.num_columns(), AuditEvent::schema().fields().len());
+    assert_eq!(block.schema().field(0), table_schema.field(0));
+    assert_eq!(
+        block.schema().field(block.num_columns() - 1).name(),
+        "event_type"
+    );
+    let users = block.try_column_by_name("user")?;
+    assert_eq!(users.get(1), DataValue::String("u2".as_bytes().to_vec()));
+
+    // The table lacks a column of the audit events.
+    let table_schema = DataSchemaRefExt::create(AuditEvent::schema().fields()[1..].to_vec());
+    let res = AuditEvent::to_table_block(&events, &table_schema);
+    assert_eq!(res.unwrap_err().code(), 1006);
+
+    Ok(())
+}
@@ -64,6 +64,7 @@ table_cache_snapshot_count = 256
 table_cache_segment_count = 10240
 table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
+table_disk_cache_enabled = false
 table_disk_cache_root = "_cache"
 table_disk_cache_mb_size = 1024
 management_mode = false
@@ -71,6 +72,11 @@ jwt_key_file = ""
 async_insert_max_data_size = 10000
 async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
+audit_log_enabled = false
+audit_log_dir = "./.databend/audit"
+audit_log_max_file_size_mb = 100
+audit_log_max_files = 10
+audit_log_table = ""
 
 [log]
 level = "INFO"
@@ -158,6 +164,7 @@ fn test_env_config_s3() -> Result<()> {
             ("QUERY_METRIC_API_ADDRESS", Some("1.2.3.4:7071")),
             ("QUERY_TABLE_CACHE_ENABLED", Some("true")),
             ("QUERY_TABLE_MEMORY_CACHE_MB_SIZE", Some("512")),
+            ("QUERY_TABLE_DISK_CACHE_ENABLED", Some("true")),
             ("QUERY_TABLE_DISK_CACHE_ROOT", Some("_cache_env")),
             ("QUERY_TABLE_DISK_CACHE_MB_SIZE", Some("512")),
             ("STORAGE_TYPE", Some("s3")),
@@ -210,6 +217,7 @@ fn test_env_config_s3() -> Result<()> {
 
             assert!(configured.query.table_cache_enabled);
             assert_eq!(512, configured.query.table_memory_cache_mb_size);
+            assert!(configured.query.table_disk_cache_enabled);
             assert_eq!("_cache_env", configured.query.table_disk_cache_root);
             assert_eq!(512, configured.query.table_disk_cache_mb_size);
         },
@@ -240,6 +248,7 @@ fn test_env_config_fs() -> Result<()> {
             ("QUERY_METRIC_API_ADDRESS", Some("1.2.3.4:7071")),
             ("QUERY_TABLE_CACHE_ENABLED", Some("true")),
             ("QUERY_TABLE_MEMORY_CACHE_MB_SIZE", Some("512")),
+            ("QUERY_TABLE_DISK_CACHE_ENABLED", Some("true")),
             ("QUERY_TABLE_DISK_CACHE_ROOT", Some("_cache_env")),
             ("QUERY_TABLE_DISK_CACHE_MB_SIZE", Some("512")),
             ("STORAGE_TYPE", Some("fs")),
@@ -292,6 +301,7 @@ fn test_env_config_fs() -> Result<()> {
 
             assert!(configured.query.table_cache_enabled);
             assert_eq!(512, configured.query.table_memory_cache_mb_size);
+            assert!(configured.query.table_disk_cache_enabled);
             assert_eq!("_cache_env", configured.query.table_disk_cache_root);
             assert_eq!(512, configured.query.table_disk_cache_mb_size);
         },
@@ -300,6 +310,22 @@ fn test_env_config_fs() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_env_config_zero_audit_log_max_files() -> Result<()> {
+    temp_env::with_vars(
+        vec![
+            ("QUERY_AUDIT_LOG_MAX_FILES", Some("0")),
+            ("CONFIG_FILE", None),
+        ],
+        || {
+            let err = Config::load().unwrap_err();
+            assert_eq!(2002, err.code());
+        },
+    );
+
+    Ok(())
+}
+
 /// Test whether override works as expected.
 #[test]
 fn test_override_config() -> Result<()> {
@@ -345,6 +371,7 @@ table_cache_snapshot_count = 256
 table_cache_segment_count = 10240
 table_cache_block_meta_count = 102400
 table_memory_cache_mb_size = 256
+table_disk_cache_enabled = false
 table_disk_cache_root = "_cache"
 table_disk_cache_mb_size = 1024
 management_mode = false
@@ -352,6 +379,11 @@ jwt_key_file = ""
 async_insert_max_data_size = 10000
 async_insert_busy_timeout = 200
 async_insert_stale_timeout = 0
+audit_log_enabled = false
+audit_log_dir = "./.databend/audit"
+audit_log_max_file_size_mb = 100
+audit_log_max_files = 10
+audit_log_table = ""
 
 [log]
 level = "INFO"
@@ -16,6 +16,7 @@ use common_base::base::tokio;
 use common_exception::Result;
 use databend_query::interpreters::*;
 use databend_query::sql::PlanParser;
+use databend_query::sql::Planner;
 use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 
@@ -52,3 +53,32 @@ async fn test_explain_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_explain_analyze_interpreter() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let mut planner = Planner::new(ctx.clone());
+
+    let query = "EXPLAIN ANALYZE SELECT number FROM numbers_mt(10) WHERE number > 3";
+    let (plan, _, _) = planner.plan_sql(query).await?;
+    let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+    assert_eq!(executor.name(), "ExplainInterpreterV2");
+
+    let stream = executor.execute().await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let formatted = common_datablocks::pretty_format_blocks(&result)?;
+
+    // The time varies from run to run, only the rows are checked
+    let filter = formatted
+        .lines()
+        .find(|line| line.contains("Filter: "))
+        .unwrap();
+    assert!(filter.contains("rows in: 10, rows out: 6"), "{}", filter);
+    let scan = formatted
+        .lines()
+        .find(|line| line.contains("TableScan: "))
+        .unwrap();
+    assert!(scan.contains("rows in: 0, rows out: 10"), "{}", scan);
+
+    Ok(())
+}
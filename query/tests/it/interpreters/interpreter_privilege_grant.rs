@@ -20,9 +20,11 @@ use common_meta_types::PasswordHashMethod;
 use common_meta_types::PrincipalIdentity;
 use common_meta_types::RoleInfo;
 use common_meta_types::UserGrantSet;
+use common_meta_types::UserIdentity;
 use common_meta_types::UserInfo;
 use databend_query::interpreters::*;
 use databend_query::sessions::TableContext;
+use databend_query::sql::PlanParser;
 use databend_query::sql::Planner;
 use futures::stream::StreamExt;
 use pretty_assertions::assert_eq;
@@ -108,3 +110,73 @@ async fn test_grant_privilege_interpreter() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_grant_column_privilege() -> Result<()> {
+    let ctx = crate::tests::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    let mut planner = Planner::new(ctx.clone());
+
+    let queries = [
+        "CREATE TABLE default.t(a INT, b INT, c INT)",
+        "CREATE USER 'analyst'@'%' IDENTIFIED BY 'password'",
+        "GRANT SELECT(a, b) ON default.t TO 'analyst'@'%'",
+    ];
+    for query in queries {
+        let (plan, _, _) = planner.plan_sql(query).await?;
+        let executor = InterpreterFactoryV2::get(ctx.clone(), &plan)?;
+        let mut stream = executor.execute().await?;
+        while let Some(_block) = stream.next().await {}
+    }
+
+    let user_info = ctx
+        .get_user_manager()
+        .get_user(&tenant, UserIdentity::new("analyst", "%"))
+        .await?;
+    assert_eq!(
+        "GRANT SELECT(a, b) ON 'default'.'default'.'t'",
+        user_info.grants.entries()[0].to_string()
+    );
+
+    // Plan the queries as the user with column privileges
+    ctx.get_current_session().set_current_user(user_info);
+
+    let denied = "Code: 1063, displayText = Permission denied, SELECT privilege on column c of table 'default'.'default'.'t' is required.";
+    let tests = [
+        ("SELECT a, b FROM default.t", None),
+        ("SELECT a FROM default.t WHERE b > 1", None),
+        ("SELECT count(*) FROM default.t", None),
+        ("SELECT c FROM default.t", Some(denied)),
+        ("SELECT a FROM default.t WHERE c > 1", Some(denied)),
+        ("SELECT * FROM default.t", Some(denied)),
+    ];
+    for (query, expected_err) in tests {
+        let r = planner.plan_sql(query).await;
+        match expected_err {
+            Some(expected_err) => assert_eq!(
+                expected_err,
+                r.unwrap_err().to_string(),
+                "expected_err eq failed on query: {}",
+                query
+            ),
+            None => assert!(r.is_ok(), "got err on query {}: {:?}", query, r.err()),
+        }
+    }
+
+    // The old planner checks the columns required by the query as well.
+    for (query, expected_err) in tests {
+        let r = PlanParser::parse(ctx.clone(), query).await;
+        match expected_err {
+            Some(_) => assert!(
+                r.unwrap_err()
+                    .message()
+                    .starts_with("Permission denied, SELECT privilege on column c of table"),
+                "expected_err eq failed on query: {}",
+                query
+            ),
+            None => assert!(r.is_ok(), "got err on query {}: {:?}", query, r.err()),
+        }
+    }
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod api;
+mod audit;
 mod auth;
 mod catalogs;
 mod clusters;
@@ -0,0 +1,86 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_datablocks::HashMethodKeysU16;
+use common_datablocks::HashMethodKeysU8;
+use common_datavalues::prelude::*;
+use common_exception::Result;
+use common_hashtable::NUM_BUCKETS;
+use databend_query::pipelines::processors::AggregatorParams;
+use databend_query::pipelines::processors::AggregatorSpiller;
+use databend_query::pipelines::processors::AggregatorState;
+use databend_query::pipelines::processors::PolymorphicKeysHelper;
+
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_aggregator_spill_and_read_back() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // the spiller reads and writes the storage in blocking way, as the processors do
+    tokio::task::spawn_blocking(move || -> Result<()> {
+        let schema =
+            DataSchemaRefExt::create(vec![DataField::new("_group_by_key", u8::to_data_type())]);
+        let params =
+            AggregatorParams::try_create_v2(schema.clone(), schema.clone(), &[], &[], &[], &[])?;
+        let method = HashMethodKeysU8::default();
+        let mut spiller = AggregatorSpiller::create(&ctx)?;
+        assert!(!spiller.is_spilled());
+
+        // spill twice, the keys of the second spill overlap with the first one
+        for keys in [0u8..100, 50u8..150] {
+            let mut state = method.aggregate_state();
+            let mut inserted = true;
+            for key in keys {
+                state.entity(key, &mut inserted);
+            }
+            spiller.spill(&method, &state, &params, &schema)?;
+        }
+        assert!(spiller.is_spilled());
+
+        let mut keys = vec![];
+        for bucket in 0..NUM_BUCKETS {
+            for block in spiller.read_bucket(bucket)? {
+                assert_eq!(block.schema(), &schema);
+                let column: &UInt8Column = Series::check_get(block.column(0))?;
+                keys.extend(column.iter().copied());
+            }
+        }
+        keys.sort_unstable();
+
+        let mut expected = (0u8..100).chain(50u8..150).collect::<Vec<_>>();
+        expected.sort_unstable();
+        assert_eq!(keys, expected);
+        Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[test]
+fn test_short_fixed_keys_allocated_bytes() {
+    // the fixed array of 65536 entities is allocated at once, only the filled ones count
+    let method = HashMethodKeysU16::default();
+    let mut state = method.aggregate_state();
+    assert!(state.allocated_bytes() < 64 * 1024);
+
+    let mut inserted = true;
+    for key in 0u16..10 {
+        state.entity(key, &mut inserted);
+    }
+    assert_eq!(state.len(), 10);
+    assert!(state.allocated_bytes() < 64 * 1024);
+}
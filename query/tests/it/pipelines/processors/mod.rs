@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod aggregator_spiller;
 mod file_splitter;
 mod port_test;
 mod resize;
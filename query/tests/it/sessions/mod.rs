@@ -15,3 +15,4 @@ mod query_ctx;
 mod session;
 mod session_context;
 mod session_setting;
+mod user_storage_usage;
@@ -14,9 +14,15 @@
 
 use common_base::base::tokio;
 use common_exception::Result;
+use common_meta_types::AuthInfo;
+use common_meta_types::UserInfo;
 use common_storage::StorageFsConfig;
 use common_storage::StorageParams;
 use common_storage::StorageS3Config;
+use databend_query::clusters::Cluster;
+use databend_query::sessions::QueryContext;
+use databend_query::sessions::QueryContextShared;
+use databend_query::sessions::SessionType;
 use databend_query::sessions::TableContext;
 use wiremock::matchers::method;
 use wiremock::matchers::path;
@@ -63,3 +69,27 @@ async fn test_get_storage_accessor_fs() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_max_threads_capped_by_user_cpu_quota() -> Result<()> {
+    let sessions = crate::tests::SessionManagerBuilder::create().build()?;
+    let session = sessions.create_session(SessionType::Dummy).await?;
+    session.get_settings().set_max_threads(8)?;
+
+    let mut user_info = UserInfo::new("quota_user", "%", AuthInfo::None);
+    user_info.quota.max_cpu = 2;
+    session.set_current_user(user_info);
+
+    let ctx = QueryContext::create_from_shared(
+        QueryContextShared::try_create((*session).clone(), Cluster::empty()).await?,
+    );
+    assert_eq!(ctx.get_settings().get_max_threads()?, 2);
+
+    // The setting of the session is not changed by the quota.
+    assert_eq!(session.get_settings().get_max_threads()?, 8);
+
+    // A smaller max_threads of the session is still honored.
+    session.get_settings().set_max_threads(1)?;
+    assert_eq!(ctx.get_settings().get_max_threads()?, 1);
+    Ok(())
+}
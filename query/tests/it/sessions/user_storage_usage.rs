@@ -0,0 +1,47 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::sessions::UserStorageUsageCache;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_user_storage_usage_of_tables_without_owner() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    let catalog_name = fixture.default_catalog_name();
+
+    // Create the table by the catalog, so that no owner is recorded.
+    let catalog = ctx.get_catalog(&catalog_name)?;
+    catalog
+        .create_table(fixture.default_crate_table_plan().into())
+        .await?;
+    append_sample_data(1, &fixture).await?;
+
+    // The table without owner is counted for every user.
+    let cache = UserStorageUsageCache::create(Duration::from_secs(60));
+    let usage = cache.get_usage(&ctx, &catalog_name, "'u1'@'%'").await?;
+    assert!(usage > 0);
+    assert_eq!(
+        cache.get_usage(&ctx, &catalog_name, "'u2'@'%'").await?,
+        usage
+    );
+    Ok(())
+}
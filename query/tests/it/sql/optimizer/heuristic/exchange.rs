@@ -93,6 +93,11 @@ pub async fn test_heuristic_optimizer_exchange() -> Result<()> {
             query: "select * from (select sum(number) as number from numbers(1) group by number) t, numbers(2) t1 where t.number = t1.number".to_string(),
             rules: DEFAULT_REWRITE_RULES.clone(),
         },
+        Suite {
+            comment: "# Right outer join can be distributed by hash keys".to_string(),
+            query: "select * from numbers(1) t right join numbers(2) t1 on t.number = t1.number".to_string(),
+            rules: DEFAULT_REWRITE_RULES.clone(),
+        },
     ];
 
     run_suites(ctx, &mut file, &suites, run_cluster_test).await
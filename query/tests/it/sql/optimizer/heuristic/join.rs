@@ -60,6 +60,16 @@ pub async fn test_heuristic_optimizer_join() -> Result<()> {
             query: "select t.number from numbers(1) as t, numbers(1) as t1, numbers(1) as t2 where t1.number = t2.number and t.number = 1"
                 .to_string(),
             rules: DEFAULT_REWRITE_RULES.clone(),
+        }, Suite {
+            comment: "# Cannot push predicates down to the null-padded side of outer join".to_string(),
+            query: "select t.number from numbers(1) as t right join numbers(1) as t1 on t.number = t1.number where t.number > 1 and t1.number > 1"
+                .to_string(),
+            rules: DEFAULT_REWRITE_RULES.clone(),
+        }, Suite {
+            comment: "".to_string(),
+            query: "select t.number from numbers(1) as t full join numbers(1) as t1 on t.number = t1.number where t.number > 1"
+                .to_string(),
+            rules: DEFAULT_REWRITE_RULES.clone(),
         },
     ];
 
@@ -86,6 +86,7 @@ fn get_dummy_read_source() -> ReadDataSourcePlan {
             partitions_scanned: 0,
             partitions_total: 0,
             is_exact: false,
+            pruning: None,
         },
         description: "".to_string(),
         tbl_args: None,
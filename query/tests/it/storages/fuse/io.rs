@@ -28,6 +28,7 @@ use databend_query::storages::fuse::io::BlockCompactor;
 use databend_query::storages::fuse::io::BlockReader;
 use databend_query::storages::fuse::io::BlockStreamWriter;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
+use databend_query::storages::fuse::ColumnIds;
 use databend_query::storages::fuse::DEFAULT_BLOCK_PER_SEGMENT;
 use futures::StreamExt;
 use futures::TryStreamExt;
@@ -47,6 +48,7 @@ use crate::tests::create_query_context;
 async fn test_fuse_table_block_appender() -> Result<()> {
     let ctx = create_query_context().await?;
     let schema = DataSchemaRefExt::create(vec![DataField::new("a", i32::to_data_type())]);
+    let column_ids = ColumnIds::from_schema(&schema)?;
 
     // single segment
     let block = DataBlock::create(schema.clone(), vec![Series::from_data(vec![1, 2, 3])]);
@@ -60,6 +62,7 @@ async fn test_fuse_table_block_appender() -> Result<()> {
         0,
         locs.clone(),
         None,
+        column_ids.clone(),
     )
     .await?
     .collect::<Vec<_>>()
@@ -87,6 +90,7 @@ async fn test_fuse_table_block_appender() -> Result<()> {
         max_blocks_per_segment,
         locs.clone(),
         None,
+        column_ids.clone(),
     )
     .await?
     .collect::<Vec<_>>()
@@ -107,6 +111,7 @@ async fn test_fuse_table_block_appender() -> Result<()> {
         0,
         locs,
         None,
+        column_ids,
     )
     .await?
     .collect::<Vec<_>>()
@@ -264,6 +269,7 @@ async fn test_block_stream_writer() -> Result<()> {
                      num_blocks,
                      case_name: &'static str| async move {
         let sample_block = gen_block(gen_rows(rows_per_sample_block));
+        let column_ids = ColumnIds::from_schema(sample_block.schema())?;
         let block_stream =
             futures::stream::iter(std::iter::repeat(Ok(sample_block)).take(num_blocks));
 
@@ -276,6 +282,7 @@ async fn test_block_stream_writer() -> Result<()> {
             max_blocks_per_segment,
             locs,
             None,
+            column_ids,
         )
         .await?;
         let segs = stream.try_collect::<Vec<_>>().await?;
@@ -18,4 +18,5 @@ mod navigate;
 mod optimize;
 mod purge_drop;
 mod purge_truncate;
+mod purge_txn;
 mod read_plan;
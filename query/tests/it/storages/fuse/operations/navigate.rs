@@ -22,6 +22,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_planners::TruncateTablePlan;
 use databend_query::pipelines::Pipeline;
+use databend_query::sql::OPT_KEY_DATA_RETENTION_PERIOD;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::FuseTable;
@@ -123,6 +124,53 @@ async fn test_fuse_navigate() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_fuse_navigate_beyond_retention() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture
+        .create_default_table_with_option(OPT_KEY_DATA_RETENTION_PERIOD, "1h")
+        .await?;
+
+    let qry = format!("insert into {}.{} values (1, (2, 3))", db, tbl);
+    execute_query(ctx.clone(), qry.as_str())
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+
+    // navigate within the retention period
+    let instant = chrono::Utc::now().sub(chrono::Duration::minutes(59));
+    let res = fuse_table
+        .navigate_to_time_point(ctx.as_ref(), instant)
+        .await;
+    match res {
+        Ok(_) => panic!("historical data should not exist"),
+        Err(e) => {
+            assert_eq!(e.code(), ErrorCode::table_historical_data_not_found_code());
+            assert!(!e.message().contains("retention"));
+        }
+    };
+
+    // navigate beyond the retention period
+    let instant = chrono::Utc::now().sub(chrono::Duration::hours(2));
+    let res = fuse_table
+        .navigate_to_time_point(ctx.as_ref(), instant)
+        .await;
+    match res {
+        Ok(_) => panic!("historical data should not exist"),
+        Err(e) => {
+            assert_eq!(e.code(), ErrorCode::table_historical_data_not_found_code());
+            assert!(e.message().contains("retention"));
+        }
+    };
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_fuse_historical_table_is_read_only() -> Result<()> {
     // 1. Setup
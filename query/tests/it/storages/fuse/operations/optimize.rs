@@ -12,8 +12,12 @@
 //  See the License for the specific language governing permissions and
 //  limitations under the License.
 
+use std::time::Duration;
+
 use common_base::base::tokio;
 use common_exception::Result;
+use databend_query::sql::OPT_KEY_DATA_RETENTION_PERIOD;
+use databend_query::storages::fuse::FuseTable;
 use futures::TryStreamExt;
 
 use crate::storages::fuse::table_test_fixture::append_sample_data;
@@ -58,6 +62,66 @@ async fn do_purge_test(case_name: &str, operation: &str) -> Result<()> {
     history_should_have_only_one_item(&fixture, case_name).await
 }
 
+#[tokio::test]
+async fn test_fuse_snapshot_optimize_purge_with_retention() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    fixture
+        .create_default_table_with_option(OPT_KEY_DATA_RETENTION_PERIOD, "1h")
+        .await?;
+
+    // insert, and then insert overwrite
+    append_sample_data(1, &fixture).await?;
+    append_sample_data_overwrite(1, true, &fixture).await?;
+
+    let qry = format!("optimize table {}.{} purge", db, tbl);
+    execute_command(fixture.ctx(), &qry).await?;
+
+    // both snapshots are within the retention period, nothing should be purged
+    check_data_dir(&fixture, "purge with retention", 2, 2, 2, 2).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_snapshot_optimize_purge_beyond_retention() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let db = fixture.default_db_name();
+    let tbl = fixture.default_table_name();
+    let ctx = fixture.ctx();
+    fixture
+        .create_default_table_with_option(OPT_KEY_DATA_RETENTION_PERIOD, "2s")
+        .await?;
+
+    // two insertions, which are beyond the retention period after a nap
+    append_sample_data(1, &fixture).await?;
+    append_sample_data(1, &fixture).await?;
+    let table = fixture.latest_default_table().await?;
+    let boundary_snapshot = FuseTable::try_from_table(table.as_ref())?
+        .snapshot_loc()
+        .unwrap();
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    append_sample_data(1, &fixture).await?;
+
+    let qry = format!("optimize table {}.{} purge", db, tbl);
+    execute_command(ctx.clone(), &qry).await?;
+
+    // only the first snapshot is purged, the second one is the version at the retention point
+    check_data_dir(&fixture, "purge beyond retention", 2, 3, 3, 3).await;
+
+    // navigate to the retention point, which moves on with the clock, so a little
+    // later than it to keep the navigation within the retention period.
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let instant = fuse_table.data_retention_point()?.unwrap() + chrono::Duration::milliseconds(500);
+    let tbl = fuse_table
+        .navigate_to_time_point(ctx.as_ref(), instant)
+        .await?;
+    assert_eq!(boundary_snapshot, tbl.snapshot_loc().unwrap());
+
+    Ok(())
+}
+
 async fn do_insertions(fixture: &TestFixture) -> Result<()> {
     fixture.create_default_table().await?;
     // ingests 1 block, 1 segment, 1 snapshot
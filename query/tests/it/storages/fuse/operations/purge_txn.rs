@@ -0,0 +1,71 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+use common_base::base::tokio;
+use common_exception::Result;
+
+use crate::storages::fuse::table_test_fixture::append_sample_data;
+use crate::storages::fuse::table_test_fixture::check_data_dir;
+use crate::storages::fuse::table_test_fixture::execute_command;
+use crate::storages::fuse::table_test_fixture::TestFixture;
+
+#[tokio::test]
+async fn test_fuse_txn_rollback_purge() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture.create_default_table().await?;
+
+    append_sample_data(1, &fixture).await?;
+    check_data_dir(&fixture, "txn_rollback_purge_before_txn", 1, 1, 1, 1).await;
+
+    // the data written by the transaction are purged by the rollback
+    execute_command(ctx.clone(), "BEGIN").await?;
+    append_sample_data(1, &fixture).await?;
+    check_data_dir(&fixture, "txn_rollback_purge_in_txn", 2, 2, 2, 2).await;
+    execute_command(ctx.clone(), "ROLLBACK").await?;
+    check_data_dir(&fixture, "txn_rollback_purge_after_rollback", 1, 1, 1, 1).await;
+
+    // but kept by the commit
+    execute_command(ctx.clone(), "BEGIN").await?;
+    append_sample_data(1, &fixture).await?;
+    execute_command(ctx.clone(), "COMMIT").await?;
+    check_data_dir(&fixture, "txn_rollback_purge_after_commit", 2, 2, 2, 2).await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_fuse_txn_commit_purge_transient() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    fixture
+        .create_default_table_with_option("TRANSIENT", "T")
+        .await?;
+
+    append_sample_data(1, &fixture).await?;
+
+    // the history of the transient table is purged once the transaction is committed
+    execute_command(ctx.clone(), "BEGIN").await?;
+    append_sample_data(1, &fixture).await?;
+    check_data_dir(&fixture, "txn_commit_purge_transient_in_txn", 2, 2, 2, 2).await;
+    execute_command(ctx.clone(), "COMMIT").await?;
+    check_data_dir(
+        &fixture,
+        "txn_commit_purge_transient_after_commit",
+        1,
+        2,
+        2,
+        2,
+    )
+    .await;
+    Ok(())
+}
@@ -97,9 +97,10 @@ fn test_to_partitions() -> Result<()> {
         .into_iter()
         .map(col_leaves_gen)
         .collect::<Vec<_>>();
+    let leaf_ids = (0..num_of_col as u32).collect::<Vec<_>>();
 
     // CASE I:  no projection
-    let (s, parts) = FuseTable::to_partitions(&blocks_metas, &column_leaves, None);
+    let (s, parts) = FuseTable::to_partitions(&blocks_metas, &column_leaves, &leaf_ids, None);
     assert_eq!(parts.len(), num_of_block as usize);
     let expected_block_size: u64 = cols_metas.iter().map(|(_, col_meta)| col_meta.len).sum();
     assert_eq!(expected_block_size * num_of_block, s.read_bytes as u64);
@@ -126,7 +127,8 @@ fn test_to_partitions() -> Result<()> {
         order_by: vec![],
     });
 
-    let (stats, parts) = FuseTable::to_partitions(&blocks_metas, &column_leaves, push_down);
+    let (stats, parts) =
+        FuseTable::to_partitions(&blocks_metas, &column_leaves, &leaf_ids, push_down);
     assert_eq!(parts.len(), num_of_block as usize);
     assert_eq!(expected_block_size * num_of_block, stats.read_bytes as u64);
 
@@ -20,12 +20,17 @@ use common_catalog::table_context::TableContext;
 use common_datablocks::DataBlock;
 use common_datavalues::prelude::*;
 use common_fuse_meta::meta::ColumnStatistics;
+use common_fuse_meta::meta::Histogram;
+use common_fuse_meta::meta::HistogramBucket;
 use databend_query::storages::fuse::io::BlockWriter;
 use databend_query::storages::fuse::io::TableMetaLocationGenerator;
 use databend_query::storages::fuse::statistics::gen_columns_statistics;
 use databend_query::storages::fuse::statistics::reducers;
 use databend_query::storages::fuse::statistics::BlockStatistics;
+use databend_query::storages::fuse::statistics::ColumnDistributionCollector;
+use databend_query::storages::fuse::statistics::HyperLogLog;
 use databend_query::storages::fuse::statistics::StatisticsAccumulator;
+use databend_query::storages::fuse::ColumnIds;
 use opendal::Accessor;
 use opendal::Operator;
 
@@ -134,6 +139,28 @@ fn test_reduce_block_statistics_in_memory_size() -> common_exception::Result<()>
     Ok(())
 }
 
+#[test]
+fn test_reduce_block_statistics_of_added_column() -> common_exception::Result<()> {
+    let col_stats = |v: i64| ColumnStatistics {
+        min: DataValue::Int64(v),
+        max: DataValue::Int64(v),
+        null_count: 0,
+        in_memory_size: 8,
+    };
+
+    // the column 1 is added after the first block was written
+    let old_block = HashMap::from([(0, col_stats(1))]);
+    let new_block = HashMap::from([(0, col_stats(2)), (1, col_stats(3))]);
+    let r = reducers::reduce_block_statistics(&[old_block, new_block.clone()])?;
+    assert_eq!(1, r.len());
+    assert_eq!(r.get(&0).unwrap().max, DataValue::Int64(2));
+
+    // empty statistics are ignored
+    let r = reducers::reduce_block_statistics(&[HashMap::new(), new_block])?;
+    assert_eq!(2, r.len());
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_accumulator() -> common_exception::Result<()> {
     let blocks = TestFixture::gen_sample_blocks(10, 1);
@@ -150,7 +177,8 @@ async fn test_accumulator() -> common_exception::Result<()> {
     for item in blocks {
         let block = item?;
         let block_statistics = BlockStatistics::from(&block, "does_not_matter".to_owned(), None)?;
-        let block_writer = BlockWriter::new(&table_ctx, &operator, &loc_generator);
+        let column_ids = ColumnIds::from_schema(block.schema())?;
+        let block_writer = BlockWriter::new(&table_ctx, &operator, &loc_generator, &column_ids);
         let block_meta = block_writer.write(block).await?;
         stats_acc.add_with_block_meta(block_meta, block_statistics)?;
     }
@@ -184,3 +212,60 @@ fn test_ft_stats_cluster_stats() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_hyper_log_log() {
+    let mut hll = HyperLogLog::new();
+    assert_eq!(hll.count(), 0);
+
+    // the duplicated values are not counted
+    let num_distinct = 100_000;
+    for _ in 0..2 {
+        for v in 0..num_distinct {
+            hll.add(&DataValue::Int64(v * 2));
+        }
+    }
+    let error = (hll.count() as f64 - num_distinct as f64).abs() / num_distinct as f64;
+    assert!(error < 0.03, "error of the estimation: {}", error);
+
+    let mut other = HyperLogLog::new();
+    for v in 0..num_distinct {
+        other.add(&DataValue::Int64(v * 2 + 1));
+    }
+    hll.merge(&other);
+    let error = (hll.count() as f64 - 2.0 * num_distinct as f64).abs() / (2 * num_distinct) as f64;
+    assert!(error < 0.03, "error of the estimation: {}", error);
+}
+
+#[test]
+fn test_column_distribution_collector() -> common_exception::Result<()> {
+    let mut collector = ColumnDistributionCollector::new();
+    collector.add_column(&Series::from_data(vec![Some(3i32), None, Some(1)]));
+    collector.add_column(&Series::from_data(vec![Some(2i32), Some(2), Some(3), None]));
+
+    let distribution = collector.finish(2);
+    assert_eq!(distribution.ndv, 3);
+    assert_eq!(distribution.histogram, Histogram {
+        buckets: vec![
+            HistogramBucket {
+                lower_bound: DataValue::Int64(1),
+                upper_bound: DataValue::Int64(2),
+                num_values: 3,
+                num_distinct: 2,
+            },
+            HistogramBucket {
+                lower_bound: DataValue::Int64(3),
+                upper_bound: DataValue::Int64(3),
+                num_values: 2,
+                num_distinct: 1,
+            },
+        ],
+    });
+    assert_eq!(distribution.histogram.num_values(), 5);
+
+    // no values at all
+    let distribution = ColumnDistributionCollector::new().finish(2);
+    assert_eq!(distribution.ndv, 0);
+    assert!(distribution.histogram.buckets.is_empty());
+    Ok(())
+}
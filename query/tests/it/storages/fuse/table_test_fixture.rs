@@ -169,6 +169,17 @@ impl TestFixture {
         Ok(())
     }
 
+    pub async fn create_default_table_with_option(&self, key: &str, value: &str) -> Result<()> {
+        let mut create_table_plan = self.default_crate_table_plan();
+        create_table_plan
+            .table_meta
+            .options
+            .insert(key.to_owned(), value.to_owned());
+        let interpreter = CreateTableInterpreter::try_create(self.ctx.clone(), create_table_plan)?;
+        interpreter.execute().await?;
+        Ok(())
+    }
+
     pub fn gen_sample_blocks(num: usize, start: i32) -> Vec<Result<DataBlock>> {
         Self::gen_sample_blocks_ex(num, 3, start)
     }
@@ -0,0 +1,78 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::Result;
+use databend_query::sessions::TableContext;
+use databend_query::storages::system::CachesTable;
+use databend_query::storages::TableStreamReadWrap;
+use databend_query::storages::ToReadDataSourcePlan;
+use futures::TryStreamExt;
+use tempfile::TempDir;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_caches_table() -> Result<()> {
+    let tmp_dir = TempDir::new().unwrap();
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.table_cache_enabled = true;
+    conf.query.table_disk_cache_enabled = true;
+    conf.query.table_disk_cache_root = tmp_dir.path().to_str().unwrap().to_string();
+    conf.query.table_disk_cache_mb_size = 1;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    // Access the disk cache, a miss then a hit.
+    let disk_cache = ctx
+        .get_storage_cache_manager()
+        .get_column_data_disk_cache()
+        .unwrap();
+    assert!(disk_cache.get("_b/1.parquet/0").await.is_none());
+    disk_cache.put("_b/1.parquet/0", vec![1, 2, 3]).await?;
+    assert_eq!(disk_cache.get("_b/1.parquet/0").await, Some(vec![1, 2, 3]));
+
+    let table = CachesTable::create(1);
+    let source_plan = table.read_plan(ctx.clone(), None).await?;
+
+    let stream = table.read(ctx, &source_plan).await?;
+    let result = stream.try_collect::<Vec<_>>().await?;
+    let block = &result[0];
+    assert_eq!(block.num_columns(), 6);
+
+    let expected = vec![
+        "+------------------+-----------+------+------------+------+--------+",
+        "| name             | num_items | size | capacity   | hits | misses |",
+        "+------------------+-----------+------+------------+------+--------+",
+        "| bloom_index      | 0         | 0    | 3000       | NULL | NULL   |",
+        "| bloom_index_meta | 0         | 0    | 1073741824 | NULL | NULL   |",
+        "| column_data_disk | 1         | 3    | 1048576    | 1    | 1      |",
+        "| table_segment    | 0         | 0    | 10240      | NULL | NULL   |",
+        "| table_snapshot   | 0         | 0    | 256        | NULL | NULL   |",
+        "+------------------+-----------+------+------------+------+--------+",
+    ];
+    common_datablocks::assert_blocks_sorted_eq(expected, result.as_slice());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_disk_cache_disabled_by_default() -> Result<()> {
+    let mut conf = crate::tests::ConfigBuilder::create().config();
+    conf.query.table_cache_enabled = true;
+    let ctx = crate::tests::create_query_context_with_config(conf, None).await?;
+
+    let cache_manager = ctx.get_storage_cache_manager();
+    assert!(cache_manager.get_column_data_disk_cache().is_none());
+    assert!(cache_manager.get_table_snapshot_cache().is_some());
+
+    Ok(())
+}
@@ -70,6 +70,11 @@ async fn test_configs_table() -> Result<()> {
         "| query   | async_insert_busy_timeout            | 200                       |             |",
         "| query   | async_insert_max_data_size           | 10000                     |             |",
         "| query   | async_insert_stale_timeout           | 0                         |             |",
+        "| query   | audit_log_dir                        | ./.databend/audit         |             |",
+        "| query   | audit_log_enabled                    | false                     |             |",
+        "| query   | audit_log_max_file_size_mb           | 100                       |             |",
+        "| query   | audit_log_max_files                  | 10                        |             |",
+        "| query   | audit_log_table                      |                           |             |",
         "| query   | clickhouse_handler_host              | 127.0.0.1                 |             |",
         "| query   | clickhouse_handler_port              | 9000                      |             |",
         "| query   | clickhouse_http_handler_host         | 127.0.0.1                 |             |",
@@ -99,6 +104,7 @@ async fn test_configs_table() -> Result<()> {
         "| query   | table_cache_enabled                  | false                     |             |",
         "| query   | table_cache_segment_count            | 10240                     |             |",
         "| query   | table_cache_snapshot_count           | 256                       |             |",
+        "| query   | table_disk_cache_enabled             | false                     |             |",
         "| query   | table_disk_cache_mb_size             | 1024                      |             |",
         "| query   | table_disk_cache_root                | _cache                    |             |",
         "| query   | table_engine_memory_enabled          | true                      |             |",
@@ -192,6 +198,11 @@ async fn test_configs_table_redact() -> Result<()> {
         "| query   | async_insert_busy_timeout            | 200                       |             |",
         "| query   | async_insert_max_data_size           | 10000                     |             |",
         "| query   | async_insert_stale_timeout           | 0                         |             |",
+        "| query   | audit_log_dir                        | ./.databend/audit         |             |",
+        "| query   | audit_log_enabled                    | false                     |             |",
+        "| query   | audit_log_max_file_size_mb           | 100                       |             |",
+        "| query   | audit_log_max_files                  | 10                        |             |",
+        "| query   | audit_log_table                      |                           |             |",
         "| query   | clickhouse_handler_host              | 127.0.0.1                 |             |",
         "| query   | clickhouse_handler_port              | 9000                      |             |",
         "| query   | clickhouse_http_handler_host         | 127.0.0.1                 |             |",
@@ -221,6 +232,7 @@ async fn test_configs_table_redact() -> Result<()> {
         "| query   | table_cache_enabled                  | false                     |             |",
         "| query   | table_cache_segment_count            | 10240                     |             |",
         "| query   | table_cache_snapshot_count           | 256                       |             |",
+        "| query   | table_disk_cache_enabled             | false                     |             |",
         "| query   | table_disk_cache_mb_size             | 1024                      |             |",
         "| query   | table_disk_cache_root                | _cache                    |             |",
         "| query   | table_engine_memory_enabled          | true                      |             |",